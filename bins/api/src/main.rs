@@ -1,5 +1,9 @@
+use std::time::Duration;
+
 use actix_web::{App, HttpServer, web};
 use events::{Key, MemoryAction};
+use loom::signal::consumers::FileEmitter;
+use loom::signal::{Emitter, SampledEmitter, Signal, SignalBroadcaster};
 use sqlx::postgres::PgPoolOptions;
 
 mod config;
@@ -14,6 +18,14 @@ pub use request_context::{RequestContext, RequestContextMiddleware};
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     let config = Config::from_env();
+    let signals = match &config.signals_path {
+        Some(path) => SignalBroadcaster::new().add(SampledEmitter::new(
+            FileEmitter::new(path)?,
+            Duration::from_secs(10),
+        )),
+        None => SignalBroadcaster::new(),
+    };
+
     let pool = PgPoolOptions::new()
         .max_connections(5)
         .connect(&config.database_url)
@@ -35,8 +47,12 @@ async fn main() -> std::io::Result<()> {
 
     let ctx = Context::new(pool, amqp);
     println!("Starting server at http://0.0.0.0:{}", config.port);
+    Signal::event("api.started")
+        .field("port", config.port)
+        .emit(&signals);
 
-    HttpServer::new(move || {
+    // `run` resolves once the server has stopped gracefully (SIGINT/SIGTERM)
+    let result = HttpServer::new(move || {
         App::new()
             .app_data(web::Data::new(ctx.clone()))
             .wrap(RequestContextMiddleware)
@@ -45,5 +61,9 @@ async fn main() -> std::io::Result<()> {
     })
     .bind(("0.0.0.0", config.port))?
     .run()
-    .await
+    .await;
+
+    Signal::event("api.stopped").emit(&signals);
+    signals.shutdown();
+    result
 }
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use actix_web::dev::{Service, ServiceRequest, ServiceResponse, Transform, forward_ready};
 use actix_web::http::header::HeaderMap;
 use actix_web::{Error, FromRequest, HttpMessage, HttpRequest, web};
+use events::SocketProducer;
 
 use crate::Context;
 
@@ -36,6 +37,15 @@ impl RequestContext {
     pub fn request_id(&self) -> &str {
         &self.request_id
     }
+
+    /// A producer that stamps each message with this request's id as its
+    /// correlation id, so it can be traced through the worker.
+    pub fn produce(&self) -> SocketProducer<'_> {
+        self.ctx
+            .amqp()
+            .produce()
+            .with_correlation_id(&self.request_id)
+    }
 }
 
 impl FromRequest for RequestContext {
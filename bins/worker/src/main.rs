@@ -1,28 +1,65 @@
 mod config;
 
-use events::{Key, MemoryAction};
+use events::{CORRELATION_ID_HEADER, Event, Headers, Key, MemoryAction, RetryPolicy};
+use loom::signal::consumers::FileEmitter;
+use loom::signal::{Emitter, Signal, SignalBroadcaster};
 
 use config::Config;
 
 #[tokio::main]
 async fn main() -> Result<(), loom::error::Error> {
     let config = Config::from_env();
+    let signals = match &config.signals_path {
+        Some(path) => SignalBroadcaster::new().add(FileEmitter::new(path)?),
+        None => SignalBroadcaster::new(),
+    };
+
     let socket = events::new(&config.rabbitmq_url)
         .with_app_id("loom[worker]")
         .with_queue(Key::memory(MemoryAction::Create))
+        .with_reconnect(RetryPolicy::new(config.connect_retries))
         .connect()
         .await?;
 
-    let mut consumer = socket.consume(Key::memory(MemoryAction::Create)).await?;
+    let mut consumer = socket
+        .consumer(Key::memory(MemoryAction::Create))
+        .with_dlq(true)
+        .with_max_retries(config.max_retries)
+        .consume()
+        .await?;
 
     println!("waiting for messages on memory.create...");
+    Signal::event("worker.started").emit(&signals);
+
+    let consume = async {
+        let signals = &signals;
+
+        while let Some(res) = consumer
+            .process_with(|event: Event<String>, headers: Headers| async move {
+                if let Some(id) = headers.get(CORRELATION_ID_HEADER) {
+                    Signal::event("worker.processing")
+                        .field("event_id", event.id.to_string())
+                        .field("correlation_id", id.as_str())
+                        .emit(signals);
+                }
+
+                Ok(())
+            })
+            .await
+        {
+            res?;
+        }
+
+        Ok(())
+    };
 
-    while let Some(res) = consumer.dequeue::<String>().await {
-        let _ = match res {
-            Err(err) => return Err(err),
-            Ok(v) => v,
-        };
-    }
+    // Stop consuming on ctrl-c so the signals below still get written
+    let result = tokio::select! {
+        result = consume => result,
+        _ = tokio::signal::ctrl_c() => Ok(()),
+    };
 
-    Ok(())
+    Signal::event("worker.stopped").emit(&signals);
+    signals.shutdown();
+    result
 }
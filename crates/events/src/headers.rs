@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use lapin::types::{AMQPValue, FieldTable};
+
+/// Header tying a message back to the request that produced it, so it can
+/// be traced from the API through the worker.
+pub const CORRELATION_ID_HEADER: &str = "x-correlation-id";
+
+/// Per-message headers, as strings.
+pub type Headers = HashMap<String, String>;
+
+/// The headers of a delivered message. String and integer values are
+/// returned as strings; other AMQP values are skipped.
+pub fn delivery_headers(delivery: &lapin::message::Delivery) -> Headers {
+    delivery
+        .properties
+        .headers()
+        .as_ref()
+        .map(from_field_table)
+        .unwrap_or_default()
+}
+
+pub(crate) fn to_field_table(headers: &Headers) -> FieldTable {
+    let mut table = FieldTable::default();
+
+    for (name, value) in headers {
+        table.insert(
+            name.as_str().into(),
+            AMQPValue::LongString(value.as_str().into()),
+        );
+    }
+
+    table
+}
+
+pub(crate) fn from_field_table(table: &FieldTable) -> Headers {
+    table
+        .inner()
+        .iter()
+        .filter_map(|(name, value)| {
+            let value = match value {
+                AMQPValue::LongString(v) => v.to_string(),
+                AMQPValue::ShortString(v) => v.to_string(),
+                AMQPValue::ShortShortInt(v) => v.to_string(),
+                AMQPValue::ShortShortUInt(v) => v.to_string(),
+                AMQPValue::ShortInt(v) => v.to_string(),
+                AMQPValue::ShortUInt(v) => v.to_string(),
+                AMQPValue::LongInt(v) => v.to_string(),
+                AMQPValue::LongUInt(v) => v.to_string(),
+                AMQPValue::LongLongInt(v) => v.to_string(),
+                _ => return None,
+            };
+
+            Some((name.to_string(), value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_table_roundtrip() {
+        let headers = Headers::from([
+            (CORRELATION_ID_HEADER.to_string(), "req-1".to_string()),
+            ("tenant".to_string(), "acme".to_string()),
+        ]);
+
+        assert_eq!(from_field_table(&to_field_table(&headers)), headers);
+    }
+
+    #[test]
+    fn test_integer_values_read_as_strings() {
+        let mut table = FieldTable::default();
+        table.insert("x-attempts".into(), AMQPValue::LongUInt(3));
+        table.insert("x-flag".into(), AMQPValue::Boolean(true));
+
+        assert_eq!(
+            from_field_table(&table),
+            Headers::from([("x-attempts".to_string(), "3".to_string())])
+        );
+    }
+}
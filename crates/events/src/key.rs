@@ -14,6 +14,35 @@ impl Key {
         Self::Facet(action)
     }
 
+    /// Parse a routing key such as `memory.create`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.split_once('.')? {
+            ("memory", action) => MemoryAction::parse(action).map(Self::Memory),
+            ("facet", action) => FacetAction::parse(action).map(Self::Facet),
+            _ => None,
+        }
+    }
+
+    /// The key a delivery was routed with, for dispatching messages taken
+    /// off a wildcard subscription.
+    pub fn from_delivery(delivery: &lapin::message::Delivery) -> Option<Self> {
+        Self::parse(delivery.routing_key.as_str())
+    }
+
+    pub fn as_memory(&self) -> Option<MemoryAction> {
+        match self {
+            Self::Memory(action) => Some(*action),
+            _ => None,
+        }
+    }
+
+    pub fn as_facet(&self) -> Option<FacetAction> {
+        match self {
+            Self::Facet(action) => Some(*action),
+            _ => None,
+        }
+    }
+
     pub fn exchange(&self) -> &str {
         match self {
             Self::Memory(_) => "memory",
@@ -27,6 +56,11 @@ impl Key {
             Self::Facet(v) => v.name(),
         }
     }
+
+    /// The dead-letter queue for this key, e.g. `memory.create.dlq`.
+    pub fn dlq(&self) -> String {
+        format!("{}.dlq", self)
+    }
 }
 
 impl std::fmt::Display for Key {
@@ -46,6 +80,14 @@ pub enum MemoryAction {
 }
 
 impl MemoryAction {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "create" => Some(Self::Create),
+            "update" => Some(Self::Update),
+            _ => None,
+        }
+    }
+
     pub fn name(&self) -> &str {
         match self {
             Self::Create => "create",
@@ -68,6 +110,14 @@ pub enum FacetAction {
 }
 
 impl FacetAction {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "create" => Some(Self::Create),
+            "update" => Some(Self::Update),
+            _ => None,
+        }
+    }
+
     pub fn name(&self) -> &str {
         match self {
             Self::Create => "create",
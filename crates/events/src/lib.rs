@@ -1,13 +1,19 @@
 mod consumer;
 mod event;
+mod headers;
 mod key;
+mod pattern;
 mod producer;
+mod retry;
 mod socket;
 
 pub use consumer::*;
 pub use event::*;
+pub use headers::*;
 pub use key::*;
+pub use pattern::*;
 pub use producer::*;
+pub use retry::*;
 pub use socket::*;
 
 pub fn new(uri: &str) -> SocketOptions {
@@ -0,0 +1,168 @@
+use loom_error::{Error, Result};
+
+use crate::Key;
+
+/// An AMQP topic pattern over routing keys, e.g. `memory.*` or `memory.#`.
+///
+/// `*` matches exactly one word and `#` zero or more. The first word names
+/// the exchange and can't be a wildcard. A pattern without wildcards that
+/// names a `Key` is exact and consumes that key's own queue; any other
+/// pattern gets a queue of its own, named after the pattern.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeyPattern {
+    pattern: String,
+    key: Option<Key>,
+}
+
+impl KeyPattern {
+    pub fn parse(pattern: &str) -> Result<Self> {
+        let words: Vec<&str> = pattern.split('.').collect();
+
+        if words.iter().any(|word| word.is_empty()) {
+            return Err(invalid(pattern, "words can't be empty"));
+        }
+
+        if matches!(words[0], "*" | "#") {
+            return Err(invalid(pattern, "the exchange can't be a wildcard"));
+        }
+
+        if words
+            .iter()
+            .any(|word| word.len() > 1 && word.contains(['*', '#']))
+        {
+            return Err(invalid(pattern, "wildcards must be whole words"));
+        }
+
+        Ok(Self {
+            pattern: pattern.to_string(),
+            key: Key::parse(pattern),
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// The key this pattern names exactly, if it has no wildcards.
+    pub fn key(&self) -> Option<Key> {
+        self.key
+    }
+
+    pub fn exchange(&self) -> &str {
+        self.pattern.split('.').next().unwrap_or_default()
+    }
+
+    pub fn queue(&self) -> String {
+        match self.key {
+            Some(key) => key.queue().to_string(),
+            None => self.pattern.clone(),
+        }
+    }
+
+    /// The dead-letter queue for this pattern, e.g. `memory.*.dlq`.
+    pub fn dlq(&self) -> String {
+        format!("{}.dlq", self.pattern)
+    }
+
+    pub fn matches(&self, key: &Key) -> bool {
+        let pattern: Vec<&str> = self.pattern.split('.').collect();
+        let key = key.to_string();
+        let words: Vec<&str> = key.split('.').collect();
+
+        matches_words(&pattern, &words)
+    }
+}
+
+impl From<Key> for KeyPattern {
+    fn from(key: Key) -> Self {
+        Self {
+            pattern: key.to_string(),
+            key: Some(key),
+        }
+    }
+}
+
+impl std::str::FromStr for KeyPattern {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl std::fmt::Display for KeyPattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pattern)
+    }
+}
+
+fn matches_words(pattern: &[&str], words: &[&str]) -> bool {
+    match (pattern.split_first(), words.split_first()) {
+        (None, None) => true,
+        (Some((&"#", rest)), _) => {
+            matches_words(rest, words) || (!words.is_empty() && matches_words(pattern, &words[1..]))
+        }
+        (Some((&"*", rest)), Some((_, words))) => matches_words(rest, words),
+        (Some((head, rest)), Some((word, words))) => head == word && matches_words(rest, words),
+        _ => false,
+    }
+}
+
+fn invalid(pattern: &str, reason: &str) -> Error {
+    Error::builder()
+        .message(format!("invalid key pattern '{}': {}", pattern, reason))
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FacetAction, MemoryAction};
+
+    #[test]
+    fn test_wildcards_match_memory_actions() {
+        let create = Key::memory(MemoryAction::Create);
+        let update = Key::memory(MemoryAction::Update);
+        let facet = Key::facet(FacetAction::Create);
+
+        for pattern in ["memory.*", "memory.#", "memory.#.create"] {
+            let pattern = KeyPattern::parse(pattern).unwrap();
+            assert!(pattern.matches(&create), "{}", pattern);
+        }
+
+        let pattern = KeyPattern::parse("memory.*").unwrap();
+        assert!(pattern.matches(&create));
+        assert!(pattern.matches(&update));
+        assert!(!pattern.matches(&facet));
+        assert_eq!(pattern.key(), None);
+        assert_eq!(pattern.exchange(), "memory");
+        assert_eq!(pattern.queue(), "memory.*");
+    }
+
+    #[test]
+    fn test_exact_pattern_uses_key_queue() {
+        let pattern = KeyPattern::parse("memory.create").unwrap();
+
+        assert_eq!(pattern.key(), Some(Key::memory(MemoryAction::Create)));
+        assert_eq!(pattern, KeyPattern::from(Key::memory(MemoryAction::Create)));
+        assert_eq!(pattern.queue(), "create");
+        assert!(!pattern.matches(&Key::memory(MemoryAction::Update)));
+    }
+
+    #[test]
+    fn test_hash_matches_zero_or_more_words() {
+        let pattern = KeyPattern::parse("memory.#").unwrap();
+
+        assert!(matches_words(&["memory", "#"], &["memory"]));
+        assert!(matches_words(&["memory", "#"], &["memory", "a", "b"]));
+        assert!(!matches_words(&["memory", "*"], &["memory", "a", "b"]));
+        assert!(pattern.matches(&Key::memory(MemoryAction::Update)));
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_patterns() {
+        for pattern in ["*.create", "#", "memory..create", "memory.cre*", ""] {
+            assert!(KeyPattern::parse(pattern).is_err(), "{}", pattern);
+        }
+    }
+}
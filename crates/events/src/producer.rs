@@ -1,31 +1,74 @@
 use lapin::{options, protocol};
 use loom_error::Result;
 
-use crate::{Event, Socket};
+use crate::headers::to_field_table;
+use crate::{CORRELATION_ID_HEADER, Event, Headers, Key, Socket};
 
 #[derive(Clone)]
 pub struct SocketProducer<'a> {
     pub(crate) socket: &'a Socket,
+    pub(crate) correlation_id: Option<String>,
 }
 
 impl<'a> SocketProducer<'a> {
     pub fn socket(&self) -> &'a Socket {
-        &self.socket
+        self.socket
+    }
+
+    /// Stamp every message sent by this producer with `id`, in the
+    /// `x-correlation-id` header and the AMQP `correlation_id` property.
+    /// A correlation id passed in a message's own headers takes precedence.
+    pub fn with_correlation_id(mut self, id: impl Into<String>) -> Self {
+        self.correlation_id = Some(id.into());
+        self
     }
 
     pub async fn enqueue<TBody: serde::Serialize>(&self, event: Event<TBody>) -> Result<()> {
+        self.publish(event, Headers::new()).await
+    }
+
+    /// Publish `payload` as a new event for `key` with per-message headers.
+    pub async fn publish_with<TBody: serde::Serialize>(
+        &self,
+        key: Key,
+        payload: TBody,
+        headers: Headers,
+    ) -> Result<()> {
+        self.publish(Event::new(key, payload), headers).await
+    }
+
+    async fn publish<TBody: serde::Serialize>(
+        &self,
+        event: Event<TBody>,
+        mut headers: Headers,
+    ) -> Result<()> {
+        if let Some(id) = &self.correlation_id {
+            headers
+                .entry(CORRELATION_ID_HEADER.to_string())
+                .or_insert_with(|| id.clone());
+        }
+
+        let mut properties = protocol::basic::AMQPProperties::default()
+            .with_app_id(self.socket().app_id().into())
+            .with_content_type("application/json".into());
+
+        if let Some(id) = headers.get(CORRELATION_ID_HEADER) {
+            properties = properties.with_correlation_id(id.as_str().into());
+        }
+
+        if !headers.is_empty() {
+            properties = properties.with_headers(to_field_table(&headers));
+        }
+
         let payload = serde_json::to_vec(&event)?;
-        let _ = self
-            .socket()
+        self.socket()
             .channel()
             .basic_publish(
                 event.key.exchange(),
                 &event.key.to_string(),
                 options::BasicPublishOptions::default(),
                 &payload,
-                protocol::basic::AMQPProperties::default()
-                    .with_app_id(self.socket().app_id().into())
-                    .with_content_type("application/json".into()),
+                properties,
             )
             .await?;
 
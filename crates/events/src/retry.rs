@@ -0,0 +1,198 @@
+use std::future::Future;
+use std::hash::{BuildHasher, RandomState};
+use std::time::{Duration, SystemTime};
+
+use loom_error::Result;
+
+/// Exponential backoff used when connecting or reconnecting to the broker.
+///
+/// Retry `n` (counting from 0) waits `initial_delay * multiplier^n`, capped
+/// at `max_delay`. With jitter on, each wait is drawn uniformly from the
+/// upper half of that delay so restarted workers don't reconnect in lockstep.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_delay: Duration,
+    max_delay: Duration,
+    multiplier: f64,
+    jitter: bool,
+}
+
+impl RetryPolicy {
+    /// Retry up to `max_retries` times, starting at 100ms and doubling up
+    /// to 10s, with jitter.
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            multiplier: 2.0,
+            jitter: true,
+        }
+    }
+
+    /// Make a single attempt.
+    pub fn none() -> Self {
+        Self::new(0)
+    }
+
+    pub fn with_initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    pub fn with_max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    pub fn initial_delay(&self) -> Duration {
+        self.initial_delay
+    }
+
+    pub fn max_delay(&self) -> Duration {
+        self.max_delay
+    }
+
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    pub fn jitter(&self) -> bool {
+        self.jitter
+    }
+
+    /// The wait before retry `retry`, counting from 0.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let exp = self.multiplier.powi(retry.min(i32::MAX as u32) as i32);
+        let delay = self
+            .initial_delay
+            .mul_f64(exp.max(0.0).min(u32::MAX as f64))
+            .min(self.max_delay);
+
+        if self.jitter {
+            delay.mul_f64(0.5 + random() / 2.0)
+        } else {
+            delay
+        }
+    }
+
+    /// Run `attempt` until it succeeds or has been retried `max_retries`
+    /// times, sleeping between attempts, and return its last result.
+    pub async fn retry<T, F, Fut>(&self, mut attempt: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut retry = 0;
+
+        loop {
+            match attempt().await {
+                Err(_) if retry < self.max_retries => {
+                    tokio::time::sleep(self.delay(retry)).await;
+                    retry += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(5)
+    }
+}
+
+/// A number in `[0, 1)`, random enough for jitter.
+fn random() -> f64 {
+    let bits = RandomState::new().hash_one(SystemTime::now());
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use loom_error::Error;
+
+    use super::*;
+
+    fn policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy::new(max_retries).with_initial_delay(Duration::from_millis(1))
+    }
+
+    #[tokio::test]
+    async fn test_retries_configured_number_of_times() {
+        let attempts = Cell::new(0);
+        let result: Result<()> = policy(3)
+            .retry(|| {
+                attempts.set(attempts.get() + 1);
+                let n = attempts.get();
+                async move { Err(Error::builder().message(format!("refused {n}")).build()) }
+            })
+            .await;
+
+        assert_eq!(attempts.get(), 4);
+        assert_eq!(result.unwrap_err().message(), Some("refused 4"));
+    }
+
+    #[tokio::test]
+    async fn test_stops_once_connected() {
+        let attempts = Cell::new(0);
+        let result = policy(5)
+            .retry(|| {
+                attempts.set(attempts.get() + 1);
+                let n = attempts.get();
+                async move {
+                    match n {
+                        1 | 2 => Err(Error::builder().message("refused").build()),
+                        _ => Ok(n),
+                    }
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(attempts.get(), 3);
+    }
+
+    #[test]
+    fn test_delay_grows_exponentially_up_to_max() {
+        let policy = RetryPolicy::new(10)
+            .with_initial_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_secs(1))
+            .with_jitter(false);
+
+        assert_eq!(policy.delay(0), Duration::from_millis(100));
+        assert_eq!(policy.delay(1), Duration::from_millis(200));
+        assert_eq!(policy.delay(3), Duration::from_millis(800));
+        assert_eq!(policy.delay(4), Duration::from_secs(1));
+        assert_eq!(policy.delay(u32::MAX), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_jitter_stays_within_upper_half() {
+        let policy = RetryPolicy::new(10).with_initial_delay(Duration::from_millis(100));
+
+        for _ in 0..100 {
+            let delay = policy.delay(1);
+            assert!(delay >= Duration::from_millis(100));
+            assert!(delay <= Duration::from_millis(200));
+        }
+    }
+}
@@ -1,16 +1,25 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
 
 use lapin::{Channel, Connection, ConnectionProperties, options, types};
-use loom_error::{Error, Result};
+use loom_error::Result;
 
-use crate::{Key, SocketConsumer, SocketProducer};
+use crate::{ConsumerOptions, Key, KeyPattern, RetryPolicy, SocketConsumer, SocketProducer};
 
 #[derive(Clone)]
 pub struct Socket {
     app_id: String,
+    uri: String,
+    reconnect: Option<RetryPolicy>,
+    link: Arc<RwLock<Link>>,
+    queues: HashMap<Key, lapin::Queue>,
+}
+
+struct Link {
     conn: Arc<Connection>,
     channel: Arc<Channel>,
-    queues: HashMap<Key, lapin::Queue>,
 }
 
 impl Socket {
@@ -18,41 +27,61 @@ impl Socket {
         &self.app_id
     }
 
-    pub fn conn(&self) -> &Connection {
-        &self.conn
+    pub fn conn(&self) -> Arc<Connection> {
+        self.link().conn.clone()
     }
 
-    pub fn channel(&self) -> &Channel {
-        &self.channel
+    pub fn channel(&self) -> Arc<Channel> {
+        self.link().channel.clone()
     }
 
     pub fn queue(&self, key: Key) -> Option<&lapin::Queue> {
         self.queues.get(&key)
     }
 
+    pub fn reconnect_policy(&self) -> Option<&RetryPolicy> {
+        self.reconnect.as_ref()
+    }
+
     pub async fn consume(&self, key: Key) -> Result<SocketConsumer<'_>> {
-        if !self.queues.contains_key(&key) {
-            return Err(Error::builder().message("queue not found").build());
-        }
+        self.consumer(key).consume().await
+    }
 
-        let consumer = self
-            .channel()
-            .basic_consume(
-                key.queue(),
-                self.app_id(),
-                options::BasicConsumeOptions::default(),
-                types::FieldTable::default(),
-            )
-            .await?;
+    /// Configure a consumer for `key`, e.g. to enable a dead-letter queue.
+    pub fn consumer(&self, key: Key) -> ConsumerOptions<'_> {
+        ConsumerOptions::new(self, key.into())
+    }
 
-        Ok(SocketConsumer {
-            socket: self,
-            consumer,
-        })
+    /// Configure a consumer for every key matching `pattern`, e.g.
+    /// `memory.*`, on a queue bound to the pattern.
+    pub fn subscriber(&self, pattern: KeyPattern) -> ConsumerOptions<'_> {
+        ConsumerOptions::new(self, pattern)
     }
 
     pub fn produce(&self) -> SocketProducer<'_> {
-        SocketProducer { socket: self }
+        SocketProducer {
+            socket: self,
+            correlation_id: None,
+        }
+    }
+
+    /// Open a new connection and channel, redeclaring every queue, and
+    /// swap them in for the current ones. Retries per the reconnect policy.
+    pub async fn reconnect(&self) -> Result<()> {
+        let keys: Vec<Key> = self.queues.keys().copied().collect();
+        let policy = self.reconnect.clone().unwrap_or_else(RetryPolicy::none);
+        let (conn, channel, _) = policy.retry(|| open(&self.uri, &keys)).await?;
+
+        *self.link.write().unwrap_or_else(|e| e.into_inner()) = Link {
+            conn: Arc::new(conn),
+            channel: Arc::new(channel),
+        };
+
+        Ok(())
+    }
+
+    fn link(&self) -> std::sync::RwLockReadGuard<'_, Link> {
+        self.link.read().unwrap_or_else(|e| e.into_inner())
     }
 }
 
@@ -60,6 +89,7 @@ pub struct SocketOptions {
     app_id: String,
     uri: String,
     queues: Vec<Key>,
+    reconnect: Option<RetryPolicy>,
 }
 
 impl SocketOptions {
@@ -68,6 +98,7 @@ impl SocketOptions {
             app_id: String::new(),
             uri: uri.to_string(),
             queues: vec![],
+            reconnect: None,
         }
     }
 
@@ -81,47 +112,68 @@ impl SocketOptions {
         self
     }
 
+    /// Retry `connect` with backoff while the broker is unreachable, and
+    /// reconnect consumers whose connection drops mid-stream.
+    pub fn with_reconnect(mut self, policy: RetryPolicy) -> Self {
+        self.reconnect = Some(policy);
+        self
+    }
+
     pub async fn connect(self) -> Result<Socket> {
-        let conn = Connection::connect(&self.uri, ConnectionProperties::default()).await?;
-        let channel = conn.create_channel().await?;
-        let mut queues = HashMap::new();
-
-        for key in self.queues {
-            channel
-                .exchange_declare(
-                    key.exchange(),
-                    lapin::ExchangeKind::Topic,
-                    options::ExchangeDeclareOptions::default(),
-                    types::FieldTable::default(),
-                )
-                .await?;
-
-            let queue = channel
-                .queue_declare(
-                    key.queue(),
-                    options::QueueDeclareOptions::default(),
-                    types::FieldTable::default(),
-                )
-                .await?;
-
-            channel
-                .queue_bind(
-                    key.queue(),
-                    key.exchange(),
-                    &key.to_string(),
-                    options::QueueBindOptions::default(),
-                    types::FieldTable::default(),
-                )
-                .await?;
-
-            queues.insert(key, queue);
-        }
+        let policy = self.reconnect.clone().unwrap_or_else(RetryPolicy::none);
+        let (conn, channel, queues) = policy.retry(|| open(&self.uri, &self.queues)).await?;
 
         Ok(Socket {
             app_id: self.app_id,
-            conn: Arc::new(conn),
-            channel: Arc::new(channel),
+            uri: self.uri,
+            reconnect: self.reconnect,
+            link: Arc::new(RwLock::new(Link {
+                conn: Arc::new(conn),
+                channel: Arc::new(channel),
+            })),
             queues,
         })
     }
 }
+
+async fn open(
+    uri: &str,
+    keys: &[Key],
+) -> Result<(Connection, Channel, HashMap<Key, lapin::Queue>)> {
+    let conn = Connection::connect(uri, ConnectionProperties::default()).await?;
+    let channel = conn.create_channel().await?;
+    let mut queues = HashMap::new();
+
+    for key in keys {
+        channel
+            .exchange_declare(
+                key.exchange(),
+                lapin::ExchangeKind::Topic,
+                options::ExchangeDeclareOptions::default(),
+                types::FieldTable::default(),
+            )
+            .await?;
+
+        let queue = channel
+            .queue_declare(
+                key.queue(),
+                options::QueueDeclareOptions::default(),
+                types::FieldTable::default(),
+            )
+            .await?;
+
+        channel
+            .queue_bind(
+                key.queue(),
+                key.exchange(),
+                &key.to_string(),
+                options::QueueBindOptions::default(),
+                types::FieldTable::default(),
+            )
+            .await?;
+
+        queues.insert(*key, queue);
+    }
+
+    Ok((conn, channel, queues))
+}
@@ -0,0 +1,14 @@
+-- Create records table
+CREATE TABLE records (
+    path TEXT PRIMARY KEY NOT NULL,
+    media_type TEXT NOT NULL,
+    etag TEXT NOT NULL,
+    size BIGINT NOT NULL,
+    content BYTEA NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Indexes
+CREATE INDEX idx_records_path_prefix ON records(path text_pattern_ops);
+CREATE INDEX idx_records_updated_at ON records(updated_at);
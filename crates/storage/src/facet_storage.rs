@@ -35,7 +35,7 @@ impl<'a> FacetStorage<'a> {
         )
         .bind(facet.id)
         .bind(facet.memory_id)
-        .bind(&facet.ty)
+        .bind(facet.ty)
         .bind(facet.confidence)
         .bind(&facet.data)
         .fetch_one(self.pool)
@@ -52,7 +52,7 @@ impl<'a> FacetStorage<'a> {
             "#,
         )
         .bind(facet.id)
-        .bind(&facet.ty)
+        .bind(facet.ty)
         .bind(facet.confidence)
         .bind(&facet.data)
         .fetch_optional(self.pool)
@@ -38,7 +38,7 @@ impl<'a> MemoryStorage<'a> {
         .bind(memory.score)
         .bind(memory.confidence)
         .bind(memory.importance)
-        .bind(&memory.sensitivity)
+        .bind(memory.sensitivity)
         .bind(&memory.tags)
         .bind(&memory.embedding)
         .bind(memory.expires_at)
@@ -59,7 +59,7 @@ impl<'a> MemoryStorage<'a> {
         .bind(memory.score)
         .bind(memory.confidence)
         .bind(memory.importance)
-        .bind(&memory.sensitivity)
+        .bind(memory.sensitivity)
         .bind(&memory.tags)
         .bind(&memory.embedding)
         .bind(memory.expires_at)
@@ -46,7 +46,7 @@ impl<'a> SourceStorage<'a> {
         .bind(source.id)
         .bind(source.scope_id)
         .bind(&source.external_id)
-        .bind(&source.ty)
+        .bind(source.ty)
         .bind(&source.uri)
         .fetch_one(self.pool)
         .await
@@ -45,8 +45,8 @@ impl<'a> TraceActionStorage<'a> {
         )
         .bind(trace_action.trace_id)
         .bind(trace_action.target_id)
-        .bind(&trace_action.target)
-        .bind(&trace_action.action)
+        .bind(trace_action.target)
+        .bind(trace_action.action)
         .fetch_one(self.pool)
         .await
     }
@@ -43,7 +43,7 @@ impl<'a> TraceStorage<'a> {
         .bind(trace.id)
         .bind(trace.parent_id)
         .bind(&trace.request_id)
-        .bind(&trace.status)
+        .bind(trace.status)
         .bind(&trace.status_message)
         .fetch_one(self.pool)
         .await
@@ -59,7 +59,7 @@ impl<'a> TraceStorage<'a> {
             "#,
         )
         .bind(trace.id)
-        .bind(&trace.status)
+        .bind(trace.status)
         .bind(&trace.status_message)
         .bind(trace.ended_at)
         .fetch_optional(self.pool)
@@ -0,0 +1,57 @@
+use std::io::Write;
+
+use clap::{Args, CommandFactory};
+use clap_complete::Shell;
+
+use super::OutputFormat;
+
+/// Print a shell completion script
+#[derive(Debug, Args)]
+pub struct CompletionsCommand {
+    /// Shell to generate completions for
+    #[arg(value_enum)]
+    pub shell: Shell,
+}
+
+impl CompletionsCommand {
+    /// The script is always written to stdout as-is; `--format` has no
+    /// effect.
+    pub async fn exec(self, _format: OutputFormat) {
+        generate(self.shell, &mut std::io::stdout());
+    }
+}
+
+/// Write the completion script for `shell`, derived from the `loom`
+/// command definition, to `out`.
+pub fn generate(shell: Shell, out: &mut dyn Write) {
+    let mut cmd = crate::Cli::command();
+    let name = cmd.get_name().to_string();
+    clap_complete::generate(shell, &mut cmd, name, out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn script(shell: Shell) -> String {
+        let mut out = Vec::new();
+        generate(shell, &mut out);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_bash_completions_include_subcommands() {
+        let script = script(Shell::Bash);
+
+        assert!(!script.is_empty());
+        assert!(script.contains("loom"));
+        assert!(script.contains("run"));
+        assert!(script.contains("completions"));
+    }
+
+    #[test]
+    fn test_zsh_and_fish_completions() {
+        assert!(script(Shell::Zsh).contains("run"));
+        assert!(script(Shell::Fish).contains("run"));
+    }
+}
@@ -0,0 +1,74 @@
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
+use loom::config::encode;
+use loom::core::Format;
+
+use super::{ConfigFormat, OutputFormat, load_config};
+
+/// Inspect configuration
+#[derive(Debug, Args)]
+pub struct ConfigCommand {
+    #[command(subcommand)]
+    pub command: ConfigSubcommand,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigSubcommand {
+    /// Print the effective config after merging the file, includes and `LOOM_` env overrides
+    Show(ConfigShowCommand),
+}
+
+#[derive(Debug, Args)]
+pub struct ConfigShowCommand {
+    /// Path to config file (YAML/JSON/TOML)
+    pub path: PathBuf,
+
+    /// Parse the config file as this format instead of inferring it from the extension
+    #[arg(long, value_enum)]
+    pub config_format: Option<ConfigFormat>,
+
+    /// Annotate each value with the provider that supplied it
+    #[arg(long)]
+    pub origin: bool,
+}
+
+impl ConfigCommand {
+    pub async fn exec(self, format: OutputFormat) {
+        match self.command {
+            ConfigSubcommand::Show(cmd) => cmd.exec(format),
+        }
+    }
+}
+
+impl ConfigShowCommand {
+    pub fn exec(self, format: OutputFormat) {
+        let config = match load_config(self.path.to_str().unwrap_or_default(), self.config_format) {
+            Ok(c) => c,
+            Err(e) => {
+                eprintln!("Error loading config: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        let value = if self.origin {
+            config.origins().annotate(config.as_value())
+        } else {
+            config.as_value().clone()
+        };
+
+        let encoding = if format.is_json() {
+            Format::Json
+        } else {
+            Format::Yaml
+        };
+
+        match encode(&value, encoding) {
+            Ok(out) => println!("{}", out.trim_end()),
+            Err(e) => {
+                eprintln!("Error serializing config: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+}
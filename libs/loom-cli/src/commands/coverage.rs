@@ -0,0 +1,94 @@
+use std::path::PathBuf;
+
+use clap::Args;
+use crossterm::style::Color;
+use loom::eval::{CoverageThresholds, LabelCoverage, SampleDataset};
+use loom::io::path::FilePath;
+use loom::runtime::{FileSystemSource, JsonCodec, Runtime, TomlCodec, YamlCodec};
+
+use super::OutputFormat;
+use crate::widgets;
+
+/// Report how many samples expect each label
+#[derive(Debug, Args)]
+pub struct CoverageCommand {
+    /// Path to the dataset file
+    pub path: PathBuf,
+
+    /// Flag labels expected by fewer samples than this as undertrained
+    #[arg(long, default_value_t = 10)]
+    pub min_samples: usize,
+
+    /// Flag labels expected by more than this share (0.0 - 1.0) of the samples
+    #[arg(long, default_value_t = 0.5)]
+    pub max_share: f32,
+}
+
+impl CoverageCommand {
+    pub async fn exec(self, format: OutputFormat) {
+        let runtime = Runtime::new()
+            .source(FileSystemSource::builder().sandbox(false).build())
+            .codec(JsonCodec::new())
+            .codec(YamlCodec::new())
+            .codec(TomlCodec::new())
+            .build();
+
+        status!(format, "Loading dataset...");
+
+        let file_path = FilePath::from(self.path.clone()).into();
+        let dataset: SampleDataset = match runtime.load("file_system", &file_path).await {
+            Ok(d) => d,
+            Err(e) => {
+                eprintln!("Error loading dataset: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        let report = dataset.coverage(&CoverageThresholds {
+            min_samples: self.min_samples,
+            max_share: self.max_share,
+        });
+
+        if format.is_json() {
+            OutputFormat::print_json(&report);
+            return;
+        }
+
+        println!("\n=== Label Coverage ({} samples) ===\n", report.total);
+
+        let mut table = widgets::Table::new().headers(vec!["Label", "Samples", "Share", "Flag"]);
+        for (label, coverage) in &report.per_label {
+            table = table.row(vec![
+                label.clone(),
+                coverage.samples.to_string(),
+                format!("{:.1}%", coverage.share * 100.0),
+                flag(coverage).to_string(),
+            ]);
+        }
+        print!("{}", highlight_flagged(table));
+
+        if report.has_flagged() {
+            println!(
+                "\nFlagged labels have fewer than {} samples or more than {:.1}% of the dataset.",
+                self.min_samples,
+                self.max_share * 100.0
+            );
+        }
+    }
+}
+
+fn flag(coverage: &LabelCoverage) -> &'static str {
+    match (coverage.undertrained, coverage.dominant) {
+        (true, true) => "undertrained, dominant",
+        (true, false) => "undertrained",
+        (false, true) => "dominant",
+        (false, false) => "",
+    }
+}
+
+/// Color rows with a non-empty flag column.
+fn highlight_flagged(table: widgets::Table) -> widgets::Table {
+    table.highlight(Color::Yellow, |row| {
+        row.get(3).is_some_and(|f| !f.is_empty())
+    })
+}
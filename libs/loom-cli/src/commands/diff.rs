@@ -0,0 +1,121 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use clap::Args;
+use crossterm::style::Color;
+use loom::eval::{EvalDiff, EvalResult, MetricDelta};
+use loom::io::path::FilePath;
+use loom::runtime::{FileSystemSource, JsonCodec, Runtime};
+
+use super::OutputFormat;
+use crate::widgets;
+
+/// Compare two evaluation results
+#[derive(Debug, Args)]
+pub struct DiffCommand {
+    /// Baseline results JSON (written by `loom run`)
+    pub old: PathBuf,
+
+    /// Results JSON to compare against the baseline
+    pub new: PathBuf,
+}
+
+impl DiffCommand {
+    pub async fn exec(self, format: OutputFormat) {
+        let runtime = Runtime::new()
+            .source(FileSystemSource::builder().sandbox(false).build())
+            .codec(JsonCodec::new())
+            .build();
+
+        let mut results = Vec::with_capacity(2);
+        for path in [&self.old, &self.new] {
+            status!(format, "Loading results from {:?}...", path);
+            let file_path = FilePath::from(path.clone()).into();
+            let result: EvalResult = match runtime.load("file_system", &file_path).await {
+                Ok(r) => r,
+                Err(e) => {
+                    eprintln!("Error loading results from {:?}: {}", path, e);
+                    std::process::exit(1);
+                }
+            };
+            results.push(result);
+        }
+
+        let diff = EvalDiff::between(&results[0], &results[1]);
+
+        if format.is_json() {
+            OutputFormat::print_json(&diff);
+            return;
+        }
+
+        println!("\n=== Overall ===\n");
+        println!("Accuracy:  {}", summary(&diff.accuracy));
+        println!("Precision: {}", summary(&diff.precision));
+        println!("Recall:    {}", summary(&diff.recall));
+        println!("F1 Score:  {}", summary(&diff.f1));
+
+        if !diff.per_category.is_empty() {
+            println!("\n=== Per-Category Accuracy ===\n");
+            let mut table = widgets::Table::new().headers(vec!["Category", "Old", "New", "Delta"]);
+            let mut regressed = HashSet::new();
+            for (category, delta) in &diff.per_category {
+                table = table.row(row(category, &delta.accuracy));
+                if delta.accuracy.is_regression() {
+                    regressed.insert(category.clone());
+                }
+            }
+            print!("{}", highlight_regressions(table, regressed));
+        }
+
+        if !diff.per_label.is_empty() {
+            println!("\n=== Per-Label F1 ===\n");
+            let mut table = widgets::Table::new().headers(vec!["Label", "Old", "New", "Delta"]);
+            let mut regressed = HashSet::new();
+            for (label, delta) in &diff.per_label {
+                table = table.row(row(label, &delta.f1));
+                if delta.f1.is_regression() {
+                    regressed.insert(label.clone());
+                }
+            }
+            print!("{}", highlight_regressions(table, regressed));
+        }
+
+        if diff.has_regressions() {
+            println!("\nRegressions found (highlighted in red).");
+        }
+    }
+}
+
+fn value(v: Option<f32>) -> String {
+    v.map(|v| format!("{:.3}", v))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn delta(m: &MetricDelta) -> String {
+    if m.is_added() {
+        return "added".to_string();
+    }
+
+    if m.is_removed() {
+        return "removed".to_string();
+    }
+
+    m.delta()
+        .map(|d| format!("{:+.3}", d))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn summary(m: &MetricDelta) -> String {
+    format!("{} -> {} ({})", value(m.old), value(m.new), delta(m))
+}
+
+fn row(name: &str, m: &MetricDelta) -> Vec<String> {
+    vec![name.to_string(), value(m.old), value(m.new), delta(m)]
+}
+
+/// Color the rows named in `regressed`.
+fn highlight_regressions(table: widgets::Table, regressed: HashSet<String>) -> widgets::Table {
+    table.highlight(Color::Red, move |row| {
+        row.first().is_some_and(|name| regressed.contains(name))
+    })
+}
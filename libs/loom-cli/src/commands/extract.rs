@@ -0,0 +1,76 @@
+use clap::Args;
+use loom::cortex::bench::{TokenScorer, TokenScorerConfig};
+
+use super::OutputFormat;
+use crate::widgets;
+
+/// Extract named entity spans from text
+#[derive(Debug, Args)]
+pub struct ExtractCommand {
+    /// Text to extract entities from
+    #[arg(required = true)]
+    pub text: Vec<String>,
+
+    /// Drop spans scoring below this threshold
+    #[arg(long, default_value_t = 0.0)]
+    pub min_score: f32,
+}
+
+impl ExtractCommand {
+    pub async fn exec(self, format: OutputFormat) {
+        status!(
+            format,
+            "Building token classifier (this may download model files on first run)..."
+        );
+
+        let config = TokenScorerConfig {
+            min_score: self.min_score,
+            ..Default::default()
+        };
+
+        // Build and run in spawn_blocking (rust-bert model download conflicts with tokio)
+        let text = self.text.join(" ");
+        let result = tokio::task::spawn_blocking(move || {
+            TokenScorer::from_config(config).and_then(|scorer| scorer.extract(&text))
+        })
+        .await;
+
+        let output = match result {
+            Ok(Ok(output)) => output,
+            Ok(Err(e)) => {
+                eprintln!("Error extracting entities: {}", e);
+                std::process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("Error extracting entities: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        if format.is_json() {
+            OutputFormat::print_json(&output);
+            return;
+        }
+
+        if output.is_empty() {
+            println!("\nNo entities found.");
+            return;
+        }
+
+        let mut table =
+            widgets::Table::new().headers(vec!["Text", "Label", "Start", "End", "Score"]);
+
+        for span in output.spans() {
+            table = table.row(vec![
+                span.text.clone(),
+                span.label.clone(),
+                span.start.to_string(),
+                span.end.to_string(),
+                format!("{:.3}", span.score),
+            ]);
+        }
+
+        println!();
+        print!("{}", table);
+    }
+}
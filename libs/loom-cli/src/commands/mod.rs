@@ -1,10 +1,142 @@
+use std::io::stderr;
 use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
+use clap::ValueEnum;
 use loom::config::{Config, ConfigError, EnvProvider, FileProvider};
+use loom::core::Format;
+use serde::Serialize;
 
+use crate::widgets::{self, WidgetResult};
+
+/// Print a status line to stdout for text output, or to stderr for JSON
+/// output so stdout stays parseable.
+macro_rules! status {
+    ($format:expr, $($arg:tt)*) => {
+        if $format.is_json() {
+            eprintln!($($arg)*);
+        } else {
+            println!($($arg)*);
+        }
+    };
+}
+
+pub mod completions;
+pub mod config;
+pub mod coverage;
+pub mod diff;
+pub mod extract;
+pub mod pipeline;
 pub mod run;
+pub mod train;
 
+pub use completions::CompletionsCommand;
+pub use config::ConfigCommand;
+pub use coverage::CoverageCommand;
+pub use diff::DiffCommand;
+pub use extract::ExtractCommand;
+pub use pipeline::PipelineCommand;
 pub use run::RunCommand;
+pub use train::TrainCommand;
+
+/// How a command finished.
+///
+/// Execution errors exit with code 1 directly; outcomes map to the remaining codes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Outcome {
+    /// Completed and met any configured thresholds (exit code 0)
+    #[default]
+    Success,
+    /// Completed but a `--fail-under` threshold was not met (exit code 2)
+    ThresholdNotMet,
+}
+
+impl Outcome {
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Success)
+    }
+
+    pub fn code(&self) -> u8 {
+        match self {
+            Self::Success => 0,
+            Self::ThresholdNotMet => 2,
+        }
+    }
+}
+
+impl From<Outcome> for ExitCode {
+    fn from(outcome: Outcome) -> Self {
+        ExitCode::from(outcome.code())
+    }
+}
+
+/// Output format for command results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable tables and summaries
+    #[default]
+    Text,
+    /// Machine-readable JSON on stdout; progress goes to stderr
+    Json,
+}
+
+impl OutputFormat {
+    pub fn is_text(&self) -> bool {
+        matches!(self, Self::Text)
+    }
+
+    pub fn is_json(&self) -> bool {
+        matches!(self, Self::Json)
+    }
+
+    /// Write an inline widget (e.g. a progress bar) to the status stream.
+    pub fn write(&self, widget: WidgetResult) {
+        match self {
+            Self::Text => widget.write(),
+            Self::Json => widget.write_to(&mut stderr()),
+        }
+    }
+
+    /// Clear an inline widget from the status stream.
+    pub fn clear_line(&self) {
+        match self {
+            Self::Text => widgets::clear_line(),
+            Self::Json => widgets::clear_line_to(&mut stderr()),
+        }
+    }
+
+    /// Print a value to stdout as pretty JSON.
+    pub fn print_json(value: &impl Serialize) {
+        match serde_json::to_string_pretty(value) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("Error serializing output: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// Format a config file is parsed as with `--config-format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ConfigFormat {
+    /// JSON
+    Json,
+    /// YAML
+    Yaml,
+    /// TOML
+    Toml,
+}
+
+impl From<ConfigFormat> for Format {
+    fn from(format: ConfigFormat) -> Self {
+        match format {
+            ConfigFormat::Json => Self::Json,
+            ConfigFormat::Yaml => Self::Yaml,
+            ConfigFormat::Toml => Self::Toml,
+        }
+    }
+}
 
 /// Resolve the output file path based on input path, optional output directory, and filename.
 pub fn resolve_output_path(
@@ -29,9 +161,16 @@ pub fn resolve_output_path(
 /// - `LOOM_CONCURRENCY=16` -> `concurrency: 16`
 /// - `LOOM_BATCH__SIZE=32` -> `batch_size: 32`
 /// - `LOOM_LAYERS_SCORE_THRESHOLD=0.8` -> `layers.score.threshold: 0.8`
-pub fn load_config(config_path: &str) -> Result<Config, ConfigError> {
+///
+/// The file's format is inferred from its extension unless `format` is set.
+pub fn load_config(config_path: &str, format: Option<ConfigFormat>) -> Result<Config, ConfigError> {
+    let mut file = FileProvider::builder(config_path);
+    if let Some(format) = format {
+        file = file.format(format.into());
+    }
+
     Config::new()
-        .with_provider(FileProvider::builder(config_path).build())
+        .with_provider(file.build())
         .with_provider(EnvProvider::new(Some("LOOM_")))
         .build()
 }
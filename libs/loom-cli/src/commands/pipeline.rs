@@ -0,0 +1,296 @@
+use std::path::PathBuf;
+
+use clap::Args;
+use loom::config::Config;
+use loom::core::ident_path;
+use loom::core::value::{PatchOp, Value};
+use loom::error::{Error, ErrorCode};
+use loom::eval::EvalLayer;
+use loom::io::path::FilePath;
+use loom::pipe::Layer;
+use loom::runtime::{FileSystemSource, JsonCodec, RunContext, Runtime, TomlCodec, YamlCodec};
+use loom::signal::Level;
+use loom::signal::consumers::JsonLinesEmitter;
+use serde::Deserialize;
+
+use super::{ConfigFormat, OutputFormat, load_config};
+
+/// Run a declarative pipeline over an input file
+#[derive(Debug, Args)]
+pub struct PipelineCommand {
+    /// Path to config file (YAML/JSON/TOML) with a `pipeline` list of layer specs
+    #[arg(short, long)]
+    pub config: PathBuf,
+
+    /// Parse the config file as this format instead of inferring it from the extension
+    #[arg(long, value_enum)]
+    pub config_format: Option<ConfigFormat>,
+
+    /// Path to the input file (JSON/YAML/TOML)
+    #[arg(short, long)]
+    pub input: PathBuf,
+
+    /// Write the output to this file (format from its extension) instead of printing it
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+/// One entry of the config's `pipeline` list, selected by `type`.
+///
+/// ```yaml
+/// pipeline:
+///   - type: select
+///     pointer: /text
+///   - type: eval        # configured by `layers.eval`
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LayerSpec {
+    /// Replace the value with the part at a JSON pointer
+    Select { pointer: String },
+
+    /// Set the value at a JSON pointer, adding object keys or array items
+    Set {
+        pointer: String,
+        value: serde_json::Value,
+    },
+
+    /// Remove the value at a JSON pointer
+    Remove { pointer: String },
+
+    /// Score the text with the eval layer configured under `layers.eval`
+    Eval,
+}
+
+impl LayerSpec {
+    fn build(self, config: &Config) -> Result<Box<dyn Layer<Input = RunContext>>, String> {
+        Ok(match self {
+            Self::Select { pointer } => Box::new(SelectLayer { pointer }),
+            Self::Set { pointer, value } => Box::new(PatchLayer {
+                name: "set",
+                op: PatchOp::Add {
+                    path: pointer,
+                    value: Value::from(value),
+                },
+            }),
+            Self::Remove { pointer } => Box::new(PatchLayer {
+                name: "remove",
+                op: PatchOp::Remove { path: pointer },
+            }),
+            Self::Eval => Box::new(EvalLayer::from_config(config).map_err(|e| e.to_string())?),
+        })
+    }
+}
+
+struct SelectLayer {
+    pointer: String,
+}
+
+impl Layer for SelectLayer {
+    type Input = RunContext;
+
+    fn process(&self, ctx: &RunContext) -> loom::error::Result<Value> {
+        ctx.input().pointer(&self.pointer).cloned().ok_or_else(|| {
+            Error::builder()
+                .code(ErrorCode::NotFound)
+                .message(format!("no value at '{}'", self.pointer))
+                .build()
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        "select"
+    }
+}
+
+struct PatchLayer {
+    name: &'static str,
+    op: PatchOp,
+}
+
+impl Layer for PatchLayer {
+    type Input = RunContext;
+
+    fn process(&self, ctx: &RunContext) -> loom::error::Result<Value> {
+        let mut value = ctx.input().clone();
+
+        value
+            .apply_patch(std::slice::from_ref(&self.op))
+            .map_err(|e| {
+                Error::builder()
+                    .code(ErrorCode::BadArguments)
+                    .message(e.to_string())
+                    .build()
+            })?;
+
+        Ok(value)
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// A `YamlCodec` that warns on stderr, as a JSON line, when a YAML input had
+/// aliases expanded, since saving the output writes each copy out in full.
+fn yaml_codec() -> YamlCodec {
+    let warnings = JsonLinesEmitter::from_writer(std::io::stderr()).with_level(Level::Warn);
+    YamlCodec::new().with_emitter(warnings)
+}
+
+impl PipelineCommand {
+    pub async fn exec(self, format: OutputFormat) {
+        let config = match load_config(self.config.to_str().unwrap_or_default(), self.config_format)
+        {
+            Ok(c) => c,
+            Err(e) => {
+                eprintln!("Error loading config: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        status!(
+            format,
+            "Building pipeline (this may download model files on first run)..."
+        );
+
+        let output = match self.run(config).await {
+            Ok(output) => output,
+            Err(e) => {
+                eprintln!("Error running pipeline: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        if let Some(path) = &self.output {
+            status!(format, "Output written to: {}", path.display());
+        } else {
+            OutputFormat::print_json(&output);
+        }
+    }
+
+    /// Build the runtime from the config's `pipeline` specs, execute it on
+    /// the input file, and save or return the output.
+    async fn run(&self, config: Config) -> Result<serde_json::Value, String> {
+        let specs: Vec<LayerSpec> = config
+            .get_section(&ident_path!("pipeline"))
+            .bind()
+            .map_err(|e| format!("invalid `pipeline` list: {}", e))?;
+
+        if specs.is_empty() {
+            return Err("config has no `pipeline` layers".to_string());
+        }
+
+        // Build layers in spawn_blocking (rust-bert model download conflicts with tokio)
+        let layers = tokio::task::spawn_blocking(move || {
+            specs
+                .into_iter()
+                .map(|spec| spec.build(&config))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .await
+        .map_err(|e| e.to_string())??;
+
+        let runtime = Runtime::new()
+            .source(FileSystemSource::builder().sandbox(false).build())
+            .codec(JsonCodec::new())
+            .codec(yaml_codec())
+            .codec(TomlCodec::new())
+            .layers(layers)
+            .build();
+
+        runtime
+            .validate()
+            .map_err(|e| format!("invalid `pipeline` list: {}", e))?;
+
+        let input_path = FilePath::from(self.input.clone()).into();
+        let input: serde_json::Value = runtime
+            .load("file_system", &input_path)
+            .await
+            .map_err(|e| format!("failed to load input: {}", e))?;
+
+        let output = runtime
+            .execute(Value::from(input))
+            .map_err(|e| e.to_string())?;
+        let output = serde_json::Value::from(&output);
+
+        if let Some(path) = &self.output {
+            let output_path = FilePath::from(path.clone()).into();
+            runtime
+                .save_auto("file_system", &output_path, &output)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(dir: &std::path::Path, name: &str, content: &str) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn runs_two_layer_pipeline_end_to_end() {
+        let dir = std::env::temp_dir().join(format!("loom-cli-pipeline-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let config = write(
+            &dir,
+            "pipeline.yaml",
+            "pipeline:\n  - type: select\n    pointer: /sample\n  - type: set\n    pointer: /reviewed\n    value: true\n",
+        );
+        let input = write(
+            &dir,
+            "data.json",
+            r#"{"sample": {"id": "a", "text": "hello"}, "ignored": 1}"#,
+        );
+        let output = dir.join("out.json");
+
+        let cmd = PipelineCommand {
+            config: config.clone(),
+            config_format: None,
+            input,
+            output: Some(output.clone()),
+        };
+
+        let result = cmd
+            .run(load_config(config.to_str().unwrap(), None).unwrap())
+            .await
+            .unwrap();
+
+        let expected = serde_json::json!({"id": "a", "text": "hello", "reviewed": true});
+        assert_eq!(result, expected);
+
+        let saved: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+        assert_eq!(saved, expected);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn layer_specs_parse_by_type() {
+        let specs: Vec<LayerSpec> = serde_json::from_value(serde_json::json!([
+            {"type": "remove", "pointer": "/a"},
+            {"type": "eval"},
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            specs,
+            vec![
+                LayerSpec::Remove {
+                    pointer: "/a".to_string()
+                },
+                LayerSpec::Eval
+            ]
+        );
+    }
+}
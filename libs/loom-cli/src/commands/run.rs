@@ -1,33 +1,21 @@
+use std::io::IsTerminal;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use clap::Args;
+use clap::{Args, ValueEnum};
 use loom::core::{Format, ident_path};
-use loom::eval::{EvalConfig, EvalLayer, EvalOutput, EvalResult, SampleDataset};
-use loom::io::path::{FilePath, Path};
-use loom::runtime::{
-    Emitter, FileSystemSource, JsonCodec, LoomConfig, Runtime, Signal, TomlCodec, YamlCodec,
+use loom::eval::{
+    Checkpoint, EvalConfig, EvalLayer, EvalMetrics, EvalOutput, EvalResult, Progress,
+    ProgressTracker, SampleDataset, ValidationError,
 };
+use loom::io::path::{FilePath, Path};
+use loom::runtime::{FileSystemSource, JsonCodec, LoomConfig, Runtime, TomlCodec, YamlCodec};
+use serde::Serialize;
 
-use super::{load_config, resolve_output_path};
+use super::{ConfigFormat, Outcome, OutputFormat, load_config, resolve_output_path};
 use crate::widgets::{self, Widget};
 
-/// Signal emitter that displays progress on stdout.
-struct ProgressEmitter;
-
-impl Emitter for ProgressEmitter {
-    fn emit(&self, signal: Signal) {
-        if signal.name() == "eval.scored" {
-            let attrs = signal.attributes();
-            let score = attrs.get("score").and_then(|v| v.as_float()).unwrap_or(0.0);
-
-            widgets::ProgressBar::new()
-                .message(&format!("{:.2}", score))
-                .render()
-                .write();
-        }
-    }
-}
-
 /// Run evaluation against a dataset
 #[derive(Debug, Args)]
 pub struct RunCommand {
@@ -38,6 +26,10 @@ pub struct RunCommand {
     #[arg(short, long)]
     pub config: PathBuf,
 
+    /// Parse the config file as this format instead of inferring it from the extension
+    #[arg(long, value_enum)]
+    pub config_format: Option<ConfigFormat>,
+
     /// Output directory for results (default: input file's directory)
     #[arg(short, long)]
     pub output: Option<PathBuf>,
@@ -45,13 +37,106 @@ pub struct RunCommand {
     /// Show detailed per-category and per-label results
     #[arg(short, long)]
     pub verbose: bool,
+
+    /// Platt params JSON (written by `loom train`) applied over the config's
+    #[arg(long, value_name = "FILE")]
+    pub calibration: Option<PathBuf>,
+
+    /// Also write the results as JUnit XML to this file, one testcase per sample
+    #[arg(long, value_name = "PATH")]
+    pub junit: Option<PathBuf>,
+
+    /// Exit with code 2 if the gated metric falls below this value (0.0 - 1.0)
+    #[arg(long)]
+    pub fail_under: Option<f32>,
+
+    /// Metric checked by --fail-under
+    #[arg(long, value_enum, default_value_t = GateMetric::Accuracy)]
+    pub gate_metric: GateMetric,
+
+    /// Write a resumable checkpoint every N samples (overrides `checkpoint_interval`)
+    #[arg(long)]
+    pub checkpoint_every: Option<usize>,
+
+    /// Resume from a checkpoint file, skipping already-scored samples
+    #[arg(long)]
+    pub resume: Option<PathBuf>,
+
+    /// Sort per-label results by column (e.g. f1, recall, tp)
+    #[arg(long, value_name = "COLUMN")]
+    pub sort_by: Option<String>,
+
+    /// Sort per-label results in descending order
+    #[arg(long, requires = "sort_by")]
+    pub descending: bool,
+
+    /// Only show per-label results with F1 below 1.0
+    #[arg(long)]
+    pub failing_only: bool,
+
+    /// Show a full-screen dashboard while running (text output on a terminal only)
+    #[arg(long)]
+    pub tui: bool,
+
+    /// Run on a stratified subset of this fraction of samples per category (0.0 - 1.0)
+    #[arg(long, value_name = "FRACTION", value_parser = parse_fraction)]
+    pub sample: Option<f64>,
+
+    /// Seed for --sample; reruns with the same seed use the same samples
+    #[arg(long, default_value_t = 0, requires = "sample")]
+    pub seed: u64,
+}
+
+fn parse_fraction(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(fraction) if fraction > 0.0 && fraction <= 1.0 => Ok(fraction),
+        Ok(_) => Err("must be greater than 0.0 and at most 1.0".to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Metric a run is gated on with `--fail-under`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum GateMetric {
+    /// Overall decision accuracy
+    #[default]
+    Accuracy,
+    /// Macro-averaged F1 across labels
+    MacroF1,
+}
+
+impl GateMetric {
+    pub fn value(&self, metrics: &EvalMetrics) -> f32 {
+        match self {
+            Self::Accuracy => metrics.accuracy,
+            Self::MacroF1 => metrics.macro_f1(),
+        }
+    }
+
+    /// Check `metrics` against an optional `--fail-under` threshold.
+    pub fn check(&self, metrics: &EvalMetrics, fail_under: Option<f32>) -> Outcome {
+        match fail_under {
+            Some(threshold) if self.value(metrics) < threshold => Outcome::ThresholdNotMet,
+            _ => Outcome::Success,
+        }
+    }
+}
+
+/// Machine-readable summary of a run, printed for `--format json`.
+#[derive(Serialize)]
+struct RunReport<'a> {
+    result: &'a EvalResult,
+    metrics: &'a EvalMetrics,
+    output: &'a std::path::Path,
+    passed: bool,
 }
 
 impl RunCommand {
-    pub async fn exec(self) {
-        println!("Loading config from {:?}...", self.config);
+    pub async fn exec(self, format: OutputFormat) -> Outcome {
+        status!(format, "Loading config from {:?}...", self.config);
 
-        let config = match load_config(self.config.to_str().unwrap_or_default()) {
+        let config = match load_config(self.config.to_str().unwrap_or_default(), self.config_format)
+        {
             Ok(c) => c,
             Err(e) => {
                 eprintln!("Error loading config: {}", e);
@@ -67,40 +152,97 @@ impl RunCommand {
             section.bind().ok()
         };
 
-        println!("Building runtime (this may download model files on first run)...");
+        status!(
+            format,
+            "Building runtime (this may download model files on first run)..."
+        );
 
         // Build eval layer in spawn_blocking (rust-bert model download conflicts with tokio)
-        let eval_layer =
-            match tokio::task::spawn_blocking(move || EvalLayer::from_config(&config)).await {
-                Ok(Ok(layer)) => layer,
-                Ok(Err(e)) => {
-                    eprintln!("Error building eval layer: {}", e);
-                    std::process::exit(1);
-                }
-                Err(e) => {
-                    eprintln!("Error building eval layer: {}", e);
-                    std::process::exit(1);
-                }
-            };
+        let model_config = eval_config.as_ref().map(|c| c.model.clone());
+        let eval_layer = match tokio::task::spawn_blocking(move || {
+            // Fetch model files up front so downloads show progress
+            if let Some(model) = model_config.filter(|m| m.resources().is_some()) {
+                let mut bar = widgets::ProgressBar::new().show_eta(true).show_rate(true);
+                model
+                    .fetch(|p| {
+                        bar = std::mem::take(&mut bar)
+                            .current(p.index)
+                            .total(p.total)
+                            .message(format!("Fetching {}", p.resource.name()));
+                        format.write(bar.render());
+                    })
+                    .map_err(|e| e.to_string())?;
+                format.clear_line();
+            }
+
+            EvalLayer::from_config(&config).map_err(|e| e.to_string())
+        })
+        .await
+        {
+            Ok(Ok(layer)) => layer,
+            Ok(Err(e)) => {
+                eprintln!("Error building eval layer: {}", e);
+                std::process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("Error building eval layer: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        if let Some(path) = &self.calibration {
+            if let Err(e) = eval_layer.load_calibration(path) {
+                eprintln!("Error loading calibration: {}", e);
+                std::process::exit(1);
+            }
+
+            status!(format, "Applied calibration from {:?}", path);
+        }
+
+        let tui = self.tui && format.is_text() && std::io::stdout().is_terminal();
+        if self.tui && !tui {
+            status!(
+                format,
+                "--tui requires text output on a terminal, showing progress bar instead"
+            );
+        }
 
         // Build runtime with externally-supplied layer
         let runtime = Runtime::new()
-            .source(FileSystemSource::builder().build())
+            .source(FileSystemSource::builder().sandbox(false).build())
             .codec(JsonCodec::new())
             .codec(YamlCodec::new())
             .codec(TomlCodec::new())
             .layer(eval_layer)
-            .emitter(ProgressEmitter)
             .build();
 
         let output_dir = self.output.as_ref().or(loom_config.output.as_ref());
         let output_path =
             resolve_output_path(&self.path, output_dir.map(|p| p.as_path()), "results.json");
+        let checkpoint_path = self.resume.clone().unwrap_or_else(|| {
+            resolve_output_path(
+                &self.path,
+                output_dir.map(|p| p.as_path()),
+                "results.checkpoint.json",
+            )
+        });
+        let checkpoint_interval = self
+            .checkpoint_every
+            .or(loom_config.checkpoint_interval)
+            .filter(|n| *n > 0);
+
+        // Ensure output directory exists
+        if let Some(parent) = output_path.parent()
+            && let Err(e) = std::fs::create_dir_all(parent)
+        {
+            eprintln!("Error creating output directory: {}", e);
+            std::process::exit(1);
+        }
 
-        println!("Loading dataset...");
+        status!(format, "Loading dataset...");
 
         let file_path = FilePath::from(self.path.clone()).into();
-        let dataset: SampleDataset = match runtime.load("file_system", &file_path).await {
+        let mut dataset: SampleDataset = match runtime.load("file_system", &file_path).await {
             Ok(d) => d,
             Err(e) => {
                 eprintln!("Error loading dataset: {}", e);
@@ -108,16 +250,114 @@ impl RunCommand {
             }
         };
 
-        let eval_start = std::time::Instant::now();
+        // Duplicate ids and empty texts always fail the run; the other issues
+        // only with `strict`, otherwise they are reported as warnings
+        let validated = match &eval_config {
+            Some(eval_config) => dataset.validate_against(eval_config),
+            None => dataset.validate(),
+        };
+
+        if let Err(err) = validated {
+            let (errors, warnings): (Vec<_>, Vec<_>) = err
+                .issues
+                .into_iter()
+                .partition(|e| loom_config.strict || e.is_fatal());
+
+            for warning in &warnings {
+                status!(format, "Warning: {}", warning);
+            }
+
+            if !errors.is_empty() {
+                let err = ValidationError::new(errors);
+
+                if format.is_json() {
+                    OutputFormat::print_json(&err);
+                } else {
+                    eprintln!("Dataset has {}", err);
+                }
+
+                std::process::exit(1);
+            }
+        }
+
+        if let Some(fraction) = self.sample {
+            let total = dataset.samples.len();
+            dataset = dataset.sample(fraction, self.seed);
+            status!(
+                format,
+                "Sampled {} of {} samples (seed {})",
+                dataset.samples.len(),
+                total,
+                self.seed
+            );
+        }
+
+        let mut checkpoint = match &self.resume {
+            Some(path) => {
+                let file_path = FilePath::from(path.clone()).into();
+                match runtime.load::<Checkpoint>("file_system", &file_path).await {
+                    Ok(c) => c,
+                    Err(e) => {
+                        eprintln!("Error loading checkpoint: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            }
+            None => Checkpoint::new(),
+        };
+
         let total = dataset.samples.len();
-        let mut result = EvalResult::new();
+        let remaining = checkpoint.remaining(&dataset);
+        let mut tracker = ProgressTracker::new(total, checkpoint.len());
+
+        if !checkpoint.is_empty() {
+            status!(
+                format,
+                "Resuming: {} of {} samples already scored",
+                checkpoint.len(),
+                total
+            );
+        }
+
+        status!(
+            format,
+            "Running evaluation on {} samples...\n",
+            remaining.len()
+        );
 
-        println!("Running evaluation on {} samples...\n", total);
+        let checkpoint_file = Path::File(FilePath::from(checkpoint_path.clone()));
+
+        let mut screen = None;
+        let dashboard = Arc::new(AtomicBool::new(false));
+        if tui {
+            match widgets::Screen::enter() {
+                Ok(s) => {
+                    screen = Some(s);
+                    dashboard.store(true, Ordering::SeqCst);
+
+                    // Drop doesn't run when Ctrl-C kills the process, so restore here.
+                    // Tokio's SIGINT handler stays installed once registered, so the
+                    // listener outlives the dashboard and exits as the default would.
+                    let dashboard = dashboard.clone();
+                    tokio::spawn(async move {
+                        if tokio::signal::ctrl_c().await.is_ok() {
+                            if dashboard.load(Ordering::SeqCst) {
+                                widgets::Screen::restore();
+                            }
+                            std::process::exit(130);
+                        }
+                    });
+                }
+                Err(e) => eprintln!("Could not start dashboard, showing progress bar: {}", e),
+            }
+        }
 
-        for sample in &dataset.samples {
+        let mut bar = widgets::ProgressBar::new().show_eta(true).show_rate(true);
+        for (i, sample) in remaining.iter().enumerate() {
             let output_value = match runtime.execute(sample.text.clone()) {
                 Ok(v) => v,
                 Err(e) => {
+                    drop(screen.take());
                     eprintln!("Error executing pipeline for sample {}: {}", sample.id, e);
                     std::process::exit(1);
                 }
@@ -126,6 +366,7 @@ impl RunCommand {
             let output: EvalOutput = match output_value.try_into() {
                 Ok(o) => o,
                 Err(e) => {
+                    drop(screen.take());
                     eprintln!("Error converting output for sample {}: {}", sample.id, e);
                     std::process::exit(1);
                 }
@@ -136,24 +377,122 @@ impl RunCommand {
                 .map(|c| c.threshold_of(sample.text.len()))
                 .unwrap_or(0.75);
 
-            result = result.merge(output.to_result(sample, threshold));
+            let score = output.score;
+            let sample_result = output.to_result(sample, threshold);
+            let progress = tracker.advance(&sample.id, sample_result.correct > 0);
+            checkpoint.record(sample_result);
+
+            match screen.as_mut() {
+                Some(screen) => screen.draw(&self.dashboard(&checkpoint, &progress)),
+                None => {
+                    bar = bar.progress(&progress).message(format!("{:.2}", score));
+                    format.write(bar.render());
+                }
+            }
+
+            if let Some(interval) = checkpoint_interval
+                && (i + 1) % interval == 0
+                && let Err(e) = runtime
+                    .save("file_system", &checkpoint_file, &checkpoint, Format::Json)
+                    .await
+            {
+                drop(screen.take());
+                eprintln!("Error writing checkpoint: {}", e);
+                std::process::exit(1);
+            }
         }
 
-        let elapsed = eval_start.elapsed();
-        result.elapsed_ms = elapsed.as_millis() as i64;
-        result.throughput = if elapsed.as_secs_f32() > 0.0 {
-            total as f32 / elapsed.as_secs_f32()
-        } else {
-            0.0
-        };
+        drop(screen);
+        dashboard.store(false, Ordering::SeqCst);
+
+        let mut result = checkpoint.into_result();
+        result.elapsed_ms = tracker.elapsed().as_millis() as i64;
+        result.throughput = tracker.items_per_sec();
 
         // Clear the progress line
-        widgets::ProgressBar::clear();
-        println!("Completed {} samples\n", total);
+        format.clear_line();
+        status!(format, "Completed {} samples\n", total);
 
         // Compute metrics from raw counts
         let metrics = result.metrics();
 
+        if format.is_text() {
+            self.print_report(&result, &metrics);
+        }
+
+        // Write results to output file
+        let file_path = Path::File(FilePath::from(output_path.clone()));
+        if let Err(e) = runtime
+            .save("file_system", &file_path, &result, Format::Json)
+            .await
+        {
+            eprintln!("Error writing output file: {}", e);
+            std::process::exit(1);
+        }
+
+        status!(format, "\nResults written to {:?}", output_path);
+
+        if let Some(junit_path) = &self.junit {
+            if let Err(e) = std::fs::write(junit_path, result.to_junit()) {
+                eprintln!("Error writing JUnit report: {}", e);
+                std::process::exit(1);
+            }
+
+            status!(format, "JUnit report written to {:?}", junit_path);
+        }
+
+        // The run is complete, so its checkpoint is no longer needed
+        if checkpoint_path.exists()
+            && let Err(e) = std::fs::remove_file(&checkpoint_path)
+        {
+            eprintln!(
+                "Warning: could not remove checkpoint {:?}: {}",
+                checkpoint_path, e
+            );
+        }
+
+        let outcome = self.gate_metric.check(&metrics, self.fail_under);
+
+        if format.is_json() {
+            OutputFormat::print_json(&RunReport {
+                result: &result,
+                metrics: &metrics,
+                output: &output_path,
+                passed: outcome.is_success(),
+            });
+        }
+
+        if let Some(threshold) = self.fail_under
+            && !outcome.is_success()
+        {
+            eprintln!(
+                "\nFAILED: {:?} {:.3} is below --fail-under {:.3}",
+                self.gate_metric,
+                self.gate_metric.value(&metrics),
+                threshold
+            );
+        }
+
+        outcome
+    }
+
+    fn dashboard(&self, checkpoint: &Checkpoint, progress: &Progress) -> widgets::Dashboard {
+        let mut categories: Vec<_> = checkpoint.result.per_category.iter().collect();
+        categories.sort_by_key(|(name, _)| name.as_str());
+
+        let mut dashboard = widgets::Dashboard::new()
+            .title(format!("loom run {}", self.path.display()))
+            .progress(progress)
+            .correct(checkpoint.result.correct);
+
+        for (name, category) in categories {
+            dashboard = dashboard.category(name, category.correct, category.total);
+        }
+
+        dashboard
+    }
+
+    fn print_report(&self, result: &EvalResult, metrics: &EvalMetrics) {
         // Display prominent score summary
         let score_out_of_100 = (metrics.accuracy * 100.0).round() as u32;
         println!("========================================");
@@ -220,6 +559,21 @@ impl RunCommand {
                 }
             }
 
+            if let Some(column) = &self.sort_by {
+                let order = if self.descending {
+                    widgets::SortOrder::Descending
+                } else {
+                    widgets::SortOrder::Ascending
+                };
+                table = table.sort_by(column, order);
+            }
+
+            if self.failing_only
+                && let Some(f1) = table.column("F1")
+            {
+                table = table.filter(|row| row[f1].parse::<f32>().is_ok_and(|v| v < 1.0));
+            }
+
             print!("{}", table);
 
             // Show misclassified samples
@@ -247,25 +601,98 @@ impl RunCommand {
                 }
             }
         }
+    }
+}
 
-        // Ensure output directory exists
-        if let Some(parent) = output_path.parent() {
-            if let Err(e) = std::fs::create_dir_all(parent) {
-                eprintln!("Error creating output directory: {}", e);
-                std::process::exit(1);
-            }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use loom::eval::LabelMetrics;
+
+    fn metrics(accuracy: f32, f1: f32) -> EvalMetrics {
+        EvalMetrics {
+            accuracy,
+            f1,
+            per_label: [("label".to_string(), label(f1, f1))].into(),
+            ..Default::default()
         }
+    }
 
-        // Write results to output file
-        let file_path = Path::File(FilePath::from(output_path.clone()));
-        if let Err(e) = runtime
-            .save("file_system", &file_path, &result, Format::Json)
-            .await
-        {
-            eprintln!("Error writing output file: {}", e);
-            std::process::exit(1);
+    fn label(precision: f32, recall: f32) -> LabelMetrics {
+        let sum = precision + recall;
+        let f1 = if sum > 0.0 {
+            2.0 * precision * recall / sum
+        } else {
+            0.0
+        };
+
+        LabelMetrics {
+            precision,
+            recall,
+            f1,
         }
+    }
+
+    #[test]
+    fn no_threshold_always_succeeds() {
+        let outcome = GateMetric::Accuracy.check(&metrics(0.0, 0.0), None);
+        assert_eq!(outcome, Outcome::Success);
+    }
+
+    #[test]
+    fn below_threshold_fails_with_code_2() {
+        let outcome = GateMetric::Accuracy.check(&metrics(0.79, 1.0), Some(0.8));
+        assert_eq!(outcome, Outcome::ThresholdNotMet);
+        assert_eq!(outcome.code(), 2);
+    }
+
+    #[test]
+    fn threshold_is_inclusive() {
+        let outcome = GateMetric::Accuracy.check(&metrics(0.8, 0.0), Some(0.8));
+        assert_eq!(outcome, Outcome::Success);
+        assert_eq!(outcome.code(), 0);
+    }
+
+    #[test]
+    fn macro_f1_gates_on_f1() {
+        let m = metrics(1.0, 0.5);
+        assert_eq!(
+            GateMetric::MacroF1.check(&m, Some(0.6)),
+            Outcome::ThresholdNotMet
+        );
+        assert_eq!(GateMetric::Accuracy.check(&m, Some(0.6)), Outcome::Success);
+    }
+
+    #[test]
+    fn macro_f1_averages_per_label_f1() {
+        // Macro precision and recall are both 0.6, so the harmonic-mean
+        // `f1` is 0.6, while each label's own F1 is only 1/3.
+        let m = EvalMetrics {
+            accuracy: 1.0,
+            precision: 0.6,
+            recall: 0.6,
+            f1: 0.6,
+            per_label: [
+                ("a".to_string(), label(1.0, 0.2)),
+                ("b".to_string(), label(0.2, 1.0)),
+            ]
+            .into(),
+            ..Default::default()
+        };
+
+        assert!((GateMetric::MacroF1.value(&m) - 1.0 / 3.0).abs() < 1e-6);
+        assert_eq!(
+            GateMetric::MacroF1.check(&m, Some(0.5)),
+            Outcome::ThresholdNotMet
+        );
+    }
 
-        println!("\nResults written to {:?}", output_path);
+    #[test]
+    fn sample_fraction_must_be_in_range() {
+        assert_eq!(parse_fraction("0.1"), Ok(0.1));
+        assert_eq!(parse_fraction("1"), Ok(1.0));
+        assert!(parse_fraction("0").is_err());
+        assert!(parse_fraction("1.5").is_err());
+        assert!(parse_fraction("ten").is_err());
     }
 }
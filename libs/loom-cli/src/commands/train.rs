@@ -0,0 +1,115 @@
+use std::path::PathBuf;
+
+use clap::{Args, ValueEnum};
+use loom::core::Format;
+use loom::cortex::bench::platt::{RawScoreExport, generate_rust_code, train_platt_params};
+use loom::cortex::bench::temperature::train_temperature;
+use loom::io::path::{FilePath, Path};
+use loom::runtime::{FileSystemSource, JsonCodec, Runtime};
+
+use super::{OutputFormat, resolve_output_path};
+
+/// Calibration method to train
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum TrainMethod {
+    /// Per-label Platt scaling (a, b)
+    #[default]
+    Platt,
+    /// Single global temperature across all labels
+    Temperature,
+}
+
+/// Train calibration parameters from exported raw scores
+#[derive(Debug, Args)]
+pub struct TrainCommand {
+    /// Path to the raw score export JSON file
+    pub path: PathBuf,
+
+    /// Calibration method
+    #[arg(short, long, value_enum, default_value_t = TrainMethod::Platt)]
+    pub method: TrainMethod,
+
+    /// Output directory for trained parameters (default: input file's directory)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+impl TrainCommand {
+    pub async fn exec(self, format: OutputFormat) {
+        let runtime = Runtime::new()
+            .source(FileSystemSource::builder().sandbox(false).build())
+            .codec(JsonCodec::new())
+            .build();
+
+        status!(format, "Loading raw scores from {:?}...", self.path);
+
+        let file_path = FilePath::from(self.path.clone()).into();
+        let export: RawScoreExport = match runtime.load("file_system", &file_path).await {
+            Ok(e) => e,
+            Err(e) => {
+                eprintln!("Error loading raw scores: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        status!(
+            format,
+            "Training {:?} calibration on {} samples...\n",
+            self.method,
+            export.samples.len()
+        );
+
+        let (filename, result) = match self.method {
+            TrainMethod::Platt => {
+                let result = train_platt_params(&export);
+                if format.is_text() {
+                    print!("{}", generate_rust_code(&result));
+                }
+                ("platt_params.json", serde_json::to_value(&result))
+            }
+            TrainMethod::Temperature => {
+                let result = train_temperature(&export);
+                status!(format, "Temperature: {:.4}", result.temperature);
+                status!(
+                    format,
+                    "NLL:         {:.4} -> {:.4}",
+                    result.metadata.nll_before,
+                    result.metadata.nll_after
+                );
+                ("temperature.json", serde_json::to_value(&result))
+            }
+        };
+
+        let result = match result {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("Error serializing training result: {}", e);
+                std::process::exit(1);
+            }
+        };
+
+        let output_path = resolve_output_path(&self.path, self.output.as_deref(), filename);
+
+        if let Some(parent) = output_path.parent()
+            && let Err(e) = std::fs::create_dir_all(parent)
+        {
+            eprintln!("Error creating output directory: {}", e);
+            std::process::exit(1);
+        }
+
+        let file_path = Path::File(FilePath::from(output_path.clone()));
+        if let Err(e) = runtime
+            .save("file_system", &file_path, &result, Format::Json)
+            .await
+        {
+            eprintln!("Error writing output file: {}", e);
+            std::process::exit(1);
+        }
+
+        status!(format, "\nParameters written to {:?}", output_path);
+
+        if format.is_json() {
+            OutputFormat::print_json(&result);
+        }
+    }
+}
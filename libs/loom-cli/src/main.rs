@@ -1,9 +1,14 @@
+use std::process::ExitCode;
+
 use clap::{Parser, Subcommand};
 
 mod commands;
 pub mod widgets;
 
-use commands::RunCommand;
+use commands::{
+    CompletionsCommand, ConfigCommand, CoverageCommand, DiffCommand, ExtractCommand, Outcome,
+    OutputFormat, PipelineCommand, RunCommand, TrainCommand,
+};
 
 /// Loom scoring engine CLI
 ///
@@ -13,6 +18,10 @@ use commands::RunCommand;
 #[command(version, author)]
 #[command(propagate_version = true)]
 struct Cli {
+    /// Output format (json writes results to stdout and progress to stderr)
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -21,13 +30,64 @@ struct Cli {
 enum Commands {
     /// Run evaluation against a dataset
     Run(RunCommand),
+
+    /// Run a declarative pipeline over an input file
+    Pipeline(PipelineCommand),
+
+    /// Train calibration parameters from raw scores
+    Train(TrainCommand),
+
+    /// Extract named entity spans from text
+    Extract(ExtractCommand),
+
+    /// Compare two evaluation results
+    Diff(DiffCommand),
+
+    /// Report how many samples expect each label
+    Coverage(CoverageCommand),
+
+    /// Inspect configuration
+    Config(ConfigCommand),
+
+    /// Print a shell completion script (bash, zsh, fish, ...)
+    Completions(CompletionsCommand),
 }
 
 #[tokio::main]
-async fn main() {
+async fn main() -> ExitCode {
     let cli = Cli::parse();
 
-    match cli.command {
-        Commands::Run(cmd) => cmd.exec().await,
-    }
+    let outcome = match cli.command {
+        Commands::Run(cmd) => cmd.exec(cli.format).await,
+        Commands::Pipeline(cmd) => {
+            cmd.exec(cli.format).await;
+            Outcome::Success
+        }
+        Commands::Train(cmd) => {
+            cmd.exec(cli.format).await;
+            Outcome::Success
+        }
+        Commands::Extract(cmd) => {
+            cmd.exec(cli.format).await;
+            Outcome::Success
+        }
+        Commands::Diff(cmd) => {
+            cmd.exec(cli.format).await;
+            Outcome::Success
+        }
+        Commands::Coverage(cmd) => {
+            cmd.exec(cli.format).await;
+            Outcome::Success
+        }
+        Commands::Config(cmd) => {
+            cmd.exec(cli.format).await;
+            Outcome::Success
+        }
+        Commands::Completions(cmd) => {
+            cmd.exec(cli.format).await;
+            Outcome::Success
+        }
+    };
+
+    outcome.into()
 }
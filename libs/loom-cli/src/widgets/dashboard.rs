@@ -0,0 +1,213 @@
+use std::fmt;
+use std::time::Duration;
+
+use loom::eval::Progress;
+
+use super::{ProgressBar, Widget, WidgetResult, format_duration};
+
+/// Full-screen run summary: overall progress, running accuracy, ETA and
+/// per-category accuracy bars. Drawn with `Screen::draw`.
+pub struct Dashboard {
+    title: String,
+    current: usize,
+    total: usize,
+    correct: usize,
+    elapsed: Duration,
+    rate: f32,
+    eta: Option<Duration>,
+    categories: Vec<(String, usize, usize)>,
+    bar_width: usize,
+}
+
+impl Dashboard {
+    pub fn new() -> Self {
+        Self {
+            title: String::new(),
+            current: 0,
+            total: 0,
+            correct: 0,
+            elapsed: Duration::ZERO,
+            rate: 0.0,
+            eta: None,
+            categories: Vec::new(),
+            bar_width: 30,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Take position, throughput and ETA from a run's `Progress` report.
+    pub fn progress(mut self, progress: &Progress) -> Self {
+        self.current = progress.current;
+        self.total = progress.total;
+        self.elapsed = progress.elapsed;
+        self.rate = progress.items_per_sec;
+        self.eta = progress.eta();
+        self
+    }
+
+    /// Samples scored so far
+    pub fn current(mut self, current: usize) -> Self {
+        self.current = current;
+        self
+    }
+
+    pub fn total(mut self, total: usize) -> Self {
+        self.total = total;
+        self
+    }
+
+    /// Correctly decided samples so far
+    pub fn correct(mut self, correct: usize) -> Self {
+        self.correct = correct;
+        self
+    }
+
+    /// Time spent scoring in this run
+    pub fn elapsed(mut self, elapsed: Duration) -> Self {
+        self.elapsed = elapsed;
+        self
+    }
+
+    /// Add a category row with its running `correct`/`total` counts
+    pub fn category(mut self, name: impl Into<String>, correct: usize, total: usize) -> Self {
+        self.categories.push((name.into(), correct, total));
+        self
+    }
+
+    pub fn bar_width(mut self, width: usize) -> Self {
+        self.bar_width = width;
+        self
+    }
+
+    /// Fraction of scored samples decided correctly
+    pub fn accuracy(&self) -> f32 {
+        if self.current > 0 {
+            self.correct as f32 / self.current as f32
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Widget for Dashboard {
+    fn render(&self) -> WidgetResult {
+        let mut lines = Vec::new();
+
+        if !self.title.is_empty() {
+            lines.push(self.title.clone());
+            lines.push(String::new());
+        }
+
+        let eta = self
+            .eta
+            .map(format_duration)
+            .unwrap_or_else(|| "--:--".to_string());
+
+        lines.push(
+            ProgressBar::new()
+                .current(self.current)
+                .total(self.total)
+                .bar_width(self.bar_width)
+                .message(format!("ETA {}", eta))
+                .render()
+                .to_string(),
+        );
+        lines.push(String::new());
+
+        lines.push(format!(
+            "Accuracy:   {:.3} ({}/{})",
+            self.accuracy(),
+            self.correct,
+            self.current
+        ));
+        lines.push(format!("Elapsed:    {}", format_duration(self.elapsed)));
+        lines.push(format!("Throughput: {:.1} samples/s", self.rate));
+
+        if !self.categories.is_empty() {
+            lines.push(String::new());
+            lines.push("Per-Category Accuracy".to_string());
+            lines.push(String::new());
+
+            let name_width = self
+                .categories
+                .iter()
+                .map(|(name, _, _)| name.len())
+                .max()
+                .unwrap_or(0);
+
+            for (name, correct, total) in &self.categories {
+                let bar = ProgressBar::new()
+                    .current(*correct)
+                    .total(*total)
+                    .bar_width(self.bar_width)
+                    .render();
+                lines.push(format!("{:name_width$}  {}", name, &*bar));
+            }
+        }
+
+        WidgetResult::new(lines.join("\n"))
+    }
+}
+
+impl fmt::Display for Dashboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", &*self.render())
+    }
+}
+
+impl Default for Dashboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn progress_sets_eta_and_throughput() {
+        let progress = Progress {
+            current: 30,
+            total: 40,
+            sample_id: "s30".to_string(),
+            correct: true,
+            elapsed: Duration::from_secs(5),
+            items_per_sec: 2.0,
+        };
+
+        let rendered = Dashboard::new().progress(&progress).to_string();
+        assert!(rendered.contains("(30/40)  ETA 00:05"), "{}", rendered);
+        assert!(rendered.contains("Throughput: 2.0 samples/s"));
+    }
+
+    #[test]
+    fn eta_unknown_before_first_sample() {
+        assert!(Dashboard::new().total(40).to_string().contains("ETA --:--"));
+    }
+
+    #[test]
+    fn render_includes_accuracy_and_category_bars() {
+        let rendered = Dashboard::new()
+            .current(4)
+            .total(8)
+            .correct(3)
+            .category("task", 2, 2)
+            .category("emotion", 1, 2)
+            .to_string();
+
+        assert!(rendered.contains("Accuracy:   0.750 (3/4)"));
+        assert!(rendered.contains("task     [")); // padded to "emotion"
+        assert!(rendered.contains("(1/2)"));
+    }
+
+    #[test]
+    fn format_duration_switches_to_hours() {
+        assert_eq!(format_duration(Duration::from_secs(83)), "01:23");
+        assert_eq!(format_duration(Duration::from_secs(3723)), "1:02:03");
+    }
+}
@@ -1,15 +1,20 @@
+mod dashboard;
 mod progress;
+mod screen;
 mod spinner;
 mod table;
 
 use std::io::{Write, stdout};
 use std::ops::Deref;
+use std::time::Duration;
 
 use crossterm::{ExecutableCommand, cursor, terminal};
 
+pub use dashboard::Dashboard;
 pub use progress::ProgressBar;
+pub use screen::Screen;
 pub use spinner::Spinner;
-pub use table::Table;
+pub use table::{SortOrder, Table};
 
 /// Result of rendering a widget, wraps the rendered string
 pub struct WidgetResult(String);
@@ -34,9 +39,8 @@ impl WidgetResult {
 
     /// Write to any writer, clearing the line first (for terminal writers)
     pub fn write_to(&self, writer: &mut impl Write) {
-        let mut stdout = stdout();
-        let _ = stdout.execute(cursor::MoveToColumn(0));
-        let _ = stdout.execute(terminal::Clear(terminal::ClearType::CurrentLine));
+        let _ = writer.execute(cursor::MoveToColumn(0));
+        let _ = writer.execute(terminal::Clear(terminal::ClearType::CurrentLine));
         let _ = write!(writer, "{}", self.0);
         let _ = writer.flush();
     }
@@ -49,8 +53,24 @@ pub trait Widget: std::fmt::Display {
 
 /// Clear the current line (useful after inline widgets)
 pub fn clear_line() {
-    let mut stdout = stdout();
-    let _ = stdout.execute(cursor::MoveToColumn(0));
-    let _ = stdout.execute(terminal::Clear(terminal::ClearType::CurrentLine));
-    let _ = stdout.flush();
+    clear_line_to(&mut stdout());
+}
+
+/// Clear the current line of any writer
+pub fn clear_line_to(writer: &mut impl Write) {
+    let _ = writer.execute(cursor::MoveToColumn(0));
+    let _ = writer.execute(terminal::Clear(terminal::ClearType::CurrentLine));
+    let _ = writer.flush();
+}
+
+/// Format a duration as `MM:SS`, or `H:MM:SS` from an hour up
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (h, m, s) = (secs / 3600, secs / 60 % 60, secs % 60);
+
+    if h > 0 {
+        format!("{}:{:02}:{:02}", h, m, s)
+    } else {
+        format!("{:02}:{:02}", m, s)
+    }
 }
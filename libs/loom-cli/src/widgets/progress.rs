@@ -1,13 +1,26 @@
 use std::fmt;
+use std::time::{Duration, Instant};
 
-use super::{Widget, WidgetResult};
+use loom::eval::Progress;
 
+use super::{Widget, WidgetResult, format_duration};
+
+/// Inline progress bar, optionally followed by an ETA and a rate:
+/// `[████░░░░]  40% (120/300) ETA 00:15 (8.0/s)`.
+///
+/// Timing is measured from when the bar was created unless a fixed
+/// elapsed time is given with `elapsed` or `progress`.
 pub struct ProgressBar {
     current: usize,
     total: usize,
     message: String,
     status_icon: Option<char>,
     bar_width: usize,
+    started: Instant,
+    elapsed: Option<Duration>,
+    rate: Option<f32>,
+    show_eta: bool,
+    show_rate: bool,
 }
 
 impl ProgressBar {
@@ -18,9 +31,23 @@ impl ProgressBar {
             message: String::new(),
             status_icon: None,
             bar_width: 30,
+            started: Instant::now(),
+            elapsed: None,
+            rate: None,
+            show_eta: false,
+            show_rate: false,
         }
     }
 
+    /// Take position and timing from a run's `Progress` report.
+    pub fn progress(mut self, progress: &Progress) -> Self {
+        self.current = progress.current;
+        self.total = progress.total;
+        self.elapsed = Some(progress.elapsed);
+        self.rate = Some(progress.items_per_sec);
+        self
+    }
+
     pub fn current(mut self, current: usize) -> Self {
         self.current = current;
         self
@@ -46,6 +73,51 @@ impl ProgressBar {
         self
     }
 
+    /// Use a fixed elapsed time instead of the time since creation.
+    pub fn elapsed(mut self, elapsed: Duration) -> Self {
+        self.elapsed = Some(elapsed);
+        self
+    }
+
+    pub fn show_eta(mut self, show: bool) -> Self {
+        self.show_eta = show;
+        self
+    }
+
+    pub fn show_rate(mut self, show: bool) -> Self {
+        self.show_rate = show;
+        self
+    }
+
+    /// Items per second, from `progress` or `current / elapsed`.
+    pub fn rate(&self) -> f32 {
+        if let Some(rate) = self.rate {
+            return rate;
+        }
+
+        let secs = self
+            .elapsed
+            .unwrap_or_else(|| self.started.elapsed())
+            .as_secs_f32();
+
+        match secs > 0.0 {
+            true => self.current as f32 / secs,
+            false => 0.0,
+        }
+    }
+
+    /// Estimated time to finish at the current rate, or `None` before any
+    /// items are done.
+    pub fn eta(&self) -> Option<Duration> {
+        let rate = self.rate();
+        if rate <= 0.0 {
+            return None;
+        }
+
+        let remaining = self.total.saturating_sub(self.current) as f32;
+        Some(Duration::from_secs_f32(remaining / rate))
+    }
+
     pub fn clear() {
         super::clear_line();
     }
@@ -66,13 +138,28 @@ impl Widget for ProgressBar {
             .map(|c| format!(" {}", c))
             .unwrap_or_default();
 
+        let mut timing = String::new();
+
+        if self.show_eta {
+            let eta = self
+                .eta()
+                .map(format_duration)
+                .unwrap_or_else(|| "--:--".to_string());
+            timing.push_str(&format!(" ETA {}", eta));
+        }
+
+        if self.show_rate {
+            timing.push_str(&format!(" ({:.1}/s)", self.rate()));
+        }
+
         WidgetResult::new(format!(
-            "[{}{}] {:3.0}% ({}/{}){}  {}",
+            "[{}{}] {:3.0}% ({}/{}){}{}  {}",
             "█".repeat(filled),
             "░".repeat(empty),
             pct * 100.0,
             self.current,
             self.total,
+            timing,
             status,
             self.message
         ))
@@ -90,3 +177,64 @@ impl Default for ProgressBar {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_eta_and_rate_from_fixed_elapsed() {
+        let bar = ProgressBar::new()
+            .current(120)
+            .total(300)
+            .bar_width(10)
+            .elapsed(Duration::from_secs(15))
+            .show_eta(true)
+            .show_rate(true);
+
+        // 120 items in 15s is 8/s, leaving 180 items for 22.5s
+        assert_eq!(
+            &*bar.render(),
+            "[████░░░░░░]  40% (120/300) ETA 00:22 (8.0/s)  "
+        );
+    }
+
+    #[test]
+    fn timing_hidden_by_default() {
+        let bar = ProgressBar::new()
+            .current(1)
+            .total(2)
+            .bar_width(4)
+            .elapsed(Duration::from_secs(1));
+
+        assert_eq!(&*bar.render(), "[██░░]  50% (1/2)  ");
+    }
+
+    #[test]
+    fn progress_report_drives_eta() {
+        let progress = Progress {
+            current: 60,
+            total: 100,
+            sample_id: "60".to_string(),
+            correct: true,
+            elapsed: Duration::from_secs(30),
+            items_per_sec: 0.5,
+        };
+
+        let bar = ProgressBar::new().progress(&progress).show_eta(true);
+
+        assert_eq!(bar.eta(), Some(Duration::from_secs(80)));
+        assert!(bar.render().contains("ETA 01:20"));
+    }
+
+    #[test]
+    fn eta_unknown_before_any_items() {
+        let bar = ProgressBar::new()
+            .total(10)
+            .elapsed(Duration::from_secs(3))
+            .show_eta(true);
+
+        assert_eq!(bar.eta(), None);
+        assert!(bar.render().contains("ETA --:--"));
+    }
+}
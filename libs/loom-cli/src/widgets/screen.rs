@@ -0,0 +1,56 @@
+use std::io::{Stdout, Write, stdout};
+use std::sync::Once;
+
+use crossterm::{cursor, execute, queue, terminal};
+
+use super::Widget;
+
+static PANIC_HOOK: Once = Once::new();
+
+/// Full-screen drawing surface on the terminal's alternate screen.
+///
+/// The terminal is restored when the screen is dropped or the process
+/// panics. Signal handlers that exit the process (e.g. on Ctrl-C) must call
+/// `Screen::restore` themselves, since `Drop` won't run.
+pub struct Screen {
+    out: Stdout,
+}
+
+impl Screen {
+    /// Switch to the alternate screen and hide the cursor.
+    pub fn enter() -> std::io::Result<Self> {
+        PANIC_HOOK.call_once(|| {
+            let hook = std::panic::take_hook();
+            std::panic::set_hook(Box::new(move |info| {
+                Self::restore();
+                hook(info);
+            }));
+        });
+
+        let mut out = stdout();
+        execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Self { out })
+    }
+
+    /// Replace the screen contents with a rendered widget.
+    pub fn draw(&mut self, widget: &impl Widget) {
+        let _ = queue!(
+            self.out,
+            cursor::MoveTo(0, 0),
+            terminal::Clear(terminal::ClearType::All)
+        );
+        let _ = write!(self.out, "{}", &*widget.render());
+        let _ = self.out.flush();
+    }
+
+    /// Leave the alternate screen and show the cursor. Safe to call more than once.
+    pub fn restore() {
+        let _ = execute!(stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        Self::restore();
+    }
+}
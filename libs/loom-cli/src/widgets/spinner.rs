@@ -2,10 +2,15 @@ use std::fmt;
 
 use super::{Widget, WidgetResult};
 
-const FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+const FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
 
+/// An inline spinner with a status message.
+///
+/// Keep one around for a long operation, calling `tick` and `set_message`
+/// as it progresses and `render().write()` to redraw it in place.
 pub struct Spinner {
     message: String,
+    frames: Vec<String>,
     frame_idx: usize,
 }
 
@@ -13,6 +18,7 @@ impl Spinner {
     pub fn new() -> Self {
         Self {
             message: String::new(),
+            frames: FRAMES.iter().map(|f| f.to_string()).collect(),
             frame_idx: 0,
         }
     }
@@ -22,13 +28,29 @@ impl Spinner {
         self
     }
 
+    /// Animate with `frames` instead of the default braille dots. An empty
+    /// slice keeps the current frames.
+    pub fn with_frames(mut self, frames: &[&str]) -> Self {
+        if !frames.is_empty() {
+            self.frames = frames.iter().map(|f| f.to_string()).collect();
+            self.frame_idx = 0;
+        }
+
+        self
+    }
+
     pub fn frame(mut self, idx: usize) -> Self {
-        self.frame_idx = idx % FRAMES.len();
+        self.frame_idx = idx % self.frames.len();
         self
     }
 
+    /// Replace the status message shown after the frame.
+    pub fn set_message(&mut self, message: &str) {
+        self.message = message.to_string();
+    }
+
     pub fn tick(&mut self) {
-        self.frame_idx = (self.frame_idx + 1) % FRAMES.len();
+        self.frame_idx = (self.frame_idx + 1) % self.frames.len();
     }
 
     pub fn clear() {
@@ -38,7 +60,7 @@ impl Spinner {
 
 impl Widget for Spinner {
     fn render(&self) -> WidgetResult {
-        WidgetResult::new(format!("{} {}", FRAMES[self.frame_idx], self.message))
+        WidgetResult::new(format!("{} {}", self.frames[self.frame_idx], self.message))
     }
 }
 
@@ -53,3 +75,36 @@ impl Default for Spinner {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_message_changes_rendered_text() {
+        let mut spinner = Spinner::new().message("loading model...");
+        assert_eq!(&*spinner.render(), "⠋ loading model...");
+
+        spinner.set_message("scoring...");
+        assert_eq!(&*spinner.render(), "⠋ scoring...");
+    }
+
+    #[test]
+    fn custom_frames_cycle() {
+        let mut spinner = Spinner::new().with_frames(&["-", "\\", "|", "/"]);
+        let mut rendered = vec![];
+
+        for _ in 0..5 {
+            rendered.push(spinner.render().trim_end().to_string());
+            spinner.tick();
+        }
+
+        assert_eq!(rendered, vec!["-", "\\", "|", "/", "-"]);
+    }
+
+    #[test]
+    fn empty_frames_keep_default() {
+        let spinner = Spinner::new().with_frames(&[]).frame(1);
+        assert_eq!(&*spinner.render(), "⠙ ");
+    }
+}
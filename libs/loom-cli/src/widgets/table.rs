@@ -1,11 +1,25 @@
+use std::cmp::Ordering;
 use std::fmt;
 
+use crossterm::style::{Color, Stylize};
+
 use super::{Widget, WidgetResult};
 
+/// Row ordering for `Table::sort_by`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+type RowPredicate = Box<dyn Fn(&[String]) -> bool>;
+
 pub struct Table {
     headers: Vec<String>,
     rows: Vec<Vec<String>>,
     column_widths: Vec<usize>,
+    highlights: Vec<(RowPredicate, Color)>,
 }
 
 impl Table {
@@ -14,6 +28,7 @@ impl Table {
             headers: Vec::new(),
             rows: Vec::new(),
             column_widths: Vec::new(),
+            highlights: Vec::new(),
         }
     }
 
@@ -37,7 +52,54 @@ impl Table {
         self
     }
 
+    /// Index of the column whose header matches `name` (case-insensitive).
+    pub fn column(&self, name: &str) -> Option<usize> {
+        self.headers
+            .iter()
+            .position(|h| h.eq_ignore_ascii_case(name))
+    }
+
+    /// Sort rows by the named column. Cells that parse as numbers compare
+    /// numerically and sort before non-numeric cells in either order, so
+    /// placeholders like `n/a` stay last. Unknown columns are ignored.
+    pub fn sort_by(mut self, column: &str, order: SortOrder) -> Self {
+        let Some(idx) = self.column(column) else {
+            return self;
+        };
+
+        self.rows
+            .sort_by(|a, b| compare_cells(a.get(idx), b.get(idx), order));
+        self
+    }
+
+    /// Keep only rows for which `predicate` returns true.
+    pub fn filter(mut self, predicate: impl Fn(&[String]) -> bool) -> Self {
+        self.rows.retain(|row| predicate(row));
+        self.update_column_widths();
+        self
+    }
+
+    /// Render rows for which `predicate` returns true in `color`.
+    /// When several highlights match a row, the first one added wins.
+    pub fn highlight(
+        mut self,
+        color: Color,
+        predicate: impl Fn(&[String]) -> bool + 'static,
+    ) -> Self {
+        self.highlights.push((Box::new(predicate), color));
+        self
+    }
+
+    fn row_color(&self, row: &[String]) -> Option<Color> {
+        self.highlights
+            .iter()
+            .find(|(predicate, _)| predicate(row))
+            .map(|(_, color)| *color)
+    }
+
     fn update_column_widths(&mut self) {
+        self.column_widths.clear();
+
         let num_cols = self
             .headers
             .len()
@@ -78,9 +140,16 @@ impl Widget for Table {
 
         // Render rows
         for row in &self.rows {
+            let mut line = String::new();
             for (i, cell) in row.iter().enumerate() {
                 let width = self.column_widths.get(i).copied().unwrap_or(cell.len());
-                output.push_str(&format!("{:>width$} ", cell, width = width));
+                line.push_str(&format!("{:>width$} ", cell, width = width));
+            }
+
+            // Style the padded line so escape codes don't affect column widths
+            match self.row_color(row) {
+                Some(color) => output.push_str(&line.with(color).to_string()),
+                None => output.push_str(&line),
             }
             output.push('\n');
         }
@@ -100,3 +169,82 @@ impl Default for Table {
         Self::new()
     }
 }
+
+fn compare_cells(a: Option<&String>, b: Option<&String>, order: SortOrder) -> Ordering {
+    let a = a.map(|s| s.as_str()).unwrap_or_default();
+    let b = b.map(|s| s.as_str()).unwrap_or_default();
+
+    let ordering = match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(x), Ok(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        (Ok(_), Err(_)) => return Ordering::Less,
+        (Err(_), Ok(_)) => return Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    };
+
+    match order {
+        SortOrder::Ascending => ordering,
+        SortOrder::Descending => ordering.reverse(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Table {
+        Table::new()
+            .headers(vec!["Label", "F1"])
+            .row(vec!["task", "0.9"])
+            .row(vec!["reminder", "10.0"])
+            .row(vec!["joy", "2.5"])
+            .row(vec!["todo", "n/a"])
+    }
+
+    fn column(table: &Table, idx: usize) -> Vec<&str> {
+        table.rows.iter().map(|r| r[idx].as_str()).collect()
+    }
+
+    #[test]
+    fn sort_numeric_column_numerically() {
+        let sorted = table().sort_by("f1", SortOrder::Ascending);
+        assert_eq!(column(&sorted, 1), vec!["0.9", "2.5", "10.0", "n/a"]);
+    }
+
+    #[test]
+    fn sort_descending_keeps_non_numeric_cells_last() {
+        let sorted = table().sort_by("F1", SortOrder::Descending);
+        assert_eq!(column(&sorted, 0), vec!["reminder", "joy", "task", "todo"]);
+    }
+
+    #[test]
+    fn sort_text_column_lexically() {
+        let sorted = table().sort_by("Label", SortOrder::Ascending);
+        assert_eq!(column(&sorted, 0), vec!["joy", "reminder", "task", "todo"]);
+    }
+
+    #[test]
+    fn sort_unknown_column_is_noop() {
+        let sorted = table().sort_by("missing", SortOrder::Ascending);
+        assert_eq!(column(&sorted, 0), vec!["task", "reminder", "joy", "todo"]);
+    }
+
+    #[test]
+    fn filter_keeps_matching_rows_and_shrinks_widths() {
+        let filtered = table().filter(|row| row[1].parse::<f32>().is_ok_and(|f1| f1 < 1.0));
+        assert_eq!(column(&filtered, 0), vec!["task"]);
+        assert_eq!(filtered.column_widths, vec![5, 3]);
+    }
+
+    #[test]
+    fn highlight_styles_matching_rows_only() {
+        let rendered = table()
+            .highlight(Color::Red, |row| row[0] == "joy")
+            .render()
+            .to_string();
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        assert!(!lines[2].contains('\u{1b}'));
+        assert!(lines[4].contains('\u{1b}'));
+        assert!(lines[4].contains("     joy  2.5 "));
+    }
+}
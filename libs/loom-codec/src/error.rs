@@ -1,11 +1,30 @@
-use crate::MediaType;
+use crate::{Format, MediaType};
 use std::fmt;
 
 #[derive(Debug)]
 pub enum CodecError {
     UnsupportedMediaType(MediaType),
+    UnsupportedFormat(Format),
+
+    /// Malformed input, with the 1-based position of the problem when the
+    /// underlying parser reports one.
+    Parse {
+        format: Format,
+        line: Option<usize>,
+        col: Option<usize>,
+        message: String,
+    },
+
     Decode(String),
+
+    /// Decoded input breached a configured `Limits` bound.
+    LimitExceeded {
+        limit: &'static str,
+        max: usize,
+    },
+
     Encode(String),
+    Io(std::io::Error),
 }
 
 impl CodecError {
@@ -17,30 +36,120 @@ impl CodecError {
         Self::Encode(e.to_string())
     }
 
+    pub fn parse(format: Format, message: impl Into<String>) -> Self {
+        Self::Parse {
+            format,
+            line: None,
+            col: None,
+            message: message.into(),
+        }
+    }
+
+    /// Set the 1-based line and column of a `Parse` error; other variants
+    /// are returned unchanged.
+    pub fn at(mut self, line: usize, col: usize) -> Self {
+        if let Self::Parse {
+            line: l, col: c, ..
+        } = &mut self
+        {
+            *l = Some(line);
+            *c = Some(col);
+        }
+
+        self
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        match self {
+            Self::Parse { line, .. } => *line,
+            _ => None,
+        }
+    }
+
+    pub fn col(&self) -> Option<usize> {
+        match self {
+            Self::Parse { col, .. } => *col,
+            _ => None,
+        }
+    }
+
     pub fn is_unsupported(&self) -> bool {
-        matches!(self, Self::UnsupportedMediaType(_))
+        matches!(
+            self,
+            Self::UnsupportedMediaType(_) | Self::UnsupportedFormat(_)
+        )
+    }
+
+    pub fn is_parse(&self) -> bool {
+        matches!(self, Self::Parse { .. })
     }
 
+    /// Whether decoding failed, including on malformed or oversized input.
     pub fn is_decode(&self) -> bool {
-        matches!(self, Self::Decode(_))
+        matches!(
+            self,
+            Self::Decode(_) | Self::Parse { .. } | Self::LimitExceeded { .. }
+        )
+    }
+
+    pub fn is_limit_exceeded(&self) -> bool {
+        matches!(self, Self::LimitExceeded { .. })
     }
 
     pub fn is_encode(&self) -> bool {
         matches!(self, Self::Encode(_))
     }
+
+    pub fn is_io(&self) -> bool {
+        matches!(self, Self::Io(_))
+    }
 }
 
 impl fmt::Display for CodecError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::UnsupportedMediaType(mt) => write!(f, "unsupported media type: {}", mt),
+            Self::UnsupportedFormat(format) => write!(f, "unsupported format: {}", format),
+            Self::Parse {
+                format,
+                line,
+                col,
+                message,
+            } => {
+                write!(f, "{} parse error", format)?;
+
+                match (line, col) {
+                    (Some(line), Some(col)) => write!(f, " at line {}, column {}", line, col)?,
+                    (Some(line), None) => write!(f, " at line {}", line)?,
+                    _ => {}
+                }
+
+                write!(f, ": {}", message)
+            }
             Self::Decode(msg) => write!(f, "decode error: {}", msg),
+            Self::LimitExceeded { limit, max } => {
+                write!(f, "limit exceeded: {} is {}", limit, max)
+            }
             Self::Encode(msg) => write!(f, "encode error: {}", msg),
+            Self::Io(err) => write!(f, "io error: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for CodecError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
         }
     }
 }
 
-impl std::error::Error for CodecError {}
+impl From<std::io::Error> for CodecError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
 
 impl From<std::str::Utf8Error> for CodecError {
     fn from(e: std::str::Utf8Error) -> Self {
@@ -53,3 +162,44 @@ impl From<std::string::FromUtf8Error> for CodecError {
         Self::Decode(e.to_string())
     }
 }
+
+/// The 1-based line and column of a byte `offset` into `text`.
+#[cfg_attr(not(feature = "toml"), allow(dead_code))]
+pub(crate) fn position(text: &str, offset: usize) -> (usize, usize) {
+    let before = &text[..text.floor_char_boundary(offset)];
+    let line = before.matches('\n').count() + 1;
+    let col = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+
+    (line, col)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_display() {
+        let err = CodecError::parse(Format::Json, "expected value").at(3, 7);
+
+        assert!(err.is_parse());
+        assert!(err.is_decode());
+        assert_eq!(err.line(), Some(3));
+        assert_eq!(err.col(), Some(7));
+        assert_eq!(
+            err.to_string(),
+            "json parse error at line 3, column 7: expected value"
+        );
+
+        let err = CodecError::parse(Format::Toml, "invalid table header");
+        assert_eq!(err.to_string(), "toml parse error: invalid table header");
+    }
+
+    #[test]
+    fn test_position() {
+        let text = "a = 1\nb = é!\n";
+
+        assert_eq!(position(text, 0), (1, 1));
+        assert_eq!(position(text, 6), (2, 1));
+        assert_eq!(position(text, 12), (2, 6));
+    }
+}
@@ -0,0 +1,329 @@
+use saphyr::{Yaml, YamlEmitter};
+
+use crate::path::IdentPath;
+use crate::value::{Object, Value};
+use crate::{Document, Entity, Format, Record};
+
+use super::{Codec, CodecError};
+
+const DELIMITER: &str = "---";
+
+/// Codec for text with a leading YAML front-matter block, as in Markdown
+/// notes:
+///
+/// ```text
+/// ---
+/// title: Notes
+/// tags: [a, b]
+/// ---
+/// # Body
+/// ```
+///
+/// Decoding yields a `Value::Object` holding the front-matter keys plus the
+/// rest of the text under `body` (`with_body_key` to rename it). Text
+/// without a leading `---` line is all body. Encoding writes the non-body
+/// keys back as a front-matter block (in key order), omitted when there are
+/// none, followed by the body. The block's lines end in `\r\n` when the body
+/// uses CRLF, so CRLF files round-trip. Accepts any text-like media type.
+#[derive(Debug, Clone)]
+pub struct FrontMatterCodec {
+    body_key: String,
+}
+
+impl Default for FrontMatterCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FrontMatterCodec {
+    pub fn new() -> Self {
+        Self {
+            body_key: "body".to_string(),
+        }
+    }
+
+    pub fn with_body_key(mut self, key: impl Into<String>) -> Self {
+        self.body_key = key.into();
+        self
+    }
+
+    /// Split `text` into its front-matter source and body. The front matter
+    /// is `None` when the text doesn't open with a delimiter line.
+    fn split(text: &str) -> Result<(Option<&str>, &str), CodecError> {
+        let Some(rest) = strip_delimiter_line(text) else {
+            return Ok((None, text));
+        };
+
+        let mut offset = 0;
+        for line in rest.split_inclusive('\n') {
+            if line.trim_end_matches(['\r', '\n']) == DELIMITER {
+                return Ok((Some(&rest[..offset]), &rest[offset + line.len()..]));
+            }
+
+            offset += line.len();
+        }
+
+        Err(CodecError::parse(
+            Format::Yaml,
+            "unterminated front matter, expected a closing `---` line",
+        )
+        .at(1, 1))
+    }
+
+    fn parse(source: &str) -> Result<Object, CodecError> {
+        // The block starts on the line after the opening delimiter
+        let docs = Yaml::load_from_str(source).map_err(|e| {
+            CodecError::parse(Format::Yaml, e.info())
+                .at(e.marker().line() + 1, e.marker().col() + 1)
+        })?;
+
+        match docs.into_iter().next().map(Value::from) {
+            None | Some(Value::Null) => Ok(Object::new()),
+            Some(Value::Object(object)) => Ok(object),
+            Some(other) => Err(CodecError::parse(
+                Format::Yaml,
+                format!("front matter must be a mapping, found {}", other.kind()),
+            )
+            .at(2, 1)),
+        }
+    }
+}
+
+impl Codec for FrontMatterCodec {
+    fn format(&self) -> Format {
+        Format::Markdown
+    }
+
+    fn decode(&self, mut record: Record) -> Result<Document, CodecError> {
+        if !record.media_type.is_textlike() {
+            return Err(CodecError::UnsupportedMediaType(record.media_type));
+        }
+
+        let text = crate::content_text(&mut record)?;
+        let (front_matter, body) = Self::split(&text)?;
+
+        let mut object = match front_matter {
+            Some(source) => Self::parse(source)?,
+            None => Object::new(),
+        };
+
+        if object.contains_key(&self.body_key) {
+            return Err(CodecError::Decode(format!(
+                "front matter key '{}' conflicts with the body",
+                self.body_key
+            )));
+        }
+
+        object.insert(self.body_key.clone(), Value::from(body));
+
+        let entity = Entity::new(
+            IdentPath::parse("root").expect("valid field path"),
+            record.media_type.as_mime_str(),
+            Value::Object(object),
+        );
+
+        Ok(Document::new(record.path, record.media_type, vec![entity]))
+    }
+
+    fn encode(&self, document: Document) -> Result<Record, CodecError> {
+        if !document.media_type.is_textlike() {
+            return Err(CodecError::UnsupportedMediaType(document.media_type));
+        }
+
+        let content = document
+            .content
+            .first()
+            .ok_or_else(|| CodecError::Encode("document has no content".to_string()))?;
+
+        let mut object = content
+            .content
+            .as_object()
+            .cloned()
+            .ok_or_else(|| CodecError::Encode("content is not an object".to_string()))?;
+
+        let body = match object.remove(&self.body_key) {
+            Some(Value::String(body)) => body,
+            None => String::new(),
+            Some(_) => {
+                return Err(CodecError::Encode(format!(
+                    "'{}' is not a string",
+                    self.body_key
+                )));
+            }
+        };
+
+        let newline = if body.contains("\r\n") { "\r\n" } else { "\n" };
+        let mut text = String::new();
+
+        if !object.is_empty() {
+            let yaml = Yaml::from(&Value::Object(object));
+            let mut out = String::new();
+            YamlEmitter::new(&mut out)
+                .dump(&yaml)
+                .map_err(|e| CodecError::Encode(e.to_string()))?;
+
+            // The emitter opens with its own document marker
+            let block = strip_delimiter_line(&out).unwrap_or(&out);
+
+            text.push_str(DELIMITER);
+            text.push_str(newline);
+            text.push_str(&block.trim_end_matches('\n').replace('\n', newline));
+            text.push_str(newline);
+            text.push_str(DELIMITER);
+            text.push_str(newline);
+        }
+
+        text.push_str(&body);
+        Ok(Record::from_str(document.path, document.media_type, &text))
+    }
+}
+
+/// The text after a leading `---` line, if it opens with one.
+fn strip_delimiter_line(text: &str) -> Option<&str> {
+    let rest = text.strip_prefix(DELIMITER)?;
+
+    rest.strip_prefix("\r\n")
+        .or_else(|| rest.strip_prefix('\n'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MediaType;
+    use crate::path::{FilePath, Path};
+
+    fn note(content: &str) -> Record {
+        Record::from_str(
+            Path::File(FilePath::parse("/notes/today.md")),
+            MediaType::TextMarkdown,
+            content,
+        )
+    }
+
+    #[test]
+    fn test_decode_front_matter() {
+        let codec = FrontMatterCodec::new();
+        let record = note("---\ntitle: Today\ntags: [a, b]\n---\n# Heading\n\ntext\n");
+
+        let document = codec.decode(record).unwrap();
+        let value = &document.content[0].content;
+
+        assert_eq!(value["title"].as_str(), Some("Today"));
+        assert_eq!(value["tags"][1].as_str(), Some("b"));
+        assert_eq!(value["body"].as_str(), Some("# Heading\n\ntext\n"));
+    }
+
+    #[test]
+    fn test_decode_crlf_front_matter() {
+        let codec = FrontMatterCodec::new();
+        let record = note("---\r\ntitle: Today\r\n---\r\nbody\r\n");
+
+        let document = codec.decode(record).unwrap();
+        let value = &document.content[0].content;
+
+        assert_eq!(value["title"].as_str(), Some("Today"));
+        assert_eq!(value["body"].as_str(), Some("body\r\n"));
+    }
+
+    #[test]
+    fn test_decode_without_front_matter() {
+        let codec = FrontMatterCodec::new();
+        let record = note("# Heading\n---\nnot front matter\n");
+
+        let document = codec.decode(record).unwrap();
+        let value = document.content[0].content.as_object().unwrap();
+
+        assert_eq!(value.len(), 1);
+        assert_eq!(
+            value["body"].as_str(),
+            Some("# Heading\n---\nnot front matter\n")
+        );
+    }
+
+    #[test]
+    fn test_decode_unterminated_block() {
+        let codec = FrontMatterCodec::new();
+        let err = codec
+            .decode(note("---\ntitle: Today\n# Heading\n"))
+            .unwrap_err();
+
+        assert!(err.is_parse());
+        assert!(
+            err.to_string().contains("unterminated front matter"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_decode_malformed_block() {
+        let codec = FrontMatterCodec::new();
+        let err = codec
+            .decode(note("---\ntitle: Today\ntags: [a, b\n---\nbody\n"))
+            .unwrap_err();
+
+        assert!(err.is_parse());
+        assert!(err.line().is_some_and(|line| line >= 3), "{}", err);
+    }
+
+    #[test]
+    fn test_decode_rejects_non_mapping() {
+        let codec = FrontMatterCodec::new();
+        let err = codec
+            .decode(note("---\n- a\n- b\n---\nbody\n"))
+            .unwrap_err();
+
+        assert!(err.to_string().contains("must be a mapping"), "{}", err);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let codec = FrontMatterCodec::new();
+        let original = "---\nlevel: 2\ntitle: Today\n---\n# Heading\n";
+
+        let document = codec.decode(note(original)).unwrap();
+        let record = codec.encode(document.clone()).unwrap();
+
+        assert_eq!(record.content_str().unwrap(), original);
+        assert_eq!(codec.decode(record).unwrap(), document);
+    }
+
+    #[test]
+    fn test_crlf_roundtrip() {
+        let codec = FrontMatterCodec::new();
+        let original = "---\r\nlevel: 2\r\ntitle: Today\r\n---\r\n# Heading\r\n";
+
+        let document = codec.decode(note(original)).unwrap();
+        let record = codec.encode(document).unwrap();
+
+        assert_eq!(record.content_str().unwrap(), original);
+    }
+
+    #[test]
+    fn test_encode_body_only() {
+        let codec = FrontMatterCodec::new().with_body_key("text");
+        let document = codec.decode(note("just text\n")).unwrap();
+
+        assert_eq!(
+            document.content[0].content["text"].as_str(),
+            Some("just text\n")
+        );
+        assert_eq!(
+            codec.encode(document).unwrap().content_str().unwrap(),
+            "just text\n"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_media_type() {
+        let codec = FrontMatterCodec::new();
+        let record = Record::new(
+            Path::File(FilePath::parse("/image.png")),
+            MediaType::ImagePng,
+            vec![0x89],
+        );
+
+        assert!(codec.decode(record).unwrap_err().is_unsupported());
+    }
+}
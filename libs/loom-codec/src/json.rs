@@ -2,11 +2,12 @@ use crate::path::IdentPath;
 use crate::value::Value;
 use crate::{Document, Entity, Format, Record};
 
-use super::{Codec, CodecError};
+use super::{Codec, CodecError, Limits};
 
 #[derive(Debug, Clone)]
 pub struct JsonCodec {
     pub pretty_print: bool,
+    pub limits: Limits,
 }
 
 impl Default for JsonCodec {
@@ -19,17 +20,26 @@ impl JsonCodec {
     pub fn new() -> Self {
         Self {
             pretty_print: false,
+            limits: Limits::new(),
         }
     }
 
     pub fn pretty() -> Self {
-        Self { pretty_print: true }
+        Self {
+            pretty_print: true,
+            ..Self::new()
+        }
     }
 
     pub fn with_pretty_print(mut self, pretty: bool) -> Self {
         self.pretty_print = pretty;
         self
     }
+
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
 }
 
 impl Codec for JsonCodec {
@@ -37,14 +47,18 @@ impl Codec for JsonCodec {
         Format::Json
     }
 
-    fn decode(&self, record: Record) -> Result<Document, CodecError> {
+    fn decode(&self, mut record: Record) -> Result<Document, CodecError> {
         if record.media_type.format() != Format::Json {
             return Err(CodecError::UnsupportedMediaType(record.media_type));
         }
 
-        let text = String::from_utf8(record.content)?;
-        let json: serde_json::Value = serde_json::from_str(&text).map_err(CodecError::decode)?;
+        let text = crate::content_text(&mut record)?;
+        self.limits.check_json(&text)?;
+
+        let json: serde_json::Value =
+            serde_json::from_str(&text).map_err(|e| parse_error(Format::Json, e))?;
         let value: Value = json.into();
+        self.limits.check(&value)?;
 
         let entity = Entity::new(
             IdentPath::parse("root").expect("valid field path"),
@@ -77,6 +91,19 @@ impl Codec for JsonCodec {
     }
 }
 
+/// A `Parse` error at the position serde_json reports, without the
+/// "at line N column M" suffix it appends to its message.
+pub(crate) fn parse_error(format: Format, e: serde_json::Error) -> CodecError {
+    let message = e.to_string();
+    let suffix = format!(" at line {} column {}", e.line(), e.column());
+    let message = message.strip_suffix(&suffix).unwrap_or(&message);
+
+    match e.line() {
+        0 => CodecError::parse(format, message),
+        line => CodecError::parse(format, message).at(line, e.column()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,6 +130,22 @@ mod tests {
         assert_eq!(document.content[0].content["value"].as_int(), Some(42));
     }
 
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_decode_gzip_record() {
+        let codec = JsonCodec::new();
+        let path = Path::File(FilePath::parse("/test.json"));
+        let content = loom_io::Encoding::Gzip
+            .encode(br#"{"name": "test"}"#)
+            .unwrap();
+        let record =
+            Record::new(path, MediaType::TextJson, content).with_encoding(loom_io::Encoding::Gzip);
+
+        let document = codec.decode(record).unwrap();
+
+        assert_eq!(document.content[0].content["name"].as_str(), Some("test"));
+    }
+
     #[test]
     fn test_encode_json() {
         let codec = JsonCodec::new();
@@ -160,6 +203,56 @@ mod tests {
         assert!(text.contains('\n'));
     }
 
+    #[test]
+    fn test_decode_reports_position() {
+        let codec = JsonCodec::new();
+        let path = Path::File(FilePath::parse("/config.json"));
+        let record = Record::from_str(
+            path,
+            MediaType::TextJson,
+            "{\n  \"name\": \"test\",\n  \"value\": 42,\n}",
+        );
+
+        let err = codec.decode(record).unwrap_err();
+
+        assert!(err.is_parse());
+        assert_eq!(err.line(), Some(4));
+        assert_eq!(err.col(), Some(1));
+        assert_eq!(
+            err.to_string(),
+            "json parse error at line 4, column 1: trailing comma"
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_deep_nesting() {
+        let codec = JsonCodec::new();
+        let path = Path::File(FilePath::parse("/deep.json"));
+        let text = format!("{}{}", "[".repeat(10_000), "]".repeat(10_000));
+        let record = Record::from_str(path, MediaType::TextJson, &text);
+
+        let err = codec.decode(record).unwrap_err();
+
+        assert!(err.is_limit_exceeded(), "{}", err);
+        assert!(err.is_decode());
+        assert_eq!(err.to_string(), "limit exceeded: max_depth is 128");
+    }
+
+    #[test]
+    fn test_decode_rejects_long_array() {
+        let codec = JsonCodec::new().with_limits(Limits::new().with_max_length(100));
+        let path = Path::File(FilePath::parse("/long.json"));
+        let items = vec!["1"; 101].join(",");
+        let record = Record::from_str(
+            path,
+            MediaType::TextJson,
+            &format!("{{\"items\": [{}]}}", items),
+        );
+
+        let err = codec.decode(record).unwrap_err();
+        assert!(err.is_limit_exceeded(), "{}", err);
+    }
+
     #[test]
     fn test_unsupported_media_type() {
         let codec = JsonCodec::new();
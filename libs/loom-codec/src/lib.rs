@@ -1,9 +1,16 @@
 mod error;
+mod limits;
 mod registry;
 
 #[cfg(feature = "json")]
 mod json;
 
+#[cfg(feature = "json")]
+mod ndjson;
+
+#[cfg(feature = "yaml")]
+mod front_matter;
+
 #[cfg(feature = "yaml")]
 mod yaml;
 
@@ -12,12 +19,22 @@ mod toml;
 
 mod text;
 
+#[cfg(feature = "zstd")]
+mod zstd;
+
 pub use error::*;
+pub use limits::*;
 pub use registry::*;
 
 #[cfg(feature = "json")]
 pub use json::*;
 
+#[cfg(feature = "json")]
+pub use ndjson::*;
+
+#[cfg(feature = "yaml")]
+pub use front_matter::*;
+
 #[cfg(feature = "yaml")]
 pub use yaml::*;
 
@@ -26,6 +43,9 @@ pub use toml::*;
 
 pub use text::*;
 
+#[cfg(feature = "zstd")]
+pub use zstd::*;
+
 // Re-export types from dependencies
 pub use loom_core::{Format, MediaType, path, value};
 pub use loom_io::{Document, Entity, Record};
@@ -36,6 +56,17 @@ pub trait Codec: Send + Sync {
     fn encode(&self, document: Document) -> Result<Record, CodecError>;
 }
 
+/// The record's content as text, decompressed according to its
+/// `content_encoding` (see `Record::content_bytes`).
+pub(crate) fn content_text(record: &mut Record) -> Result<String, CodecError> {
+    let content = match record.content_encoding {
+        Some(encoding) if !encoding.is_identity() => record.content_bytes()?.into_owned(),
+        _ => std::mem::take(&mut record.content),
+    };
+
+    Ok(String::from_utf8(content)?)
+}
+
 #[macro_export]
 macro_rules! encode {
     ($value:expr) => {{
@@ -0,0 +1,175 @@
+use crate::value::Value;
+
+use super::CodecError;
+
+/// Bounds on decoded values, guarding against hostile input such as
+/// deeply nested or enormous documents fetched from a remote source.
+///
+/// `max_depth` counts nested arrays and objects (a scalar root is depth 0)
+/// and defaults to 128, serde_json's own recursion limit. `max_length` caps
+/// the items in any single array or object and is unbounded by default.
+/// Breaching either fails the decode with `CodecError::LimitExceeded`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub max_depth: usize,
+    pub max_length: Option<usize>,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Limits {
+    pub const DEFAULT_MAX_DEPTH: usize = 128;
+
+    pub fn new() -> Self {
+        Self {
+            max_depth: Self::DEFAULT_MAX_DEPTH,
+            max_length: None,
+        }
+    }
+
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Check a decoded value against the limits. Walks the value with an
+    /// explicit stack, so checking can't overflow on deep input.
+    pub fn check(&self, value: &Value) -> Result<(), CodecError> {
+        let mut stack = vec![(value, 0)];
+
+        while let Some((value, depth)) = stack.pop() {
+            let len = match value {
+                Value::Array(items) => {
+                    stack.extend(items.iter().map(|item| (item, depth + 1)));
+                    items.len()
+                }
+                Value::Object(fields) => {
+                    stack.extend(fields.values().map(|field| (field, depth + 1)));
+                    fields.len()
+                }
+                _ => continue,
+            };
+
+            self.check_depth(depth + 1)?;
+            self.check_length(len)?;
+        }
+
+        Ok(())
+    }
+
+    /// Scan JSON text for breaches before parsing it, so hostile input is
+    /// rejected without building any of it. Only brackets outside strings
+    /// are counted; malformed text is left for the parser to report.
+    #[cfg(feature = "json")]
+    pub(crate) fn check_json(&self, text: &str) -> Result<(), CodecError> {
+        // Separators seen in each open array or object
+        let mut commas: Vec<usize> = Vec::new();
+        let mut in_string = false;
+        let mut escaped = false;
+
+        for b in text.bytes() {
+            if in_string {
+                match b {
+                    _ if escaped => escaped = false,
+                    b'\\' => escaped = true,
+                    b'"' => in_string = false,
+                    _ => {}
+                }
+
+                continue;
+            }
+
+            match b {
+                b'"' => in_string = true,
+                b'[' | b'{' => {
+                    commas.push(0);
+                    self.check_depth(commas.len())?;
+                }
+                b']' | b'}' => {
+                    commas.pop();
+                }
+                b',' => {
+                    if let Some(count) = commas.last_mut() {
+                        *count += 1;
+                        self.check_length(*count + 1)?;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_depth(&self, depth: usize) -> Result<(), CodecError> {
+        match depth > self.max_depth {
+            true => Err(CodecError::LimitExceeded {
+                limit: "max_depth",
+                max: self.max_depth,
+            }),
+            false => Ok(()),
+        }
+    }
+
+    pub(crate) fn check_length(&self, len: usize) -> Result<(), CodecError> {
+        match self.max_length {
+            Some(max) if len > max => Err(CodecError::LimitExceeded {
+                limit: "max_length",
+                max,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_depth() {
+        let value = Value::from(serde_json::json!({"a": [[1]]}));
+
+        assert!(Limits::new().with_max_depth(3).check(&value).is_ok());
+
+        let err = Limits::new().with_max_depth(2).check(&value).unwrap_err();
+        assert!(err.is_limit_exceeded());
+        assert_eq!(err.to_string(), "limit exceeded: max_depth is 2");
+
+        assert!(
+            Limits::new()
+                .with_max_depth(0)
+                .check(&Value::from(1))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_check_length() {
+        let value = Value::from(serde_json::json!({"a": [1, 2, 3], "b": 4}));
+
+        assert!(Limits::new().with_max_length(3).check(&value).is_ok());
+
+        let err = Limits::new().with_max_length(2).check(&value).unwrap_err();
+        assert!(err.is_limit_exceeded());
+        assert_eq!(err.to_string(), "limit exceeded: max_length is 2");
+    }
+
+    #[test]
+    fn test_check_json_skips_strings() {
+        let limits = Limits::new().with_max_depth(1).with_max_length(2);
+
+        assert!(limits.check_json(r#"{"a": "[[[,,,\"{{"}"#).is_ok());
+        assert!(limits.check_json(r#"[[1]]"#).is_err());
+        assert!(limits.check_json(r#"[1, 2, 3]"#).is_err());
+    }
+}
@@ -0,0 +1,201 @@
+use crate::path::IdentPath;
+use crate::value::Value;
+use crate::{Document, Entity, Format, Record};
+
+use super::json::parse_error;
+use super::{Codec, CodecError, Limits};
+
+/// Newline-delimited JSON codec.
+///
+/// Each non-blank line is an independent JSON value. Decoding yields a
+/// `Value::Array` with one element per line; encoding writes each element
+/// of the array as compact JSON on its own line. A malformed line fails the
+/// whole decode with its 1-based line number. `Limits` apply to each line,
+/// with `max_length` also capping the number of lines.
+#[derive(Debug, Clone, Default)]
+pub struct NdJsonCodec {
+    pub limits: Limits,
+}
+
+impl NdJsonCodec {
+    pub fn new() -> Self {
+        Self {
+            limits: Limits::new(),
+        }
+    }
+
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+}
+
+impl Codec for NdJsonCodec {
+    fn format(&self) -> Format {
+        Format::NdJson
+    }
+
+    fn decode(&self, mut record: Record) -> Result<Document, CodecError> {
+        if record.media_type.format() != Format::NdJson {
+            return Err(CodecError::UnsupportedMediaType(record.media_type));
+        }
+
+        let text = crate::content_text(&mut record)?;
+        let mut values = Vec::new();
+
+        for (i, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            self.limits.check_json(line)?;
+
+            let json: serde_json::Value = serde_json::from_str(line).map_err(|e| {
+                let col = e.column();
+                parse_error(Format::NdJson, e).at(i + 1, col)
+            })?;
+
+            let value = Value::from(json);
+            self.limits.check(&value)?;
+            values.push(value);
+            self.limits.check_length(values.len())?;
+        }
+
+        let entity = Entity::new(
+            IdentPath::parse("root").expect("valid field path"),
+            record.media_type.as_mime_str(),
+            Value::Array(values.into()),
+        );
+
+        Ok(Document::new(record.path, record.media_type, vec![entity]))
+    }
+
+    fn encode(&self, document: Document) -> Result<Record, CodecError> {
+        if document.media_type.format() != Format::NdJson {
+            return Err(CodecError::UnsupportedMediaType(document.media_type));
+        }
+
+        let content = document
+            .content
+            .first()
+            .ok_or_else(|| CodecError::Encode("document has no content".to_string()))?;
+
+        let items = content
+            .content
+            .as_array()
+            .ok_or_else(|| CodecError::Encode("content is not an array".to_string()))?;
+
+        let mut text = String::new();
+        for item in items.iter() {
+            let json: serde_json::Value = item.into();
+            text.push_str(&serde_json::to_string(&json).map_err(CodecError::encode)?);
+            text.push('\n');
+        }
+
+        Ok(Record::from_str(document.path, document.media_type, &text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MediaType;
+    use crate::path::FilePath;
+    use crate::path::Path;
+
+    fn ndjson_record(content: &str) -> Record {
+        Record::from_str(
+            Path::File(FilePath::parse("/samples.ndjson")),
+            MediaType::ApplicationNdjson,
+            content,
+        )
+    }
+
+    #[test]
+    fn test_decode_ndjson() {
+        let codec = NdJsonCodec::new();
+        let record = ndjson_record("{\"id\":1,\"text\":\"a\"}\r\n\n{\"id\":2,\"text\":\"b\"}\n3\n");
+
+        let document = codec.decode(record).unwrap();
+        let items = document.content[0].content.as_array().unwrap();
+
+        assert_eq!(items.len(), 3);
+        assert_eq!(items[0]["id"].as_int(), Some(1));
+        assert_eq!(items[1]["text"].as_str(), Some("b"));
+        assert_eq!(items[2].as_int(), Some(3));
+    }
+
+    #[test]
+    fn test_decode_reports_malformed_line() {
+        let codec = NdJsonCodec::new();
+        let record = ndjson_record("{\"id\":1}\n{\"id\":2}\n{\"id\":\n{\"id\":4}\n");
+
+        let err = codec.decode(record).unwrap_err();
+
+        assert!(err.is_parse());
+        assert_eq!(err.line(), Some(3));
+        assert!(err.to_string().contains("line 3"), "{}", err);
+    }
+
+    #[test]
+    fn test_decode_enforces_limits() {
+        let codec = NdJsonCodec::new().with_limits(Limits::new().with_max_length(2));
+
+        assert!(
+            codec
+                .decode(ndjson_record("{\"id\":1}\n{\"id\":2}\n"))
+                .is_ok()
+        );
+
+        let err = codec
+            .decode(ndjson_record("{\"id\":1}\n{\"id\":2}\n{\"id\":3}\n"))
+            .unwrap_err();
+        assert!(err.is_limit_exceeded(), "{}", err);
+
+        let err = NdJsonCodec::new()
+            .decode(ndjson_record(&"[".repeat(10_000)))
+            .unwrap_err();
+        assert!(err.is_limit_exceeded(), "{}", err);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let codec = NdJsonCodec::new();
+        let original = "{\"id\":1,\"tags\":[\"x\",\"y\"]}\n{\"id\":2,\"tags\":[]}\n";
+
+        let document = codec.decode(ndjson_record(original)).unwrap();
+        let record = codec.encode(document.clone()).unwrap();
+
+        assert_eq!(record.content_str().unwrap(), original);
+        assert_eq!(codec.decode(record).unwrap(), document);
+    }
+
+    #[test]
+    fn test_encode_requires_array() {
+        let codec = NdJsonCodec::new();
+        let entity = Entity::new(
+            IdentPath::parse("root").unwrap(),
+            "application/x-ndjson",
+            Value::from("not rows"),
+        );
+        let document = Document::new(
+            Path::File(FilePath::parse("/samples.ndjson")),
+            MediaType::ApplicationNdjson,
+            vec![entity],
+        );
+
+        assert!(codec.encode(document).unwrap_err().is_encode());
+    }
+
+    #[test]
+    fn test_unsupported_media_type() {
+        let codec = NdJsonCodec::new();
+        let record = Record::from_str(
+            Path::File(FilePath::parse("/test.json")),
+            MediaType::TextJson,
+            "{}",
+        );
+
+        assert!(codec.decode(record).unwrap_err().is_unsupported());
+    }
+}
@@ -2,7 +2,8 @@ use std::collections::HashMap;
 
 use loom_core::Format;
 
-use super::Codec;
+use super::{Codec, CodecError};
+use crate::{Document, Record};
 
 pub struct CodecRegistry {
     codecs: HashMap<Format, Box<dyn Codec>>,
@@ -28,6 +29,22 @@ impl CodecRegistry {
     pub fn get(&self, format: Format) -> Option<&dyn Codec> {
         self.codecs.get(&format).map(|c| c.as_ref())
     }
+
+    /// Decode with the codec registered for the record's format.
+    pub fn decode(&self, record: Record) -> Result<Document, CodecError> {
+        let format = record.media_type.format();
+        self.get(format)
+            .ok_or(CodecError::UnsupportedFormat(format))?
+            .decode(record)
+    }
+
+    /// Encode with the codec registered for the document's format.
+    pub fn encode(&self, document: Document) -> Result<Record, CodecError> {
+        let format = document.media_type.format();
+        self.get(format)
+            .ok_or(CodecError::UnsupportedFormat(format))?
+            .encode(document)
+    }
 }
 
 #[derive(Default)]
@@ -51,3 +68,26 @@ impl CodecRegistryBuilder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MediaType;
+    use crate::TextCodec;
+    use crate::path::{FilePath, Path};
+
+    #[test]
+    fn test_decode_unregistered_format() {
+        let registry = CodecRegistry::new().codec(TextCodec::new()).build();
+        let record = Record::from_str(
+            Path::File(FilePath::parse("/config.toml")),
+            MediaType::TextToml,
+            "a = 1",
+        );
+
+        let err = registry.decode(record).unwrap_err();
+
+        assert!(matches!(err, CodecError::UnsupportedFormat(Format::Toml)));
+        assert_eq!(err.to_string(), "unsupported format: toml");
+    }
+}
@@ -4,12 +4,77 @@ use crate::{Document, Entity, Format, Record};
 
 use super::{Codec, CodecError};
 
-#[derive(Debug, Clone, Default)]
-pub struct TextCodec;
+/// Plain text codec.
+///
+/// By default a record is one `Value::String`. In line mode (`lines()`) it
+/// decodes to a `Value::Array` of lines as split by `str::lines`, so LF and
+/// CRLF input decode the same and a final newline does not produce an empty
+/// last line. Encoding normalizes to `\n`, joining the lines with it and,
+/// unless `with_trailing_newline(false)`, ending with one.
+#[derive(Debug, Clone)]
+pub struct TextCodec {
+    line_mode: bool,
+    trailing_newline: bool,
+}
+
+impl Default for TextCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl TextCodec {
     pub fn new() -> Self {
-        Self
+        Self {
+            line_mode: false,
+            trailing_newline: true,
+        }
+    }
+
+    pub fn lines() -> Self {
+        Self {
+            line_mode: true,
+            ..Self::new()
+        }
+    }
+
+    pub fn with_line_mode(mut self, line_mode: bool) -> Self {
+        self.line_mode = line_mode;
+        self
+    }
+
+    pub fn with_trailing_newline(mut self, trailing_newline: bool) -> Self {
+        self.trailing_newline = trailing_newline;
+        self
+    }
+
+    fn split_lines(text: &str) -> Value {
+        let lines: Vec<Value> = text.lines().map(Value::from).collect();
+        Value::Array(lines.into())
+    }
+
+    fn join_lines(&self, value: &Value) -> Result<String, CodecError> {
+        let lines = value
+            .as_array()
+            .ok_or_else(|| CodecError::Encode("content is not an array of lines".to_string()))?;
+
+        let mut text = String::new();
+        for (i, line) in lines.iter().enumerate() {
+            let line = line
+                .as_str()
+                .ok_or_else(|| CodecError::Encode(format!("line {} is not a string", i)))?;
+
+            if i > 0 {
+                text.push('\n');
+            }
+            text.push_str(line);
+        }
+
+        if self.trailing_newline && !lines.is_empty() {
+            text.push('\n');
+        }
+
+        Ok(text)
     }
 }
 
@@ -18,16 +83,21 @@ impl Codec for TextCodec {
         Format::Text
     }
 
-    fn decode(&self, record: Record) -> Result<Document, CodecError> {
+    fn decode(&self, mut record: Record) -> Result<Document, CodecError> {
         if record.media_type.format() != Format::Text {
             return Err(CodecError::UnsupportedMediaType(record.media_type));
         }
 
-        let text = String::from_utf8(record.content)?;
+        let text = crate::content_text(&mut record)?;
+        let value = match self.line_mode {
+            true => Self::split_lines(&text),
+            false => Value::String(text),
+        };
+
         let entity = Entity::new(
             IdentPath::parse("root").expect("valid field path"),
             record.media_type.as_mime_str(),
-            Value::String(text),
+            value,
         );
 
         Ok(Document::new(record.path, record.media_type, vec![entity]))
@@ -43,6 +113,11 @@ impl Codec for TextCodec {
             .first()
             .ok_or_else(|| CodecError::Encode("document has no content".to_string()))?;
 
+        if self.line_mode {
+            let text = self.join_lines(&content.content)?;
+            return Ok(Record::from_str(document.path, document.media_type, &text));
+        }
+
         let text = content
             .content
             .as_str()
@@ -109,4 +184,86 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().is_unsupported());
     }
+
+    fn text_record(content: &str) -> Record {
+        Record::from_str(
+            Path::File(FilePath::parse("/app.log")),
+            MediaType::TextPlain,
+            content,
+        )
+    }
+
+    fn decoded_lines(codec: &TextCodec, content: &str) -> Vec<String> {
+        let document = codec.decode(text_record(content)).unwrap();
+        document.content[0]
+            .content
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|line| line.as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_lines_decode_lf_and_crlf_alike() {
+        let codec = TextCodec::lines();
+
+        assert_eq!(
+            decoded_lines(&codec, "a\nb\n\nc\n"),
+            vec!["a", "b", "", "c"]
+        );
+        assert_eq!(
+            decoded_lines(&codec, "a\r\nb\r\n\r\nc\r\n"),
+            vec!["a", "b", "", "c"]
+        );
+        assert_eq!(decoded_lines(&codec, "a\nb"), vec!["a", "b"]);
+        assert!(decoded_lines(&codec, "").is_empty());
+    }
+
+    #[test]
+    fn test_lines_encode_trailing_newline_toggle() {
+        let with = TextCodec::lines();
+        let without = TextCodec::lines().with_trailing_newline(false);
+
+        let document = with.decode(text_record("a\r\nb\r\n")).unwrap();
+
+        assert_eq!(
+            with.encode(document.clone())
+                .unwrap()
+                .content_str()
+                .unwrap(),
+            "a\nb\n"
+        );
+        assert_eq!(
+            without.encode(document).unwrap().content_str().unwrap(),
+            "a\nb"
+        );
+    }
+
+    #[test]
+    fn test_lines_crlf_roundtrip() {
+        let codec = TextCodec::lines();
+        let document = codec.decode(text_record("a\r\n\r\nb\r\n")).unwrap();
+
+        let record = codec.encode(document.clone()).unwrap();
+        assert_eq!(record.content_str().unwrap(), "a\n\nb\n");
+        assert_eq!(codec.decode(record).unwrap(), document);
+    }
+
+    #[test]
+    fn test_lines_encode_rejects_non_string_lines() {
+        let codec = TextCodec::lines();
+        let entity = Entity::new(
+            IdentPath::parse("root").unwrap(),
+            "text/plain",
+            Value::Array(vec![Value::from("a"), Value::from(1i64)].into()),
+        );
+        let document = Document::new(
+            Path::File(FilePath::parse("/app.log")),
+            MediaType::TextPlain,
+            vec![entity],
+        );
+
+        assert!(codec.encode(document).is_err());
+    }
 }
@@ -2,11 +2,20 @@ use crate::path::IdentPath;
 use crate::value::Value;
 use crate::{Document, Entity, Format, Record};
 
-use super::{Codec, CodecError};
+use super::{Codec, CodecError, Limits, position};
+
+/// Key of the single-entry object a TOML datetime decodes into, e.g.
+/// `{"$datetime": "1979-05-27T07:32:00-08:00"}`.
+///
+/// `Value` has no datetime type, so the tag is what lets `encode` write the
+/// value back as a datetime literal instead of a quoted string.
+pub const TOML_DATETIME_KEY: &str = "$datetime";
 
 #[derive(Debug, Clone)]
 pub struct TomlCodec {
     pub pretty_print: bool,
+    pub limits: Limits,
+    pub tag_datetimes: bool,
 }
 
 impl Default for TomlCodec {
@@ -19,17 +28,73 @@ impl TomlCodec {
     pub fn new() -> Self {
         Self {
             pretty_print: false,
+            limits: Limits::new(),
+            tag_datetimes: false,
         }
     }
 
     pub fn pretty() -> Self {
-        Self { pretty_print: true }
+        Self {
+            pretty_print: true,
+            ..Self::new()
+        }
     }
 
     pub fn with_pretty_print(mut self, pretty: bool) -> Self {
         self.pretty_print = pretty;
         self
     }
+
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Decode datetimes as `TOML_DATETIME_KEY` objects so `encode` writes
+    /// them back as datetime literals. Off by default, when datetimes
+    /// decode as plain strings and encode back as strings.
+    pub fn with_tagged_datetimes(mut self, tag: bool) -> Self {
+        self.tag_datetimes = tag;
+        self
+    }
+}
+
+/// Replace every datetime in `value` with a `TOML_DATETIME_KEY` table.
+fn tag_datetimes(value: &mut toml::Value) {
+    match value {
+        toml::Value::Datetime(dt) => {
+            let mut table = toml::Table::new();
+            table.insert(
+                TOML_DATETIME_KEY.to_string(),
+                toml::Value::String(dt.to_string()),
+            );
+            *value = toml::Value::Table(table);
+        }
+        toml::Value::Array(arr) => arr.iter_mut().for_each(tag_datetimes),
+        toml::Value::Table(table) => table.iter_mut().for_each(|(_, v)| tag_datetimes(v)),
+        _ => {}
+    }
+}
+
+/// Turn `TOML_DATETIME_KEY` tables back into datetimes. Tables whose tag
+/// isn't a valid datetime are left as they are.
+fn untag_datetimes(value: &mut toml::Value) {
+    match value {
+        toml::Value::Table(table) if table.len() == 1 => {
+            let datetime = match table.get(TOML_DATETIME_KEY) {
+                Some(toml::Value::String(s)) => s.parse::<toml::value::Datetime>().ok(),
+                _ => None,
+            };
+
+            match datetime {
+                Some(dt) => *value = toml::Value::Datetime(dt),
+                None => table.iter_mut().for_each(|(_, v)| untag_datetimes(v)),
+            }
+        }
+        toml::Value::Table(table) => table.iter_mut().for_each(|(_, v)| untag_datetimes(v)),
+        toml::Value::Array(arr) => arr.iter_mut().for_each(untag_datetimes),
+        _ => {}
+    }
 }
 
 impl Codec for TomlCodec {
@@ -37,14 +102,30 @@ impl Codec for TomlCodec {
         Format::Toml
     }
 
-    fn decode(&self, record: Record) -> Result<Document, CodecError> {
+    fn decode(&self, mut record: Record) -> Result<Document, CodecError> {
         if record.media_type.format() != Format::Toml {
             return Err(CodecError::UnsupportedMediaType(record.media_type));
         }
 
-        let text = String::from_utf8(record.content)?;
-        let toml_val: toml::Value = toml::from_str(&text).map_err(CodecError::decode)?;
+        let text = crate::content_text(&mut record)?;
+        let mut toml_val: toml::Value = toml::from_str(&text).map_err(|e| {
+            let err = CodecError::parse(Format::Toml, e.message().trim_end());
+
+            match e.span() {
+                Some(span) => {
+                    let (line, col) = position(&text, span.start);
+                    err.at(line, col)
+                }
+                None => err,
+            }
+        })?;
+
+        if self.tag_datetimes {
+            tag_datetimes(&mut toml_val);
+        }
+
         let value: Value = toml_val.into();
+        self.limits.check(&value)?;
 
         let entity = Entity::new(
             IdentPath::parse("root").expect("valid field path"),
@@ -65,7 +146,9 @@ impl Codec for TomlCodec {
             .first()
             .ok_or_else(|| CodecError::Encode("document has no content".to_string()))?;
 
-        let toml_val: toml::Value = (&content.content).into();
+        let mut toml_val: toml::Value = (&content.content).into();
+        untag_datetimes(&mut toml_val);
+
         let text = if self.pretty_print {
             toml::to_string_pretty(&toml_val)
         } else {
@@ -136,12 +219,76 @@ mod tests {
 
         // Re-decode to verify
         let path2 = Path::File(FilePath::parse("/test.toml"));
-        let record2 = Record::from_str(path2, MediaType::TextToml, text);
+        let record2 = Record::from_str(path2, MediaType::TextToml, &text);
         let doc2 = codec.decode(record2).unwrap();
 
         assert_eq!(doc2.content[0].content["test"].as_int(), Some(123));
     }
 
+    #[test]
+    fn test_roundtrip_datetimes() {
+        let codec = TomlCodec::new().with_tagged_datetimes(true);
+        let path = Path::File(FilePath::parse("/test.toml"));
+        let original = Record::from_str(
+            path,
+            MediaType::TextToml,
+            "created = 1979-05-27T07:32:00-08:00\nday = 1979-05-27\nname = \"2024-01-01\"\n",
+        );
+
+        let document = codec.decode(original).unwrap();
+        let created = &document.content[0].content["created"];
+        assert_eq!(
+            created[TOML_DATETIME_KEY].as_str(),
+            Some("1979-05-27T07:32:00-08:00")
+        );
+
+        let record = codec.encode(document).unwrap();
+        let text = record.content_str().unwrap();
+
+        assert!(
+            text.contains("created = 1979-05-27T07:32:00-08:00"),
+            "{}",
+            text
+        );
+        assert!(text.contains("day = 1979-05-27\n"), "{}", text);
+        assert!(text.contains("name = \"2024-01-01\""), "{}", text);
+    }
+
+    #[test]
+    fn test_datetimes_decode_as_strings_by_default() {
+        let codec = TomlCodec::new();
+        let path = Path::File(FilePath::parse("/test.toml"));
+        let record = Record::from_str(
+            path,
+            MediaType::TextToml,
+            "created = 1979-05-27T07:32:00-08:00",
+        );
+
+        let document = codec.decode(record).unwrap();
+
+        assert_eq!(
+            document.content[0].content["created"].as_str(),
+            Some("1979-05-27T07:32:00-08:00")
+        );
+    }
+
+    #[test]
+    fn test_decode_reports_position() {
+        let codec = TomlCodec::new();
+        let path = Path::File(FilePath::parse("/config.toml"));
+        let record = Record::from_str(path, MediaType::TextToml, "name = \"test\"\nvalue = \n");
+
+        let err = codec.decode(record).unwrap_err();
+
+        assert!(err.is_parse());
+        assert_eq!(err.line(), Some(2));
+        assert!(
+            err.to_string().starts_with("toml parse error at line 2"),
+            "{}",
+            err
+        );
+    }
+
     #[test]
     fn test_unsupported_media_type() {
         let codec = TomlCodec::new();
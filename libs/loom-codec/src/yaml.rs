@@ -1,18 +1,92 @@
+use std::sync::Arc;
+
+use loom_signal::{Emitter, Level, Signal};
 use saphyr::{Yaml, YamlEmitter};
+use saphyr_parser::{Event, Parser};
 
-use crate::path::IdentPath;
+use crate::path::{IdentPath, Path};
 use crate::value::Value;
 use crate::{Document, Entity, Format, Record};
 
-use super::{Codec, CodecError};
+use super::{Codec, CodecError, Limits};
 
-#[derive(Debug, Clone, Default)]
-pub struct YamlCodec;
+/// Decodes and encodes YAML documents.
+///
+/// Anchors and aliases are expanded on decode: every alias becomes its own
+/// copy of the anchored node, and `encode` writes each copy out in full, so
+/// the sharing is lost and output can grow. With an emitter set, decoding a
+/// document that uses aliases emits a `yaml.aliases_expanded` warning.
+#[derive(Clone, Default)]
+pub struct YamlCodec {
+    pub limits: Limits,
+    emitter: Option<Arc<dyn Emitter + Send + Sync>>,
+}
 
 impl YamlCodec {
     pub fn new() -> Self {
-        Self
+        Self {
+            limits: Limits::new(),
+            emitter: None,
+        }
+    }
+
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Emit a warning signal to `emitter` when a decoded document had
+    /// aliases expanded.
+    pub fn with_emitter(mut self, emitter: impl Emitter + Send + Sync + 'static) -> Self {
+        self.emitter = Some(Arc::new(emitter));
+        self
     }
+
+    fn warn_aliases(&self, path: &Path, text: &str) {
+        let Some(emitter) = &self.emitter else {
+            return;
+        };
+
+        // Cheap check before re-parsing: every alias starts with `*`
+        if !text.contains('*') {
+            return;
+        }
+
+        let aliases = count_aliases(text);
+
+        if aliases > 0 {
+            Signal::event("yaml.aliases_expanded")
+                .level(Level::Warn)
+                .field("path", path.to_string())
+                .field("aliases", aliases as u64)
+                .field(
+                    "message",
+                    format!(
+                        "{} yaml aliases in '{}' were expanded and will be written out in full on encode",
+                        aliases, path
+                    ),
+                )
+                .emit(emitter.as_ref());
+        }
+    }
+}
+
+impl std::fmt::Debug for YamlCodec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlCodec")
+            .field("limits", &self.limits)
+            .field("emitter", &self.emitter.is_some())
+            .finish()
+    }
+}
+
+/// Number of alias nodes in the first document of `text`.
+fn count_aliases(text: &str) -> usize {
+    Parser::new_from_str(text)
+        .map_while(Result::ok)
+        .take_while(|(event, _)| !matches!(event, Event::DocumentEnd))
+        .filter(|(event, _)| matches!(event, Event::Alias(_)))
+        .count()
 }
 
 impl Codec for YamlCodec {
@@ -20,15 +94,21 @@ impl Codec for YamlCodec {
         Format::Yaml
     }
 
-    fn decode(&self, record: Record) -> Result<Document, CodecError> {
+    fn decode(&self, mut record: Record) -> Result<Document, CodecError> {
         if record.media_type.format() != Format::Yaml {
             return Err(CodecError::UnsupportedMediaType(record.media_type));
         }
 
-        let text = String::from_utf8(record.content)?;
-        let docs = Yaml::load_from_str(&text).map_err(|e| CodecError::Decode(e.to_string()))?;
+        let text = crate::content_text(&mut record)?;
+        let docs = Yaml::load_from_str(&text).map_err(|e| {
+            CodecError::parse(Format::Yaml, e.info()).at(e.marker().line(), e.marker().col() + 1)
+        })?;
+        self.warn_aliases(&record.path, &text);
+
         let yaml = docs.into_iter().next().unwrap_or(Yaml::Null);
         let value = Value::from(yaml);
+        self.limits.check(&value)?;
+
         let entity = Entity::new(
             IdentPath::parse("root").expect("valid field path"),
             record.media_type.as_mime_str(),
@@ -70,6 +150,7 @@ mod tests {
     use crate::path::FilePath;
     use crate::path::Path;
     use crate::value::Object;
+    use loom_signal::consumers::MemoryEmitter;
 
     #[test]
     fn test_decode_yaml() {
@@ -118,12 +199,85 @@ mod tests {
 
         // Re-decode to verify
         let path2 = Path::File(FilePath::parse("/test.yaml"));
-        let record2 = Record::from_str(path2, MediaType::TextYaml, text);
+        let record2 = Record::from_str(path2, MediaType::TextYaml, &text);
         let doc2 = codec.decode(record2).unwrap();
 
         assert_eq!(doc2.content[0].content["test"].as_int(), Some(123));
     }
 
+    #[test]
+    fn test_decode_reports_position() {
+        let codec = YamlCodec::new();
+        let path = Path::File(FilePath::parse("/config.yaml"));
+        let record = Record::from_str(path, MediaType::TextYaml, "name: test\nvalue: [1, 2\n");
+
+        let err = codec.decode(record).unwrap_err();
+
+        assert!(err.is_parse());
+        assert!(err.line().is_some());
+        assert!(
+            err.to_string().starts_with("yaml parse error at line"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_decode_enforces_limits() {
+        let path = Path::File(FilePath::parse("/config.yaml"));
+        let text = "a:\n  b:\n    c: [1, 2, 3]\n";
+
+        let codec = YamlCodec::new().with_limits(Limits::new().with_max_depth(2));
+        let record = Record::from_str(path.clone(), MediaType::TextYaml, text);
+        let err = codec.decode(record).unwrap_err();
+        assert!(err.is_limit_exceeded(), "{}", err);
+
+        let codec = YamlCodec::new().with_limits(Limits::new().with_max_length(2));
+        let record = Record::from_str(path, MediaType::TextYaml, text);
+        let err = codec.decode(record).unwrap_err();
+        assert_eq!(err.to_string(), "limit exceeded: max_length is 2");
+    }
+
+    #[test]
+    fn test_alias_expansion_emits_warning() {
+        let memory = MemoryEmitter::new();
+        let codec = YamlCodec::new().with_emitter(memory.clone());
+        let path = Path::File(FilePath::parse("/test.yaml"));
+        let record = Record::from_str(
+            path,
+            MediaType::TextYaml,
+            "defaults: &defaults\n  retries: 3\ndev: *defaults\nprod: *defaults\n",
+        );
+
+        let document = codec.decode(record).unwrap();
+
+        assert_eq!(
+            document.content[0].content["prod"]["retries"].as_int(),
+            Some(3)
+        );
+
+        let warnings = memory.find_by_name("yaml.aliases_expanded");
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].level(), Level::Warn);
+        assert_eq!(warnings[0].field("aliases").unwrap().as_int(), Some(2));
+        assert_eq!(
+            warnings[0].field("path").unwrap().as_str(),
+            Some("/test.yaml")
+        );
+    }
+
+    #[test]
+    fn test_no_warning_without_aliases() {
+        let memory = MemoryEmitter::new();
+        let codec = YamlCodec::new().with_emitter(memory.clone());
+        let path = Path::File(FilePath::parse("/test.yaml"));
+        let record = Record::from_str(path, MediaType::TextYaml, "name: \"*\"\nvalue: 42");
+
+        codec.decode(record).unwrap();
+
+        assert!(memory.is_empty());
+    }
+
     #[test]
     fn test_unsupported_media_type() {
         let codec = YamlCodec::new();
@@ -0,0 +1,161 @@
+use loom_io::Encoding;
+
+use crate::{Document, Format, Record};
+
+use super::{Codec, CodecError};
+
+/// Wraps a codec to store its records zstd-compressed.
+///
+/// Encoding compresses the inner codec's output and marks the record's
+/// `content_encoding` as `Zstd`, so `Record::content_str` still yields the
+/// inner text. Decoding decompresses records marked `Zstd` or unmarked ones
+/// (such as a `.json.zst` read from disk) before handing them to the inner
+/// codec; records marked `Identity` pass through as is.
+#[derive(Debug, Clone)]
+pub struct ZstdCodec<C: Codec> {
+    inner: C,
+    level: i32,
+}
+
+impl<C: Codec> ZstdCodec<C> {
+    /// zstd's own default compression level.
+    pub const DEFAULT_LEVEL: i32 = 3;
+
+    pub fn new(inner: C) -> Self {
+        Self {
+            inner,
+            level: Self::DEFAULT_LEVEL,
+        }
+    }
+
+    /// Compression level, from 1 (fastest) to 22 (smallest).
+    pub fn with_level(mut self, level: i32) -> Self {
+        self.level = level;
+        self
+    }
+
+    pub fn level(&self) -> i32 {
+        self.level
+    }
+
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+}
+
+impl<C: Codec> Codec for ZstdCodec<C> {
+    fn format(&self) -> Format {
+        self.inner.format()
+    }
+
+    fn decode(&self, mut record: Record) -> Result<Document, CodecError> {
+        match record.content_encoding {
+            Some(Encoding::Identity) => {}
+            None | Some(Encoding::Zstd) => {
+                record.content = zstd::decode_all(record.content.as_slice())
+                    .map_err(|e| CodecError::Decode(format!("invalid zstd content: {}", e)))?;
+                record.size = record.content.len();
+                record.content_encoding = None;
+            }
+            Some(encoding) => {
+                return Err(CodecError::Decode(format!(
+                    "expected zstd content, found {} content",
+                    encoding
+                )));
+            }
+        }
+
+        self.inner.decode(record)
+    }
+
+    fn encode(&self, document: Document) -> Result<Record, CodecError> {
+        let record = self.inner.encode(document)?;
+        let content =
+            zstd::encode_all(record.content.as_slice(), self.level).map_err(CodecError::encode)?;
+
+        Ok(Record::new(record.path, record.media_type, content).with_encoding(Encoding::Zstd))
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+    use crate::path::{FilePath, IdentPath, Path};
+    use crate::value::Value;
+    use crate::{Entity, JsonCodec, MediaType};
+
+    fn document() -> Document {
+        let mut rows = Vec::new();
+        for i in 0..50 {
+            rows.push(Value::from(format!("sample text number {}", i)));
+        }
+
+        Document::new(
+            Path::File(FilePath::parse("/samples.json")),
+            MediaType::TextJson,
+            vec![Entity::new(
+                IdentPath::parse("root").unwrap(),
+                "text/json",
+                Value::Array(rows.into()),
+            )],
+        )
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let codec = ZstdCodec::new(JsonCodec::new()).with_level(19);
+        let plain = JsonCodec::new().encode(document()).unwrap();
+
+        let record = codec.encode(document()).unwrap();
+
+        assert_eq!(record.content_encoding, Some(Encoding::Zstd));
+        assert!(record.content.len() < plain.content.len());
+        assert_eq!(record.content_str().unwrap(), plain.content_str().unwrap());
+        assert_eq!(
+            codec.decode(record).unwrap(),
+            JsonCodec::new().decode(plain).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_decode_unmarked_record() {
+        let codec = ZstdCodec::new(JsonCodec::new());
+        let mut record = codec.encode(document()).unwrap();
+        record.content_encoding = None;
+
+        let plain = JsonCodec::new().encode(document()).unwrap();
+        assert_eq!(
+            codec.decode(record).unwrap(),
+            JsonCodec::new().decode(plain).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_decode_corrupt_frame() {
+        let codec = ZstdCodec::new(JsonCodec::new());
+        let record = Record::new(
+            Path::File(FilePath::parse("/samples.json")),
+            MediaType::TextJson,
+            b"not a zstd frame".to_vec(),
+        )
+        .with_encoding(Encoding::Zstd);
+
+        let err = codec.decode(record).unwrap_err();
+
+        assert!(err.is_decode());
+        assert!(err.to_string().contains("invalid zstd content"), "{}", err);
+    }
+
+    #[test]
+    fn test_decode_rejects_other_encoding() {
+        let codec = ZstdCodec::new(JsonCodec::new());
+        let record = Record::new(
+            Path::File(FilePath::parse("/samples.json")),
+            MediaType::TextJson,
+            b"{}".to_vec(),
+        )
+        .with_encoding(Encoding::Gzip);
+
+        assert!(codec.decode(record).unwrap_err().is_decode());
+    }
+}
@@ -3,11 +3,20 @@ use loom_core::path::Path;
 use loom_core::value::{Object, Value};
 
 use super::providers::Provider;
-use super::{Config, ConfigError, Env};
+use super::{Config, ConfigError, ConfigOrigins, Env};
 
+/// Collects providers and merges them into a `Config`.
+///
+/// Providers are merged from lowest to highest precedence, so a key set by
+/// several providers takes the value of the last one merged. Precedence
+/// follows registration order (later wins) unless a provider is given an
+/// explicit `priority`; higher priorities merge later, and equal
+/// priorities keep registration order. Every provider starts at priority 0.
+/// A `with_defaults` tree sits under all of them.
 #[derive(Default)]
 pub struct ConfigBuilder {
-    providers: Vec<Box<dyn Provider>>,
+    defaults: Option<Value>,
+    providers: Vec<(i32, Box<dyn Provider>)>,
     env: Option<Env>,
     path: Option<Path>,
     format: Option<Format>,
@@ -19,7 +28,25 @@ impl ConfigBuilder {
     }
 
     pub fn with_provider<P: Provider + 'static>(mut self, provider: P) -> Self {
-        self.providers.push(Box::new(provider));
+        self.providers.push((0, Box::new(provider)));
+        self
+    }
+
+    /// Set the priority of the provider added last. Higher priorities take
+    /// precedence over lower ones regardless of registration order.
+    pub fn priority(mut self, priority: i32) -> Self {
+        if let Some((p, _)) = self.providers.last_mut() {
+            *p = priority;
+        }
+
+        self
+    }
+
+    /// Layer `defaults` under every provider, whatever its priority, so
+    /// providers only need to set the keys that differ. Keys it supplies
+    /// are reported with the origin `"defaults"`.
+    pub fn with_defaults(mut self, defaults: Value) -> Self {
+        self.defaults = Some(defaults);
         self
     }
 
@@ -38,16 +65,33 @@ impl ConfigBuilder {
         self
     }
 
-    pub fn build(self) -> Result<Config, ConfigError> {
+    pub fn build(mut self) -> Result<Config, ConfigError> {
         use super::ConfigSource;
 
+        // Stable, so equal priorities keep registration order
+        self.providers.sort_by_key(|(priority, _)| *priority);
+
         let env = self.env.unwrap_or_else(Env::from_env);
         let mut merged = Value::Object(Object::new());
         let mut sources = Vec::new();
+        let mut origins = ConfigOrigins::new();
+
+        if let Some(defaults) = self.defaults
+            && !defaults.is_null()
+        {
+            origins.record(&defaults, DEFAULTS_SOURCE);
+            merged.merge(defaults);
+            sources.push(ConfigSource {
+                name: DEFAULTS_SOURCE.to_string(),
+                path: Path::Empty,
+                format: Format::Json,
+            });
+        }
 
-        for provider in &self.providers {
+        for (_, provider) in &self.providers {
             match provider.load() {
                 Ok(Some(value)) => {
+                    origins.record(&value, provider.name());
                     merged.merge(value);
                     sources.push(ConfigSource {
                         name: provider.name().to_string(),
@@ -74,13 +118,18 @@ impl ConfigBuilder {
             format: self.format,
             data: merged,
             sources,
+            origins,
+            strict_numbers: false,
         })
     }
 }
 
+/// Source name of the tree passed to `ConfigBuilder::with_defaults`.
+pub const DEFAULTS_SOURCE: &str = "defaults";
+
 #[cfg(test)]
 mod tests {
-    use super::super::providers::MemoryProvider;
+    use super::super::providers::{EnvProvider, FileProvider, MemoryProvider};
     use super::*;
     use loom_core::path::{FilePath, IdentPath};
 
@@ -147,6 +196,117 @@ mod tests {
         assert!(config.env().is_dev());
     }
 
+    #[test]
+    fn test_builder_tracks_origins() {
+        let config = Config::new()
+            .with_provider(MemoryProvider::from_pairs([
+                ("database.host", "localhost"),
+                ("database.port", "5432"),
+            ]))
+            .with_provider(FileProvider::builder("missing.json").optional(true).build())
+            .build()
+            .unwrap();
+
+        let path = IdentPath::parse("database.port").unwrap();
+        assert_eq!(config.origin(&path), Some("memory"));
+        assert_eq!(config.origins().len(), 2);
+    }
+
+    #[test]
+    fn test_builder_env_override_origin() {
+        let env = EnvProvider::from_vars(
+            Some("LOOM_"),
+            [("LOOM_DATABASE_HOST".to_string(), "envhost".to_string())],
+        );
+
+        let config = Config::new()
+            .with_provider(MemoryProvider::from_pairs([
+                ("database.host", "localhost"),
+                ("database.port", "5432"),
+            ]))
+            .with_provider(env)
+            .build()
+            .unwrap();
+
+        let host = IdentPath::parse("database.host").unwrap();
+        assert_eq!(config.get_str(&host), Some("envhost"));
+        assert_eq!(config.origin(&host), Some("environment"));
+
+        let port = IdentPath::parse("database.port").unwrap();
+        assert_eq!(config.origin(&port), Some("memory"));
+    }
+
+    #[test]
+    fn test_builder_defaults_lowest_precedence() {
+        let defaults = MemoryProvider::from_pairs([
+            ("eval.threshold", Value::from(0.75)),
+            ("eval.top_k", Value::from(2)),
+        ])
+        .load()
+        .unwrap()
+        .unwrap();
+
+        let config = Config::new()
+            .with_defaults(defaults)
+            .with_provider(MemoryProvider::from_pairs([("eval.threshold", 0.9)]))
+            .priority(-10)
+            .build()
+            .unwrap();
+
+        let threshold = IdentPath::parse("eval.threshold").unwrap();
+        let top_k = IdentPath::parse("eval.top_k").unwrap();
+
+        assert_eq!(config.get_float(&threshold), Some(0.9));
+        assert_eq!(config.origin(&threshold), Some("memory"));
+        assert_eq!(config.get_int(&top_k), Some(2));
+        assert_eq!(config.origin(&top_k), Some(DEFAULTS_SOURCE));
+        assert_eq!(config.provider_order(), vec![DEFAULTS_SOURCE, "memory"]);
+        assert_eq!(config.sources()[0].format, Format::Json);
+    }
+
+    #[test]
+    fn test_builder_priority_overrides_registration_order() {
+        let env = EnvProvider::from_vars(
+            Some("LOOM_"),
+            [("LOOM_DATABASE_HOST".to_string(), "envhost".to_string())],
+        );
+
+        let config = Config::new()
+            .with_provider(env)
+            .priority(10)
+            .with_provider(MemoryProvider::from_pairs([("database.host", "localhost")]))
+            .build()
+            .unwrap();
+
+        let path = IdentPath::parse("database.host").unwrap();
+        assert_eq!(config.provider_order(), vec!["memory", "environment"]);
+        assert_eq!(
+            config.get_with_source(&path),
+            Some((Value::from("envhost"), "environment"))
+        );
+    }
+
+    #[test]
+    fn test_builder_provider_order_follows_registration() {
+        let env = EnvProvider::from_vars(
+            Some("LOOM_"),
+            [("LOOM_DATABASE_HOST".to_string(), "envhost".to_string())],
+        );
+
+        let config = Config::new()
+            .with_provider(env)
+            .with_provider(MemoryProvider::from_pairs([("database.host", "localhost")]))
+            .build()
+            .unwrap();
+
+        let path = IdentPath::parse("database.host").unwrap();
+        assert_eq!(config.provider_order(), vec!["environment", "memory"]);
+        assert_eq!(
+            config.get_with_source(&path),
+            Some((Value::from("localhost"), "memory"))
+        );
+    }
+
     #[test]
     fn test_builder_with_path_and_format() {
         let config = Config::new()
@@ -2,9 +2,9 @@ use serde::de::DeserializeOwned;
 
 use loom_core::Format;
 use loom_core::path::{IdentPath, Path};
-use loom_core::value::Value;
+use loom_core::value::{Number, Value};
 
-use super::{ConfigBuilder, ConfigError, ConfigSection, Env};
+use super::{ConfigBuilder, ConfigError, ConfigOrigins, ConfigSection, Env};
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 pub struct ConfigSource {
@@ -20,6 +20,10 @@ pub struct Config {
     pub(crate) path: Option<Path>,
     pub(crate) format: Option<Format>,
     pub(crate) sources: Vec<ConfigSource>,
+    #[serde(default)]
+    pub(crate) origins: ConfigOrigins,
+    #[serde(default)]
+    pub(crate) strict_numbers: bool,
 }
 
 impl Config {
@@ -31,6 +35,19 @@ impl Config {
         &self.env
     }
 
+    /// Whether `get_int`/`get_float` (and their `require_*` forms) only
+    /// accept values stored as that exact number type. By default they are
+    /// lenient: an int reads as a float, and a float with no fractional part
+    /// (`5.0`) reads as an int.
+    pub fn strict_numbers(mut self, strict: bool) -> Self {
+        self.strict_numbers = strict;
+        self
+    }
+
+    pub fn is_strict_numbers(&self) -> bool {
+        self.strict_numbers
+    }
+
     pub fn as_value(&self) -> &Value {
         &self.data
     }
@@ -39,6 +56,30 @@ impl Config {
         &self.sources
     }
 
+    /// Which provider supplied each leaf value.
+    pub fn origins(&self) -> &ConfigOrigins {
+        &self.origins
+    }
+
+    /// Name of the provider that supplied the value at `path`.
+    pub fn origin(&self, path: &IdentPath) -> Option<&str> {
+        self.origins.get(path)
+    }
+
+    /// Names of the providers that were merged, from lowest to highest
+    /// precedence. Providers that loaded nothing (optional and missing) are
+    /// left out.
+    pub fn provider_order(&self) -> Vec<&str> {
+        self.sources.iter().map(|s| s.name.as_str()).collect()
+    }
+
+    /// The leaf value at `path` along with the name of the provider that
+    /// supplied it. `None` if the path is unset or not a leaf.
+    pub fn get_with_source(&self, path: &IdentPath) -> Option<(Value, &str)> {
+        let source = self.origin(path)?;
+        self.get(path).map(|value| (value.clone(), source))
+    }
+
     pub fn path(&self) -> Option<&Path> {
         self.path.as_ref()
     }
@@ -56,17 +97,77 @@ impl Config {
     }
 
     pub fn get_int(&self, path: &IdentPath) -> Option<i64> {
-        self.get(path).and_then(|v| v.as_int())
+        self.get(path).and_then(|v| self.cast_int(v))
     }
 
     pub fn get_float(&self, path: &IdentPath) -> Option<f64> {
-        self.get(path).and_then(|v| v.as_float())
+        self.get(path).and_then(|v| self.cast_float(v))
     }
 
     pub fn get_bool(&self, path: &IdentPath) -> Option<bool> {
         self.get(path).and_then(|v| v.as_bool())
     }
 
+    /// The string at `path`, failing with `ConfigError::MissingKey` if it
+    /// isn't set or `ConfigError::TypeMismatch` if it isn't a string.
+    pub fn require_str(&self, path: &IdentPath) -> Result<&str, ConfigError> {
+        self.require(path, "string", Value::as_str)
+    }
+
+    pub fn require_int(&self, path: &IdentPath) -> Result<i64, ConfigError> {
+        self.require(path, "int", |v| self.cast_int(v))
+    }
+
+    pub fn require_float(&self, path: &IdentPath) -> Result<f64, ConfigError> {
+        self.require(path, "float", |v| self.cast_float(v))
+    }
+
+    pub fn require_bool(&self, path: &IdentPath) -> Result<bool, ConfigError> {
+        self.require(path, "bool", Value::as_bool)
+    }
+
+    fn cast_int(&self, value: &Value) -> Option<i64> {
+        match value {
+            Value::Number(Number::Int(v)) => Some(*v),
+            Value::Number(Number::Float(v))
+                if !self.strict_numbers
+                    && v.fract() == 0.0
+                    && *v >= i64::MIN as f64
+                    && *v < i64::MAX as f64 =>
+            {
+                Some(*v as i64)
+            }
+            _ => None,
+        }
+    }
+
+    fn cast_float(&self, value: &Value) -> Option<f64> {
+        match value {
+            Value::Number(Number::Float(v)) => Some(*v),
+            Value::Number(Number::Int(v)) if !self.strict_numbers => Some(*v as f64),
+            _ => None,
+        }
+    }
+
+    fn require<'a, T>(
+        &'a self,
+        path: &IdentPath,
+        expected: &str,
+        cast: impl FnOnce(&'a Value) -> Option<T>,
+    ) -> Result<T, ConfigError> {
+        let value = self
+            .get(path)
+            .ok_or_else(|| ConfigError::missing_key(path.to_string()))?;
+
+        cast(value).ok_or_else(|| {
+            ConfigError::type_mismatch(
+                path.to_string(),
+                expected.to_string(),
+                value.kind().to_string(),
+            )
+        })
+    }
+
     pub fn get_section(&self, path: &IdentPath) -> ConfigSection {
         let value = self.get(path).cloned().unwrap_or(Value::Null);
         ConfigSection::new(value, path.clone())
@@ -83,12 +184,17 @@ impl Config {
         let mut sources = self.sources;
         sources.extend(other.sources);
 
+        let mut origins = self.origins;
+        origins.merge(other.origins);
+
         Self {
             env: self.env,
             data,
             path: self.path.or(other.path),
             format: self.format.or(other.format),
             sources,
+            origins,
+            strict_numbers: self.strict_numbers,
         }
     }
 
@@ -110,50 +216,52 @@ impl Config {
             _ => return Err(ConfigError::provider("Can only write to file paths")),
         };
 
-        #[cfg(feature = "json")]
-        if format == Format::Json {
-            let json: serde_json::Value = (&self.data).into();
-            let content = serde_json::to_string_pretty(&json).map_err(ConfigError::parse)?;
-            std::fs::write(file_path, content)?;
-            return Ok(());
-        }
-
-        #[cfg(feature = "yaml")]
-        if format == Format::Yaml {
-            let yaml: saphyr::Yaml = (&self.data).into();
-            let mut out = String::new();
-            let mut emitter = saphyr::YamlEmitter::new(&mut out);
-            emitter.dump(&yaml).map_err(ConfigError::parse)?;
-            std::fs::write(file_path, out)?;
-            return Ok(());
-        }
-
-        #[cfg(feature = "toml")]
-        if format == Format::Toml {
-            let toml_value: toml::Value = (&self.data).into();
-            let content = toml::to_string_pretty(&toml_value).map_err(ConfigError::parse)?;
-            std::fs::write(file_path, content)?;
-            return Ok(());
-        }
-
-        Err(ConfigError::provider(format!(
-            "Unsupported format: {:?}",
-            format
-        )))
+        std::fs::write(file_path, encode(&self.data, format)?)?;
+        Ok(())
     }
 
     pub fn bind<T: DeserializeOwned>(&self) -> Result<T, ConfigError> {
-        let json: serde_json::Value = (&self.data).into();
-        serde_json::from_value(json).map_err(ConfigError::deserialize)
+        self.data
+            .deserialize_into()
+            .map_err(ConfigError::deserialize)
     }
 
     pub fn bind_section<T: DeserializeOwned>(&self, path: &IdentPath) -> Result<T, ConfigError> {
         let value = self
             .get(path)
             .ok_or_else(|| ConfigError::not_found(path.to_string()))?;
+
+        value.deserialize_into().map_err(ConfigError::deserialize)
+    }
+}
+
+/// Serialize a value as `format` (json, yaml or toml, depending on enabled features).
+pub fn encode(value: &Value, format: Format) -> Result<String, ConfigError> {
+    #[cfg(feature = "json")]
+    if format == Format::Json {
         let json: serde_json::Value = value.into();
-        serde_json::from_value(json).map_err(ConfigError::deserialize)
+        return serde_json::to_string_pretty(&json).map_err(ConfigError::parse);
+    }
+
+    #[cfg(feature = "yaml")]
+    if format == Format::Yaml {
+        let yaml: saphyr::Yaml = value.into();
+        let mut out = String::new();
+        let mut emitter = saphyr::YamlEmitter::new(&mut out);
+        emitter.dump(&yaml).map_err(ConfigError::parse)?;
+        return Ok(out);
+    }
+
+    #[cfg(feature = "toml")]
+    if format == Format::Toml {
+        let toml_value: toml::Value = value.into();
+        return toml::to_string_pretty(&toml_value).map_err(ConfigError::parse);
     }
+
+    Err(ConfigError::provider(format!(
+        "Unsupported format: {:?}",
+        format
+    )))
 }
 
 #[cfg(test)]
@@ -208,6 +316,42 @@ mod tests {
         assert_eq!(config.get_int(&path), Some(5432));
     }
 
+    #[test]
+    fn test_numbers_coerce_by_default() {
+        let mut root = loom_core::value::Object::new();
+        root.insert("int".to_string(), Value::from(5));
+        root.insert("whole".to_string(), Value::from(5.0));
+        root.insert("fraction".to_string(), Value::from(5.5));
+
+        let config = Config::new()
+            .with_provider(MemoryProvider::from_value(Value::Object(root)))
+            .build()
+            .unwrap();
+        let int = IdentPath::parse("int").unwrap();
+        let whole = IdentPath::parse("whole").unwrap();
+        let fraction = IdentPath::parse("fraction").unwrap();
+
+        assert!(!config.is_strict_numbers());
+        assert_eq!(config.get_float(&int), Some(5.0));
+        assert_eq!(config.get_int(&whole), Some(5));
+        assert_eq!(config.require_int(&whole).unwrap(), 5);
+        assert_eq!(config.get_int(&fraction), None);
+        assert!(
+            config
+                .require_int(&fraction)
+                .unwrap_err()
+                .is_type_mismatch()
+        );
+
+        let config = config.strict_numbers(true);
+
+        assert_eq!(config.get_float(&int), None);
+        assert_eq!(config.get_int(&whole), None);
+        assert_eq!(config.get_int(&int), Some(5));
+        assert_eq!(config.get_float(&whole), Some(5.0));
+        assert!(config.require_float(&int).unwrap_err().is_type_mismatch());
+    }
+
     #[test]
     fn test_get_bool() {
         let config = create_test_config();
@@ -215,6 +359,51 @@ mod tests {
         assert_eq!(config.get_bool(&path), Some(true));
     }
 
+    #[test]
+    fn test_require() {
+        let config = create_test_config();
+
+        let host = IdentPath::parse("database.host").unwrap();
+        let port = IdentPath::parse("database.port").unwrap();
+        let debug = IdentPath::parse("debug").unwrap();
+
+        assert_eq!(config.require_str(&host).unwrap(), "localhost");
+        assert_eq!(config.require_int(&port).unwrap(), 5432);
+        assert!(config.require_bool(&debug).unwrap());
+    }
+
+    #[test]
+    fn test_require_missing_key() {
+        let config = create_test_config();
+        let path = IdentPath::parse("database.url").unwrap();
+
+        let err = config.require_str(&path).unwrap_err();
+
+        assert!(err.is_missing_key());
+        assert_eq!(err.to_string(), "missing required key: database.url");
+        assert!(config.require_int(&path).unwrap_err().is_missing_key());
+        assert!(config.require_float(&path).unwrap_err().is_missing_key());
+        assert!(config.require_bool(&path).unwrap_err().is_missing_key());
+    }
+
+    #[test]
+    fn test_require_type_mismatch() {
+        let config = create_test_config();
+        let host = IdentPath::parse("database.host").unwrap();
+        let port = IdentPath::parse("database.port").unwrap();
+
+        let err = config.require_int(&host).unwrap_err();
+
+        assert!(err.is_type_mismatch());
+        assert_eq!(
+            err.to_string(),
+            "type mismatch at database.host: expected int, found string"
+        );
+        assert!(config.require_str(&port).unwrap_err().is_type_mismatch());
+        assert!(config.require_bool(&port).unwrap_err().is_type_mismatch());
+        assert!(config.require_float(&host).unwrap_err().is_type_mismatch());
+    }
+
     #[test]
     fn test_get_array_element() {
         let config = create_test_config();
@@ -2,22 +2,17 @@ use std::borrow::Cow;
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Env {
     Dev,
     Stage,
+    #[default]
     Prod,
     #[serde(untagged)]
     Custom(Cow<'static, str>),
 }
 
-impl Default for Env {
-    fn default() -> Self {
-        Env::Prod
-    }
-}
-
 impl Env {
     pub fn from_str(s: &str) -> Self {
         match s.to_lowercase().as_str() {
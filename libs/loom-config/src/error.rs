@@ -28,6 +28,16 @@ pub enum ConfigError {
 
     /// Include file not found
     IncludeNotFound { path: String, source_file: String },
+
+    /// Required key not set
+    MissingKey(String),
+
+    /// Key set to a value of the wrong kind
+    TypeMismatch {
+        path: String,
+        expected: String,
+        found: String,
+    },
 }
 
 impl ConfigError {
@@ -61,6 +71,18 @@ impl ConfigError {
         }
     }
 
+    pub fn missing_key<S: Into<String>>(path: S) -> Self {
+        Self::MissingKey(path.into())
+    }
+
+    pub fn type_mismatch<S: Into<String>>(path: S, expected: S, found: S) -> Self {
+        Self::TypeMismatch {
+            path: path.into(),
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
     pub fn is_not_found(&self) -> bool {
         matches!(self, Self::NotFound(_))
     }
@@ -92,6 +114,14 @@ impl ConfigError {
     pub fn is_include_not_found(&self) -> bool {
         matches!(self, Self::IncludeNotFound { .. })
     }
+
+    pub fn is_missing_key(&self) -> bool {
+        matches!(self, Self::MissingKey(_))
+    }
+
+    pub fn is_type_mismatch(&self) -> bool {
+        matches!(self, Self::TypeMismatch { .. })
+    }
 }
 
 impl std::fmt::Display for ConfigError {
@@ -118,6 +148,16 @@ impl std::fmt::Display for ConfigError {
                     path, source_file
                 )
             }
+            Self::MissingKey(path) => write!(f, "missing required key: {}", path),
+            Self::TypeMismatch {
+                path,
+                expected,
+                found,
+            } => write!(
+                f,
+                "type mismatch at {}: expected {}, found {}",
+                path, expected, found
+            ),
         }
     }
 }
@@ -8,36 +8,25 @@ use crate::ConfigError;
 
 const INCLUDE_KEY: &str = "$include";
 
-fn infer_format(path: &Path) -> Format {
-    match path.extension().and_then(|e| e.to_str()) {
-        Some("json") => Format::Json,
-        Some("yaml") | Some("yml") => Format::Yaml,
-        Some("toml") => Format::Toml,
-        _ => Format::Json,
-    }
-}
-
-fn parse_content(content: &str, format: Format) -> Result<Value, ConfigError> {
+/// Parse config file `content` into a `Value`, via loom-core's conversions
+/// from each format's own value type.
+pub(crate) fn parse_content(content: &str, format: Format) -> Result<Value, ConfigError> {
     #[cfg(feature = "json")]
     if format == Format::Json {
         let json: serde_json::Value = serde_json::from_str(content).map_err(ConfigError::parse)?;
-        return Ok(json.into());
+        return Ok(Value::from(json));
     }
 
     #[cfg(feature = "yaml")]
     if format == Format::Yaml {
         let docs = saphyr::Yaml::load_from_str(content).map_err(ConfigError::parse)?;
-        if let Some(doc) = docs.into_iter().next() {
-            return Ok(doc.into());
-        } else {
-            return Ok(Value::Null);
-        }
+        return Ok(docs.into_iter().next().map(Value::from).unwrap_or_default());
     }
 
     #[cfg(feature = "toml")]
     if format == Format::Toml {
-        let toml_value: toml::Value = toml::from_str(content).map_err(ConfigError::parse)?;
-        return Ok(toml_value.into());
+        let toml: toml::Value = toml::from_str(content).map_err(ConfigError::parse)?;
+        return Ok(Value::from(toml));
     }
 
     Err(ConfigError::provider(format!(
@@ -53,6 +42,7 @@ fn parse_content(content: &str, format: Format) -> Result<Value, ConfigError> {
 pub struct IncludeResolver {
     visited: HashSet<PathBuf>,
     include_chain: Vec<PathBuf>,
+    format: Option<Format>,
 }
 
 impl Default for IncludeResolver {
@@ -67,9 +57,18 @@ impl IncludeResolver {
         Self {
             visited: HashSet::new(),
             include_chain: Vec::new(),
+            format: None,
         }
     }
 
+    /// Parse included files whose extension doesn't name a format as
+    /// `format`, instead of JSON. Set when the root file's format was
+    /// forced with `FileProviderBuilder::format`.
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.format = Some(format);
+        self
+    }
+
     /// Resolve all `$include` directives in the given value.
     ///
     /// The `source_file` is the path to the file containing this value,
@@ -166,12 +165,20 @@ impl IncludeResolver {
         }
 
         let content = std::fs::read_to_string(path)?;
-        let format = infer_format(path);
+        let format = self.infer_format(path);
         let value = parse_content(&content, format)?;
 
         // Recursively resolve includes in the loaded file
         self.resolve(value, path)
     }
+
+    /// Format of an included file: its extension if it names one, then the
+    /// explicitly set format, then JSON.
+    fn infer_format(&self, path: &Path) -> Format {
+        Format::from_extension(path)
+            .or(self.format)
+            .unwrap_or(Format::Json)
+    }
 }
 
 #[cfg(test)]
@@ -4,6 +4,7 @@ mod env;
 mod error;
 mod include;
 mod logging;
+mod origin;
 pub mod providers;
 mod section;
 
@@ -13,6 +14,7 @@ pub use env::*;
 pub use error::*;
 pub use include::IncludeResolver;
 pub use logging::*;
+pub use origin::ConfigOrigins;
 pub use providers::{EnvProvider, FileProvider, MemoryProvider, Provider};
 pub use section::*;
 
@@ -43,10 +45,17 @@ macro_rules! get {
             .ok()
             .and_then(|p| $config.get(&p))
     }};
+    // With a default: missing or mistyped keys yield `$default`.
+    ($config:expr, $path:expr, default = $default:expr) => {{ $crate::get!($config, $path).unwrap_or($default) }};
+    ($config:expr, $path:expr, int, default = $default:expr) => {{ $crate::get!($config, $path, int).unwrap_or($default) }};
+    ($config:expr, $path:expr, float, default = $default:expr) => {{ $crate::get!($config, $path, float).unwrap_or($default) }};
+    ($config:expr, $path:expr, bool, default = $default:expr) => {{ $crate::get!($config, $path, bool).unwrap_or($default) }};
 }
 
 #[cfg(test)]
 mod tests {
+    use loom_core::value::Value;
+
     use super::*;
 
     #[test]
@@ -82,11 +91,11 @@ mod tests {
     #[test]
     fn test_get_float() {
         let config = Config::new()
-            .with_provider(MemoryProvider::from_pairs([("rate", 3.14f64)]))
+            .with_provider(MemoryProvider::from_pairs([("rate", 2.5f64)]))
             .build()
             .unwrap();
 
-        assert_eq!(crate::get!(config, "rate", float), Some(3.14));
+        assert_eq!(crate::get!(config, "rate", float), Some(2.5));
     }
 
     #[test]
@@ -99,9 +108,91 @@ mod tests {
         assert!(crate::get!(config, "data", value).is_some());
     }
 
+    #[test]
+    fn test_get_array_index() {
+        let config = Config::new()
+            .with_provider(MemoryProvider::from_pairs([
+                ("servers[0].host", Value::from("a.internal")),
+                ("servers[0].port", Value::from(8080i64)),
+                ("servers[1].host", Value::from("b.internal")),
+                ("queues[0]", Value::from("memory.create")),
+            ]))
+            .build()
+            .unwrap();
+
+        assert_eq!(crate::get!(config, "servers[0].host"), Some("a.internal"));
+        assert_eq!(crate::get!(config, "servers[1].host"), Some("b.internal"));
+        assert_eq!(crate::get!(config, "servers[0].port", int), Some(8080));
+        assert_eq!(crate::get!(config, "queues[0]"), Some("memory.create"));
+        assert_eq!(
+            crate::get!(config, "servers", value)
+                .and_then(|v| v.as_array())
+                .map(|a| a.len()),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_get_array_index_out_of_range() {
+        let config = Config::new()
+            .with_provider(MemoryProvider::from_pairs([(
+                "servers[0].host",
+                "a.internal",
+            )]))
+            .build()
+            .unwrap();
+
+        assert_eq!(crate::get!(config, "servers[1].host"), None);
+        assert_eq!(
+            crate::get!(config, "servers[9].host", default = "none"),
+            "none"
+        );
+        assert_eq!(crate::get!(config, "servers.host"), None);
+    }
+
     #[test]
     fn test_get_nonexistent() {
         let config = Config::new().build().unwrap();
         assert_eq!(crate::get!(config, "nonexistent"), None);
     }
+
+    #[test]
+    fn test_get_with_default_present() {
+        let config = Config::new()
+            .with_provider(MemoryProvider::from_pairs([("workers", 4i64)]))
+            .with_provider(MemoryProvider::from_pairs([("rate", 2.5f64)]))
+            .with_provider(MemoryProvider::from_pairs([("debug", true)]))
+            .with_provider(MemoryProvider::from_pairs([("name", "loom")]))
+            .build()
+            .unwrap();
+
+        let workers: i64 = crate::get!(config, "workers", int, default = 8);
+        assert_eq!(workers, 4);
+        assert_eq!(crate::get!(config, "rate", float, default = 1.0), 2.5);
+        assert!(crate::get!(config, "debug", bool, default = false));
+        assert_eq!(crate::get!(config, "name", default = "default"), "loom");
+    }
+
+    #[test]
+    fn test_get_with_default_missing() {
+        let config = Config::new().build().unwrap();
+
+        assert_eq!(crate::get!(config, "workers", int, default = 8), 8);
+        assert_eq!(crate::get!(config, "rate", float, default = 1.0), 1.0);
+        assert!(crate::get!(config, "debug", bool, default = true));
+        assert_eq!(crate::get!(config, "name", default = "default"), "default");
+    }
+
+    #[test]
+    fn test_get_with_default_wrong_type() {
+        let config = Config::new()
+            .with_provider(MemoryProvider::from_pairs([("workers", "many")]))
+            .with_provider(MemoryProvider::from_pairs([("name", 42i64)]))
+            .build()
+            .unwrap();
+
+        assert_eq!(crate::get!(config, "workers", int, default = 8), 8);
+        assert!(!crate::get!(config, "workers", bool, default = false));
+        assert_eq!(crate::get!(config, "name", default = "default"), "default");
+    }
 }
@@ -0,0 +1,241 @@
+use std::collections::BTreeMap;
+
+use loom_core::path::IdentPath;
+use loom_core::value::{Object, Value};
+
+/// Tracks which provider supplied each leaf value of a merged config.
+///
+/// Keys are leaf paths in `IdentPath` form (`database.hosts[0]`), values are
+/// provider names (`Provider::name`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct ConfigOrigins(BTreeMap<String, String>);
+
+impl ConfigOrigins {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Name of the provider that supplied the leaf at `path`.
+    pub fn get(&self, path: &IdentPath) -> Option<&str> {
+        self.0.get(&path.to_string()).map(|s| s.as_str())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Record `value` from `provider` as merged on top of the values seen so
+    /// far. Mirrors `Value::merge`: objects merge key by key, anything else
+    /// replaces the whole subtree.
+    pub(crate) fn record(&mut self, value: &Value, provider: &str) {
+        self.record_at(String::new(), value, provider);
+    }
+
+    /// Apply `other` on top of these origins, as `Config::merge` does for data.
+    pub(crate) fn merge(&mut self, other: Self) {
+        for (path, provider) in other.0 {
+            self.set(path, provider);
+        }
+    }
+
+    /// Copy `value`, replacing each leaf with `{ value, origin }`.
+    pub fn annotate(&self, value: &Value) -> Value {
+        self.annotate_at(String::new(), value)
+    }
+
+    fn record_at(&mut self, path: String, value: &Value, provider: &str) {
+        match value {
+            Value::Object(obj) => {
+                // An object merges into an object but replaces anything else
+                if !path.is_empty() {
+                    self.0.remove(&path);
+                    self.clear_indices(&path);
+                }
+
+                for (key, v) in obj.iter() {
+                    self.record_at(join_key(&path, key), v, provider);
+                }
+            }
+            Value::Array(arr) => {
+                self.clear(&path);
+                for (i, v) in arr.iter().enumerate() {
+                    self.record_at(format!("{}[{}]", path, i), v, provider);
+                }
+            }
+            _ => self.set(path, provider.to_string()),
+        }
+    }
+
+    fn annotate_at(&self, path: String, value: &Value) -> Value {
+        match value {
+            Value::Object(obj) => {
+                let mut out = Object::new();
+                for (key, v) in obj.iter() {
+                    out.insert(key.clone(), self.annotate_at(join_key(&path, key), v));
+                }
+                Value::Object(out)
+            }
+            Value::Array(arr) => Value::Array(
+                arr.iter()
+                    .enumerate()
+                    .map(|(i, v)| self.annotate_at(format!("{}[{}]", path, i), v))
+                    .collect::<Vec<_>>()
+                    .into(),
+            ),
+            leaf => {
+                let origin = match self.0.get(&path) {
+                    Some(provider) => Value::String(provider.clone()),
+                    None => Value::Null,
+                };
+
+                let mut out = Object::new();
+                out.insert("value".to_string(), leaf.clone());
+                out.insert("origin".to_string(), origin);
+                Value::Object(out)
+            }
+        }
+    }
+
+    /// Set a leaf, dropping origins of any ancestor or descendant it replaces.
+    fn set(&mut self, path: String, provider: String) {
+        self.clear(&path);
+
+        for (i, c) in path.char_indices() {
+            if i > 0 && (c == '.' || c == '[') {
+                self.0.remove(&path[..i]);
+            }
+        }
+
+        self.0.insert(path, provider);
+    }
+
+    /// Remove `path` and everything below it.
+    fn clear(&mut self, path: &str) {
+        if path.is_empty() {
+            self.0.clear();
+            return;
+        }
+
+        self.0
+            .retain(|k, _| !(k == path || is_child(k, path, '.') || is_child(k, path, '[')));
+    }
+
+    /// Remove array elements below `path`.
+    fn clear_indices(&mut self, path: &str) {
+        self.0.retain(|k, _| !is_child(k, path, '['));
+    }
+}
+
+fn join_key(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn is_child(key: &str, path: &str, separator: char) -> bool {
+    key.strip_prefix(path)
+        .is_some_and(|rest| rest.starts_with(separator))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use loom_core::value::Number;
+
+    fn path(s: &str) -> IdentPath {
+        IdentPath::parse(s).unwrap()
+    }
+
+    fn object(pairs: Vec<(&str, Value)>) -> Value {
+        let mut obj = Object::new();
+        for (k, v) in pairs {
+            obj.insert(k.to_string(), v);
+        }
+        Value::Object(obj)
+    }
+
+    #[test]
+    fn later_provider_overrides_leaf() {
+        let mut origins = ConfigOrigins::new();
+        origins.record(
+            &object(vec![(
+                "db",
+                object(vec![
+                    ("host", Value::String("localhost".into())),
+                    ("port", Value::Number(Number::Int(5432))),
+                ]),
+            )]),
+            "file",
+        );
+        origins.record(
+            &object(vec![(
+                "db",
+                object(vec![("host", Value::String("remote".into()))]),
+            )]),
+            "environment",
+        );
+
+        assert_eq!(origins.get(&path("db.host")), Some("environment"));
+        assert_eq!(origins.get(&path("db.port")), Some("file"));
+    }
+
+    #[test]
+    fn shorter_array_drops_stale_elements() {
+        let mut origins = ConfigOrigins::new();
+        origins.record(
+            &object(vec![("hosts", Value::Array(vec!["a", "b"].into()))]),
+            "file",
+        );
+        origins.record(
+            &object(vec![("hosts", Value::Array(vec!["c"].into()))]),
+            "memory",
+        );
+
+        assert_eq!(origins.get(&path("hosts[0]")), Some("memory"));
+        assert_eq!(origins.get(&path("hosts[1]")), None);
+    }
+
+    #[test]
+    fn scalar_replaces_object() {
+        let mut origins = ConfigOrigins::new();
+        origins.record(
+            &object(vec![(
+                "db",
+                object(vec![("host", Value::String("x".into()))]),
+            )]),
+            "file",
+        );
+        origins.record(&object(vec![("db", Value::Null)]), "environment");
+
+        assert_eq!(origins.get(&path("db")), Some("environment"));
+        assert_eq!(origins.get(&path("db.host")), None);
+        assert_eq!(origins.len(), 1);
+    }
+
+    #[test]
+    fn annotate_wraps_leaves() {
+        let value = object(vec![("debug", Value::Bool(true))]);
+        let mut origins = ConfigOrigins::new();
+        origins.record(&value, "memory");
+
+        let annotated = origins.annotate(&value);
+        assert_eq!(
+            annotated.get_by_path(&path("debug.origin")),
+            Some(&Value::String("memory".into()))
+        );
+        assert_eq!(
+            annotated.get_by_path(&path("debug.value")),
+            Some(&Value::Bool(true))
+        );
+    }
+}
@@ -1,7 +1,8 @@
+use std::collections::HashSet;
 use std::env;
 
 use loom_core::path::{IdentPath, Path};
-use loom_core::value::{Number, Object, Value};
+use loom_core::value::{Array, Number, Object, Value};
 
 use super::{ConfigError, Provider};
 
@@ -14,17 +15,47 @@ use super::{ConfigError, Provider};
 /// Example with prefix "APP_":
 /// - APP_DATABASE_HOST -> database.host
 /// - APP_DATABASE__CONNECTION_STRING -> database.connection_string
+///
+/// Values are scalars by default. Arrays can be set two ways:
+/// - A double underscore followed by digits is an index:
+///   APP_QUEUES__0=a, APP_QUEUES__1=b -> queues = ["a", "b"], and
+///   APP_SERVERS__0_HOST -> servers[0].host
+/// - Keys registered with `with_list_key` are split on commas:
+///   APP_QUEUES=a,b,c -> queues = ["a", "b", "c"]
 pub struct EnvProvider {
     prefix: Option<String>,
+    list_keys: HashSet<String>,
+    vars: Option<Vec<(String, String)>>,
 }
 
 impl EnvProvider {
     pub fn new(prefix: Option<&str>) -> Self {
         Self {
             prefix: prefix.map(|s| s.to_uppercase()),
+            list_keys: HashSet::new(),
+            vars: None,
+        }
+    }
+
+    /// Read `vars` instead of the process environment, with the same key
+    /// mapping as `new`.
+    pub fn from_vars<I>(prefix: Option<&str>, vars: I) -> Self
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        Self {
+            vars: Some(vars.into_iter().collect()),
+            ..Self::new(prefix)
         }
     }
 
+    /// Parse the variable for `key` (a config path such as `queues` or
+    /// `worker.queues`) as a comma-separated list.
+    pub fn with_list_key(mut self, key: &str) -> Self {
+        self.list_keys.insert(key.to_lowercase());
+        self
+    }
+
     fn parse_key(&self, key: &str) -> Option<String> {
         let key = match &self.prefix {
             Some(prefix) => {
@@ -41,12 +72,26 @@ impl EnvProvider {
             return None;
         }
 
-        // Replace double underscore with placeholder, then single underscore with dot
-        let normalized = key
-            .replace("__", "\x00")
-            .replace('_', ".")
-            .replace('\x00', "_")
-            .to_lowercase();
+        // Single underscores become dots. A double underscore is a literal
+        // underscore, unless it is followed by digits, which become an index.
+        let mut normalized = String::new();
+
+        for (i, part) in key.to_lowercase().split("__").enumerate() {
+            let index_len = part.find('_').unwrap_or(part.len());
+            let rest = match &part[..index_len] {
+                _ if i == 0 => part,
+                digits if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) => {
+                    normalized.push_str(&format!("[{}]", digits));
+                    &part[index_len..]
+                }
+                _ => {
+                    normalized.push('_');
+                    part
+                }
+            };
+
+            normalized.push_str(&rest.replace('_', "."));
+        }
 
         if normalized.is_empty() {
             None
@@ -80,47 +125,21 @@ impl EnvProvider {
         Value::String(s.to_string())
     }
 
-    fn set_by_path(root: &mut Value, path_str: &str, value: Value) {
-        let path = match IdentPath::parse(path_str) {
-            Ok(p) => p,
-            Err(_) => return,
-        };
-
-        use loom_core::path::IdentSegment;
-
-        let segments = path.segments();
-        if segments.is_empty() {
-            return;
-        }
-
-        if segments.len() == 1 {
-            if let (Value::Object(obj), IdentSegment::Key(key)) = (root, &segments[0]) {
-                obj.insert(key.clone(), value);
-            }
-            return;
+    fn parse_list(s: &str) -> Value {
+        if s.trim().is_empty() {
+            return Value::Array(Array::new());
         }
 
-        let mut current = root;
-
-        for (i, segment) in segments.iter().enumerate() {
-            let is_last = i == segments.len() - 1;
-
-            if is_last {
-                if let (Value::Object(obj), IdentSegment::Key(key)) = (current, segment) {
-                    obj.insert(key.clone(), value);
-                }
-                return;
-            }
+        let items: Vec<Value> = s
+            .split(',')
+            .map(|item| Self::parse_value(item.trim()))
+            .collect();
+        Value::Array(items.into())
+    }
 
-            current = match (current, segment) {
-                (Value::Object(obj), IdentSegment::Key(key)) => {
-                    if !obj.contains_key(key) {
-                        obj.insert(key.clone(), Value::Object(Object::new()));
-                    }
-                    obj.get_mut(key).unwrap()
-                }
-                _ => return,
-            };
+    fn set_by_path(root: &mut Value, path_str: &str, value: Value) {
+        if let Ok(path) = IdentPath::parse(path_str) {
+            root.set_by_path(&path, value);
         }
     }
 }
@@ -146,10 +165,17 @@ impl Provider for EnvProvider {
 
     fn load(&self) -> Result<Option<Value>, ConfigError> {
         let mut root = Value::Object(Object::new());
+        let vars = match &self.vars {
+            Some(vars) => vars.clone(),
+            None => env::vars().collect(),
+        };
 
-        for (key, value) in env::vars() {
+        for (key, value) in vars {
             if let Some(path) = self.parse_key(&key) {
-                let parsed_value = Self::parse_value(&value);
+                let parsed_value = match self.list_keys.contains(&path) {
+                    true => Self::parse_list(&value),
+                    false => Self::parse_value(&value),
+                };
                 Self::set_by_path(&mut root, &path, parsed_value);
             }
         }
@@ -204,6 +230,94 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_key_index() {
+        let provider = EnvProvider::new(Some("APP_"));
+
+        assert_eq!(
+            provider.parse_key("APP_QUEUES__0"),
+            Some("queues[0]".to_string())
+        );
+        assert_eq!(
+            provider.parse_key("APP_SERVERS__12_HOST"),
+            Some("servers[12].host".to_string())
+        );
+        assert_eq!(
+            provider.parse_key("APP_DB__2FA_ENABLED"),
+            Some("db_2fa.enabled".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(
+            EnvProvider::parse_list("a, b,c"),
+            Value::from(["a", "b", "c"])
+        );
+        assert_eq!(
+            EnvProvider::parse_list("1,2"),
+            Value::Array(vec![Value::from(1i64), Value::from(2i64)].into())
+        );
+        assert_eq!(EnvProvider::parse_list(""), Value::Array(Array::new()));
+    }
+
+    fn vars<const N: usize>(pairs: [(&str, &str); N]) -> Vec<(String, String)> {
+        pairs
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_load_csv_list() {
+        let vars = vars([("APP_QUEUES", "a,b,c"), ("APP_NAME", "x,y")]);
+        let value = EnvProvider::from_vars(Some("APP_"), vars)
+            .with_list_key("queues")
+            .load()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(value["queues"], Value::from(["a", "b", "c"]));
+        assert_eq!(value["name"], Value::from("x,y"), "other keys stay scalar");
+    }
+
+    #[test]
+    fn test_load_indexed_list() {
+        let vars = vars([
+            ("APP_QUEUES__1", "b"),
+            ("APP_QUEUES__0", "a"),
+            ("APP_SERVERS__0_PORT", "8080"),
+        ]);
+
+        let value = EnvProvider::from_vars(Some("APP_"), vars)
+            .load()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(value["queues"], Value::from(["a", "b"]));
+
+        let path = IdentPath::parse("servers[0].port").unwrap();
+        assert_eq!(value.get_by_path(&path), Some(&Value::from(8080i64)));
+    }
+
+    #[test]
+    fn test_load_from_vars_skips_other_prefixes() {
+        let vars = vars([("APP_DATABASE_HOST", "localhost"), ("OTHER_HOST", "x")]);
+        let value = EnvProvider::from_vars(Some("APP_"), vars)
+            .load()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(value["database"]["host"], Value::from("localhost"));
+        assert!(value["other"].is_null());
+        assert!(
+            EnvProvider::from_vars(Some("APP_"), Vec::new())
+                .load()
+                .unwrap()
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_parse_value_bool() {
         assert_eq!(EnvProvider::parse_value("true"), Value::Bool(true));
@@ -227,8 +341,8 @@ mod tests {
     #[test]
     fn test_parse_value_float() {
         assert_eq!(
-            EnvProvider::parse_value("3.14"),
-            Value::Number(Number::Float(3.14))
+            EnvProvider::parse_value("2.5"),
+            Value::Number(Number::Float(2.5))
         );
     }
 
@@ -5,16 +5,7 @@ use loom_core::value::Value;
 use loom_core::{Format, path::Path};
 
 use super::{ConfigError, Provider};
-use crate::include::IncludeResolver;
-
-fn infer_format(path: &std::path::Path) -> Format {
-    match path.extension().and_then(|e| e.to_str()) {
-        Some("json") => Format::Json,
-        Some("yaml") | Some("yml") => Format::Yaml,
-        Some("toml") => Format::Toml,
-        _ => Format::Json,
-    }
-}
+use crate::include::{IncludeResolver, parse_content};
 
 #[derive(Debug, Clone)]
 pub struct FileProviderBuilder {
@@ -52,10 +43,11 @@ impl FileProviderBuilder {
     }
 
     pub fn build(self) -> FileProvider {
-        let format = self.format.unwrap_or_else(|| infer_format(&self.path));
+        let format = self.format.unwrap_or_else(|| Format::from_path(&self.path));
         FileProvider {
             path: self.path,
             format,
+            forced: self.format.is_some(),
             is_optional: self.optional,
             includes: self.includes,
         }
@@ -65,6 +57,7 @@ impl FileProviderBuilder {
 pub struct FileProvider {
     path: PathBuf,
     format: Format,
+    forced: bool,
     is_optional: bool,
     includes: bool,
 }
@@ -73,36 +66,6 @@ impl FileProvider {
     pub fn builder(path: impl Into<PathBuf>) -> FileProviderBuilder {
         FileProviderBuilder::new(path)
     }
-
-    fn parse_content(&self, content: &str) -> Result<Value, ConfigError> {
-        #[cfg(feature = "json")]
-        if self.format == Format::Json {
-            let json: serde_json::Value =
-                serde_json::from_str(content).map_err(ConfigError::parse)?;
-            return Ok(json.into());
-        }
-
-        #[cfg(feature = "yaml")]
-        if self.format == Format::Yaml {
-            let docs = saphyr::Yaml::load_from_str(content).map_err(ConfigError::parse)?;
-            if let Some(doc) = docs.into_iter().next() {
-                return Ok(doc.into());
-            } else {
-                return Ok(Value::Null);
-            }
-        }
-
-        #[cfg(feature = "toml")]
-        if self.format == Format::Toml {
-            let toml_value: toml::Value = toml::from_str(content).map_err(ConfigError::parse)?;
-            return Ok(toml_value.into());
-        }
-
-        Err(ConfigError::provider(format!(
-            "unsupported format: {:?}",
-            self.format
-        )))
-    }
 }
 
 impl Provider for FileProvider {
@@ -128,10 +91,14 @@ impl Provider for FileProvider {
         }
 
         let content = std::fs::read_to_string(&self.path)?;
-        let mut value = self.parse_content(&content)?;
+        let mut value = parse_content(&content, self.format)?;
 
         if self.includes {
             let mut resolver = IncludeResolver::new();
+            if self.forced {
+                resolver = resolver.with_format(self.format);
+            }
+
             value = resolver.resolve(value, &self.path)?;
         }
 
@@ -143,43 +110,6 @@ impl Provider for FileProvider {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_infer_format_json() {
-        assert_eq!(
-            infer_format(std::path::Path::new("config.json")),
-            Format::Json
-        );
-    }
-
-    #[test]
-    fn test_infer_format_yaml() {
-        assert_eq!(
-            infer_format(std::path::Path::new("config.yaml")),
-            Format::Yaml
-        );
-        assert_eq!(
-            infer_format(std::path::Path::new("config.yml")),
-            Format::Yaml
-        );
-    }
-
-    #[test]
-    fn test_infer_format_toml() {
-        assert_eq!(
-            infer_format(std::path::Path::new("config.toml")),
-            Format::Toml
-        );
-    }
-
-    #[test]
-    fn test_infer_format_default() {
-        assert_eq!(infer_format(std::path::Path::new("config")), Format::Json);
-        assert_eq!(
-            infer_format(std::path::Path::new("config.txt")),
-            Format::Json
-        );
-    }
-
     #[test]
     fn test_builder_infers_format() {
         let provider = FileProvider::builder("config.yaml").build();
@@ -200,6 +130,31 @@ mod tests {
         assert!(provider.is_optional);
     }
 
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_forced_format_loads_unknown_extension() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        std::fs::write(tmp.path().join("base.conf"), "batch_size: 32\n").unwrap();
+
+        let path = tmp.path().join("loom.conf");
+        std::fs::write(
+            &path,
+            "$include: ./base.conf\nlayers:\n  score:\n    threshold: 0.7\n",
+        )
+        .unwrap();
+
+        let value = FileProvider::builder(&path)
+            .format(Format::Yaml)
+            .build()
+            .load()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(value["layers"]["score"]["threshold"].as_float(), Some(0.7));
+        assert_eq!(value["batch_size"].as_int(), Some(32));
+        assert!(FileProvider::builder(&path).build().load().is_err());
+    }
+
     #[cfg(feature = "json")]
     #[test]
     fn test_file_provider_not_found_optional() {
@@ -1,4 +1,4 @@
-use loom_core::path::{IdentPath, IdentSegment, Path};
+use loom_core::path::{IdentPath, Path};
 use loom_core::value::{Object, Value};
 
 use super::{ConfigError, Provider};
@@ -15,10 +15,14 @@ impl MemoryProvider {
         }
     }
 
+    /// Use an already-structured value as the config tree, stored as is
+    /// like a parsed config file. Keys are not split on dots.
     pub fn from_value(value: Value) -> Self {
         Self { data: value }
     }
 
+    /// Build the config tree from `IdentPath` keys such as `database.host`
+    /// or `servers[0].host`; indexed segments create arrays.
     pub fn from_pairs<I, K, V>(items: I) -> Self
     where
         I: IntoIterator<Item = (K, V)>,
@@ -29,45 +33,12 @@ impl MemoryProvider {
 
         for (k, v) in items {
             if let Ok(path) = IdentPath::parse(k.as_ref()) {
-                Self::set_by_path(&mut root, &path, v.into());
+                root.set_by_path(&path, v.into());
             }
         }
 
         Self { data: root }
     }
-
-    fn set_by_path(root: &mut Value, path: &IdentPath, value: Value) {
-        let segments = path.segments();
-        if segments.is_empty() {
-            return;
-        }
-
-        Self::set_nested(root, segments, value);
-    }
-
-    fn set_nested(current: &mut Value, segments: &[IdentSegment], value: Value) {
-        if segments.is_empty() {
-            return;
-        }
-
-        let segment = &segments[0];
-        let is_last = segments.len() == 1;
-
-        if let IdentSegment::Key(key) = segment {
-            if let Value::Object(obj) = current {
-                if is_last {
-                    obj.insert(key.clone(), value);
-                } else {
-                    if !obj.contains_key(key) {
-                        obj.insert(key.clone(), Value::Object(Object::new()));
-                    }
-                    if let Some(child) = obj.get_mut(key) {
-                        Self::set_nested(child, &segments[1..], value);
-                    }
-                }
-            }
-        }
-    }
 }
 
 impl Default for MemoryProvider {
@@ -127,6 +98,24 @@ mod tests {
         assert_eq!(value.get_by_path(&path).unwrap().as_str(), Some("value"));
     }
 
+    #[test]
+    fn test_memory_provider_from_nested_value() {
+        let mut database = Object::new();
+        database.insert("host".to_string(), Value::from("localhost"));
+        database.insert("port".to_string(), Value::from(5432i64));
+
+        let mut root = Object::new();
+        root.insert("database".to_string(), Value::Object(database));
+
+        let config = crate::Config::new()
+            .with_provider(MemoryProvider::from_value(Value::Object(root)))
+            .build()
+            .unwrap();
+
+        assert_eq!(crate::get!(config, "database.host"), Some("localhost"));
+        assert_eq!(crate::get!(config, "database.port", int), Some(5432));
+    }
+
     #[test]
     fn test_memory_provider_nested_path() {
         let provider = MemoryProvider::from_pairs([("a.b.c", "deep")]);
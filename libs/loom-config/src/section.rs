@@ -91,8 +91,9 @@ impl ConfigSection {
             return Err(ConfigError::not_found(self.path.to_string()));
         }
 
-        let json: serde_json::Value = (&self.value).into();
-        serde_json::from_value(json).map_err(ConfigError::deserialize)
+        self.value
+            .deserialize_into()
+            .map_err(ConfigError::deserialize)
     }
 
     pub fn keys(&self) -> Option<impl Iterator<Item = &str>> {
@@ -80,10 +80,10 @@ impl<K: Eq + Hash, V: Clone> Cache<K, V> {
         let entry = entries.get(key)?;
 
         // Check TTL
-        if let Some(ttl) = self.config.ttl {
-            if entry.inserted_at.elapsed() > ttl {
-                return None; // Expired
-            }
+        if let Some(ttl) = self.config.ttl
+            && entry.inserted_at.elapsed() > ttl
+        {
+            return None; // Expired
         }
 
         Some(entry.value.clone())
@@ -364,7 +364,7 @@ mod tests {
             handle.join().unwrap();
         }
 
-        assert!(cache.len() > 0);
+        assert!(!cache.is_empty());
     }
 
     #[test]
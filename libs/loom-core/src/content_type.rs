@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+
+use crate::{Format, MediaType};
+
+/// A media type with its parameters, as in a `Content-Type` header
+/// (`application/json; charset=utf-8`).
+///
+/// Parameter names are lowercased and quoted values unquoted; the charset
+/// value is also lowercased since charsets are case-insensitive.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct ContentType {
+    pub essence: MediaType,
+    pub params: BTreeMap<String, String>,
+}
+
+impl ContentType {
+    pub fn new(essence: MediaType) -> Self {
+        Self {
+            essence,
+            params: BTreeMap::new(),
+        }
+    }
+
+    /// Parse a content type, skipping malformed parameters.
+    pub fn parse(value: &str) -> Self {
+        let mut parts = value.split(';');
+        let essence = MediaType::from_mime_str(parts.next().unwrap_or_default());
+        let mut params = BTreeMap::new();
+
+        for part in parts {
+            let Some((name, value)) = part.split_once('=') else {
+                continue;
+            };
+
+            let name = name.trim().to_ascii_lowercase();
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+
+            if name.is_empty() {
+                continue;
+            }
+
+            let value = match name.as_str() {
+                "charset" => value.to_ascii_lowercase(),
+                _ => value.to_string(),
+            };
+
+            params.insert(name, value);
+        }
+
+        Self { essence, params }
+    }
+
+    pub fn with_param(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.params.insert(name.to_ascii_lowercase(), value.into());
+        self
+    }
+
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .get(&name.to_ascii_lowercase())
+            .map(|v| v.as_str())
+    }
+
+    /// The declared `charset` parameter, lowercased.
+    pub fn charset(&self) -> Option<&str> {
+        self.param("charset")
+    }
+
+    pub fn format(&self) -> Format {
+        self.essence.format()
+    }
+
+    /// Whether content can be read as UTF-8: no charset is declared, or it
+    /// is UTF-8 or its ASCII subset.
+    pub fn is_utf8(&self) -> bool {
+        matches!(
+            self.charset(),
+            None | Some("utf-8" | "utf8" | "us-ascii" | "ascii")
+        )
+    }
+}
+
+impl From<MediaType> for ContentType {
+    fn from(essence: MediaType) -> Self {
+        Self::new(essence)
+    }
+}
+
+impl std::fmt::Display for ContentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.essence)?;
+
+        for (name, value) in &self.params {
+            write!(f, "; {}={}", name, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_parameterized_content_type() {
+        let content_type = ContentType::parse("Application/JSON; Charset=\"UTF-8\"; profile=v1");
+
+        assert_eq!(content_type.essence, MediaType::TextJson);
+        assert_eq!(content_type.format(), Format::Json);
+        assert_eq!(content_type.charset(), Some("utf-8"));
+        assert_eq!(content_type.param("PROFILE"), Some("v1"));
+        assert!(content_type.is_utf8());
+        assert_eq!(
+            content_type.to_string(),
+            "application/json; charset=utf-8; profile=v1"
+        );
+    }
+
+    #[test]
+    fn parse_without_params() {
+        let content_type = ContentType::parse("text/yaml");
+
+        assert_eq!(content_type, ContentType::from(MediaType::TextYaml));
+        assert_eq!(content_type.charset(), None);
+        assert!(content_type.is_utf8());
+    }
+
+    #[test]
+    fn non_utf8_charset() {
+        let content_type = ContentType::parse("text/plain; charset=ISO-8859-1; ; junk");
+
+        assert_eq!(content_type.format(), Format::Text);
+        assert_eq!(content_type.charset(), Some("iso-8859-1"));
+        assert_eq!(content_type.params.len(), 1);
+        assert!(!content_type.is_utf8());
+    }
+}
@@ -2,6 +2,9 @@
 #[serde(rename_all = "snake_case")]
 pub enum Format {
     Json,
+    /// Newline-delimited JSON: one JSON value per line.
+    #[serde(rename = "ndjson")]
+    NdJson,
     Yaml,
     Toml,
     Xml,
@@ -12,10 +15,31 @@ pub enum Format {
     Binary,
 }
 
+impl Format {
+    /// Infer the format from a file extension (`json`, `ndjson`/`jsonl`,
+    /// `yaml`/`yml`, `toml`), falling back to `Json` for anything else.
+    pub fn from_path(path: impl AsRef<std::path::Path>) -> Self {
+        Self::from_extension(path).unwrap_or(Self::Json)
+    }
+
+    /// Infer the format from a file extension like `from_path`, or `None`
+    /// if the extension is missing or not one of those.
+    pub fn from_extension(path: impl AsRef<std::path::Path>) -> Option<Self> {
+        match path.as_ref().extension().and_then(|e| e.to_str()) {
+            Some("json") => Some(Self::Json),
+            Some("ndjson") | Some("jsonl") => Some(Self::NdJson),
+            Some("yaml") | Some("yml") => Some(Self::Yaml),
+            Some("toml") => Some(Self::Toml),
+            _ => None,
+        }
+    }
+}
+
 impl std::fmt::Display for Format {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Json => write!(f, "json"),
+            Self::NdJson => write!(f, "ndjson"),
             Self::Yaml => write!(f, "yaml"),
             Self::Toml => write!(f, "toml"),
             Self::Xml => write!(f, "xml"),
@@ -27,3 +51,43 @@ impl std::fmt::Display for Format {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_path_json() {
+        assert_eq!(Format::from_path("config.json"), Format::Json);
+    }
+
+    #[test]
+    fn test_from_path_ndjson() {
+        assert_eq!(Format::from_path("samples.ndjson"), Format::NdJson);
+        assert_eq!(Format::from_path("samples.jsonl"), Format::NdJson);
+    }
+
+    #[test]
+    fn test_from_path_yaml() {
+        assert_eq!(Format::from_path("config.yaml"), Format::Yaml);
+        assert_eq!(Format::from_path("config.yml"), Format::Yaml);
+    }
+
+    #[test]
+    fn test_from_path_toml() {
+        assert_eq!(Format::from_path("config.toml"), Format::Toml);
+    }
+
+    #[test]
+    fn test_from_path_default() {
+        assert_eq!(Format::from_path("config"), Format::Json);
+        assert_eq!(Format::from_path("config.txt"), Format::Json);
+    }
+
+    #[test]
+    fn test_from_extension_unknown() {
+        assert_eq!(Format::from_extension("config.yml"), Some(Format::Yaml));
+        assert_eq!(Format::from_extension("config.conf"), None);
+        assert_eq!(Format::from_extension("config"), None);
+    }
+}
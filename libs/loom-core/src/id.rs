@@ -1,3 +1,9 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use sha2::{Digest, Sha256};
+
+use crate::value::{Number, Value};
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
 pub struct Id([u8; 32]);
 
@@ -6,11 +12,129 @@ impl Id {
         Self(*blake3::hash(key.as_bytes()).as_bytes())
     }
 
+    /// A random id holding the 16 bytes of a v4 UUID, zero padded.
+    pub fn uuid() -> Self {
+        let mut bytes = [0; 32];
+        bytes[..16].copy_from_slice(uuid::Uuid::new_v4().as_bytes());
+        Self(bytes)
+    }
+
+    /// A random id holding the 16 bytes of a ULID, zero padded: a 48-bit
+    /// big-endian millisecond timestamp followed by 80 random bits, so ids
+    /// made in different milliseconds sort by creation time.
+    pub fn ulid() -> Self {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+
+        // Every byte of a v4 UUID but 6 and 8 (version and variant) is random
+        let random = *uuid::Uuid::new_v4().as_bytes();
+
+        let mut bytes = [0; 32];
+        bytes[..6].copy_from_slice(&millis.to_be_bytes()[2..]);
+        bytes[6..12].copy_from_slice(&random[..6]);
+        bytes[12..16].copy_from_slice(&random[9..13]);
+        Self(bytes)
+    }
+
+    /// A stable id for the content of `value`: the SHA-256 of its
+    /// canonical bytes.
+    ///
+    /// Equal values always get the same id: object keys are hashed in
+    /// sorted order, so key order in the source document doesn't matter.
+    /// Ints and floats hash differently, so `1` and `1.0` get different ids.
+    pub fn content_hash(value: &Value) -> Self {
+        let mut hasher = Sha256::new();
+        hash_value(&mut hasher, value);
+        Self(hasher.finalize().into())
+    }
+
     pub fn as_bytes(&self) -> &[u8; 32] {
         &self.0
     }
 }
 
+/// How ids are assigned to records.
+#[derive(
+    Debug, Copy, Clone, Default, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum IdStrategy {
+    /// Hash of the record's key, e.g. its path (`Id::new`)
+    #[default]
+    Key,
+
+    /// A new random UUID every time (`Id::uuid`)
+    Uuid,
+
+    /// A new ULID every time (`Id::ulid`), sortable by creation time
+    Ulid,
+
+    /// Hash of the record's content (`Id::content_hash`), for ids that are
+    /// reproducible across runs
+    ContentHash,
+}
+
+impl IdStrategy {
+    pub fn generate(&self, key: &str, value: &Value) -> Id {
+        match self {
+            Self::Key => Id::new(key),
+            Self::Uuid => Id::uuid(),
+            Self::Ulid => Id::ulid(),
+            Self::ContentHash => Id::content_hash(value),
+        }
+    }
+}
+
+/// Feed `value` to `hasher` with a type tag per variant and length
+/// prefixes, so distinct values never produce the same byte stream.
+fn hash_value(hasher: &mut Sha256, value: &Value) {
+    match value {
+        Value::Null => {
+            hasher.update([0u8]);
+        }
+        Value::Bool(v) => {
+            hasher.update([1, *v as u8]);
+        }
+        Value::Number(Number::Int(v)) => {
+            hasher.update([2u8]);
+            hasher.update(v.to_le_bytes());
+        }
+        Value::Number(Number::Float(v)) => {
+            hasher.update([3u8]);
+            hasher.update(v.to_bits().to_le_bytes());
+        }
+        Value::String(v) => {
+            hasher.update([4u8]);
+            hash_str(hasher, v);
+        }
+        Value::Array(arr) => {
+            hasher.update([5u8]);
+            hasher.update((arr.len() as u64).to_le_bytes());
+
+            for item in arr.iter() {
+                hash_value(hasher, item);
+            }
+        }
+        Value::Object(obj) => {
+            hasher.update([6u8]);
+            hasher.update((obj.len() as u64).to_le_bytes());
+
+            // `Object` iterates in key order
+            for (key, item) in obj.iter() {
+                hash_str(hasher, key);
+                hash_value(hasher, item);
+            }
+        }
+    }
+}
+
+fn hash_str(hasher: &mut Sha256, value: &str) {
+    hasher.update((value.len() as u64).to_le_bytes());
+    hasher.update(value.as_bytes());
+}
+
 impl std::fmt::Display for Id {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", String::from_utf8_lossy(&self.0))
@@ -24,3 +148,108 @@ impl std::ops::Deref for Id {
         &self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value::Object;
+
+    fn object(fields: &[(&str, Value)]) -> Value {
+        let mut obj = Object::new();
+        for (key, value) in fields {
+            obj.insert(key.to_string(), value.clone());
+        }
+
+        Value::Object(obj)
+    }
+
+    #[test]
+    fn test_content_hash_ignores_key_order() {
+        let a = object(&[
+            ("id", Value::from(1)),
+            ("text", Value::from("hello")),
+            (
+                "meta",
+                object(&[("lang", "en".into()), ("source", "web".into())]),
+            ),
+        ]);
+        let b = object(&[
+            (
+                "meta",
+                object(&[("source", "web".into()), ("lang", "en".into())]),
+            ),
+            ("text", Value::from("hello")),
+            ("id", Value::from(1)),
+        ]);
+
+        assert_eq!(Id::content_hash(&a), Id::content_hash(&b));
+    }
+
+    #[test]
+    fn test_content_hash_distinguishes_values() {
+        let ids = [
+            Value::Null,
+            Value::from(false),
+            Value::from(1),
+            Value::from(1.0),
+            Value::from("1"),
+            Value::from(["a", "b"]),
+            Value::from(["ab"]),
+            object(&[("a", Value::from("b"))]),
+            object(&[("ab", Value::Null)]),
+        ]
+        .map(|v| Id::content_hash(&v));
+
+        for (i, a) in ids.iter().enumerate() {
+            for b in &ids[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_strategies() {
+        let value = Value::from("hello");
+
+        assert_eq!(IdStrategy::Key.generate("a/b", &value), Id::new("a/b"));
+        assert_eq!(
+            IdStrategy::ContentHash.generate("a/b", &value),
+            IdStrategy::ContentHash.generate("c/d", &value)
+        );
+        assert_ne!(
+            IdStrategy::Uuid.generate("a/b", &value),
+            IdStrategy::Uuid.generate("a/b", &value)
+        );
+        assert_ne!(
+            IdStrategy::Ulid.generate("a/b", &value),
+            IdStrategy::Ulid.generate("a/b", &value)
+        );
+    }
+
+    #[test]
+    fn test_content_hash_is_sha256() {
+        // Null is the single type tag byte 0
+        assert_eq!(
+            Id::content_hash(&Value::Null).as_bytes(),
+            &<[u8; 32]>::from(Sha256::digest([0u8]))
+        );
+    }
+
+    #[test]
+    fn test_ulid_sorts_by_creation_time() {
+        let first = Id::ulid();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let second = Id::ulid();
+
+        assert!(first.as_bytes() < second.as_bytes());
+        assert_eq!(&first[16..], &[0; 16]);
+
+        let mut millis = [0; 8];
+        millis[2..].copy_from_slice(&first[..6]);
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        assert!(now - u64::from_be_bytes(millis) < 1000);
+    }
+}
@@ -1,12 +1,17 @@
 mod cache;
+mod content_type;
 mod format;
 mod id;
 mod map;
 mod media_type;
+#[cfg(feature = "json")]
+#[doc(hidden)]
+pub mod ndjson;
 pub mod path;
 pub mod value;
 
 pub use cache::*;
+pub use content_type::*;
 pub use format::*;
 pub use id::*;
 pub use map::*;
@@ -20,6 +25,7 @@ pub use media_type::*;
 /// let json = encode!(&data; json)?;
 /// let yaml = encode!(&data; yaml)?;
 /// let toml = encode!(&data; toml)?;
+/// let ndjson = encode!(&rows; ndjson)?;
 ///
 /// // Encode using Format enum (runtime dispatch)
 /// let s = encode!(&data, Format::Json)?;
@@ -37,6 +43,16 @@ macro_rules! encode {
             compile_error!("json feature not enabled")
         }
     }};
+    ($value:expr; ndjson) => {{
+        #[cfg(feature = "json")]
+        {
+            $crate::ndjson::to_string($value)
+        }
+        #[cfg(not(feature = "json"))]
+        {
+            compile_error!("json feature not enabled")
+        }
+    }};
     ($value:expr; yaml) => {{
         #[cfg(feature = "yaml")]
         {
@@ -64,6 +80,8 @@ macro_rules! encode {
             $crate::Format::Json => {
                 ::serde_json::to_string_pretty($value).map_err(|e| e.to_string())
             }
+            #[cfg(feature = "json")]
+            $crate::Format::NdJson => $crate::ndjson::to_string($value),
             #[cfg(feature = "yaml")]
             $crate::Format::Yaml => ::serde_saphyr::to_string($value).map_err(|e| e.to_string()),
             #[cfg(feature = "toml")]
@@ -82,6 +100,7 @@ macro_rules! encode {
 /// let data: MyType = decode!(content; json)?;
 /// let data: MyType = decode!(content; yaml)?;
 /// let data: MyType = decode!(content; toml)?;
+/// let rows: Vec<MyType> = decode!(content; ndjson)?;
 ///
 /// // Decode using Format enum (runtime dispatch)
 /// let data: MyType = decode!(content, Format::Json)?;
@@ -99,6 +118,16 @@ macro_rules! decode {
             compile_error!("json feature not enabled")
         }
     }};
+    ($value:expr; ndjson) => {{
+        #[cfg(feature = "json")]
+        {
+            $crate::ndjson::from_str($value)
+        }
+        #[cfg(not(feature = "json"))]
+        {
+            compile_error!("json feature not enabled")
+        }
+    }};
     ($value:expr; yaml) => {{
         #[cfg(feature = "yaml")]
         {
@@ -124,6 +153,8 @@ macro_rules! decode {
         match $format {
             #[cfg(feature = "json")]
             $crate::Format::Json => ::serde_json::from_str($value).map_err(|e| e.to_string()),
+            #[cfg(feature = "json")]
+            $crate::Format::NdJson => $crate::ndjson::from_str($value),
             #[cfg(feature = "yaml")]
             $crate::Format::Yaml => ::serde_saphyr::from_str($value).map_err(|e| e.to_string()),
             #[cfg(feature = "toml")]
@@ -2,7 +2,9 @@ use std::path::Path;
 
 use crate::Format;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum MediaType {
     // --- Text / structured text ---
@@ -14,6 +16,7 @@ pub enum MediaType {
     TextToml,
     TextYaml,
     TextJson,
+    ApplicationNdjson,
 
     // --- Code (optional but handy for memory services) ---
     CodeRust,
@@ -64,15 +67,10 @@ pub enum MediaType {
     /// Known to be binary, but not otherwise classified.
     Binary,
     /// Completely unknown - we don't even know if it's text or binary.
+    #[default]
     Unknown,
 }
 
-impl Default for MediaType {
-    fn default() -> Self {
-        Self::Unknown
-    }
-}
-
 impl MediaType {
     pub fn as_mime_str(self) -> &'static str {
         match self {
@@ -84,6 +82,7 @@ impl MediaType {
             Self::TextToml => "application/toml",
             Self::TextYaml => "application/yaml",
             Self::TextJson => "application/json",
+            Self::ApplicationNdjson => "application/x-ndjson",
 
             Self::CodeRust => "text/x-rust",
             Self::CodeCSharp => "text/x-csharp",
@@ -142,6 +141,7 @@ impl MediaType {
                 | Self::TextToml
                 | Self::TextYaml
                 | Self::TextJson
+                | Self::ApplicationNdjson
                 | Self::CodeRust
                 | Self::CodeCSharp
                 | Self::CodeTypeScript
@@ -163,6 +163,7 @@ impl MediaType {
     pub fn format(self) -> Format {
         match self {
             Self::TextJson => Format::Json,
+            Self::ApplicationNdjson => Format::NdJson,
             Self::TextYaml => Format::Yaml,
             Self::TextToml => Format::Toml,
             Self::TextXml => Format::Xml,
@@ -206,6 +207,7 @@ impl MediaType {
             Some("toml") => Self::TextToml,
             Some("yaml") | Some("yml") => Self::TextYaml,
             Some("json") => Self::TextJson,
+            Some("ndjson") | Some("jsonl") => Self::ApplicationNdjson,
 
             Some("rs") => Self::CodeRust,
             Some("cs") => Self::CodeCSharp,
@@ -250,8 +252,10 @@ impl MediaType {
         }
     }
 
+    /// Parse a MIME type, ignoring parameters such as `; charset=utf-8`.
     pub fn from_mime_str(mime: &str) -> Self {
-        let m = mime.trim().to_ascii_lowercase();
+        let essence = mime.split(';').next().unwrap_or_default();
+        let m = essence.trim().to_ascii_lowercase();
         match m.as_str() {
             "text/plain" => Self::TextPlain,
             "text/markdown" => Self::TextMarkdown,
@@ -261,6 +265,9 @@ impl MediaType {
             "application/toml" => Self::TextToml,
             "application/yaml" | "text/yaml" => Self::TextYaml,
             "application/json" | "text/json" => Self::TextJson,
+            "application/x-ndjson" | "application/ndjson" | "application/jsonl" => {
+                Self::ApplicationNdjson
+            }
 
             "application/pdf" => Self::Pdf,
             "application/octet-stream" => Self::Binary,
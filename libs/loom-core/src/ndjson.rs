@@ -0,0 +1,69 @@
+//! Newline-delimited JSON helpers behind the `encode!`/`decode!` macros.
+//!
+//! A document is a sequence of values, one per non-blank line. It decodes
+//! as an array, and an array encodes as one compact value per line.
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Encode `value` as NDJSON: each element of an array on its own line, or
+/// any other value as a single line.
+pub fn to_string<T: Serialize + ?Sized>(value: &T) -> Result<String, String> {
+    let lines = match serde_json::to_value(value).map_err(|e| e.to_string())? {
+        serde_json::Value::Array(items) => items,
+        other => vec![other],
+    };
+
+    let mut out = String::new();
+    for line in &lines {
+        out.push_str(&serde_json::to_string(line).map_err(|e| e.to_string())?);
+        out.push('\n');
+    }
+
+    Ok(out)
+}
+
+/// Decode NDJSON into `T` by collecting every non-blank line into an array.
+/// Errors name the 1-based line that failed to parse.
+pub fn from_str<T: DeserializeOwned>(content: &str) -> Result<T, String> {
+    let mut items = Vec::new();
+
+    for (i, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let item: serde_json::Value =
+            serde_json::from_str(line).map_err(|e| format!("line {}: {}", i + 1, e))?;
+        items.push(item);
+    }
+
+    serde_json::from_value(serde_json::Value::Array(items)).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_arrays_one_value_per_line() {
+        let rows = vec![vec![1, 2], vec![3]];
+        let content = to_string(&rows).unwrap();
+        assert_eq!(content, "[1,2]\n[3]\n");
+
+        let decoded: Vec<Vec<i32>> = from_str(&content).unwrap();
+        assert_eq!(decoded, rows);
+    }
+
+    #[test]
+    fn decode_skips_blank_lines_and_crlf() {
+        let decoded: Vec<i32> = from_str("1\r\n\r\n2\n").unwrap();
+        assert_eq!(decoded, vec![1, 2]);
+    }
+
+    #[test]
+    fn decode_error_names_line() {
+        let err = from_str::<Vec<i32>>("1\n{oops\n").unwrap_err();
+        assert!(err.starts_with("line 2:"), "{}", err);
+    }
+}
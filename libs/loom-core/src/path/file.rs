@@ -14,6 +14,73 @@ impl FilePath {
     pub fn is_empty(&self) -> bool {
         self.0.as_os_str().is_empty()
     }
+
+    /// The path with `segment` appended. As with `std::path::Path::join`,
+    /// an absolute `segment` replaces the path.
+    pub fn join(&self, segment: &str) -> FilePath {
+        Self(self.0.join(segment))
+    }
+
+    /// The path without its final component, or `None` for a root or an
+    /// empty path. The parent of a single relative component is empty.
+    pub fn parent(&self) -> Option<FilePath> {
+        self.0.parent().map(|p| Self(p.to_path_buf()))
+    }
+
+    /// Lexically collapse `.` and `..` segments without touching the file
+    /// system, so symlinks are not resolved. `..` at the root of an
+    /// absolute path stays at the root, a relative path keeps the leading
+    /// `..` segments it can't collapse, and an empty result is `.`.
+    pub fn normalize(&self) -> FilePath {
+        use std::path::Component;
+
+        let mut out: Vec<Component> = Vec::new();
+
+        for component in self.0.components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => match out.last() {
+                    Some(Component::Normal(_)) => {
+                        out.pop();
+                    }
+                    Some(Component::RootDir | Component::Prefix(_)) => {}
+                    _ => out.push(component),
+                },
+                _ => out.push(component),
+            }
+        }
+
+        if out.is_empty() {
+            return Self::parse(".");
+        }
+
+        Self(out.iter().collect())
+    }
+
+    /// `true` if this path, once normalized, is `base` or lies below it.
+    ///
+    /// The check is lexical: an absolute path is never within a relative
+    /// base (or vice versa), and symlinks are not followed.
+    pub fn is_within(&self, base: &FilePath) -> bool {
+        use std::path::Component;
+
+        let (path, base) = (self.normalize(), base.normalize());
+
+        if path.is_absolute() != base.is_absolute() {
+            return false;
+        }
+
+        let path: Vec<Component> = path
+            .components()
+            .filter(|c| *c != Component::CurDir)
+            .collect();
+        let base: Vec<Component> = base
+            .components()
+            .filter(|c| *c != Component::CurDir)
+            .collect();
+
+        path.starts_with(&base) && !path[base.len()..].contains(&Component::ParentDir)
+    }
 }
 
 impl From<std::path::PathBuf> for FilePath {
@@ -64,6 +131,67 @@ mod tests {
         assert_eq!(path.file_name().unwrap(), "file.txt");
     }
 
+    #[test]
+    fn test_join() {
+        let dir = FilePath::parse("/data/evals");
+        assert_eq!(dir.join("a.json").to_string(), "/data/evals/a.json");
+        assert_eq!(dir.join("x/b.json").to_string(), "/data/evals/x/b.json");
+        assert_eq!(dir.join("/etc/c.json").to_string(), "/etc/c.json");
+    }
+
+    #[test]
+    fn test_parent() {
+        let path = FilePath::parse("/data/evals/a.json");
+        assert_eq!(path.parent().unwrap().to_string(), "/data/evals");
+        assert_eq!(FilePath::parse("a.json").parent().unwrap().to_string(), "");
+        assert!(FilePath::parse("/").parent().is_none());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let cases = [
+            ("/data/./evals/../samples/a.json", "/data/samples/a.json"),
+            ("data//evals/", "data/evals"),
+            ("a/b/../../c", "c"),
+            ("a/..", "."),
+            ("../a/../../b", "../../b"),
+            ("/../../etc/passwd", "/etc/passwd"),
+            ("./", "."),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(
+                FilePath::parse(input).normalize().to_string(),
+                expected,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_within() {
+        let root = FilePath::parse("/srv/data");
+
+        assert!(FilePath::parse("/srv/data").is_within(&root));
+        assert!(FilePath::parse("/srv/data/evals/a.json").is_within(&root));
+        assert!(FilePath::parse("/srv/data/x/../a.json").is_within(&root));
+        assert!(!FilePath::parse("/srv/database").is_within(&root));
+        assert!(!FilePath::parse("/srv/data/../other").is_within(&root));
+        assert!(!FilePath::parse("data/a.json").is_within(&root));
+    }
+
+    #[test]
+    fn test_is_within_rejects_traversal_escape() {
+        let root = FilePath::parse("/srv/data");
+        let escape = FilePath::parse("/srv/data/../../etc/passwd");
+        assert!(!escape.is_within(&root));
+
+        let cwd = FilePath::parse(".");
+        assert!(FilePath::parse("evals/a.json").is_within(&cwd));
+        assert!(!FilePath::parse("../../etc/passwd").is_within(&cwd));
+        assert!(!FilePath::parse("evals/../../etc/passwd").is_within(&cwd));
+    }
+
     #[test]
     fn test_display() {
         let path = FilePath::parse("./relative/path");
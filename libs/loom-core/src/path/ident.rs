@@ -38,6 +38,38 @@ impl IdentPath {
     pub fn segments(&self) -> &[IdentSegment] {
         &self.0
     }
+
+    /// The path with `segment` parsed as a relative ident path and appended,
+    /// so `join("a.b")` adds two keys and `join("[0]")` an index. A segment
+    /// that doesn't parse is appended verbatim as a single key.
+    pub fn join(&self, segment: &str) -> IdentPath {
+        let mut segments = self.0.clone();
+
+        match Self::parse(segment) {
+            Ok(path) => segments.extend(path.0),
+            Err(_) => segments.push(IdentSegment::Key(segment.to_string())),
+        }
+
+        Self(segments)
+    }
+
+    /// The path without its last segment, or `None` for an empty path. As
+    /// with `FilePath::parent`, the parent of a single segment is empty.
+    pub fn parent(&self) -> Option<IdentPath> {
+        let (_, parent) = self.0.split_last()?;
+        Some(Self(parent.to_vec()))
+    }
+
+    /// `true` if any segment is a `*` or `[*]` wildcard.
+    pub fn is_wildcard(&self) -> bool {
+        self.0.iter().any(IdentSegment::is_wildcard)
+    }
+}
+
+impl From<Vec<IdentSegment>> for IdentPath {
+    fn from(segments: Vec<IdentSegment>) -> Self {
+        Self(segments)
+    }
 }
 
 impl std::fmt::Display for IdentPath {
@@ -47,6 +79,9 @@ impl std::fmt::Display for IdentPath {
                 IdentSegment::Key(v) if i == 0 => write!(f, "{}", v)?,
                 IdentSegment::Key(v) => write!(f, ".{}", v)?,
                 IdentSegment::Index(v) => write!(f, "[{}]", v)?,
+                IdentSegment::AnyKey if i == 0 => write!(f, "*")?,
+                IdentSegment::AnyKey => write!(f, ".*")?,
+                IdentSegment::AnyIndex => write!(f, "[*]")?,
             }
         }
 
@@ -58,9 +93,19 @@ impl std::fmt::Display for IdentPath {
 pub enum IdentSegment {
     Key(String),
     Index(usize),
+
+    /// `*`: every value of an object, in key order.
+    AnyKey,
+
+    /// `[*]`: every element of an array, in order.
+    AnyIndex,
 }
 
 impl IdentSegment {
+    pub fn is_wildcard(&self) -> bool {
+        matches!(self, Self::AnyKey | Self::AnyIndex)
+    }
+
     fn parse_next(
         chars: &mut std::iter::Peekable<std::str::Chars>,
         expect_separator: bool,
@@ -103,11 +148,11 @@ impl IdentSegment {
             }
         }
 
-        if key.is_empty() {
-            return Err(IdentPathError::EmptySegment);
+        match key.as_str() {
+            "" => Err(IdentPathError::EmptySegment),
+            "*" => Ok(Self::AnyKey),
+            _ => Ok(Self::Key(key)),
         }
-
-        Ok(Self::Key(key))
     }
 
     fn parse_index(
@@ -129,6 +174,10 @@ impl IdentSegment {
             return Err(IdentPathError::EmptyBracket);
         }
 
+        if index == "*" {
+            return Ok(Self::AnyIndex);
+        }
+
         let value = index.parse().map_err(|_| IdentPathError::InvalidIndex)?;
         Ok(Self::Index(value))
     }
@@ -139,6 +188,8 @@ impl std::fmt::Display for IdentSegment {
         match self {
             Self::Key(v) => write!(f, ".{}", v),
             Self::Index(v) => write!(f, "[{}]", v),
+            Self::AnyKey => write!(f, ".*"),
+            Self::AnyIndex => write!(f, "[*]"),
         }
     }
 }
@@ -231,6 +282,51 @@ mod tests {
         assert_eq!(err, IdentPathError::InvalidIndex);
     }
 
+    #[test]
+    fn test_join_parses_segment() {
+        let path = IdentPath::parse("items[0]").unwrap();
+        assert_eq!(path.join("name").to_string(), "items[0].name");
+        assert_eq!(path.join("a.b"), IdentPath::parse("items[0].a.b").unwrap());
+        assert_eq!(path.join("[1]"), IdentPath::parse("items[0][1]").unwrap());
+        assert_eq!(
+            path.join("a[").segments()[2],
+            IdentSegment::Key("a[".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parent() {
+        let path = IdentPath::parse("object.items[0]").unwrap();
+        assert_eq!(path.parent().unwrap().to_string(), "object.items");
+
+        let parent = IdentPath::parse("object").unwrap().parent().unwrap();
+        assert!(parent.is_empty());
+        assert_eq!(parent.to_string(), "");
+        assert!(parent.parent().is_none());
+    }
+
+    #[test]
+    fn test_parse_wildcards() {
+        let path = IdentPath::parse("object.*.items[*]").unwrap();
+        assert_eq!(
+            path.segments(),
+            &[
+                IdentSegment::Key("object".to_string()),
+                IdentSegment::AnyKey,
+                IdentSegment::Key("items".to_string()),
+                IdentSegment::AnyIndex,
+            ]
+        );
+        assert!(path.is_wildcard());
+        assert!(!IdentPath::parse("object.items[0]").unwrap().is_wildcard());
+    }
+
+    #[test]
+    fn test_parse_star_inside_key_is_literal() {
+        let path = IdentPath::parse("a.b*c").unwrap();
+        assert_eq!(path.segments()[1], IdentSegment::Key("b*c".to_string()));
+    }
+
     #[test]
     fn test_display_roundtrip() {
         let inputs = [
@@ -240,6 +336,9 @@ mod tests {
             "object.field[2].test",
             "arr[0][1]",
             "a[0].b",
+            "object.*.name",
+            "items[*]",
+            "*[*].x",
         ];
 
         for input in inputs {
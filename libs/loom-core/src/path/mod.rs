@@ -9,6 +9,9 @@ pub use uri::*;
 
 /// Creates a `Path` from a string literal.
 ///
+/// The `uri` and `ident` forms panic on invalid input, so only use them
+/// with literals known to be valid; parse runtime strings with `try_path!`.
+///
 /// # Variants
 ///
 /// - `path!(file => "path/to/file")` - Creates a `Path::File`
@@ -37,6 +40,36 @@ macro_rules! path {
     };
 }
 
+/// Parses a `Path` without panicking, for runtime strings.
+///
+/// # Variants
+///
+/// - `try_path!(file => s)` - `Ok(Path::File)`, file paths always parse
+/// - `try_path!(uri => s)` - `Result<Path, UriError>`
+/// - `try_path!(ident => s)` - `Result<Path, IdentPathError>`
+///
+/// # Examples
+///
+/// ```ignore
+/// use loom_core::try_path;
+///
+/// let field = try_path!(ident => user_input)?;
+/// ```
+#[macro_export]
+macro_rules! try_path {
+    (file => $path:expr) => {
+        ::std::result::Result::<$crate::path::Path, ::std::convert::Infallible>::Ok(
+            $crate::path::Path::from($crate::file_path!($path)),
+        )
+    };
+    (uri => $path:expr) => {
+        $crate::try_uri_path!($path).map($crate::path::Path::from)
+    };
+    (ident => $path:expr) => {
+        $crate::try_ident_path!($path).map($crate::path::Path::from)
+    };
+}
+
 #[macro_export]
 macro_rules! file_path {
     ($path:expr) => {
@@ -44,6 +77,8 @@ macro_rules! file_path {
     };
 }
 
+/// Creates a `UriPath`, panicking on an invalid URI. For literals only; use
+/// `try_uri_path!` for runtime strings.
 #[macro_export]
 macro_rules! uri_path {
     ($path:expr) => {
@@ -51,6 +86,16 @@ macro_rules! uri_path {
     };
 }
 
+/// Parses a `UriPath`, returning `Result<UriPath, UriError>`.
+#[macro_export]
+macro_rules! try_uri_path {
+    ($path:expr) => {
+        $crate::path::UriPath::parse($path)
+    };
+}
+
+/// Creates an `IdentPath`, panicking on an invalid path. For literals only;
+/// use `try_ident_path!` for runtime strings.
 #[macro_export]
 macro_rules! ident_path {
     ($path:expr) => {
@@ -58,8 +103,17 @@ macro_rules! ident_path {
     };
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+/// Parses an `IdentPath`, returning `Result<IdentPath, IdentPathError>`.
+#[macro_export]
+macro_rules! try_ident_path {
+    ($path:expr) => {
+        $crate::path::IdentPath::parse($path)
+    };
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize, Default)]
 pub enum Path {
+    #[default]
     Empty,
     File(FilePath),
     Uri(UriPath),
@@ -82,11 +136,27 @@ impl Path {
     pub fn is_ident(&self) -> bool {
         matches!(self, Self::Ident(_))
     }
-}
 
-impl Default for Path {
-    fn default() -> Self {
-        Self::Empty
+    /// A child path: a file path component, a URI path segment or the ident
+    /// segments parsed from `segment`, depending on the variant. `Empty` has no base to join onto
+    /// and stays `Empty`.
+    pub fn join(&self, segment: &str) -> Path {
+        match self {
+            Self::Empty => Self::Empty,
+            Self::File(v) => Self::File(v.join(segment)),
+            Self::Uri(v) => Self::Uri(v.join(segment)),
+            Self::Ident(v) => Self::Ident(v.join(segment)),
+        }
+    }
+
+    /// The enclosing path, or `None` if there is none (including `Empty`).
+    pub fn parent(&self) -> Option<Path> {
+        match self {
+            Self::Empty => None,
+            Self::File(v) => v.parent().map(Self::File),
+            Self::Uri(v) => v.parent().map(Self::Uri),
+            Self::Ident(v) => v.parent().map(Self::Ident),
+        }
     }
 }
 
@@ -144,6 +214,39 @@ mod tests {
         assert_eq!(path.to_string(), "object.field[0]");
     }
 
+    #[test]
+    fn test_try_path_macro_invalid_ident() {
+        let input = String::from("items[0");
+
+        assert_eq!(
+            try_path!(ident => &input),
+            Err(IdentPathError::UnmatchedBracket)
+        );
+        assert_eq!(try_ident_path!("a..b"), Err(IdentPathError::EmptySegment));
+    }
+
+    #[test]
+    fn test_try_path_macro_valid() {
+        assert_eq!(
+            try_path!(ident => "object.field[0]"),
+            Ok(path!(ident => "object.field[0]"))
+        );
+        assert_eq!(
+            try_path!(uri => "https://example.com/path").unwrap(),
+            path!(uri => "https://example.com/path")
+        );
+        assert_eq!(
+            try_path!(file => "/tmp/a.json"),
+            Ok(path!(file => "/tmp/a.json"))
+        );
+    }
+
+    #[test]
+    fn test_try_path_macro_invalid_uri() {
+        assert!(try_path!(uri => "not a uri").is_err());
+        assert!(try_uri_path!("://missing-scheme").is_err());
+    }
+
     #[test]
     fn test_path_is_file() {
         let path = Path::File(FilePath::parse("/home/user/file.txt"));
@@ -168,6 +271,62 @@ mod tests {
         assert!(path.is_ident());
     }
 
+    #[test]
+    fn test_path_join_file() {
+        let path = path!(file => "/data/evals").join("a.json");
+        assert_eq!(path, path!(file => "/data/evals/a.json"));
+        assert_eq!(path.parent().unwrap(), path!(file => "/data/evals"));
+    }
+
+    #[test]
+    fn test_path_join_uri() {
+        let base = path!(uri => "https://example.com/api/v1?token=x#top");
+        assert_eq!(
+            base.join("records/a.json").to_string(),
+            "https://example.com/api/v1/records/a.json"
+        );
+        assert_eq!(
+            path!(uri => "https://example.com/api/")
+                .join("/a")
+                .to_string(),
+            "https://example.com/api/a"
+        );
+        assert_eq!(
+            path!(uri => "https://example.com").join("a").to_string(),
+            "https://example.com/a"
+        );
+    }
+
+    #[test]
+    fn test_path_parent_uri() {
+        let path = path!(uri => "https://example.com/api/v1/");
+        assert_eq!(
+            path.parent().unwrap().to_string(),
+            "https://example.com/api"
+        );
+        assert_eq!(
+            path!(uri => "https://example.com/api")
+                .parent()
+                .unwrap()
+                .to_string(),
+            "https://example.com/"
+        );
+        assert!(path!(uri => "https://example.com/").parent().is_none());
+    }
+
+    #[test]
+    fn test_path_join_ident() {
+        let path = path!(ident => "data.items[0]").join("name");
+        assert_eq!(path.to_string(), "data.items[0].name");
+        assert_eq!(path.parent().unwrap(), path!(ident => "data.items[0]"));
+    }
+
+    #[test]
+    fn test_path_join_empty_stays_empty() {
+        assert_eq!(Path::Empty.join("a"), Path::Empty);
+        assert!(Path::Empty.parent().is_none());
+    }
+
     #[test]
     fn test_path_display_file() {
         let path = Path::File(FilePath::parse("/home/user/file.txt"));
@@ -36,12 +36,8 @@ impl Authority {
                 .ok_or(AuthorityError::InvalidSyntax)?;
             let host = &host_and_port[1..bracket_end];
             let after_bracket = &host_and_port[bracket_end + 1..];
-            let port = if after_bracket.starts_with(':') {
-                Some(
-                    after_bracket[1..]
-                        .parse()
-                        .map_err(|_| AuthorityError::InvalidPort)?,
-                )
+            let port = if let Some(port) = after_bracket.strip_prefix(':') {
+                Some(port.parse().map_err(|_| AuthorityError::InvalidPort)?)
             } else {
                 None
             };
@@ -35,10 +35,9 @@ impl UriPath {
 
         let (scheme_str, rest) = s.split_once(':').ok_or(UriError::MissingScheme)?;
         let scheme = Scheme::parse(scheme_str).map_err(UriError::InvalidScheme)?;
-        let (authority, path_and_rest) = if rest.starts_with("//") {
-            let after_slashes = &rest[2..];
+        let (authority, path_and_rest) = if let Some(after_slashes) = rest.strip_prefix("//") {
             let auth_end = after_slashes
-                .find(|c| c == '/' || c == '?' || c == '#')
+                .find(['/', '?', '#'])
                 .unwrap_or(after_slashes.len());
             let auth_str = &after_slashes[..auth_end];
             let authority = if auth_str.is_empty() {
@@ -119,6 +118,41 @@ impl UriPath {
     pub fn fragment(&self) -> Option<&str> {
         self.fragment.as_deref()
     }
+
+    /// The URI with `segment` appended to its path, joined by exactly one
+    /// `/`. Scheme and authority are kept; the query and fragment belong to
+    /// the base resource and are dropped.
+    pub fn join(&self, segment: &str) -> UriPath {
+        let mut path = self.path.clone();
+        if !path.ends_with('/') {
+            path.push('/');
+        }
+        path.push_str(segment.trim_start_matches('/'));
+
+        self.with_path(path)
+    }
+
+    /// The URI without the last segment of its path, or `None` at the root.
+    /// The query and fragment are dropped.
+    pub fn parent(&self) -> Option<UriPath> {
+        let trimmed = self.path.trim_end_matches('/');
+        let end = trimmed.rfind('/')?;
+
+        Some(self.with_path(match end {
+            0 => "/",
+            _ => &trimmed[..end],
+        }))
+    }
+
+    fn with_path(&self, path: impl Into<String>) -> UriPath {
+        Self {
+            scheme: self.scheme.clone(),
+            authority: self.authority.clone(),
+            path: path.into(),
+            query: None,
+            fragment: None,
+        }
+    }
 }
 
 impl std::fmt::Display for UriPath {
@@ -7,6 +7,20 @@ impl Array {
     pub fn new() -> Self {
         Self(vec![])
     }
+
+    /// Insert at `index`, shifting later elements right.
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, value: Value) {
+        self.0.insert(index, value);
+    }
+
+    /// Remove the element at `index`, shifting later elements left.
+    ///
+    /// Panics if `index >= len`.
+    pub fn remove(&mut self, index: usize) -> Value {
+        self.0.remove(index)
+    }
 }
 
 impl std::ops::Deref for Array {
@@ -51,6 +65,21 @@ impl<T: Into<Value>, const N: usize> From<[T; N]> for Array {
     }
 }
 
+impl FromIterator<Value> for Array {
+    fn from_iter<I: IntoIterator<Item = Value>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for Array {
+    type Item = Value;
+    type IntoIter = std::vec::IntoIter<Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
 impl Default for Array {
     fn default() -> Self {
         Self::new()
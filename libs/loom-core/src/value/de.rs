@@ -0,0 +1,276 @@
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{self, IntoDeserializer, Visitor};
+use serde::forward_to_deserialize_any;
+
+use super::{Number, Value, ValueError};
+
+/// Deserializes a Rust type straight from a borrowed `Value`, the inverse of
+/// `ValueSerializer`. Strings are borrowed from the value where the target
+/// type allows it, and integers coerce to floats.
+impl<'de> de::Deserializer<'de> for &'de Value {
+    type Error = ValueError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        match self {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(v) => visitor.visit_bool(*v),
+            Value::Number(Number::Int(v)) => visitor.visit_i64(*v),
+            Value::Number(Number::Float(v)) => visitor.visit_f64(*v),
+            Value::String(v) => visitor.visit_borrowed_str(v),
+            Value::Array(items) => {
+                let mut seq = SeqDeserializer::new(items.iter());
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Value::Object(fields) => {
+                let mut map = MapDeserializer::new(fields.iter().map(|(k, v)| (k.as_str(), v)));
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        match self {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ValueError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ValueError> {
+        match self {
+            Value::String(variant) => visitor.visit_enum(variant.as_str().into_deserializer()),
+            Value::Object(fields) if fields.len() == 1 => {
+                let (variant, value) = fields.iter().next().expect("one field");
+                visitor.visit_enum(EnumDeserializer { variant, value })
+            }
+            other => Err(de::Error::invalid_type(unexpected(other), &visitor)),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de, ValueError> for &'de Value {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+/// An externally tagged enum variant: `{"variant": value}`.
+struct EnumDeserializer<'de> {
+    variant: &'de str,
+    value: &'de Value,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumDeserializer<'de> {
+    type Error = ValueError;
+    type Variant = VariantDeserializer<'de>;
+
+    fn variant_seed<S: de::DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<(S::Value, Self::Variant), ValueError> {
+        let variant = seed.deserialize(self.variant.into_deserializer())?;
+        Ok((variant, VariantDeserializer(self.value)))
+    }
+}
+
+struct VariantDeserializer<'de>(&'de Value);
+
+impl<'de> de::VariantAccess<'de> for VariantDeserializer<'de> {
+    type Error = ValueError;
+
+    fn unit_variant(self) -> Result<(), ValueError> {
+        de::Deserialize::deserialize(self.0)
+    }
+
+    fn newtype_variant_seed<S: de::DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<S::Value, ValueError> {
+        seed.deserialize(self.0)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, ValueError> {
+        de::Deserializer::deserialize_seq(self.0, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ValueError> {
+        de::Deserializer::deserialize_map(self.0, visitor)
+    }
+}
+
+fn unexpected(value: &Value) -> de::Unexpected<'_> {
+    match value {
+        Value::Null => de::Unexpected::Unit,
+        Value::Bool(v) => de::Unexpected::Bool(*v),
+        Value::Number(Number::Int(v)) => de::Unexpected::Signed(*v),
+        Value::Number(Number::Float(v)) => de::Unexpected::Float(*v),
+        Value::String(v) => de::Unexpected::Str(v),
+        Value::Array(_) => de::Unexpected::Seq,
+        Value::Object(_) => de::Unexpected::Map,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::value::Object;
+
+    fn object(fields: Vec<(&str, Value)>) -> Value {
+        Value::Object(
+            fields
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v))
+                .collect::<Object>(),
+        )
+    }
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Server<'a> {
+        host: &'a str,
+        port: u16,
+        timeout: Option<f64>,
+        #[serde(default)]
+        tags: Vec<String>,
+        #[serde(default = "default_retries")]
+        retries: u32,
+        mode: Mode,
+    }
+
+    fn default_retries() -> u32 {
+        3
+    }
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum Mode {
+        Plain,
+        Tls { verify: bool },
+        Proxy(String),
+    }
+
+    #[test]
+    fn test_deserialize_object_into_struct() {
+        let value = object(vec![
+            ("host", Value::from("localhost")),
+            ("port", Value::from(8080)),
+            ("timeout", Value::from(5)),
+            (
+                "mode",
+                object(vec![("tls", object(vec![("verify", Value::Bool(true))]))]),
+            ),
+        ]);
+
+        let server: Server = value.deserialize_into().unwrap();
+
+        assert_eq!(
+            server,
+            Server {
+                host: "localhost",
+                port: 8080,
+                timeout: Some(5.0),
+                tags: vec![],
+                retries: 3,
+                mode: Mode::Tls { verify: true },
+            }
+        );
+    }
+
+    #[test]
+    fn test_deserialize_null_option_and_unit_variant() {
+        let value = object(vec![
+            ("host", Value::from("db")),
+            ("port", Value::from(5432)),
+            ("timeout", Value::Null),
+            ("tags", Value::Array(vec!["a", "b"].into())),
+            ("mode", Value::from("plain")),
+        ]);
+
+        let server: Server = value.deserialize_into().unwrap();
+
+        assert_eq!(server.timeout, None);
+        assert_eq!(server.tags, vec!["a", "b"]);
+        assert_eq!(server.mode, Mode::Plain);
+    }
+
+    #[test]
+    fn test_deserialize_reports_type_errors() {
+        let value = object(vec![
+            ("host", Value::from("db")),
+            ("port", Value::from(70000)),
+            ("timeout", Value::Null),
+            ("mode", Value::from("plain")),
+        ]);
+
+        let err = value.deserialize_into::<Server>().unwrap_err();
+        assert!(err.message().contains("70000"), "{}", err);
+
+        let err = Value::from("x").deserialize_into::<u32>().unwrap_err();
+        assert_eq!(err.to_string(), "invalid type: string \"x\", expected u32");
+    }
+
+    #[test]
+    fn test_serialize_roundtrip() {
+        let server = Server {
+            host: "example.com",
+            port: 443,
+            timeout: None,
+            tags: vec!["edge".to_string()],
+            retries: 1,
+            mode: Mode::Proxy("squid".to_string()),
+        };
+
+        let value = Value::from_serialize(&server).unwrap();
+
+        assert_eq!(value["port"].as_int(), Some(443));
+        assert!(value["timeout"].is_null());
+        assert_eq!(value["mode"]["proxy"].as_str(), Some("squid"));
+        assert_eq!(value.deserialize_into::<Server>().unwrap(), server);
+    }
+
+    #[test]
+    fn test_serialize_map_keys() {
+        let map = BTreeMap::from([(1, "one"), (2, "two")]);
+        let value = Value::from_serialize(&map).unwrap();
+
+        assert_eq!(value["1"].as_str(), Some("one"));
+
+        let map = BTreeMap::from([((1, 2), "pair")]);
+        assert!(Value::from_serialize(&map).is_err());
+    }
+}
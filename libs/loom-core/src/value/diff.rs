@@ -0,0 +1,299 @@
+use crate::path::{IdentPath, IdentSegment};
+
+use super::Value;
+
+/// A single difference found by `Value::diff`.
+///
+/// The path of a difference at the root of the compared values is empty.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiffEntry {
+    /// `path` exists only in the new value.
+    Added { path: IdentPath, value: Value },
+
+    /// `path` exists only in the old value.
+    Removed { path: IdentPath, value: Value },
+
+    /// `path` holds a different value, possibly of a different kind.
+    Changed {
+        path: IdentPath,
+        from: Value,
+        to: Value,
+    },
+}
+
+impl DiffEntry {
+    pub fn path(&self) -> &IdentPath {
+        match self {
+            Self::Added { path, .. } | Self::Removed { path, .. } | Self::Changed { path, .. } => {
+                path
+            }
+        }
+    }
+
+    pub fn is_added(&self) -> bool {
+        matches!(self, Self::Added { .. })
+    }
+
+    pub fn is_removed(&self) -> bool {
+        matches!(self, Self::Removed { .. })
+    }
+
+    pub fn is_changed(&self) -> bool {
+        matches!(self, Self::Changed { .. })
+    }
+
+    /// `true` for a change between values of different kinds, e.g. a
+    /// number that became a string.
+    pub fn is_type_change(&self) -> bool {
+        match self {
+            Self::Changed { from, to, .. } => from.kind() != to.kind(),
+            _ => false,
+        }
+    }
+}
+
+/// The structural differences between two values, in document order.
+///
+/// `Display` prints one line per side, unified-diff style:
+///
+/// ```text
+/// - a.b: 1
+/// + a.b: "one"
+/// + a.c: true
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValueDiff(Vec<DiffEntry>);
+
+impl ValueDiff {
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn entries(&self) -> &[DiffEntry] {
+        &self.0
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, DiffEntry> {
+        self.0.iter()
+    }
+
+    /// The difference at `path`, if any.
+    pub fn get(&self, path: &IdentPath) -> Option<&DiffEntry> {
+        self.0.iter().find(|e| e.path() == path)
+    }
+}
+
+impl<'a> IntoIterator for &'a ValueDiff {
+    type Item = &'a DiffEntry;
+    type IntoIter = std::slice::Iter<'a, DiffEntry>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl std::fmt::Display for ValueDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for entry in &self.0 {
+            let path = match entry.path() {
+                p if p.is_empty() => "(root)".to_string(),
+                p => p.to_string(),
+            };
+
+            match entry {
+                DiffEntry::Added { value, .. } => writeln!(f, "+ {}: {}", path, Literal(value))?,
+                DiffEntry::Removed { value, .. } => writeln!(f, "- {}: {}", path, Literal(value))?,
+                DiffEntry::Changed { from, to, .. } => {
+                    writeln!(f, "- {}: {}", path, Literal(from))?;
+                    writeln!(f, "+ {}: {}", path, Literal(to))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Displays top-level strings quoted so `"1"` and `1` are told apart.
+struct Literal<'a>(&'a Value);
+
+impl std::fmt::Display for Literal<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Value::String(v) => write!(f, "{:?}", v),
+            v => write!(f, "{}", v),
+        }
+    }
+}
+
+impl Value {
+    /// The differences from `self` to `other`.
+    ///
+    /// Objects are compared key by key and arrays index by index,
+    /// recursively; any other pair of unequal values is a single `Changed`
+    /// entry. Array elements past the shorter array's end are `Added` or
+    /// `Removed`.
+    pub fn diff(&self, other: &Value) -> ValueDiff {
+        let mut entries = Vec::new();
+        diff_into(&mut Vec::new(), self, other, &mut entries);
+        ValueDiff(entries)
+    }
+}
+
+fn diff_into(path: &mut Vec<IdentSegment>, from: &Value, to: &Value, entries: &mut Vec<DiffEntry>) {
+    match (from, to) {
+        (Value::Object(a), Value::Object(b)) => {
+            for (key, value) in a.iter() {
+                path.push(IdentSegment::Key(key.clone()));
+
+                match b.get(key) {
+                    Some(other) => diff_into(path, value, other, entries),
+                    None => entries.push(DiffEntry::Removed {
+                        path: path.clone().into(),
+                        value: value.clone(),
+                    }),
+                }
+
+                path.pop();
+            }
+
+            for (key, value) in b.iter().filter(|(key, _)| !a.contains_key(*key)) {
+                path.push(IdentSegment::Key(key.clone()));
+                entries.push(DiffEntry::Added {
+                    path: path.clone().into(),
+                    value: value.clone(),
+                });
+                path.pop();
+            }
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            for i in 0..a.len().max(b.len()) {
+                path.push(IdentSegment::Index(i));
+
+                match (a.get(i), b.get(i)) {
+                    (Some(x), Some(y)) => diff_into(path, x, y, entries),
+                    (Some(x), None) => entries.push(DiffEntry::Removed {
+                        path: path.clone().into(),
+                        value: x.clone(),
+                    }),
+                    (None, Some(y)) => entries.push(DiffEntry::Added {
+                        path: path.clone().into(),
+                        value: y.clone(),
+                    }),
+                    (None, None) => {}
+                }
+
+                path.pop();
+            }
+        }
+        (a, b) if a != b => entries.push(DiffEntry::Changed {
+            path: path.clone().into(),
+            from: a.clone(),
+            to: b.clone(),
+        }),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::path::IdentPath;
+    use crate::value::Object;
+
+    fn object(fields: &[(&str, Value)]) -> Value {
+        let mut object = Object::new();
+        for (key, value) in fields {
+            object.insert(key.to_string(), value.clone());
+        }
+        Value::Object(object)
+    }
+
+    fn path(s: &str) -> IdentPath {
+        IdentPath::parse(s).unwrap()
+    }
+
+    #[test]
+    fn test_equal_values_have_no_diff() {
+        let value = object(&[("a", Value::from(1i64)), ("b", Value::from([1i64, 2]))]);
+        let diff = value.diff(&value.clone());
+
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "");
+    }
+
+    #[test]
+    fn test_added_and_removed_fields() {
+        let old = object(&[("name", Value::from("loom")), ("legacy", Value::from(true))]);
+        let new = object(&[
+            ("name", Value::from("loom")),
+            ("version", Value::from(2i64)),
+        ]);
+
+        let diff = old.diff(&new);
+
+        assert_eq!(diff.len(), 2);
+        assert_eq!(
+            diff.get(&path("legacy")),
+            Some(&DiffEntry::Removed {
+                path: path("legacy"),
+                value: Value::from(true)
+            })
+        );
+        assert!(diff.get(&path("version")).unwrap().is_added());
+    }
+
+    #[test]
+    fn test_type_changed_field() {
+        let old = object(&[("threshold", Value::from(1i64))]);
+        let new = object(&[("threshold", Value::from("1"))]);
+
+        let diff = old.diff(&new);
+        let entry = diff.get(&path("threshold")).unwrap();
+
+        assert!(entry.is_changed());
+        assert!(entry.is_type_change());
+        assert_eq!(diff.to_string(), "- threshold: 1\n+ threshold: \"1\"\n");
+    }
+
+    #[test]
+    fn test_nested_objects_and_arrays() {
+        let old = object(&[(
+            "layers",
+            object(&[
+                ("eval", object(&[("threshold", Value::from(0.75))])),
+                ("tags", Value::from(["a", "b", "c"])),
+            ]),
+        )]);
+        let new = object(&[(
+            "layers",
+            object(&[
+                ("eval", object(&[("threshold", Value::from(0.8))])),
+                ("tags", Value::from(["a", "x"])),
+            ]),
+        )]);
+
+        let diff = old.diff(&new);
+        let paths: Vec<String> = diff.iter().map(|e| e.path().to_string()).collect();
+
+        assert_eq!(
+            paths,
+            vec!["layers.eval.threshold", "layers.tags[1]", "layers.tags[2]"]
+        );
+        assert!(!diff.entries()[0].is_type_change());
+        assert!(diff.entries()[2].is_removed());
+    }
+
+    #[test]
+    fn test_root_scalars() {
+        let diff = Value::from(1i64).diff(&Value::Null);
+
+        assert_eq!(diff.len(), 1);
+        assert!(diff.entries()[0].path().is_empty());
+        assert_eq!(diff.to_string(), "- (root): 1\n+ (root): null\n");
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+
+use super::Value;
+use crate::path::{IdentPath, IdentPathError, IdentSegment};
+
+impl Value {
+    /// Flatten the value into a map from dotted `IdentPath` keys to leaf
+    /// values, e.g. `{"a": {"b": [1, 2]}}` becomes `{"a.b[0]": 1, "a.b[1]": 2}`.
+    ///
+    /// Leaves are scalars and empty objects or arrays. A scalar at the root
+    /// is keyed by `""`. Keys are not escaped, so object keys containing `.`
+    /// or `[` don't survive `unflatten`.
+    pub fn flatten(&self) -> BTreeMap<String, Value> {
+        let mut map = BTreeMap::new();
+        let mut path = Vec::new();
+        flatten_into(self, &mut path, &mut map);
+        map
+    }
+
+    /// Rebuild a value from a map produced by `flatten`. Missing array
+    /// elements are filled with nulls; an entry that conflicts with a
+    /// scalar set by an earlier key, such as `a.b` after `a`, is skipped.
+    pub fn unflatten(map: &BTreeMap<String, Value>) -> Result<Value, IdentPathError> {
+        if let Some(root) = map.get("") {
+            return Ok(root.clone());
+        }
+
+        let mut value = Value::Null;
+
+        for (key, leaf) in map {
+            let path = IdentPath::parse(key)?;
+            value.set_by_path(&path, leaf.clone());
+        }
+
+        Ok(value)
+    }
+}
+
+fn flatten_into(value: &Value, path: &mut Vec<IdentSegment>, map: &mut BTreeMap<String, Value>) {
+    match value {
+        Value::Object(obj) if !obj.is_empty() => {
+            for (key, child) in obj.iter() {
+                path.push(IdentSegment::Key(key.clone()));
+                flatten_into(child, path, map);
+                path.pop();
+            }
+        }
+        Value::Array(arr) if !arr.is_empty() => {
+            for (index, child) in arr.iter().enumerate() {
+                path.push(IdentSegment::Index(index));
+                flatten_into(child, path, map);
+                path.pop();
+            }
+        }
+        leaf => {
+            let key = IdentPath::from(path.clone()).to_string();
+            map.insert(key, leaf.clone());
+        }
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> Value {
+        Value::from(serde_json::from_str::<serde_json::Value>(json).unwrap())
+    }
+
+    #[test]
+    fn test_flatten_arrays_nested_in_objects() {
+        let value = parse(r#"{"a": {"b": [1, 2]}, "c": "x"}"#);
+        let flat = value.flatten();
+
+        let keys: Vec<&str> = flat.keys().map(String::as_str).collect();
+        assert_eq!(keys, ["a.b[0]", "a.b[1]", "c"]);
+        assert_eq!(flat["a.b[1]"], Value::from(2));
+        assert_eq!(flat["c"], Value::from("x"));
+    }
+
+    #[test]
+    fn test_flatten_objects_nested_in_arrays() {
+        let value = parse(r#"{"servers": [{"host": "a", "ports": [80]}, {"host": "b"}]}"#);
+        let flat = value.flatten();
+
+        assert_eq!(flat["servers[0].host"], Value::from("a"));
+        assert_eq!(flat["servers[0].ports[0]"], Value::from(80));
+        assert_eq!(flat["servers[1].host"], Value::from("b"));
+        assert_eq!(flat.len(), 3);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let values = [
+            r#"{"a": {"b": [1, 2]}, "c": "x"}"#,
+            r#"{"layers": {"eval": {"threshold": 0.8, "labels": ["a", "b"]}}, "debug": true}"#,
+            r#"{"matrix": [[1, 2], [3]], "empty": {}, "none": [], "null": null}"#,
+            r#"[{"id": 1}, {"id": 2}]"#,
+            r#""scalar""#,
+        ];
+
+        for json in values {
+            let value = parse(json);
+            assert_eq!(
+                Value::unflatten(&value.flatten()).unwrap(),
+                value,
+                "{}",
+                json
+            );
+        }
+    }
+
+    #[test]
+    fn test_unflatten_pads_and_skips_conflicts() {
+        let mut map = BTreeMap::new();
+        map.insert("a".to_string(), Value::from(1));
+        map.insert("a.b".to_string(), Value::from(2));
+        map.insert("list[2]".to_string(), Value::from("c"));
+
+        let value = Value::unflatten(&map).unwrap();
+
+        assert_eq!(value, parse(r#"{"a": 1, "list": [null, null, "c"]}"#));
+    }
+
+    #[test]
+    fn test_unflatten_rejects_invalid_keys() {
+        let mut map = BTreeMap::new();
+        map.insert("a..b".to_string(), Value::from(1));
+
+        assert!(Value::unflatten(&map).is_err());
+    }
+}
@@ -1,13 +1,24 @@
 mod array;
+mod de;
+mod diff;
+mod error;
+mod flatten;
 mod number;
 mod object;
+mod patch;
+mod ser;
 
 pub use array::*;
+pub use diff::*;
+pub use error::*;
 pub use number::*;
 pub use object::*;
+pub use patch::*;
+pub use ser::*;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize, Default)]
 pub enum Value {
+    #[default]
     Null,
     Bool(bool),
     Number(Number),
@@ -17,6 +28,17 @@ pub enum Value {
 }
 
 impl Value {
+    /// Serialize `value` straight into a `Value`, see `ValueSerializer`.
+    pub fn from_serialize<T: ?Sized + serde::Serialize>(value: &T) -> Result<Self, ValueError> {
+        value.serialize(ValueSerializer)
+    }
+
+    /// Deserialize a `T` straight from this value, borrowing strings where
+    /// `T` allows.
+    pub fn deserialize_into<'de, T: serde::Deserialize<'de>>(&'de self) -> Result<T, ValueError> {
+        T::deserialize(self)
+    }
+
     pub fn kind(&self) -> &str {
         match self {
             Self::Null => "null",
@@ -151,6 +173,8 @@ impl Value {
         }
     }
 
+    /// The value at a concrete `path`. Wildcard segments never match; use
+    /// `select` for those.
     pub fn get_by_path(&self, path: &crate::path::IdentPath) -> Option<&Value> {
         use crate::path::IdentSegment;
 
@@ -167,6 +191,38 @@ impl Value {
         Some(current)
     }
 
+    /// Every value matching `path`, which may contain wildcards.
+    ///
+    /// `*` matches every value of an object and `[*]` every element of an
+    /// array. Like concrete segments, a wildcard only descends into its own
+    /// kind of container: `*` skips arrays and `[*]` skips objects. Branches
+    /// that don't match the rest of the path are dropped, so
+    /// `items[*].name` returns the `name` of each element that has one.
+    /// Matches are returned in document order.
+    pub fn select(&self, path: &crate::path::IdentPath) -> Vec<&Value> {
+        use crate::path::IdentSegment;
+
+        let mut current = vec![self];
+
+        for segment in path.segments() {
+            let mut next = Vec::new();
+
+            for value in current {
+                match (value, segment) {
+                    (Value::Object(obj), IdentSegment::Key(key)) => next.extend(obj.get(key)),
+                    (Value::Array(arr), IdentSegment::Index(idx)) => next.extend(arr.get(*idx)),
+                    (Value::Object(obj), IdentSegment::AnyKey) => next.extend(obj.values()),
+                    (Value::Array(arr), IdentSegment::AnyIndex) => next.extend(arr.iter()),
+                    _ => {}
+                }
+            }
+
+            current = next;
+        }
+
+        current
+    }
+
     pub fn get_by_path_mut(&mut self, path: &crate::path::IdentPath) -> Option<&mut Value> {
         use crate::path::IdentSegment;
 
@@ -182,6 +238,94 @@ impl Value {
 
         Some(current)
     }
+
+    /// How far past the end of an array `set_by_path` may write, padding
+    /// the gap with nulls.
+    pub const MAX_ARRAY_GAP: usize = 1024;
+
+    /// Set the value at a concrete `path`, creating missing objects and
+    /// arrays along the way and replacing nulls. Arrays are padded with
+    /// nulls up to the index.
+    ///
+    /// Returns `false`, leaving `self` unchanged, if the path contains a
+    /// wildcard, an index more than `MAX_ARRAY_GAP` past the end of its
+    /// array, or runs into a value of the wrong kind, e.g. a key under a
+    /// string.
+    pub fn set_by_path(&mut self, path: &crate::path::IdentPath, value: Value) -> bool {
+        use crate::path::IdentSegment;
+
+        if path.is_wildcard() {
+            return false;
+        }
+
+        let segments = path.segments();
+        let mut existing = Some(&*self);
+
+        for segment in segments {
+            if let IdentSegment::Index(index) = segment {
+                let len = match existing {
+                    Some(Value::Array(arr)) => arr.len(),
+                    _ => 0,
+                };
+
+                if *index > len + Self::MAX_ARRAY_GAP {
+                    return false;
+                }
+            }
+
+            existing = match (existing, segment) {
+                (Some(Value::Object(obj)), IdentSegment::Key(key)) => obj.get(key),
+                (Some(Value::Array(arr)), IdentSegment::Index(index)) => arr.get(*index),
+                _ => None,
+            };
+        }
+
+        if self.is_null() {
+            *self = match segments.first() {
+                Some(IdentSegment::Index(_)) => Value::Array(Array::new()),
+                _ => Value::Object(Object::new()),
+            };
+        }
+
+        let mut current = self;
+
+        for (i, segment) in segments.iter().enumerate() {
+            let empty = match segments.get(i + 1) {
+                None => Value::Null,
+                Some(IdentSegment::Index(_)) => Value::Array(Array::new()),
+                Some(_) => Value::Object(Object::new()),
+            };
+
+            current = match (current, segment) {
+                (Value::Object(obj), IdentSegment::Key(key)) => {
+                    let slot = obj.entry(key.clone()).or_insert(Value::Null);
+                    if slot.is_null() {
+                        *slot = empty;
+                    }
+
+                    slot
+                }
+                (Value::Array(arr), IdentSegment::Index(index)) => {
+                    while arr.len() <= *index {
+                        arr.insert(arr.len(), Value::Null);
+                    }
+
+                    let slot = &mut arr[*index];
+                    if slot.is_null() {
+                        *slot = empty;
+                    }
+
+                    slot
+                }
+                // Missing values are created with the right kind, so only
+                // an existing value can mismatch, before anything changed.
+                _ => return false,
+            };
+        }
+
+        *current = value;
+        true
+    }
 }
 
 impl std::fmt::Display for Value {
@@ -305,12 +449,6 @@ impl<T: Into<Value>, const N: usize> From<[T; N]> for Value {
     }
 }
 
-impl Default for Value {
-    fn default() -> Self {
-        Self::Null
-    }
-}
-
 impl std::ops::Index<&str> for Value {
     type Output = Value;
 
@@ -335,6 +473,8 @@ impl std::ops::Index<usize> for Value {
     }
 }
 
+/// Integers that fit an `i64` convert to `Number::Int`, other numbers to
+/// `Number::Float`.
 #[cfg(feature = "json")]
 impl From<serde_json::Value> for Value {
     fn from(json: serde_json::Value) -> Self {
@@ -351,17 +491,9 @@ impl From<serde_json::Value> for Value {
                 }
             }
             serde_json::Value::String(s) => Self::String(s),
-            serde_json::Value::Array(arr) => Self::Array(Array::from(
-                arr.into_iter().map(Self::from).collect::<Vec<_>>(),
-            )),
+            serde_json::Value::Array(arr) => Self::Array(arr.into_iter().map(Self::from).collect()),
             serde_json::Value::Object(obj) => {
-                let mut map = Object::new();
-
-                for (k, v) in obj {
-                    map.insert(k, Self::from(v));
-                }
-
-                Self::Object(map)
+                Self::Object(obj.into_iter().map(|(k, v)| (k, Self::from(v))).collect())
             }
         }
     }
@@ -390,6 +522,8 @@ impl From<&Value> for serde_json::Value {
     }
 }
 
+/// Moves strings, arrays and objects across without cloning. Non-finite
+/// floats, which JSON can't represent, become `null`.
 #[cfg(feature = "json")]
 impl From<Value> for serde_json::Value {
     fn from(value: Value) -> Self {
@@ -403,11 +537,7 @@ impl From<Value> for serde_json::Value {
             Value::String(s) => Self::String(s),
             Value::Array(arr) => Self::Array(arr.into_iter().map(Self::from).collect()),
             Value::Object(obj) => {
-                let map: serde_json::Map<String, Self> = obj
-                    .iter()
-                    .map(|(k, v)| (k.clone(), Self::from(v)))
-                    .collect();
-                Self::Object(map)
+                Self::Object(obj.into_iter().map(|(k, v)| (k, Self::from(v))).collect())
             }
         }
     }
@@ -481,7 +611,7 @@ impl From<Value> for saphyr::Yaml {
             Value::Number(Number::Int(i)) => Self::Integer(i),
             Value::Number(Number::Float(f)) => Self::Real(f.to_string()),
             Value::String(s) => Self::String(s),
-            Value::Array(arr) => Self::Array(arr.into_iter().map(Self::from).collect()),
+            Value::Array(arr) => Self::Array(arr.iter().map(Self::from).collect()),
             Value::Object(obj) => {
                 let hash: saphyr::Hash = obj
                     .iter()
@@ -546,7 +676,7 @@ impl From<Value> for toml::Value {
             Value::Number(Number::Int(i)) => Self::Integer(i),
             Value::Number(Number::Float(f)) => Self::Float(f),
             Value::String(s) => Self::String(s),
-            Value::Array(arr) => Self::Array(arr.into_iter().map(Self::from).collect()),
+            Value::Array(arr) => Self::Array(arr.iter().map(Self::from).collect()),
             Value::Object(obj) => {
                 let table: toml::Table = obj
                     .iter()
@@ -557,3 +687,136 @@ impl From<Value> for toml::Value {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ident_path, try_ident_path};
+
+    fn object(fields: &[(&str, Value)]) -> Value {
+        let mut obj = Object::new();
+        for (key, value) in fields {
+            obj.insert(key.to_string(), value.clone());
+        }
+        Value::Object(obj)
+    }
+
+    fn users() -> Value {
+        object(&[(
+            "users",
+            Value::Array(
+                vec![
+                    object(&[("name", "ada".into()), ("age", 36.into())]),
+                    object(&[("age", 41.into())]),
+                    object(&[("name", "grace".into())]),
+                    Value::from("not an object"),
+                ]
+                .into(),
+            ),
+        )])
+    }
+
+    #[test]
+    fn test_select_array_wildcard() {
+        let value = users();
+
+        let names = value.select(&ident_path!("users[*].name"));
+        assert_eq!(names, vec![&Value::from("ada"), &Value::from("grace")]);
+        assert_eq!(value.select(&ident_path!("users[*]")).len(), 4);
+    }
+
+    #[test]
+    fn test_select_object_wildcard() {
+        let value = object(&[
+            ("a", object(&[("name", "first".into())])),
+            ("b", object(&[("name", "second".into())])),
+            ("c", object(&[("other", 1.into())])),
+        ]);
+
+        let names = value.select(&ident_path!("*.name"));
+        assert_eq!(names, vec![&Value::from("first"), &Value::from("second")]);
+    }
+
+    #[test]
+    fn test_select_wildcard_only_matches_its_container_kind() {
+        let value = users();
+
+        assert!(value.select(&ident_path!("users.*")).is_empty());
+        assert!(value.select(&ident_path!("users[0][*]")).is_empty());
+        assert_eq!(value.select(&ident_path!("users[0].*")).len(), 2);
+    }
+
+    #[test]
+    fn test_select_concrete_path_matches_get_by_path() {
+        let value = users();
+        let path = ident_path!("users[1].age");
+
+        assert_eq!(value.select(&path), vec![value.get_by_path(&path).unwrap()]);
+        assert!(value.select(&ident_path!("users[9].age")).is_empty());
+    }
+
+    #[test]
+    fn test_set_by_path_pads_arrays_within_gap() {
+        let mut value = users();
+
+        let index = 4 + Value::MAX_ARRAY_GAP;
+        let path = try_ident_path!(&format!("users[{}]", index)).unwrap();
+        assert!(value.set_by_path(&path, Value::from("last")));
+        assert_eq!(value["users"].len(), index + 1);
+        assert!(value["users"][index - 1].is_null());
+    }
+
+    #[test]
+    fn test_set_by_path_rejects_index_far_past_end() {
+        let mut value = users();
+
+        let far = ident_path!("users[99999999999]");
+        assert!(!value.set_by_path(&far, Value::from(1)));
+        assert_eq!(value, users());
+
+        let nested = ident_path!("extra.list[5000].name");
+        assert!(!value.set_by_path(&nested, Value::from(1)));
+        assert_eq!(value, users());
+
+        let mut empty = Value::Null;
+        assert!(!empty.set_by_path(&ident_path!("[2000]"), Value::from(1)));
+        assert!(empty.is_null());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_roundtrip_all_variants() {
+        let json = serde_json::json!({
+            "null": null,
+            "bool": true,
+            "int": -42,
+            "float": 1.5,
+            "string": "text",
+            "array": [1, [2, [null]], {"nested": "object"}],
+            "object": {"inner": {"empty_array": [], "empty_object": {}}},
+        });
+
+        let value = Value::from(json.clone());
+
+        assert!(value["null"].is_null());
+        assert_eq!(value["int"].as_int(), Some(-42));
+        assert_eq!(value["array"][1][1][0], Value::Null);
+        assert_eq!(value["array"][2]["nested"].as_str(), Some("object"));
+        assert_eq!(serde_json::Value::from(&value), json);
+        assert_eq!(serde_json::Value::from(value.clone()), json);
+        assert_eq!(Value::from(serde_json::Value::from(value.clone())), value);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_json_number_edge_cases() {
+        let big = serde_json::json!(u64::MAX);
+        assert_eq!(
+            Value::from(big),
+            Value::Number(Number::Float(u64::MAX as f64))
+        );
+
+        let nan = Value::Number(Number::Float(f64::NAN));
+        assert_eq!(serde_json::Value::from(nan), serde_json::Value::Null);
+    }
+}
@@ -0,0 +1,378 @@
+use super::Value;
+
+/// One operation of a `Value::apply_patch` patch, modelled on RFC 6902.
+///
+/// Paths are JSON pointers (RFC 6901): `""` is the whole value, `/a/0/b`
+/// walks object keys and array indices, and `~1`/`~0` escape `/` and `~`
+/// in keys. `add` also accepts `-` as the index one past the end of an
+/// array.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum PatchOp {
+    /// Insert `value` into an array at `path`, or set an object key,
+    /// replacing any existing value.
+    Add { path: String, value: Value },
+
+    /// Remove the existing value at `path`.
+    Remove { path: String },
+
+    /// Replace the existing value at `path`.
+    Replace { path: String, value: Value },
+
+    /// Remove the value at `from` and add it at `path`.
+    Move { from: String, path: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatchError {
+    /// The pointer isn't empty and doesn't start with `/`.
+    InvalidPointer(String),
+
+    /// Nothing exists at the pointer, or its parent isn't a container.
+    NotFound(String),
+
+    /// An array index past the end of the array.
+    OutOfBounds(String),
+
+    /// The operation can't apply here, e.g. removing the whole value or
+    /// moving a value into one of its own children.
+    InvalidTarget(String),
+}
+
+impl PatchError {
+    /// The pointer the failing operation was addressed at.
+    pub fn pointer(&self) -> &str {
+        match self {
+            Self::InvalidPointer(p)
+            | Self::NotFound(p)
+            | Self::OutOfBounds(p)
+            | Self::InvalidTarget(p) => p,
+        }
+    }
+}
+
+impl std::fmt::Display for PatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidPointer(p) => write!(f, "invalid json pointer: '{}'", p),
+            Self::NotFound(p) => write!(f, "path not found: '{}'", p),
+            Self::OutOfBounds(p) => write!(f, "array index out of bounds: '{}'", p),
+            Self::InvalidTarget(p) => write!(f, "invalid patch target: '{}'", p),
+        }
+    }
+}
+
+impl std::error::Error for PatchError {}
+
+impl Value {
+    /// The value at a JSON `pointer` (RFC 6901), e.g. `/users/0/name`.
+    pub fn pointer(&self, pointer: &str) -> Option<&Value> {
+        let mut current = self;
+
+        for token in tokens(pointer).ok()? {
+            current = match current {
+                Value::Object(obj) => obj.get(&token)?,
+                Value::Array(arr) => arr.get(index(&token)?)?,
+                _ => return None,
+            };
+        }
+
+        Some(current)
+    }
+
+    pub fn pointer_mut(&mut self, pointer: &str) -> Option<&mut Value> {
+        let mut current = self;
+
+        for token in tokens(pointer).ok()? {
+            current = match current {
+                Value::Object(obj) => obj.get_mut(&token)?,
+                Value::Array(arr) => arr.get_mut(index(&token)?)?,
+                _ => return None,
+            };
+        }
+
+        Some(current)
+    }
+
+    /// Apply `patch` in order. The patch is all or nothing: if any
+    /// operation fails, `self` is left unchanged and the error names the
+    /// offending pointer.
+    pub fn apply_patch(&mut self, patch: &[PatchOp]) -> Result<(), PatchError> {
+        let mut patched = self.clone();
+
+        for op in patch {
+            patched.apply_op(op)?;
+        }
+
+        *self = patched;
+        Ok(())
+    }
+
+    fn apply_op(&mut self, op: &PatchOp) -> Result<(), PatchError> {
+        match op {
+            PatchOp::Add { path, value } => self.patch_add(path, value.clone()),
+            PatchOp::Remove { path } => self.patch_remove(path).map(|_| ()),
+            PatchOp::Replace { path, value } => {
+                tokens(path)?;
+                let target = self
+                    .pointer_mut(path)
+                    .ok_or_else(|| PatchError::NotFound(path.clone()))?;
+
+                *target = value.clone();
+                Ok(())
+            }
+            PatchOp::Move { from, path } => {
+                if path.starts_with(&format!("{}/", from)) {
+                    return Err(PatchError::InvalidTarget(path.clone()));
+                }
+
+                let value = self.patch_remove(from)?;
+                self.patch_add(path, value)
+            }
+        }
+    }
+
+    fn patch_add(&mut self, path: &str, value: Value) -> Result<(), PatchError> {
+        let Some((parent, last)) = split_last(path)? else {
+            *self = value;
+            return Ok(());
+        };
+
+        match self.pointer_mut(&parent) {
+            Some(Value::Object(obj)) => {
+                obj.insert(last, value);
+                Ok(())
+            }
+            Some(Value::Array(arr)) => {
+                let i = match last.as_str() {
+                    "-" => arr.len(),
+                    token => index(token).ok_or_else(|| PatchError::NotFound(path.to_string()))?,
+                };
+
+                if i > arr.len() {
+                    return Err(PatchError::OutOfBounds(path.to_string()));
+                }
+
+                arr.insert(i, value);
+                Ok(())
+            }
+            _ => Err(PatchError::NotFound(path.to_string())),
+        }
+    }
+
+    fn patch_remove(&mut self, path: &str) -> Result<Value, PatchError> {
+        let Some((parent, last)) = split_last(path)? else {
+            return Err(PatchError::InvalidTarget(path.to_string()));
+        };
+
+        match self.pointer_mut(&parent) {
+            Some(Value::Object(obj)) => obj
+                .remove(&last)
+                .ok_or_else(|| PatchError::NotFound(path.to_string())),
+            Some(Value::Array(arr)) => match index(&last) {
+                Some(i) if i < arr.len() => Ok(arr.remove(i)),
+                Some(_) => Err(PatchError::OutOfBounds(path.to_string())),
+                None => Err(PatchError::NotFound(path.to_string())),
+            },
+            _ => Err(PatchError::NotFound(path.to_string())),
+        }
+    }
+}
+
+/// The unescaped reference tokens of a JSON pointer.
+fn tokens(pointer: &str) -> Result<Vec<String>, PatchError> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let Some(rest) = pointer.strip_prefix('/') else {
+        return Err(PatchError::InvalidPointer(pointer.to_string()));
+    };
+
+    Ok(rest
+        .split('/')
+        .map(|t| t.replace("~1", "/").replace("~0", "~"))
+        .collect())
+}
+
+/// The pointer to the parent and the last token, or `None` for the root.
+fn split_last(pointer: &str) -> Result<Option<(String, String)>, PatchError> {
+    tokens(pointer)?;
+
+    Ok(pointer.rfind('/').map(|i| {
+        let last = pointer[i + 1..].replace("~1", "/").replace("~0", "~");
+        (pointer[..i].to_string(), last)
+    }))
+}
+
+/// An array index token: digits only, without leading zeros.
+fn index(token: &str) -> Option<usize> {
+    let digits = !token.is_empty() && token.bytes().all(|b| b.is_ascii_digit());
+
+    match digits && (token == "0" || !token.starts_with('0')) {
+        true => token.parse().ok(),
+        false => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value::Object;
+
+    fn doc() -> Value {
+        let mut eval = Object::new();
+        eval.insert("threshold".to_string(), Value::from(0.75));
+        eval.insert("labels".to_string(), Value::from(["joy", "anger"]));
+
+        let mut root = Object::new();
+        root.insert("eval".to_string(), Value::Object(eval));
+        root.insert("a/b".to_string(), Value::from(1i64));
+        Value::Object(root)
+    }
+
+    fn add(path: &str, value: Value) -> PatchOp {
+        PatchOp::Add {
+            path: path.to_string(),
+            value,
+        }
+    }
+
+    fn remove(path: &str) -> PatchOp {
+        PatchOp::Remove {
+            path: path.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_pointer_lookup() {
+        let value = doc();
+
+        assert_eq!(value.pointer(""), Some(&value));
+        assert_eq!(value.pointer("/eval/labels/1"), Some(&Value::from("anger")));
+        assert_eq!(value.pointer("/a~1b"), Some(&Value::from(1i64)));
+        assert_eq!(value.pointer("/eval/labels/01"), None);
+        assert_eq!(value.pointer("eval"), None);
+    }
+
+    #[test]
+    fn test_add() {
+        let mut value = doc();
+        value
+            .apply_patch(&[
+                add("/eval/top_k", Value::from(2i64)),
+                add("/eval/labels/0", Value::from("fear")),
+                add("/eval/labels/-", Value::from("trust")),
+            ])
+            .unwrap();
+
+        assert_eq!(value.pointer("/eval/top_k"), Some(&Value::from(2i64)));
+        assert_eq!(
+            value.pointer("/eval/labels"),
+            Some(&Value::from(["fear", "joy", "anger", "trust"]))
+        );
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut value = doc();
+        value
+            .apply_patch(&[remove("/eval/labels/0"), remove("/a~1b")])
+            .unwrap();
+
+        assert_eq!(value.pointer("/eval/labels"), Some(&Value::from(["anger"])));
+        assert_eq!(value.pointer("/a~1b"), None);
+    }
+
+    #[test]
+    fn test_replace() {
+        let mut value = doc();
+        value
+            .apply_patch(&[PatchOp::Replace {
+                path: "/eval/threshold".to_string(),
+                value: Value::from(0.8),
+            }])
+            .unwrap();
+
+        assert_eq!(value.pointer("/eval/threshold"), Some(&Value::from(0.8)));
+
+        let err = value
+            .apply_patch(&[PatchOp::Replace {
+                path: "/eval/missing".to_string(),
+                value: Value::Null,
+            }])
+            .unwrap_err();
+        assert_eq!(err, PatchError::NotFound("/eval/missing".to_string()));
+    }
+
+    #[test]
+    fn test_move() {
+        let mut value = doc();
+        value
+            .apply_patch(&[PatchOp::Move {
+                from: "/eval/threshold".to_string(),
+                path: "/threshold".to_string(),
+            }])
+            .unwrap();
+
+        assert_eq!(value.pointer("/threshold"), Some(&Value::from(0.75)));
+        assert_eq!(value.pointer("/eval/threshold"), None);
+
+        let err = value
+            .apply_patch(&[PatchOp::Move {
+                from: "/eval".to_string(),
+                path: "/eval/nested".to_string(),
+            }])
+            .unwrap_err();
+        assert!(matches!(err, PatchError::InvalidTarget(_)));
+    }
+
+    #[test]
+    fn test_errors_name_the_pointer() {
+        let mut value = doc();
+
+        let err = value
+            .apply_patch(&[add("/eval/labels/5", Value::Null)])
+            .unwrap_err();
+        assert_eq!(err, PatchError::OutOfBounds("/eval/labels/5".to_string()));
+        assert_eq!(err.pointer(), "/eval/labels/5");
+
+        let err = value.apply_patch(&[remove("/nope/x")]).unwrap_err();
+        assert_eq!(err.to_string(), "path not found: '/nope/x'");
+
+        let err = value.apply_patch(&[remove("eval")]).unwrap_err();
+        assert!(matches!(err, PatchError::InvalidPointer(_)));
+    }
+
+    #[test]
+    fn test_failed_patch_rolls_back() {
+        let mut value = doc();
+        let original = value.clone();
+
+        let err = value
+            .apply_patch(&[
+                add("/eval/top_k", Value::from(2i64)),
+                remove("/eval/labels/0"),
+                remove("/eval/missing"),
+            ])
+            .unwrap_err();
+
+        assert_eq!(err.pointer(), "/eval/missing");
+        assert_eq!(value, original);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_patch_op_serde_shape() {
+        let op: PatchOp =
+            serde_json::from_str(r#"{"op": "move", "from": "/a", "path": "/b"}"#).unwrap();
+
+        assert_eq!(
+            op,
+            PatchOp::Move {
+                from: "/a".to_string(),
+                path: "/b".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use std::collections::BTreeMap;
+
+use rust_bert::RustBertError;
+use rust_bert::pipelines::sentence_embeddings::SentenceEmbeddingsModel;
+use serde::{Deserialize, Serialize};
+
+use super::{LabelScore, Scorer, ScorerOutput};
+use crate::config::CortexSentenceEmbeddingsConfig;
+
+/// Anything that can turn a batch of texts into embedding vectors.
+pub trait Embedder {
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, RustBertError>;
+}
+
+impl Embedder for SentenceEmbeddingsModel {
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, RustBertError> {
+        self.encode(texts)
+    }
+}
+
+/// Where label prototype embeddings come from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EmbeddingPrototypeSource {
+    /// Example texts per label, embedded at load time and mean-pooled
+    Examples {
+        labels: BTreeMap<String, Vec<String>>,
+    },
+    /// Precomputed embedding vectors per label
+    Embeddings { labels: BTreeMap<String, Vec<f32>> },
+}
+
+impl EmbeddingPrototypeSource {
+    pub fn is_examples(&self) -> bool {
+        matches!(self, Self::Examples { .. })
+    }
+
+    pub fn is_embeddings(&self) -> bool {
+        matches!(self, Self::Embeddings { .. })
+    }
+}
+
+impl Default for EmbeddingPrototypeSource {
+    fn default() -> Self {
+        Self::Examples {
+            labels: BTreeMap::new(),
+        }
+    }
+}
+
+/// Configuration for an `EmbeddingScorer`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EmbeddingScorerConfig {
+    #[serde(default)]
+    pub model: CortexSentenceEmbeddingsConfig,
+
+    #[serde(default)]
+    pub prototypes: EmbeddingPrototypeSource,
+}
+
+/// Scores text by cosine similarity between its embedding and
+/// a prototype embedding for each label.
+///
+/// Negative similarities are clamped to `0.0` so scores stay in `[0, 1]`.
+pub struct EmbeddingScorer<E = SentenceEmbeddingsModel> {
+    embedder: E,
+    prototypes: BTreeMap<String, Vec<f32>>,
+}
+
+impl EmbeddingScorer {
+    /// Build the sentence embeddings model and resolve the configured prototypes.
+    pub fn from_config(config: EmbeddingScorerConfig) -> Result<Self, RustBertError> {
+        let model = SentenceEmbeddingsModel::new(config.model.into())?;
+        Self::new(model, config.prototypes)
+    }
+}
+
+impl<E: Embedder> EmbeddingScorer<E> {
+    pub fn new(embedder: E, source: EmbeddingPrototypeSource) -> Result<Self, RustBertError> {
+        let prototypes = match source {
+            EmbeddingPrototypeSource::Embeddings { labels } => labels,
+            EmbeddingPrototypeSource::Examples { labels } => {
+                let mut prototypes = BTreeMap::new();
+
+                for (label, examples) in labels {
+                    if examples.is_empty() {
+                        return Err(RustBertError::ValueError(format!(
+                            "label '{}' has no prototype examples",
+                            label
+                        )));
+                    }
+
+                    let texts: Vec<&str> = examples.iter().map(|s| s.as_str()).collect();
+                    let embeddings = embedder.embed(&texts)?;
+                    prototypes.insert(label, mean(&embeddings));
+                }
+
+                prototypes
+            }
+        };
+
+        Ok(Self {
+            embedder,
+            prototypes,
+        })
+    }
+
+    /// Get the resolved prototype embeddings, keyed by label.
+    pub fn prototypes(&self) -> &BTreeMap<String, Vec<f32>> {
+        &self.prototypes
+    }
+}
+
+impl<E: Embedder> Scorer for EmbeddingScorer<E> {
+    fn score(&self, text: &str) -> Result<ScorerOutput, RustBertError> {
+        let embedding = self
+            .embedder
+            .embed(&[text])?
+            .into_iter()
+            .next()
+            .ok_or_else(|| RustBertError::ValueError("embedder returned no output".to_string()))?;
+
+        let labels = self
+            .prototypes
+            .iter()
+            .map(|(label, prototype)| {
+                let score = cosine_similarity(&embedding, prototype).max(0.0);
+                LabelScore::new(label.clone(), score)
+            })
+            .collect();
+
+        Ok(ScorerOutput::new(labels))
+    }
+}
+
+/// Cosine similarity between two vectors, or `0.0` if either has zero norm.
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+
+    dot / (norm_a * norm_b)
+}
+
+fn mean(vectors: &[Vec<f32>]) -> Vec<f32> {
+    let dim = vectors.iter().map(|v| v.len()).max().unwrap_or(0);
+    let mut out = vec![0.0f32; dim];
+
+    for vector in vectors {
+        for (i, x) in vector.iter().enumerate() {
+            out[i] += x;
+        }
+    }
+
+    for x in &mut out {
+        *x /= vectors.len() as f32;
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    struct TableEmbedder(HashMap<&'static str, Vec<f32>>);
+
+    impl TableEmbedder {
+        fn new() -> Self {
+            Self(HashMap::from([
+                ("cat", vec![1.0, 0.0, 0.0]),
+                ("kitten", vec![0.9, 0.1, 0.0]),
+                ("dog", vec![0.0, 1.0, 0.0]),
+                ("puppy", vec![0.1, 0.9, 0.0]),
+                ("car", vec![0.0, 0.0, 1.0]),
+            ]))
+        }
+    }
+
+    impl Embedder for TableEmbedder {
+        fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>, RustBertError> {
+            texts
+                .iter()
+                .map(|t| {
+                    self.0
+                        .get(t)
+                        .cloned()
+                        .ok_or_else(|| RustBertError::ValueError(format!("unknown text '{}'", t)))
+                })
+                .collect()
+        }
+    }
+
+    fn examples() -> EmbeddingPrototypeSource {
+        EmbeddingPrototypeSource::Examples {
+            labels: BTreeMap::from([
+                ("feline".to_string(), vec!["cat".to_string()]),
+                (
+                    "canine".to_string(),
+                    vec!["dog".to_string(), "puppy".to_string()],
+                ),
+            ]),
+        }
+    }
+
+    #[test]
+    fn cosine_similarity_identical_is_one() {
+        let result = cosine_similarity(&[0.3, 0.4], &[0.3, 0.4]);
+        assert!((result - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn cosine_similarity_zero_vector_is_zero() {
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 0.0]), 0.0);
+    }
+
+    #[test]
+    fn examples_are_mean_pooled() {
+        let scorer = EmbeddingScorer::new(TableEmbedder::new(), examples()).unwrap();
+        let canine = scorer.prototypes().get("canine").unwrap();
+        assert!((canine[0] - 0.05).abs() < 1e-6);
+        assert!((canine[1] - 0.95).abs() < 1e-6);
+    }
+
+    #[test]
+    fn score_ranks_closest_prototype_highest() {
+        let scorer = EmbeddingScorer::new(TableEmbedder::new(), examples()).unwrap();
+        let output = scorer.score("kitten").unwrap();
+
+        assert_eq!(output.labels().len(), 2);
+        assert!(output.label_score("feline") > output.label_score("canine"));
+        assert!(output.label_score("feline") > 0.9);
+    }
+
+    #[test]
+    fn score_clamps_negative_similarity() {
+        let source = EmbeddingPrototypeSource::Embeddings {
+            labels: BTreeMap::from([("opposite".to_string(), vec![-1.0, 0.0, 0.0])]),
+        };
+
+        let scorer = EmbeddingScorer::new(TableEmbedder::new(), source).unwrap();
+        let output = scorer.score("cat").unwrap();
+        assert_eq!(output.label_score("opposite"), 0.0);
+    }
+
+    #[test]
+    fn score_orthogonal_is_zero() {
+        let scorer = EmbeddingScorer::new(TableEmbedder::new(), examples()).unwrap();
+        let output = scorer.score("car").unwrap();
+        assert_eq!(output.label_score("feline"), 0.0);
+    }
+
+    #[test]
+    fn empty_examples_is_error() {
+        let source = EmbeddingPrototypeSource::Examples {
+            labels: BTreeMap::from([("empty".to_string(), vec![])]),
+        };
+
+        assert!(EmbeddingScorer::new(TableEmbedder::new(), source).is_err());
+    }
+}
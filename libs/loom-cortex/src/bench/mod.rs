@@ -2,11 +2,22 @@
 //!
 //! This module contains:
 //! - `Decision` enum for accept/reject outcomes
+//! - `Scorer` trait and `ScorerOutput` for per-label scoring
+//! - `EmbeddingScorer` for sentence-embedding prototype similarity
+//! - `TokenScorer` for token-classification entity span extraction
 //! - `platt` submodule for Platt calibration training
+//! - `temperature` submodule for global temperature scaling
 //!
 //! For operational types (datasets, results, runner), see `loom_eval`.
 
 mod decision;
+mod embedding;
 pub mod platt;
+mod scorer;
+pub mod temperature;
+mod token;
 
 pub use decision::*;
+pub use embedding::*;
+pub use scorer::*;
+pub use token::*;
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use rust_bert::RustBertError;
+use serde::{Deserialize, Serialize};
+
+use super::PlattParams;
+use crate::bench::{Scorer, ScorerOutput};
+
+/// Per-label Platt parameters loaded at runtime, so retrained params can be
+/// applied without regenerating code.
+///
+/// Reads `{"params": {"<label>": {"a": .., "b": ..}}}`; other fields are
+/// ignored, so the `platt_params.json` written by training loads as is.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CalibrationConfig {
+    #[serde(default)]
+    pub params: HashMap<String, PlattParams>,
+}
+
+impl CalibrationConfig {
+    pub fn new(params: HashMap<String, PlattParams>) -> Self {
+        Self { params }
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, RustBertError> {
+        let config: Self = serde_json::from_str(json).map_err(|e| {
+            RustBertError::InvalidConfigurationError(format!("invalid calibration: {}", e))
+        })?;
+
+        if let Some((label, _)) = config.params.iter().find(|(_, p)| !p.is_finite()) {
+            return Err(RustBertError::InvalidConfigurationError(format!(
+                "invalid calibration: params for '{}' are not finite",
+                label
+            )));
+        }
+
+        Ok(config)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RustBertError> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| {
+            RustBertError::IOError(format!(
+                "failed to read calibration {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        Self::from_json(&json)
+    }
+
+    pub fn get(&self, label: &str) -> Option<&PlattParams> {
+        self.params.get(label)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+
+    /// Calibrate `output` with these params; see `ScorerOutput::calibrate`.
+    pub fn apply(&self, output: ScorerOutput) -> ScorerOutput {
+        output.calibrate(&self.params)
+    }
+}
+
+/// A `Scorer` whose outputs are calibrated with a `CalibrationConfig`.
+/// Built with `Scorer::calibrated` or `Scorer::load_calibration`.
+pub struct CalibratedScorer<S> {
+    scorer: S,
+    calibration: CalibrationConfig,
+}
+
+impl<S: Scorer> CalibratedScorer<S> {
+    pub fn new(scorer: S, calibration: CalibrationConfig) -> Self {
+        Self {
+            scorer,
+            calibration,
+        }
+    }
+
+    pub fn calibration(&self) -> &CalibrationConfig {
+        &self.calibration
+    }
+
+    pub fn set_calibration(&mut self, calibration: CalibrationConfig) {
+        self.calibration = calibration;
+    }
+
+    /// Replace the calibration with the params in `path`. The current
+    /// calibration is kept if the file can't be loaded.
+    pub fn reload(&mut self, path: impl AsRef<Path>) -> Result<(), RustBertError> {
+        self.calibration = CalibrationConfig::load(path)?;
+        Ok(())
+    }
+
+    pub fn inner(&self) -> &S {
+        &self.scorer
+    }
+
+    pub fn into_inner(self) -> S {
+        self.scorer
+    }
+}
+
+impl<S: Scorer> Scorer for CalibratedScorer<S> {
+    fn score(&self, text: &str) -> Result<ScorerOutput, RustBertError> {
+        Ok(self.calibration.apply(self.scorer.score(text)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::LabelScore;
+
+    struct FixedScorer;
+
+    impl Scorer for FixedScorer {
+        fn score(&self, _text: &str) -> Result<ScorerOutput, RustBertError> {
+            Ok(ScorerOutput::new(vec![
+                LabelScore::new("toxic", 0.6),
+                LabelScore::new("spam", 0.2),
+            ]))
+        }
+    }
+
+    fn temp_file(name: &str, content: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "loom-calibration-{}-{}.json",
+            std::process::id(),
+            name
+        ));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn loaded_params_change_calibrated_score() {
+        let path = temp_file(
+            "load",
+            r#"{
+                "params": {"toxic": {"a": 4.0, "b": -1.0}},
+                "metadata": {"total_samples": 10, "samples_per_label": {}}
+            }"#,
+        );
+
+        let scorer = FixedScorer.load_calibration(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let output = scorer.score("hello").unwrap();
+        let expected = PlattParams { a: 4.0, b: -1.0 }.apply(0.6);
+
+        assert!((output.label_score("toxic") - expected).abs() < 1e-6);
+        assert_ne!(output.label_score("toxic"), 0.6);
+        assert_eq!(output.raw_labels()[0].score, 0.6);
+        assert_eq!(output.label_score("spam"), 0.2);
+    }
+
+    #[test]
+    fn reload_replaces_params() {
+        let mut scorer = FixedScorer.calibrated(CalibrationConfig::default());
+        assert_eq!(scorer.score("hello").unwrap().label_score("toxic"), 0.6);
+
+        let path = temp_file("reload", r#"{"params": {"toxic": {"a": 2.0, "b": 0.0}}}"#);
+        scorer.reload(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let expected = PlattParams { a: 2.0, b: 0.0 }.apply(0.6);
+        assert!((scorer.score("hello").unwrap().label_score("toxic") - expected).abs() < 1e-6);
+
+        assert!(scorer.reload("/nonexistent/platt_params.json").is_err());
+        assert!(scorer.calibration().get("toxic").is_some());
+    }
+
+    #[test]
+    fn from_json_rejects_malformed_params() {
+        assert!(CalibrationConfig::from_json(r#"{"params": {"toxic": {"a": 1.0}}}"#).is_err());
+        assert!(CalibrationConfig::from_json("not json").is_err());
+        assert!(CalibrationConfig::from_json("{}").unwrap().is_empty());
+    }
+}
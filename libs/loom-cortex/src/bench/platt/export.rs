@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::bench::ScorerOutput;
+
 /// Raw score export data for Platt calibration training.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RawScoreExport {
@@ -16,3 +18,42 @@ pub struct SampleScores {
     pub scores: HashMap<String, f32>,
     pub expected_labels: Vec<String>,
 }
+
+impl SampleScores {
+    /// Build an export row from a scorer output, taking the pre-calibration
+    /// scores so a calibrated inference can be exported without rescoring.
+    pub fn from_output(
+        id: impl Into<String>,
+        text: impl Into<String>,
+        output: &ScorerOutput,
+        expected_labels: Vec<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            text: text.into(),
+            scores: output
+                .raw_labels()
+                .iter()
+                .map(|l| (l.label.clone(), l.score))
+                .collect(),
+            expected_labels,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::{LabelScore, platt::PlattParams};
+
+    #[test]
+    fn from_output_exports_raw_scores() {
+        let params = HashMap::from([("toxic".to_string(), PlattParams { a: 3.0, b: 0.5 })]);
+        let output = ScorerOutput::new(vec![LabelScore::new("toxic", 0.4)]).calibrate(&params);
+
+        let row = SampleScores::from_output("1", "text", &output, vec!["toxic".to_string()]);
+
+        assert_eq!(row.scores["toxic"], 0.4);
+        assert_ne!(output.label_score("toxic"), 0.4);
+    }
+}
@@ -1,7 +1,9 @@
+mod calibration;
 mod export;
 mod params;
 mod training;
 
+pub use calibration::*;
 pub use export::*;
 pub use params::*;
 pub use training::*;
@@ -9,6 +9,23 @@ pub struct PlattParams {
     pub b: f32,
 }
 
+impl PlattParams {
+    /// Returns true if both parameters are finite numbers.
+    pub fn is_finite(&self) -> bool {
+        self.a.is_finite() && self.b.is_finite()
+    }
+
+    /// Calibrate a raw score: `1 / (1 + exp(-a * raw - b))`. Identity
+    /// params return `raw` unchanged.
+    pub fn apply(&self, raw: f32) -> f32 {
+        if (self.a - 1.0).abs() < f32::EPSILON && self.b.abs() < f32::EPSILON {
+            return raw;
+        }
+
+        1.0 / (1.0 + (-self.a * raw - self.b).exp())
+    }
+}
+
 impl Default for PlattParams {
     fn default() -> Self {
         Self { a: 1.0, b: 0.0 }
@@ -35,4 +52,7 @@ pub struct LabelStats {
     pub positive: usize,
     pub negative: usize,
     pub skipped: bool,
+    /// Set when the label's data was degenerate and parameters fell back to defaults or were clamped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
 }
@@ -13,6 +13,10 @@ const LEARNING_RATE: f64 = 0.1;
 /// Number of optimization iterations.
 const NUM_ITERATIONS: usize = 100;
 
+/// Maximum magnitude of fitted parameters.
+/// Separable data would otherwise push the fit toward infinity.
+const MAX_PARAM_MAGNITUDE: f64 = 50.0;
+
 /// Train Platt scaling parameters for all labels in the dataset.
 pub fn train_platt_params(export: &RawScoreExport) -> PlattTrainingResult {
     let mut params = HashMap::new();
@@ -43,6 +47,7 @@ pub fn train_platt_params(export: &RawScoreExport) -> PlattTrainingResult {
             positive: positive_count,
             negative: negative_count,
             skipped: positive_count < MIN_POSITIVE_SAMPLES || negative_count < MIN_POSITIVE_SAMPLES,
+            warning: detect_degenerate(&raw_scores, &targets).map(|d| d.to_string()),
         };
         samples_per_label.insert(label.clone(), stats.clone());
 
@@ -64,10 +69,61 @@ pub fn train_platt_params(export: &RawScoreExport) -> PlattTrainingResult {
     }
 }
 
+/// Kind of degenerate training data that the logistic fit cannot handle well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Degenerate {
+    /// Every sample has the same target, so there is nothing to separate.
+    SingleClass,
+    /// Positive and negative scores don't overlap, so the optimum is at infinity.
+    Separable,
+}
+
+impl std::fmt::Display for Degenerate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SingleClass => write!(f, "single-class training data, using default parameters"),
+            Self::Separable => write!(
+                f,
+                "separable training data, parameters clamped to +/-{}",
+                MAX_PARAM_MAGNITUDE
+            ),
+        }
+    }
+}
+
+/// Detect single-class or perfectly separable training data.
+fn detect_degenerate(raw_scores: &[f32], targets: &[f32]) -> Option<Degenerate> {
+    let (pos, neg): (Vec<_>, Vec<_>) = raw_scores.iter().zip(targets).partition(|&(_, &t)| t > 0.5);
+
+    if pos.is_empty() || neg.is_empty() {
+        return Some(Degenerate::SingleClass);
+    }
+
+    let min_pos = pos.iter().map(|(s, _)| **s).fold(f32::INFINITY, f32::min);
+    let max_pos = pos
+        .iter()
+        .map(|(s, _)| **s)
+        .fold(f32::NEG_INFINITY, f32::max);
+    let min_neg = neg.iter().map(|(s, _)| **s).fold(f32::INFINITY, f32::min);
+    let max_neg = neg
+        .iter()
+        .map(|(s, _)| **s)
+        .fold(f32::NEG_INFINITY, f32::max);
+
+    if max_neg < min_pos || max_pos < min_neg {
+        return Some(Degenerate::Separable);
+    }
+
+    None
+}
+
 /// Fit Platt scaling parameters (A, B) using gradient descent.
+///
+/// Single-class data returns the identity parameters, and the result is always
+/// clamped to finite values within `MAX_PARAM_MAGNITUDE`.
 fn fit_platt_params(raw_scores: &[f32], targets: &[f32]) -> PlattParams {
     let n = raw_scores.len();
-    if n == 0 {
+    if n == 0 || detect_degenerate(raw_scores, targets) == Some(Degenerate::SingleClass) {
         return PlattParams::default();
     }
 
@@ -101,9 +157,13 @@ fn fit_platt_params(raw_scores: &[f32], targets: &[f32]) -> PlattParams {
         b -= LEARNING_RATE * grad_b;
     }
 
+    if !a.is_finite() || !b.is_finite() {
+        return PlattParams::default();
+    }
+
     PlattParams {
-        a: a as f32,
-        b: b as f32,
+        a: a.clamp(-MAX_PARAM_MAGNITUDE, MAX_PARAM_MAGNITUDE) as f32,
+        b: b.clamp(-MAX_PARAM_MAGNITUDE, MAX_PARAM_MAGNITUDE) as f32,
     }
 }
 
@@ -124,14 +184,12 @@ pub fn generate_rust_code(result: &PlattTrainingResult) -> String {
 
     for (label, params) in sorted_labels {
         let stats = result.metadata.samples_per_label.get(label);
-        let comment = if let Some(s) = stats {
-            if s.skipped {
-                " // SKIPPED: insufficient data"
-            } else {
-                ""
-            }
-        } else {
-            ""
+        let comment = match stats {
+            Some(s) if s.skipped => " // SKIPPED: insufficient data".to_string(),
+            Some(LabelStats {
+                warning: Some(w), ..
+            }) => format!(" // WARNING: {}", w),
+            _ => String::new(),
         };
         code.push_str(&format!(
             "// {}: a={:.4}, b={:.4}{}\n",
@@ -145,6 +203,7 @@ pub fn generate_rust_code(result: &PlattTrainingResult) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bench::platt::SampleScores;
 
     #[test]
     fn platt_params_default_is_identity() {
@@ -160,6 +219,54 @@ mod tests {
         assert!(params.b.abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn fit_platt_params_with_all_positive_data_is_finite() {
+        let raw_scores = vec![0.9f32; 20];
+        let targets = vec![1.0f32; 20];
+
+        let params = fit_platt_params(&raw_scores, &targets);
+        assert!(params.is_finite());
+        assert!((params.a - 1.0).abs() < f32::EPSILON);
+        assert!(params.b.abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn train_platt_params_with_all_positive_data_warns() {
+        let samples = (0..20)
+            .map(|i| SampleScores {
+                id: format!("s{}", i),
+                text: String::new(),
+                scores: HashMap::from([("task".to_string(), 0.9)]),
+                expected_labels: vec!["task".to_string()],
+            })
+            .collect();
+
+        let result = train_platt_params(&RawScoreExport { samples });
+        let params = result.params.get("task").unwrap();
+        let stats = result.metadata.samples_per_label.get("task").unwrap();
+
+        assert!(params.is_finite());
+        assert!(stats.warning.is_some());
+        assert!(!generate_rust_code(&result).contains("NaN"));
+    }
+
+    #[test]
+    fn detect_degenerate_finds_separable_data() {
+        let raw_scores = [0.9, 0.8, 0.2, 0.1];
+        let targets = [1.0, 1.0, 0.0, 0.0];
+        assert_eq!(
+            detect_degenerate(&raw_scores, &targets),
+            Some(Degenerate::Separable)
+        );
+    }
+
+    #[test]
+    fn detect_degenerate_accepts_overlapping_data() {
+        let raw_scores = [0.9, 0.3, 0.4, 0.1];
+        let targets = [1.0, 1.0, 0.0, 0.0];
+        assert_eq!(detect_degenerate(&raw_scores, &targets), None);
+    }
+
     #[test]
     fn fit_platt_params_with_balanced_data() {
         let raw_scores: Vec<f32> = (0..20)
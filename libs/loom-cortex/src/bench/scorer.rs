@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use rust_bert::RustBertError;
+use serde::{Deserialize, Serialize};
+
+use super::platt::{CalibratedScorer, CalibrationConfig, PlattParams};
+
+/// Scores a piece of text against a set of labels.
+pub trait Scorer {
+    fn score(&self, text: &str) -> Result<ScorerOutput, RustBertError>;
+
+    /// Calibrate every output of this scorer with `calibration`.
+    fn calibrated(self, calibration: CalibrationConfig) -> CalibratedScorer<Self>
+    where
+        Self: Sized,
+    {
+        CalibratedScorer::new(self, calibration)
+    }
+
+    /// Calibrate every output of this scorer with the Platt params in the
+    /// JSON file at `path`, e.g. the `platt_params.json` written by training.
+    fn load_calibration(
+        self,
+        path: impl AsRef<Path>,
+    ) -> Result<CalibratedScorer<Self>, RustBertError>
+    where
+        Self: Sized,
+    {
+        Ok(self.calibrated(CalibrationConfig::load(path)?))
+    }
+}
+
+/// Per-label scores produced by a `Scorer`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScorerOutput {
+    labels: Vec<LabelScore>,
+
+    /// Pre-calibration scores, kept once `calibrate` has run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    raw: Option<Vec<LabelScore>>,
+}
+
+impl ScorerOutput {
+    pub fn new(labels: Vec<LabelScore>) -> Self {
+        Self { labels, raw: None }
+    }
+
+    /// Apply Platt scaling to each label with params in `params`, keeping
+    /// the scores the scorer produced for `raw_labels`. Labels without
+    /// params are left as they are.
+    pub fn calibrate(mut self, params: &HashMap<String, PlattParams>) -> Self {
+        let raw = self.raw.take().unwrap_or_else(|| self.labels.clone());
+
+        self.labels = raw
+            .iter()
+            .map(|l| match params.get(&l.label) {
+                Some(p) => LabelScore::new(l.label.clone(), p.apply(l.score)),
+                None => l.clone(),
+            })
+            .collect();
+
+        self.raw = Some(raw);
+        self
+    }
+
+    /// All label scores, in the order the scorer produced them. Calibrated
+    /// if `calibrate` has run; use these for decisions.
+    pub fn labels(&self) -> &[LabelScore] {
+        &self.labels
+    }
+
+    /// Label scores before calibration, e.g. to export for Platt training.
+    /// Same as `labels` if the output was never calibrated.
+    pub fn raw_labels(&self) -> &[LabelScore] {
+        self.raw.as_deref().unwrap_or(&self.labels)
+    }
+
+    /// Get a label score by name.
+    pub fn label(&self, name: &str) -> Option<&LabelScore> {
+        self.labels.iter().find(|l| l.label == name)
+    }
+
+    /// Get the score for a label, or `0.0` if the label is unknown.
+    pub fn label_score(&self, name: &str) -> f32 {
+        self.label(name).map(|l| l.score).unwrap_or_default()
+    }
+}
+
+/// Score for a single label.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LabelScore {
+    pub label: String,
+    pub score: f32,
+}
+
+impl LabelScore {
+    pub fn new(label: impl Into<String>, score: f32) -> Self {
+        Self {
+            label: label.into(),
+            score,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calibrate_keeps_raw_scores() {
+        let output = ScorerOutput::new(vec![
+            LabelScore::new("toxic", 0.6),
+            LabelScore::new("spam", 0.2),
+        ]);
+        let params = HashMap::from([("toxic".to_string(), PlattParams { a: 4.0, b: -1.0 })]);
+
+        let output = output.calibrate(&params);
+
+        assert_eq!(output.raw_labels()[0].score, 0.6);
+        assert_ne!(output.labels()[0].score, output.raw_labels()[0].score);
+        assert!(
+            (output.label_score("toxic") - PlattParams { a: 4.0, b: -1.0 }.apply(0.6)).abs() < 1e-6
+        );
+
+        // labels without params are untouched
+        assert_eq!(output.label_score("spam"), 0.2);
+        assert_eq!(output.raw_labels()[1].score, 0.2);
+    }
+
+    #[test]
+    fn raw_labels_default_to_labels() {
+        let output = ScorerOutput::new(vec![LabelScore::new("toxic", 0.6)]);
+        assert_eq!(output.raw_labels(), output.labels());
+    }
+}
@@ -0,0 +1,216 @@
+//! Temperature scaling calibration.
+//!
+//! A single global temperature `T` is fitted across all labels by minimizing
+//! the negative log-likelihood, then applied as `score -> sigmoid(logit(score) / T)`.
+
+use serde::{Deserialize, Serialize};
+
+use super::platt::RawScoreExport;
+
+/// Lower bound of the inverse temperature search range.
+const MIN_INV_TEMPERATURE: f64 = 0.01;
+
+/// Upper bound of the inverse temperature search range.
+const MAX_INV_TEMPERATURE: f64 = 100.0;
+
+/// Number of golden-section search iterations.
+const NUM_ITERATIONS: usize = 100;
+
+/// Scores are clamped away from 0 and 1 before taking the logit.
+const EPSILON: f64 = 1e-7;
+
+/// Calibrates scores with a single global temperature.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TemperatureScaler {
+    pub temperature: f32,
+}
+
+impl TemperatureScaler {
+    pub fn new(temperature: f32) -> Self {
+        Self { temperature }
+    }
+
+    /// Apply temperature scaling to a raw score in `[0, 1]`.
+    pub fn apply(&self, score: f32) -> f32 {
+        let logit = logit(score as f64);
+        sigmoid(logit / self.temperature as f64) as f32
+    }
+}
+
+impl Default for TemperatureScaler {
+    fn default() -> Self {
+        Self { temperature: 1.0 }
+    }
+}
+
+/// Result of fitting a temperature across all labels.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemperatureTrainingResult {
+    pub temperature: f32,
+    pub metadata: TemperatureTrainingMetadata,
+}
+
+impl TemperatureTrainingResult {
+    pub fn scaler(&self) -> TemperatureScaler {
+        TemperatureScaler::new(self.temperature)
+    }
+}
+
+/// Metadata about the temperature fitting process.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemperatureTrainingMetadata {
+    pub total_samples: usize,
+    /// Number of (label, score) pairs used for fitting.
+    pub total_scores: usize,
+    /// Negative log-likelihood per score before scaling (`T = 1`).
+    pub nll_before: f32,
+    /// Negative log-likelihood per score after scaling.
+    pub nll_after: f32,
+}
+
+/// Fit a single temperature minimizing NLL across every label in the export.
+pub fn train_temperature(export: &RawScoreExport) -> TemperatureTrainingResult {
+    let mut logits = Vec::new();
+    let mut targets = Vec::new();
+
+    for sample in &export.samples {
+        for (label, &score) in &sample.scores {
+            logits.push(logit(score as f64));
+            targets.push(if sample.expected_labels.contains(label) {
+                1.0
+            } else {
+                0.0
+            });
+        }
+    }
+
+    let nll_before = nll(&logits, &targets, 1.0);
+    let temperature = fit_temperature(&logits, &targets);
+    let nll_after = nll(&logits, &targets, 1.0 / temperature);
+
+    TemperatureTrainingResult {
+        temperature: temperature as f32,
+        metadata: TemperatureTrainingMetadata {
+            total_samples: export.samples.len(),
+            total_scores: logits.len(),
+            nll_before: nll_before as f32,
+            nll_after: nll_after as f32,
+        },
+    }
+}
+
+/// Golden-section search over the log inverse temperature.
+/// NLL is convex in `1 / T`, so the search finds the global minimum.
+/// Ties move toward smaller `1 / T`, where clamped probabilities flatten the curve.
+fn fit_temperature(logits: &[f64], targets: &[f64]) -> f64 {
+    if logits.is_empty() {
+        return 1.0;
+    }
+
+    let ratio = (5f64.sqrt() - 1.0) / 2.0;
+    let mut lo = MIN_INV_TEMPERATURE.ln();
+    let mut hi = MAX_INV_TEMPERATURE.ln();
+
+    for _ in 0..NUM_ITERATIONS {
+        let c = hi - ratio * (hi - lo);
+        let d = lo + ratio * (hi - lo);
+
+        if nll(logits, targets, c.exp()) <= nll(logits, targets, d.exp()) {
+            hi = d;
+        } else {
+            lo = c;
+        }
+    }
+
+    1.0 / ((lo + hi) / 2.0).exp()
+}
+
+/// Mean negative log-likelihood for logits scaled by `inv_temperature`.
+fn nll(logits: &[f64], targets: &[f64], inv_temperature: f64) -> f64 {
+    if logits.is_empty() {
+        return 0.0;
+    }
+
+    let total: f64 = logits
+        .iter()
+        .zip(targets)
+        .map(|(&z, &y)| {
+            let p = sigmoid(z * inv_temperature).clamp(EPSILON, 1.0 - EPSILON);
+            -(y * p.ln() + (1.0 - y) * (1.0 - p).ln())
+        })
+        .sum();
+
+    total / logits.len() as f64
+}
+
+fn logit(p: f64) -> f64 {
+    let p = p.clamp(EPSILON, 1.0 - EPSILON);
+    (p / (1.0 - p)).ln()
+}
+
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::bench::platt::SampleScores;
+
+    fn sample(id: &str, score: f32, present: bool) -> SampleScores {
+        SampleScores {
+            id: id.to_string(),
+            text: String::new(),
+            scores: HashMap::from([("task".to_string(), score)]),
+            expected_labels: if present {
+                vec!["task".to_string()]
+            } else {
+                vec![]
+            },
+        }
+    }
+
+    #[test]
+    fn unit_temperature_is_noop() {
+        let scaler = TemperatureScaler::default();
+        for score in [0.01, 0.25, 0.5, 0.75, 0.99] {
+            let result = scaler.apply(score);
+            assert!(
+                (result - score).abs() < 1e-5,
+                "T=1 should be a no-op: expected {}, got {}",
+                score,
+                result
+            );
+        }
+    }
+
+    #[test]
+    fn higher_temperature_softens_scores() {
+        let scaler = TemperatureScaler::new(2.0);
+        assert!(scaler.apply(0.9) < 0.9);
+        assert!(scaler.apply(0.1) > 0.1);
+        assert!((scaler.apply(0.5) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn overconfident_scores_fit_temperature_above_one() {
+        // 0.99 is right only 70% of the time, so the fit should soften it.
+        let samples = (0..10)
+            .map(|i| sample(&format!("s{}", i), 0.99, i < 7))
+            .chain((10..20).map(|i| sample(&format!("s{}", i), 0.01, i >= 17)))
+            .collect();
+
+        let result = train_temperature(&RawScoreExport { samples });
+        assert!(result.temperature > 1.0);
+        assert!(result.metadata.nll_after <= result.metadata.nll_before);
+        assert_eq!(result.metadata.total_scores, 20);
+    }
+
+    #[test]
+    fn empty_export_returns_unit_temperature() {
+        let result = train_temperature(&RawScoreExport { samples: vec![] });
+        assert!((result.temperature - 1.0).abs() < f32::EPSILON);
+    }
+}
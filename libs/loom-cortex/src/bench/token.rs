@@ -0,0 +1,297 @@
+use rust_bert::RustBertError;
+use rust_bert::pipelines::token_classification::TokenClassificationModel;
+use serde::{Deserialize, Serialize};
+
+use super::{LabelScore, Scorer, ScorerOutput};
+use crate::config::CortexTokenClassificationConfig;
+
+/// Label assigned to tokens outside any entity.
+const OUTSIDE_LABEL: &str = "O";
+
+/// Anything that can tag a batch of texts with token-level entity spans.
+pub trait TokenClassifier {
+    fn classify(&self, texts: &[&str]) -> Result<Vec<Vec<EntitySpan>>, RustBertError>;
+}
+
+impl TokenClassifier for TokenClassificationModel {
+    fn classify(&self, texts: &[&str]) -> Result<Vec<Vec<EntitySpan>>, RustBertError> {
+        let output = self
+            .predict(texts, true, false)
+            .into_iter()
+            .map(|tokens| {
+                tokens
+                    .into_iter()
+                    .filter_map(|token| {
+                        let offset = token.offset?;
+                        Some(EntitySpan::new(
+                            token.text,
+                            token.label,
+                            offset.begin as usize,
+                            offset.end as usize,
+                            token.score as f32,
+                        ))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(output)
+    }
+}
+
+/// A labelled span of text extracted by a `TokenScorer`.
+///
+/// `start` and `end` are character offsets into the source text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EntitySpan {
+    pub text: String,
+    pub label: String,
+    pub start: usize,
+    pub end: usize,
+    pub score: f32,
+}
+
+impl EntitySpan {
+    pub fn new(
+        text: impl Into<String>,
+        label: impl Into<String>,
+        start: usize,
+        end: usize,
+        score: f32,
+    ) -> Self {
+        Self {
+            text: text.into(),
+            label: label.into(),
+            start,
+            end,
+            score,
+        }
+    }
+}
+
+/// Entity spans produced by a `TokenScorer`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExtractorOutput {
+    spans: Vec<EntitySpan>,
+}
+
+impl ExtractorOutput {
+    pub fn new(spans: Vec<EntitySpan>) -> Self {
+        Self { spans }
+    }
+
+    /// All extracted spans, ordered by start offset.
+    pub fn spans(&self) -> &[EntitySpan] {
+        &self.spans
+    }
+
+    /// Get all spans with the given label.
+    pub fn by_label<'a>(&'a self, label: &'a str) -> impl Iterator<Item = &'a EntitySpan> {
+        self.spans.iter().filter(move |s| s.label == label)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+}
+
+/// Configuration for a `TokenScorer`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TokenScorerConfig {
+    #[serde(default)]
+    pub model: CortexTokenClassificationConfig,
+
+    /// Spans scoring below this are dropped
+    #[serde(default)]
+    pub min_score: f32,
+}
+
+/// Extracts named entity spans using a token classification model.
+///
+/// Adjacent tokens tagged `B-X`/`I-X` are merged into a single `X` span
+/// scored by the mean of its tokens. Tokens tagged `O` are dropped.
+pub struct TokenScorer<C = TokenClassificationModel> {
+    classifier: C,
+    min_score: f32,
+}
+
+impl TokenScorer {
+    /// Build the token classification model from config.
+    pub fn from_config(config: TokenScorerConfig) -> Result<Self, RustBertError> {
+        let model = TokenClassificationModel::new(config.model.into())?;
+        Ok(Self::new(model, config.min_score))
+    }
+}
+
+impl<C: TokenClassifier> TokenScorer<C> {
+    pub fn new(classifier: C, min_score: f32) -> Self {
+        Self {
+            classifier,
+            min_score,
+        }
+    }
+
+    /// Extract entity spans from a single text.
+    pub fn extract(&self, text: &str) -> Result<ExtractorOutput, RustBertError> {
+        let tokens = self
+            .classifier
+            .classify(&[text])?
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+
+        let spans = merge_spans(text, tokens)
+            .into_iter()
+            .filter(|s| s.score >= self.min_score)
+            .collect();
+
+        Ok(ExtractorOutput::new(spans))
+    }
+}
+
+impl<C: TokenClassifier> Scorer for TokenScorer<C> {
+    /// Score each entity label by its highest-scoring span.
+    fn score(&self, text: &str) -> Result<ScorerOutput, RustBertError> {
+        let mut labels: Vec<LabelScore> = Vec::new();
+
+        for span in self.extract(text)?.spans {
+            match labels.iter_mut().find(|l| l.label == span.label) {
+                Some(existing) => existing.score = existing.score.max(span.score),
+                None => labels.push(LabelScore::new(span.label, span.score)),
+            }
+        }
+
+        Ok(ScorerOutput::new(labels))
+    }
+}
+
+/// Strip a `B-`/`I-` prefix, returning the entity type and whether the tag begins a new entity.
+fn parse_tag(label: &str) -> (&str, bool) {
+    match label.split_once('-') {
+        Some(("B", entity)) => (entity, true),
+        Some(("I", entity)) => (entity, false),
+        _ => (label, false),
+    }
+}
+
+fn merge_spans(text: &str, mut tokens: Vec<EntitySpan>) -> Vec<EntitySpan> {
+    tokens.sort_by_key(|t| t.start);
+
+    let mut spans: Vec<EntitySpan> = Vec::new();
+    let mut counts: Vec<usize> = Vec::new();
+
+    for token in tokens {
+        if token.label == OUTSIDE_LABEL {
+            continue;
+        }
+
+        let (entity, begins) = parse_tag(&token.label);
+
+        if !begins
+            && let Some(last) = spans.last_mut()
+            && last.label == entity
+            && text_between(text, last.end, token.start).trim().is_empty()
+        {
+            let count = counts.last_mut().expect("counts tracks spans");
+            last.score = (last.score * *count as f32 + token.score) / (*count + 1) as f32;
+            last.end = token.end;
+            last.text = text_between(text, last.start, last.end);
+            *count += 1;
+            continue;
+        }
+
+        spans.push(EntitySpan::new(
+            token.text,
+            entity,
+            token.start,
+            token.end,
+            token.score,
+        ));
+        counts.push(1);
+    }
+
+    spans
+}
+
+fn text_between(text: &str, start: usize, end: usize) -> String {
+    text.chars()
+        .skip(start)
+        .take(end.saturating_sub(start))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedClassifier(Vec<EntitySpan>);
+
+    impl TokenClassifier for FixedClassifier {
+        fn classify(&self, texts: &[&str]) -> Result<Vec<Vec<EntitySpan>>, RustBertError> {
+            Ok(texts.iter().map(|_| self.0.clone()).collect())
+        }
+    }
+
+    const TEXT: &str = "Ada Lovelace met Charles Babbage in London";
+
+    fn tokens() -> Vec<EntitySpan> {
+        vec![
+            EntitySpan::new("Ada", "B-PER", 0, 3, 0.9),
+            EntitySpan::new("Lovelace", "I-PER", 4, 12, 0.7),
+            EntitySpan::new("met", "O", 13, 16, 0.99),
+            EntitySpan::new("Charles", "B-PER", 17, 24, 0.95),
+            EntitySpan::new("Babbage", "I-PER", 25, 32, 0.85),
+            EntitySpan::new("London", "B-LOC", 36, 42, 0.4),
+        ]
+    }
+
+    #[test]
+    fn extract_merges_bio_tokens_into_spans() {
+        let scorer = TokenScorer::new(FixedClassifier(tokens()), 0.0);
+        let output = scorer.extract(TEXT).unwrap();
+
+        assert_eq!(output.len(), 3);
+        let first = &output.spans()[0];
+        assert_eq!(first.text, "Ada Lovelace");
+        assert_eq!(first.label, "PER");
+        assert_eq!((first.start, first.end), (0, 12));
+        assert!((first.score - 0.8).abs() < 1e-6);
+        assert_eq!(output.spans()[1].text, "Charles Babbage");
+        assert_eq!(output.by_label("LOC").count(), 1);
+    }
+
+    #[test]
+    fn extract_drops_spans_below_min_score() {
+        let scorer = TokenScorer::new(FixedClassifier(tokens()), 0.5);
+        let output = scorer.extract(TEXT).unwrap();
+
+        assert_eq!(output.by_label("PER").count(), 2);
+        assert_eq!(output.by_label("LOC").count(), 0);
+    }
+
+    #[test]
+    fn adjacent_begin_tags_stay_separate() {
+        let tokens = vec![
+            EntitySpan::new("Paris", "B-LOC", 0, 5, 0.9),
+            EntitySpan::new("Rome", "B-LOC", 6, 10, 0.8),
+        ];
+
+        let scorer = TokenScorer::new(FixedClassifier(tokens), 0.0);
+        let output = scorer.extract("Paris Rome").unwrap();
+        assert_eq!(output.len(), 2);
+    }
+
+    #[test]
+    fn score_uses_best_span_per_label() {
+        let scorer = TokenScorer::new(FixedClassifier(tokens()), 0.0);
+        let output = scorer.score(TEXT).unwrap();
+
+        assert_eq!(output.labels().len(), 2);
+        assert!((output.label_score("PER") - 0.9).abs() < 1e-6);
+        assert!((output.label_score("LOC") - 0.4).abs() < 1e-6);
+    }
+}
@@ -13,6 +13,10 @@ pub struct CortexConversationConfig {
     #[serde(default)]
     pub device: CortexDevice,
 
+    /// Fail instead of downloading when model files aren't cached locally
+    #[serde(default)]
+    pub offline: bool,
+
     #[serde(default = "CortexConversationConfig::default_min_length_for_response")]
     pub min_length_for_response: i64,
 
@@ -44,6 +48,7 @@ pub struct CortexConversationConfigBuilder {
     model: CortexModelType,
     source: CortexModelSource,
     device: CortexDevice,
+    offline: bool,
     min_length_for_response: i64,
     max_length: Option<i64>,
     do_sample: bool,
@@ -56,6 +61,7 @@ impl CortexConversationConfigBuilder {
             model,
             source: CortexModelSource::default(),
             device: CortexDevice::default(),
+            offline: false,
             min_length_for_response: CortexConversationConfig::default_min_length_for_response(),
             max_length: CortexConversationConfig::default_max_length(),
             do_sample: false,
@@ -73,6 +79,11 @@ impl CortexConversationConfigBuilder {
         self
     }
 
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
     pub fn min_length_for_response(mut self, min_length_for_response: i64) -> Self {
         self.min_length_for_response = min_length_for_response;
         self
@@ -98,6 +109,7 @@ impl CortexConversationConfigBuilder {
             model: self.model,
             source: self.source,
             device: self.device,
+            offline: self.offline,
             min_length_for_response: self.min_length_for_response,
             max_length: self.max_length,
             do_sample: self.do_sample,
@@ -112,6 +124,7 @@ impl Default for CortexConversationConfig {
             model: CortexModelType::GPT2,
             source: CortexModelSource::Default,
             device: CortexDevice::default(),
+            offline: false,
             min_length_for_response: 32,
             max_length: Some(1000),
             do_sample: false,
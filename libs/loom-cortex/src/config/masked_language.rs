@@ -13,6 +13,10 @@ pub struct CortexMaskedLanguageConfig {
     #[serde(default)]
     pub device: CortexDevice,
 
+    /// Fail instead of downloading when model files aren't cached locally
+    #[serde(default)]
+    pub offline: bool,
+
     #[serde(default)]
     pub lower_case: bool,
 
@@ -35,6 +39,7 @@ impl Default for CortexMaskedLanguageConfig {
             model: CortexModelType::Bert,
             source: CortexModelSource::Default,
             device: CortexDevice::default(),
+            offline: false,
             lower_case: false,
             strip_accents: None,
             add_prefix_space: None,
@@ -46,6 +51,7 @@ pub struct CortexMaskedLanguageConfigBuilder {
     model: CortexModelType,
     source: CortexModelSource,
     device: CortexDevice,
+    offline: bool,
     lower_case: bool,
     strip_accents: Option<bool>,
     add_prefix_space: Option<bool>,
@@ -57,6 +63,7 @@ impl CortexMaskedLanguageConfigBuilder {
             model,
             source: CortexModelSource::default(),
             device: CortexDevice::default(),
+            offline: false,
             lower_case: false,
             strip_accents: None,
             add_prefix_space: None,
@@ -73,6 +80,11 @@ impl CortexMaskedLanguageConfigBuilder {
         self
     }
 
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
     pub fn lower_case(mut self, lower_case: bool) -> Self {
         self.lower_case = lower_case;
         self
@@ -93,6 +105,7 @@ impl CortexMaskedLanguageConfigBuilder {
             model: self.model,
             source: self.source,
             device: self.device,
+            offline: self.offline,
             lower_case: self.lower_case,
             strip_accents: self.strip_accents,
             add_prefix_space: self.add_prefix_space,
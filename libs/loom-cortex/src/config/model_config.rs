@@ -1,4 +1,16 @@
+use std::path::PathBuf;
+
 use rust_bert::RustBertError;
+use rust_bert::bart::{
+    BartConfigResources, BartMergesResources, BartModelResources, BartVocabResources,
+};
+use rust_bert::bert::{BertConfigResources, BertModelResources, BertVocabResources};
+use rust_bert::distilbert::{
+    DistilBertConfigResources, DistilBertModelResources, DistilBertVocabResources,
+};
+use rust_bert::gpt2::{
+    Gpt2ConfigResources, Gpt2MergesResources, Gpt2ModelResources, Gpt2VocabResources,
+};
 use rust_bert::pipelines::*;
 use serde::{Deserialize, Serialize};
 
@@ -10,7 +22,7 @@ use super::{
     CortexTranslationConfig, CortexZeroShotConfig,
 };
 use crate::model::CortexModel;
-use crate::{CortexDevice, CortexModelSource, CortexModelType};
+use crate::{CortexDevice, CortexModelSource, CortexModelType, CortexResource};
 
 /// Serializable configuration for all pipeline types
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,8 +43,24 @@ pub enum CortexModelConfig {
     ZeroShotClassification(CortexZeroShotConfig),
 }
 
+/// Progress of `CortexModelConfig::fetch`, reported before each resource is resolved.
+#[derive(Debug, Clone, Copy)]
+pub struct FetchProgress<'a> {
+    /// Zero-based index of the resource being fetched
+    pub index: usize,
+    pub total: usize,
+    pub resource: &'a CortexResource,
+}
+
 impl CortexModelConfig {
+    /// Build the model. In offline mode, fails before loading if any
+    /// model file is missing from the local cache, or if `resources` can't
+    /// list the files to check.
     pub fn build(self) -> Result<CortexModel, RustBertError> {
+        if self.offline() {
+            self.fetch(|_| {})?;
+        }
+
         Ok(match self {
             Self::Conversation(c) => {
                 let model_type = c.model.clone();
@@ -70,7 +98,7 @@ impl CortexModelConfig {
                 }
             }
             Self::SentenceEmbeddings(c) => {
-                let model_type = c.model.clone();
+                let model_type = c.model;
                 CortexModel::SentenceEmbeddings {
                     model: sentence_embeddings::SentenceEmbeddingsModel::new(c.into())?,
                     model_type,
@@ -148,6 +176,132 @@ impl CortexModelConfig {
         }
     }
 
+    /// Returns whether the model must be loaded without network access.
+    /// All config variants have this field.
+    pub fn offline(&self) -> bool {
+        match self {
+            Self::Conversation(c) => c.offline,
+            Self::MaskedLanguage(c) => c.offline,
+            Self::Ner(c) => c.offline,
+            Self::PosTagging(c) => c.offline,
+            Self::QuestionAnswering(c) => c.offline,
+            Self::SentenceEmbeddings(c) => c.offline,
+            Self::Sentiment(c) => c.offline,
+            Self::SequenceClassification(c) => c.offline,
+            Self::Summarization(c) => c.offline,
+            Self::TextGeneration(c) => c.offline,
+            Self::TokenClassification(c) => c.offline,
+            Self::Translation(c) => c.offline,
+            Self::ZeroShotClassification(c) => c.offline,
+        }
+    }
+
+    /// Returns the model files `build` loads: the pipeline's default files
+    /// when `model` is the architecture they belong to.
+    /// Returns `None` when they can't be listed up front: SentenceEmbeddings
+    /// and Translation resolve their files inside rust-bert, and there are
+    /// no default files for any other model type.
+    pub fn resources(&self) -> Option<Vec<CortexResource>> {
+        let (architecture, model, config, vocab, merges) = match self {
+            Self::Conversation(_) => (
+                CortexModelType::GPT2,
+                Gpt2ModelResources::DIALOGPT_MEDIUM,
+                Gpt2ConfigResources::DIALOGPT_MEDIUM,
+                Gpt2VocabResources::DIALOGPT_MEDIUM,
+                Some(Gpt2MergesResources::DIALOGPT_MEDIUM),
+            ),
+            Self::MaskedLanguage(_) => (
+                CortexModelType::Bert,
+                BertModelResources::BERT,
+                BertConfigResources::BERT,
+                BertVocabResources::BERT,
+                None,
+            ),
+            Self::Ner(_) | Self::PosTagging(_) | Self::TokenClassification(_) => (
+                CortexModelType::Bert,
+                BertModelResources::BERT_NER,
+                BertConfigResources::BERT_NER,
+                BertVocabResources::BERT_NER,
+                None,
+            ),
+            Self::QuestionAnswering(_) => (
+                CortexModelType::DistilBert,
+                DistilBertModelResources::DISTIL_BERT_SQUAD,
+                DistilBertConfigResources::DISTIL_BERT_SQUAD,
+                DistilBertVocabResources::DISTIL_BERT_SQUAD,
+                None,
+            ),
+            Self::Sentiment(_) | Self::SequenceClassification(_) => (
+                CortexModelType::DistilBert,
+                DistilBertModelResources::DISTIL_BERT_SST2,
+                DistilBertConfigResources::DISTIL_BERT_SST2,
+                DistilBertVocabResources::DISTIL_BERT_SST2,
+                None,
+            ),
+            Self::Summarization(_) => (
+                CortexModelType::Bart,
+                BartModelResources::BART_CNN,
+                BartConfigResources::BART_CNN,
+                BartVocabResources::BART_CNN,
+                Some(BartMergesResources::BART_CNN),
+            ),
+            Self::TextGeneration(_) => (
+                CortexModelType::GPT2,
+                Gpt2ModelResources::GPT2_MEDIUM,
+                Gpt2ConfigResources::GPT2_MEDIUM,
+                Gpt2VocabResources::GPT2_MEDIUM,
+                Some(Gpt2MergesResources::GPT2_MEDIUM),
+            ),
+            Self::ZeroShotClassification(_) => (
+                CortexModelType::Bart,
+                BartModelResources::BART_MNLI,
+                BartConfigResources::BART_MNLI,
+                BartVocabResources::BART_MNLI,
+                Some(BartMergesResources::BART_MNLI),
+            ),
+            Self::SentenceEmbeddings(_) | Self::Translation(_) => return None,
+        };
+
+        if self.model() != Some(&architecture) {
+            return None;
+        }
+
+        Some(
+            [Some(model), Some(config), Some(vocab), merges]
+                .into_iter()
+                .flatten()
+                .map(|(name, url)| CortexResource::remote(name, url))
+                .collect(),
+        )
+    }
+
+    /// Resolve every model file into the local cache, calling `on_progress`
+    /// before each one. Nothing is downloaded in offline mode; a missing file
+    /// is an error naming its expected cache path.
+    pub fn fetch(
+        &self,
+        mut on_progress: impl FnMut(FetchProgress),
+    ) -> Result<Vec<PathBuf>, RustBertError> {
+        let resources = self.resources().ok_or_else(|| {
+            RustBertError::InvalidConfigurationError(
+                "model files for this pipeline can't be checked ahead of loading".to_string(),
+            )
+        })?;
+
+        resources
+            .iter()
+            .enumerate()
+            .map(|(index, resource)| {
+                on_progress(FetchProgress {
+                    index,
+                    total: resources.len(),
+                    resource,
+                });
+                resource.fetch(self.offline())
+            })
+            .collect()
+    }
+
     /// Returns a reference to the model type.
     /// Returns `None` for SentenceEmbeddings which uses a different model type.
     pub fn model(&self) -> Option<&CortexModelType> {
@@ -333,3 +487,48 @@ impl From<CortexPosTaggingConfig> for CortexModelConfig {
         Self::PosTagging(config)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resources_follow_default_source_for_matching_model() {
+        let config: CortexModelConfig = CortexSentimentConfig::default().into();
+        let resources = config.resources().unwrap();
+
+        assert_eq!(resources.len(), 3);
+        assert_eq!(
+            resources[0],
+            CortexResource::remote(
+                DistilBertModelResources::DISTIL_BERT_SST2.0,
+                DistilBertModelResources::DISTIL_BERT_SST2.1
+            )
+        );
+    }
+
+    #[test]
+    fn resources_are_unknown_for_default_source_of_other_model() {
+        let config: CortexModelConfig = CortexSentimentConfig::new(CortexModelType::Roberta)
+            .build()
+            .into();
+
+        assert_eq!(config.resources(), None);
+    }
+
+    #[test]
+    fn offline_without_listable_resources_is_error() {
+        let config: CortexModelConfig = CortexSentimentConfig::new(CortexModelType::Roberta)
+            .offline(true)
+            .build()
+            .into();
+
+        let err = config.build().err().unwrap();
+        assert!(
+            err.to_string()
+                .contains("can't be checked ahead of loading"),
+            "{}",
+            err
+        );
+    }
+}
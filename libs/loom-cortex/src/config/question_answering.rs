@@ -13,6 +13,10 @@ pub struct CortexQuestionAnsweringConfig {
     #[serde(default)]
     pub device: CortexDevice,
 
+    /// Fail instead of downloading when model files aren't cached locally
+    #[serde(default)]
+    pub offline: bool,
+
     #[serde(default)]
     pub lower_case: bool,
 
@@ -54,6 +58,7 @@ pub struct CortexQuestionAnsweringConfigBuilder {
     model: CortexModelType,
     source: CortexModelSource,
     device: CortexDevice,
+    offline: bool,
     lower_case: bool,
     strip_accents: Option<bool>,
     add_prefix_space: Option<bool>,
@@ -68,6 +73,7 @@ impl CortexQuestionAnsweringConfigBuilder {
             model,
             source: CortexModelSource::default(),
             device: CortexDevice::default(),
+            offline: false,
             lower_case: false,
             strip_accents: None,
             add_prefix_space: None,
@@ -87,6 +93,11 @@ impl CortexQuestionAnsweringConfigBuilder {
         self
     }
 
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
     pub fn lower_case(mut self, lower_case: bool) -> Self {
         self.lower_case = lower_case;
         self
@@ -122,6 +133,7 @@ impl CortexQuestionAnsweringConfigBuilder {
             model: self.model,
             source: self.source,
             device: self.device,
+            offline: self.offline,
             lower_case: self.lower_case,
             strip_accents: self.strip_accents,
             add_prefix_space: self.add_prefix_space,
@@ -138,6 +150,7 @@ impl Default for CortexQuestionAnsweringConfig {
             model: CortexModelType::DistilBert,
             source: CortexModelSource::Default,
             device: CortexDevice::default(),
+            offline: false,
             lower_case: false,
             strip_accents: None,
             add_prefix_space: None,
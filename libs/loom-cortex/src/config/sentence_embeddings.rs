@@ -89,6 +89,10 @@ pub struct CortexSentenceEmbeddingsConfig {
 
     #[serde(default)]
     pub device: CortexDevice,
+
+    /// Fail instead of downloading when model files aren't cached locally
+    #[serde(default)]
+    pub offline: bool,
 }
 
 impl CortexSentenceEmbeddingsConfig {
@@ -102,6 +106,7 @@ impl Default for CortexSentenceEmbeddingsConfig {
         Self {
             model: CortexSentenceEmbeddingsModelType::AllMiniLmL12V2,
             device: CortexDevice::default(),
+            offline: false,
         }
     }
 }
@@ -109,6 +114,7 @@ impl Default for CortexSentenceEmbeddingsConfig {
 pub struct CortexSentenceEmbeddingsConfigBuilder {
     model: CortexSentenceEmbeddingsModelType,
     device: CortexDevice,
+    offline: bool,
 }
 
 impl CortexSentenceEmbeddingsConfigBuilder {
@@ -116,6 +122,7 @@ impl CortexSentenceEmbeddingsConfigBuilder {
         Self {
             model,
             device: CortexDevice::default(),
+            offline: false,
         }
     }
 
@@ -124,10 +131,16 @@ impl CortexSentenceEmbeddingsConfigBuilder {
         self
     }
 
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
     pub fn build(self) -> CortexSentenceEmbeddingsConfig {
         CortexSentenceEmbeddingsConfig {
             model: self.model,
             device: self.device,
+            offline: self.offline,
         }
     }
 }
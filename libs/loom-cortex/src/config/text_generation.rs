@@ -13,6 +13,10 @@ pub struct CortexTextGenerationConfig {
     #[serde(default)]
     pub device: CortexDevice,
 
+    /// Fail instead of downloading when model files aren't cached locally
+    #[serde(default)]
+    pub offline: bool,
+
     #[serde(default = "CortexTextGenerationConfig::default_min_length")]
     pub min_length: i64,
 
@@ -106,6 +110,7 @@ impl Default for CortexTextGenerationConfig {
             model: CortexModelType::GPT2,
             source: CortexModelSource::Default,
             device: CortexDevice::default(),
+            offline: false,
             min_length: Self::default_min_length(),
             max_length: Self::default_max_length(),
             do_sample: false,
@@ -126,6 +131,7 @@ pub struct CortexTextGenerationConfigBuilder {
     model: CortexModelType,
     source: CortexModelSource,
     device: CortexDevice,
+    offline: bool,
     min_length: i64,
     max_length: Option<i64>,
     do_sample: bool,
@@ -146,6 +152,7 @@ impl CortexTextGenerationConfigBuilder {
             model,
             source: CortexModelSource::default(),
             device: CortexDevice::default(),
+            offline: false,
             min_length: CortexTextGenerationConfig::default_min_length(),
             max_length: CortexTextGenerationConfig::default_max_length(),
             do_sample: false,
@@ -171,6 +178,11 @@ impl CortexTextGenerationConfigBuilder {
         self
     }
 
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
     pub fn min_length(mut self, min_length: i64) -> Self {
         self.min_length = min_length;
         self
@@ -236,6 +248,7 @@ impl CortexTextGenerationConfigBuilder {
             model: self.model,
             source: self.source,
             device: self.device,
+            offline: self.offline,
             min_length: self.min_length,
             max_length: self.max_length,
             do_sample: self.do_sample,
@@ -12,6 +12,10 @@ pub struct CortexTranslationConfig {
     #[serde(default)]
     pub device: CortexDevice,
 
+    /// Fail instead of downloading when model files aren't cached locally
+    #[serde(default)]
+    pub offline: bool,
+
     #[serde(default)]
     pub source_languages: Vec<String>,
 
@@ -31,6 +35,7 @@ impl Default for CortexTranslationConfig {
             model: CortexModelType::Marian,
             source: CortexModelSource::Default,
             device: CortexDevice::default(),
+            offline: false,
             source_languages: Vec::new(),
             target_languages: Vec::new(),
         }
@@ -41,6 +46,7 @@ pub struct CortexTranslationConfigBuilder {
     model: CortexModelType,
     source: CortexModelSource,
     device: CortexDevice,
+    offline: bool,
     source_languages: Vec<String>,
     target_languages: Vec<String>,
 }
@@ -51,6 +57,7 @@ impl CortexTranslationConfigBuilder {
             model,
             source: CortexModelSource::default(),
             device: CortexDevice::default(),
+            offline: false,
             source_languages: Vec::new(),
             target_languages: Vec::new(),
         }
@@ -66,6 +73,11 @@ impl CortexTranslationConfigBuilder {
         self
     }
 
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
     pub fn source_languages(mut self, source_languages: Vec<String>) -> Self {
         self.source_languages = source_languages;
         self
@@ -81,6 +93,7 @@ impl CortexTranslationConfigBuilder {
             model: self.model,
             source: self.source,
             device: self.device,
+            offline: self.offline,
             source_languages: self.source_languages,
             target_languages: self.target_languages,
         }
@@ -1,5 +1,7 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
+use cached_path::{Cache, Options};
+use rust_bert::RustBertError;
 use rust_bert::pipelines::common::ModelResource;
 use rust_bert::resources::{LocalResource, RemoteResource, ResourceProvider};
 use serde::{Deserialize, Serialize};
@@ -47,6 +49,64 @@ impl CortexResource {
     pub fn into_model_resource(self) -> ModelResource {
         ModelResource::Torch(self.into_provider())
     }
+
+    /// Short name for progress output: the cache name of remote resources,
+    /// or the file name of local ones.
+    pub fn name(&self) -> String {
+        match self {
+            Self::Local { path } => path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| path.display().to_string()),
+            Self::Remote { name, .. } => name.clone(),
+        }
+    }
+
+    /// Resolve the resource to a local file, downloading remote resources
+    /// into the model cache unless `offline` is set.
+    pub fn fetch(&self, offline: bool) -> Result<PathBuf, RustBertError> {
+        self.fetch_in(&cache_dir(), offline)
+    }
+
+    fn fetch_in(&self, cache_dir: &Path, offline: bool) -> Result<PathBuf, RustBertError> {
+        match self {
+            Self::Local { path } if path.exists() => Ok(path.clone()),
+            Self::Local { path } => Err(RustBertError::IOError(format!(
+                "model file not found: {}",
+                path.display()
+            ))),
+            Self::Remote { name, url } => {
+                let cache = Cache::builder()
+                    .dir(cache_dir.to_path_buf())
+                    .progress_bar(None)
+                    .offline(offline)
+                    .build()?;
+
+                cache
+                    .cached_path_with_options(url, &Options::default().subdir(name))
+                    .map_err(|e| match e {
+                        cached_path::Error::NoCachedVersions(_) => RustBertError::IOError(format!(
+                            "offline mode: '{}' is not cached, expected it under {}",
+                            url,
+                            cache_dir.join(name).display()
+                        )),
+                        other => other.into(),
+                    })
+            }
+        }
+    }
+}
+
+/// Directory rust-bert caches downloaded model files in.
+///
+/// Uses `RUSTBERT_CACHE` if set, otherwise `.rustbert` under the platform cache directory.
+pub fn cache_dir() -> PathBuf {
+    match std::env::var("RUSTBERT_CACHE") {
+        Ok(dir) => PathBuf::from(dir),
+        Err(_) => dirs::cache_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join(".rustbert"),
+    }
 }
 
 /// Simplified model source - either use defaults or specify custom resources
@@ -130,3 +190,48 @@ impl CortexModelSource {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("loom-cortex-{}-{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn offline_fetch_of_uncached_remote_names_cache_path() {
+        let dir = temp_cache("offline");
+        let resource =
+            CortexResource::remote("bart-mnli/model", "https://example.com/rust_model.ot");
+
+        let err = resource.fetch_in(&dir, true).unwrap_err().to_string();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert!(err.contains("offline mode"), "{}", err);
+        assert!(
+            err.contains(&dir.join("bart-mnli/model").display().to_string()),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn fetch_of_missing_local_names_path() {
+        let resource = CortexResource::local("/nonexistent/loom/rust_model.ot");
+        let err = resource.fetch(true).unwrap_err().to_string();
+        assert!(err.contains("/nonexistent/loom/rust_model.ot"), "{}", err);
+    }
+
+    #[test]
+    fn name_uses_cache_name_or_file_name() {
+        assert_eq!(
+            CortexResource::remote("bert/vocab", "https://x").name(),
+            "bert/vocab"
+        );
+        assert_eq!(
+            CortexResource::local("/models/vocab.txt").name(),
+            "vocab.txt"
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::BTreeMap;
+
+use actix_web::{HttpResponse, ResponseError, http::StatusCode};
+
+use crate::{Error, ErrorCode};
+
+/// The JSON body of an error response. Backtraces and inner errors stay
+/// server side.
+#[derive(serde::Serialize)]
+struct ErrorBody<'a> {
+    code: &'a ErrorCode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<&'a str>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    fields: &'a BTreeMap<String, String>,
+}
+
+impl ResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.code().http_status()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(ErrorBody {
+            code: self.code(),
+            message: self.message(),
+            fields: &self.fields,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_code_follows_error_code() {
+        let err = Error::builder()
+            .code(ErrorCode::NotFound)
+            .message("scope not found")
+            .build();
+
+        assert_eq!(err.status_code(), StatusCode::NOT_FOUND);
+        assert_eq!(err.error_response().status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            Error::new().status_code(),
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+        assert_eq!(
+            Error::builder()
+                .code(ErrorCode::Cancel)
+                .build()
+                .status_code()
+                .as_u16(),
+            499
+        );
+    }
+}
@@ -10,6 +10,12 @@ pub struct ErrorBuilder {
     inner: Option<Arc<dyn std::error::Error + Send + Sync + 'static>>,
 }
 
+impl Default for ErrorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ErrorBuilder {
     pub fn new() -> Self {
         Self {
@@ -49,6 +55,12 @@ impl ErrorBuilder {
         self
     }
 
+    /// Attach the underlying cause, returned by `Error::source` and walked
+    /// by `Error::chain`.
+    pub fn source<TError: std::error::Error + Send + Sync + 'static>(self, source: TError) -> Self {
+        self.inner(source)
+    }
+
     pub fn build(self) -> Error {
         Error {
             code: self.code,
@@ -1,46 +1,46 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
 pub enum ErrorCode {
+    #[default]
     Unknown,
     Cancel,
     NotFound,
     BadArguments,
+    Conflict,
 }
 
 impl ErrorCode {
     pub fn is_unknown(&self) -> bool {
-        match self {
-            Self::Unknown => true,
-            _ => false,
-        }
+        matches!(self, Self::Unknown)
     }
 
     pub fn is_cancel(&self) -> bool {
-        match self {
-            Self::Cancel => true,
-            _ => false,
-        }
+        matches!(self, Self::Cancel)
     }
 
     pub fn is_not_found(&self) -> bool {
-        match self {
-            Self::NotFound => true,
-            _ => false,
-        }
+        matches!(self, Self::NotFound)
     }
 
     pub fn is_bad_arguments(&self) -> bool {
-        match self {
-            Self::BadArguments => true,
-            _ => false,
-        }
+        matches!(self, Self::BadArguments)
     }
-}
 
-impl Default for ErrorCode {
-    fn default() -> Self {
-        Self::Unknown
+    pub fn is_conflict(&self) -> bool {
+        matches!(self, Self::Conflict)
+    }
+
+    /// The HTTP status a response for this code should carry. `Cancel`
+    /// maps to the non-standard 499 (client closed request).
+    pub fn http_status(&self) -> u16 {
+        match self {
+            Self::Unknown => 500,
+            Self::Cancel => 499,
+            Self::NotFound => 404,
+            Self::BadArguments => 400,
+            Self::Conflict => 409,
+        }
     }
 }
 
@@ -51,6 +51,21 @@ impl std::fmt::Display for ErrorCode {
             Self::Unknown => write!(f, "unknown"),
             Self::NotFound => write!(f, "not-found"),
             Self::BadArguments => write!(f, "bad-arguments"),
+            Self::Conflict => write!(f, "conflict"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_http_status() {
+        assert_eq!(ErrorCode::NotFound.http_status(), 404);
+        assert_eq!(ErrorCode::BadArguments.http_status(), 400);
+        assert_eq!(ErrorCode::Conflict.http_status(), 409);
+        assert_eq!(ErrorCode::Cancel.http_status(), 499);
+        assert_eq!(ErrorCode::default().http_status(), 500);
+    }
+}
@@ -2,6 +2,9 @@ mod builder;
 mod code;
 mod group;
 
+#[cfg(feature = "actix")]
+mod actix;
+
 pub use builder::*;
 pub use code::*;
 pub use group::*;
@@ -23,6 +26,12 @@ pub struct Error {
     inner: Option<Arc<dyn std::error::Error + Send + Sync + 'static>>,
 }
 
+impl Default for Error {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Error {
     pub fn new() -> Self {
         Self {
@@ -84,6 +93,22 @@ impl Error {
             Some(v) => Some(v.as_ref()),
         }
     }
+
+    /// The underlying cause, as `std::error::Error::source` would return it.
+    ///
+    /// `Error` can't implement `std::error::Error` itself, since that would
+    /// overlap with its blanket `From` impl, so the chain starts here.
+    pub fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.inner {
+            None => None,
+            Some(v) => Some(v.as_ref()),
+        }
+    }
+
+    /// The cause chain, from the attached source down to the root cause.
+    pub fn chain(&self) -> impl Iterator<Item = &(dyn std::error::Error + 'static)> {
+        std::iter::successors(self.source(), |e| e.source())
+    }
 }
 
 impl<T: std::error::Error + Send + Sync + 'static> From<T> for Error {
@@ -117,3 +142,52 @@ impl std::fmt::Display for Error {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Outer(std::io::Error);
+
+    impl std::fmt::Display for Outer {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "failed to read config")
+        }
+    }
+
+    impl std::error::Error for Outer {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn test_source_returns_wrapped_error() {
+        let err = Error::builder()
+            .code(ErrorCode::NotFound)
+            .message("Failed to load from path 'a.json'")
+            .source(std::io::Error::new(std::io::ErrorKind::NotFound, "missing"))
+            .build();
+
+        let source = err.source().expect("source");
+        let io = source.downcast_ref::<std::io::Error>().expect("io error");
+
+        assert_eq!(io.kind(), std::io::ErrorKind::NotFound);
+        assert_eq!(err.message(), Some("Failed to load from path 'a.json'"));
+        assert!(err.to_string().contains("inner error: missing"));
+    }
+
+    #[test]
+    fn test_chain_walks_nested_sources() {
+        let err = Error::builder()
+            .source(Outer(std::io::Error::other("disk on fire")))
+            .build();
+
+        let chain: Vec<String> = err.chain().map(|e| e.to_string()).collect();
+
+        assert_eq!(chain, vec!["failed to read config", "disk on fire"]);
+        assert!(Error::new().source().is_none());
+        assert_eq!(Error::new().chain().count(), 0);
+    }
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+/// How label scores are combined into the overall score that is compared
+/// against `EvalConfig::threshold`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Aggregation {
+    /// Highest category score, where each category averages its top-k
+    /// weighted label scores
+    #[default]
+    Max,
+
+    /// Sum of every weighted label score, capped at 1.0
+    Sum,
+
+    /// Weighted label scores divided by the sum of label weights
+    WeightedMean,
+}
@@ -14,6 +14,13 @@ pub struct CategoryConfig {
     #[validate(minimum = 1)]
     pub top_k: usize,
 
+    /// Minimum score gap between the top label and the runner-up; closer
+    /// calls are treated as ambiguous and the category scores 0
+    #[serde(default)]
+    #[validate(minimum = 0.0)]
+    #[validate(maximum = 1.0)]
+    pub min_margin: f32,
+
     /// Labels belonging to this category (keyed by label name)
     pub labels: BTreeMap<String, LabelConfig>,
 }
@@ -28,6 +35,7 @@ impl Default for CategoryConfig {
     fn default() -> Self {
         Self {
             top_k: Self::top_k(),
+            min_margin: 0.0,
             labels: BTreeMap::new(),
         }
     }
@@ -1,13 +1,20 @@
+mod aggregation;
 mod category;
 mod label;
 mod modifier;
+mod preprocess;
+mod shared;
 
+pub use aggregation::*;
 pub use category::*;
 pub use label::*;
 pub use modifier::*;
+pub use preprocess::*;
+pub use shared::*;
 
 use std::collections::BTreeMap;
 
+use loom_cortex::bench::platt::{CalibrationConfig, PlattParams};
 use loom_cortex::config::{CortexModelConfig, CortexZeroShotConfig};
 use serde::{Deserialize, Serialize};
 use serde_valid::Validate;
@@ -35,6 +42,14 @@ pub struct EvalConfig {
     #[validate]
     pub modifiers: ModifierConfig,
 
+    /// How label scores combine into the overall score
+    #[serde(default)]
+    pub aggregation: Aggregation,
+
+    /// Text transforms applied before inference
+    #[serde(default)]
+    pub preprocess: PreprocessConfig,
+
     /// Category definitions with their labels (keyed by category name)
     pub categories: BTreeMap<String, CategoryConfig>,
 }
@@ -89,22 +104,51 @@ impl EvalConfig {
             .unwrap_or_else(|| format!("This example is {}.", label_name))
     }
 
+    /// The Platt params of every label, as a `CalibrationConfig`.
+    pub fn calibration(&self) -> CalibrationConfig {
+        CalibrationConfig::new(
+            self.labels()
+                .into_iter()
+                .map(|(name, l)| {
+                    let params = PlattParams {
+                        a: l.platt_a,
+                        b: l.platt_b,
+                    };
+                    (name, params)
+                })
+                .collect(),
+        )
+    }
+
+    /// Set `platt_a`/`platt_b` of every label with params in `calibration`.
+    /// Labels without params keep their current ones.
+    pub fn apply_calibration(&mut self, calibration: &CalibrationConfig) {
+        for category in self.categories.values_mut() {
+            for (name, label) in category.labels.iter_mut() {
+                if let Some(params) = calibration.get(name) {
+                    label.platt_a = params.a;
+                    label.platt_b = params.b;
+                }
+            }
+        }
+    }
+
     /// Validate the full config (including nested BTreeMap items).
     pub fn validate_full(&self) -> loom_error::Result<()> {
         self.validate()
-            .map_err(|e| loom_error::Error::builder().message(&e.to_string()).build())?;
+            .map_err(|e| loom_error::Error::builder().message(e.to_string()).build())?;
 
         for (cat_name, cat_config) in &self.categories {
             cat_config.validate().map_err(|e| {
                 loom_error::Error::builder()
-                    .message(&format!("Category '{}': {}", cat_name, e))
+                    .message(format!("Category '{}': {}", cat_name, e))
                     .build()
             })?;
 
             for (label_name, label_config) in &cat_config.labels {
                 label_config.validate().map_err(|e| {
                     loom_error::Error::builder()
-                        .message(&format!(
+                        .message(format!(
                             "Category '{}', Label '{}': {}",
                             cat_name, label_name, e
                         ))
@@ -130,6 +174,8 @@ impl Default for EvalConfig {
             threshold: Self::threshold(),
             top_k: Self::top_k(),
             modifiers: ModifierConfig::default(),
+            aggregation: Aggregation::default(),
+            preprocess: PreprocessConfig::default(),
             categories: BTreeMap::new(),
         }
     }
@@ -163,13 +209,22 @@ mod tests {
         );
 
         let mut categories = BTreeMap::new();
-        categories.insert("test".to_string(), CategoryConfig { top_k: 2, labels });
+        categories.insert(
+            "test".to_string(),
+            CategoryConfig {
+                top_k: 2,
+                min_margin: 0.0,
+                labels,
+            },
+        );
 
         EvalConfig {
             model: CortexModelConfig::default(),
             threshold: 0.75,
             top_k: 2,
             modifiers: ModifierConfig::default(),
+            aggregation: Aggregation::default(),
+            preprocess: PreprocessConfig::default(),
             categories,
         }
     }
@@ -219,6 +274,27 @@ mod tests {
         assert_eq!(category.unwrap().labels.len(), 2);
     }
 
+    #[test]
+    fn apply_calibration_changes_label_scores() {
+        let mut config = test_config();
+        let before = crate::LabelOutput::new(0.6, 0, config.label("label1").unwrap());
+
+        let calibration = CalibrationConfig::from_json(
+            r#"{"params": {"label1": {"a": 4.0, "b": -1.0}, "unknown": {"a": 2.0, "b": 0.0}}}"#,
+        )
+        .unwrap();
+        config.apply_calibration(&calibration);
+
+        let label = config.label("label1").unwrap();
+        assert_eq!((label.platt_a, label.platt_b), (4.0, -1.0));
+        assert_eq!(config.label("label2").unwrap().platt_a, 1.0);
+        assert_eq!(config.calibration().get("label1").unwrap().a, 4.0);
+
+        let after = crate::LabelOutput::new(0.6, 0, label);
+        assert_eq!(before.score, 0.0);
+        assert!((after.score - PlattParams { a: 4.0, b: -1.0 }.apply(0.6) * 0.5).abs() < 1e-6);
+    }
+
     #[test]
     fn invalid_threshold_fails_validation() {
         let mut config = test_config();
@@ -228,9 +304,11 @@ mod tests {
 
     #[test]
     fn invalid_weight_fails_validation() {
-        let mut label = LabelConfig::default();
-        label.hypothesis = "Test".to_string();
-        label.weight = -0.5;
+        let label = LabelConfig {
+            hypothesis: "Test".to_string(),
+            weight: -0.5,
+            ..Default::default()
+        };
         assert!(label.validate().is_err());
     }
 
@@ -261,6 +339,8 @@ mod tests {
 
         assert_eq!(config.threshold, 0.75);
         assert_eq!(config.top_k, 2);
+        assert_eq!(config.categories["test"].min_margin, 0.0);
+        assert_eq!(config.aggregation, Aggregation::Max);
         assert_eq!(config.modifiers.short_text_delta, 0.05);
         assert_eq!(config.modifiers.long_text_delta, 0.05);
         assert!(config.model.is_conversation());
@@ -0,0 +1,98 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::preprocess::{Lowercase, NormalizeWhitespace, StripUrls, TextPreprocessor, Truncate};
+
+/// Transforms applied to sample text before inference. All are off by
+/// default, so the model sees the raw text.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PreprocessConfig {
+    /// Lowercase the text
+    #[serde(default)]
+    pub lowercase: bool,
+
+    /// Remove `http(s)://` and `www.` links
+    #[serde(default)]
+    pub strip_urls: bool,
+
+    /// Trim and collapse whitespace runs into single spaces
+    #[serde(default)]
+    pub normalize_whitespace: bool,
+
+    /// Keep at most this many tokens (see `Truncate`), so long texts are
+    /// cut at a known point instead of by the model
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<usize>,
+}
+
+impl PreprocessConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.lowercase || self.strip_urls || self.normalize_whitespace || self.max_tokens.is_some()
+    }
+
+    /// The enabled transforms in the order they run: URLs are stripped
+    /// first, truncation runs last so the budget counts the final text.
+    pub fn preprocessors(&self) -> Vec<Box<dyn TextPreprocessor>> {
+        let mut preprocessors: Vec<Box<dyn TextPreprocessor>> = Vec::new();
+
+        if self.strip_urls {
+            preprocessors.push(Box::new(StripUrls));
+        }
+
+        if self.lowercase {
+            preprocessors.push(Box::new(Lowercase));
+        }
+
+        if self.normalize_whitespace {
+            preprocessors.push(Box::new(NormalizeWhitespace));
+        }
+
+        if let Some(max_tokens) = self.max_tokens {
+            preprocessors.push(Box::new(Truncate::new(max_tokens)));
+        }
+
+        preprocessors
+    }
+
+    /// Run every enabled transform over `text`.
+    pub fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if !self.is_enabled() {
+            return Cow::Borrowed(text);
+        }
+
+        let text = self
+            .preprocessors()
+            .iter()
+            .fold(text.to_string(), |text, p| p.process(&text));
+
+        Cow::Owned(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_config_borrows_text() {
+        let config = PreprocessConfig::default();
+        assert!(matches!(
+            config.apply("Some TEXT"),
+            Cow::Borrowed("Some TEXT")
+        ));
+    }
+
+    #[test]
+    fn apply_runs_transforms_in_order() {
+        let config: PreprocessConfig = serde_json::from_str(
+            r#"{"lowercase": true, "strip_urls": true, "normalize_whitespace": true, "max_tokens": 3}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.apply("  Read  https://example.com THIS,\n then that "),
+            "read this,"
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::{Arc, RwLock};
+
+use super::EvalConfig;
+
+/// A reloadable `EvalConfig` shared between an `EvalLayer` and whoever
+/// pushes config changes to it.
+///
+/// Readers take a snapshot with `load`, so a sample that is already being
+/// scored keeps the config it started with and the next one sees the update.
+/// Clones share the same config.
+#[derive(Debug, Clone)]
+pub struct SharedEvalConfig(Arc<RwLock<Arc<EvalConfig>>>);
+
+impl SharedEvalConfig {
+    pub fn new(config: EvalConfig) -> Self {
+        Self(Arc::new(RwLock::new(Arc::new(config))))
+    }
+
+    /// The current config.
+    pub fn load(&self) -> Arc<EvalConfig> {
+        self.0.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Replace the config after checking it with `validate_full`. An invalid
+    /// config is rejected and the current one stays in place.
+    pub fn store(&self, config: EvalConfig) -> loom_error::Result<()> {
+        config.validate_full()?;
+        *self.0.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(config);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Decision, EvalOutput};
+
+    #[test]
+    fn store_changes_decision_for_next_sample() {
+        let shared = SharedEvalConfig::new(EvalConfig::default());
+        let output = EvalOutput {
+            score: 0.72,
+            ..Default::default()
+        };
+        let text_len = 100;
+
+        let before = shared.load();
+        assert_eq!(
+            output.decide(before.threshold_of(text_len)),
+            Decision::Reject
+        );
+
+        shared
+            .store(EvalConfig {
+                threshold: 0.70,
+                ..EvalConfig::default()
+            })
+            .unwrap();
+
+        let after = shared.load();
+        assert_eq!(
+            output.decide(after.threshold_of(text_len)),
+            Decision::Accept
+        );
+        assert_eq!(before.threshold, 0.75, "earlier snapshots are unchanged");
+    }
+
+    #[test]
+    fn store_rejects_invalid_config() {
+        let shared = SharedEvalConfig::new(EvalConfig::default());
+        let handle = shared.clone();
+
+        let result = handle.store(EvalConfig {
+            threshold: 1.5,
+            ..EvalConfig::default()
+        });
+
+        assert!(result.is_err());
+        assert_eq!(shared.load().threshold, 0.75);
+    }
+}
@@ -0,0 +1,194 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Limits a label's sample count must stay within, used by `CoverageReport`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CoverageThresholds {
+    /// Labels expected by fewer samples than this are undertrained
+    #[serde(default = "CoverageThresholds::min_samples")]
+    pub min_samples: usize,
+
+    /// Labels expected by more than this share (0.0 - 1.0) of the samples
+    /// dominate the dataset
+    #[serde(default = "CoverageThresholds::max_share")]
+    pub max_share: f32,
+}
+
+impl CoverageThresholds {
+    fn min_samples() -> usize {
+        10
+    }
+
+    fn max_share() -> f32 {
+        0.5
+    }
+}
+
+impl Default for CoverageThresholds {
+    fn default() -> Self {
+        Self {
+            min_samples: Self::min_samples(),
+            max_share: Self::max_share(),
+        }
+    }
+}
+
+/// How many samples expect a label and whether that's too few or too many.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LabelCoverage {
+    pub samples: usize,
+    /// Fraction of all samples expecting this label
+    pub share: f32,
+    /// Fewer than `min_samples` samples expect this label
+    pub undertrained: bool,
+    /// More than `max_share` of the samples expect this label
+    pub dominant: bool,
+}
+
+impl LabelCoverage {
+    pub fn is_flagged(&self) -> bool {
+        self.undertrained || self.dominant
+    }
+}
+
+/// Per-label sample counts for a dataset, with under- and over-represented
+/// labels flagged.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CoverageReport {
+    pub total: usize,
+    pub per_label: BTreeMap<String, LabelCoverage>,
+}
+
+impl CoverageReport {
+    /// Build a report from per-label sample counts out of `total` samples.
+    pub fn from_counts<L: Into<String>>(
+        counts: impl IntoIterator<Item = (L, usize)>,
+        total: usize,
+        thresholds: &CoverageThresholds,
+    ) -> Self {
+        let per_label = counts
+            .into_iter()
+            .map(|(label, samples)| {
+                let share = match total {
+                    0 => 0.0,
+                    total => samples as f32 / total as f32,
+                };
+
+                let coverage = LabelCoverage {
+                    samples,
+                    share,
+                    undertrained: samples < thresholds.min_samples,
+                    dominant: share > thresholds.max_share,
+                };
+
+                (label.into(), coverage)
+            })
+            .collect();
+
+        Self { total, per_label }
+    }
+
+    /// Labels that are undertrained or dominant, by name.
+    pub fn flagged(&self) -> impl Iterator<Item = (&str, &LabelCoverage)> {
+        self.per_label
+            .iter()
+            .filter(|(_, coverage)| coverage.is_flagged())
+            .map(|(label, coverage)| (label.as_str(), coverage))
+    }
+
+    pub fn has_flagged(&self) -> bool {
+        self.flagged().next().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Decision, Difficulty, EvalResult, Sample, SampleDataset, SampleResult};
+
+    fn sample(id: &str, labels: &[&str]) -> Sample {
+        Sample {
+            id: id.to_string(),
+            text: "Hello".to_string(),
+            context: None,
+            expected_decision: Decision::Accept,
+            expected_labels: labels.iter().map(|l| l.to_string()).collect(),
+            primary_category: "emotional".to_string(),
+            difficulty: Difficulty::Easy,
+            notes: None,
+            metadata: None,
+        }
+    }
+
+    fn dataset() -> SampleDataset {
+        let mut dataset = SampleDataset::new();
+
+        for i in 0..6 {
+            dataset
+                .samples
+                .push(sample(&format!("pos-{}", i), &["positive"]));
+        }
+
+        for i in 0..3 {
+            dataset
+                .samples
+                .push(sample(&format!("neg-{}", i), &["negative"]));
+        }
+
+        dataset.samples.push(sample("rare", &["negative", "grief"]));
+        dataset
+    }
+
+    #[test]
+    fn coverage_flags_rare_and_dominant_labels() {
+        let thresholds = CoverageThresholds {
+            min_samples: 2,
+            max_share: 0.5,
+        };
+
+        let report = dataset().coverage(&thresholds);
+
+        assert_eq!(report.total, 10);
+        assert_eq!(report.per_label["positive"].samples, 6);
+        assert_eq!(report.per_label["positive"].share, 0.6);
+        assert_eq!(report.per_label["negative"].share, 0.4);
+        assert_eq!(report.per_label["grief"].share, 0.1);
+
+        let flagged: Vec<&str> = report.flagged().map(|(label, _)| label).collect();
+        assert_eq!(flagged, vec!["grief", "positive"]);
+        assert!(report.per_label["grief"].undertrained);
+        assert!(!report.per_label["grief"].dominant);
+        assert!(report.per_label["positive"].dominant);
+        assert!(!report.per_label["negative"].is_flagged());
+    }
+
+    #[test]
+    fn coverage_from_eval_result_matches_dataset() {
+        let dataset = dataset();
+        let mut result = EvalResult::new();
+
+        for sample in &dataset.samples {
+            let sample_result = SampleResult {
+                id: sample.id.clone(),
+                category: sample.primary_category.clone(),
+                expected_decision: Decision::Accept,
+                actual_decision: Decision::Accept,
+                correct: true,
+                score: 1.0,
+                expected_labels: sample.expected_labels.clone(),
+                detected_labels: vec![],
+                elapsed_ms: None,
+                contributions: Vec::new(),
+            };
+            result.total += 1;
+            result.accumulate(sample, &sample_result);
+        }
+
+        let thresholds = CoverageThresholds::default();
+        assert_eq!(
+            result.coverage(&thresholds).per_label,
+            dataset.coverage(&thresholds).per_label
+        );
+    }
+}
@@ -1,8 +1,11 @@
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use serde::{Deserialize, Serialize};
 
-use super::{Sample, ValidationError};
+use super::{
+    CoverageReport, CoverageThresholds, EvalConfig, Sample, ValidationError, ValidationIssue,
+    ValidationKind, ValidationPath,
+};
 
 /// A benchmark dataset containing samples for evaluation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,19 +25,100 @@ impl SampleDataset {
         }
     }
 
+    /// A dataset with the samples matching `predicate`, in order.
+    pub fn filter(&self, mut predicate: impl FnMut(&Sample) -> bool) -> SampleDataset {
+        SampleDataset {
+            version: self.version.clone(),
+            created: self.created.clone(),
+            samples: self
+                .samples
+                .iter()
+                .filter(|s| predicate(s))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// A stratified random subset with about `fraction` (0.0 - 1.0) of the
+    /// samples of each `primary_category`, in dataset order.
+    ///
+    /// Each category keeps `round(count * fraction)` samples, but at least
+    /// one while `fraction` is above 0. Which samples are kept depends only
+    /// on `seed` and the sample ids, so reruns with the same seed compare
+    /// like for like.
+    pub fn sample(&self, fraction: f64, seed: u64) -> SampleDataset {
+        let fraction = fraction.clamp(0.0, 1.0);
+        let mut strata: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+
+        for (i, sample) in self.samples.iter().enumerate() {
+            strata
+                .entry(sample.primary_category.as_str())
+                .or_default()
+                .push(i);
+        }
+
+        let mut keep = HashSet::new();
+        for mut indices in strata.into_values() {
+            let count = if fraction > 0.0 {
+                ((indices.len() as f64 * fraction).round() as usize).max(1)
+            } else {
+                0
+            };
+
+            indices.sort_by_cached_key(|&i| (sample_key(seed, &self.samples[i].id), i));
+            keep.extend(indices.into_iter().take(count));
+        }
+
+        SampleDataset {
+            version: self.version.clone(),
+            created: self.created.clone(),
+            samples: (0..self.samples.len())
+                .filter(|i| keep.contains(i))
+                .map(|i| self.samples[i].clone())
+                .collect(),
+        }
+    }
+
+    /// How many samples expect each label, flagging labels outside
+    /// `thresholds`.
+    pub fn coverage(&self, thresholds: &CoverageThresholds) -> CoverageReport {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+
+        for sample in &self.samples {
+            for label in &sample.expected_labels {
+                *counts.entry(label.as_str()).or_default() += 1;
+            }
+        }
+
+        CoverageReport::from_counts(counts, self.samples.len(), thresholds)
+    }
+
     /// Validate the dataset without label validation.
-    pub fn validate(&self) -> Vec<ValidationError> {
+    pub fn validate(&self) -> Result<(), ValidationError> {
         self.validate_with_labels(None)
     }
 
     /// Validate the dataset with optional label validation.
-    pub fn validate_with_labels(&self, valid_labels: Option<&[String]>) -> Vec<ValidationError> {
+    pub fn validate_with_labels(
+        &self,
+        valid_labels: Option<&[String]>,
+    ) -> Result<(), ValidationError> {
         self.validate_with_config(None, valid_labels)
     }
 
+    /// Validate the dataset against an eval config: every `primary_category`
+    /// must be a configured category and every expected label must belong to
+    /// one, on top of the checks in `validate_with_config`.
+    pub fn validate_against(&self, config: &EvalConfig) -> Result<(), ValidationError> {
+        let categories: Vec<String> = config.categories.keys().cloned().collect();
+        let labels: Vec<String> = config.labels().into_iter().map(|(name, _)| name).collect();
+
+        self.validate_with_config(Some(&categories), Some(&labels))
+    }
+
     /// Validate the dataset with optional category and label validation.
     ///
-    /// This is the most comprehensive validation method that checks:
+    /// Every issue is reported in the error, not just the first. Checks:
     /// - Duplicate sample IDs
     /// - Empty text
     /// - Missing expected labels
@@ -44,8 +128,8 @@ impl SampleDataset {
         &self,
         valid_categories: Option<&[String]>,
         valid_labels: Option<&[String]>,
-    ) -> Vec<ValidationError> {
-        let mut errors = Vec::new();
+    ) -> Result<(), ValidationError> {
+        let mut issues = Vec::new();
         let mut seen_ids = HashSet::new();
 
         let valid_category_set: Option<HashSet<&String>> =
@@ -55,51 +139,73 @@ impl SampleDataset {
 
         for sample in &self.samples {
             if !seen_ids.insert(&sample.id) {
-                errors.push(ValidationError {
-                    sample_id: sample.id.clone(),
-                    message: "Duplicate sample ID".to_string(),
-                });
+                issues.push(ValidationIssue::new(
+                    ValidationKind::DuplicateId,
+                    ValidationPath::sample(&sample.id),
+                    "Duplicate sample ID",
+                ));
             }
 
             if sample.text.trim().is_empty() {
-                errors.push(ValidationError {
-                    sample_id: sample.id.clone(),
-                    message: "Empty text".to_string(),
-                });
+                issues.push(ValidationIssue::new(
+                    ValidationKind::EmptyText,
+                    ValidationPath::sample(&sample.id),
+                    "Empty text",
+                ));
             }
 
             if sample.expected_labels.is_empty() {
-                errors.push(ValidationError {
-                    sample_id: sample.id.clone(),
-                    message: "No expected labels".to_string(),
-                });
+                issues.push(ValidationIssue::new(
+                    ValidationKind::MissingLabels,
+                    ValidationPath::sample(&sample.id),
+                    "No expected labels",
+                ));
             }
 
             // Validate category against config
-            if let Some(ref valid) = valid_category_set {
-                if !valid.contains(&sample.primary_category) {
-                    errors.push(ValidationError {
-                        sample_id: sample.id.clone(),
-                        message: format!("Invalid category: '{}'", sample.primary_category),
-                    });
-                }
+            if let Some(ref valid) = valid_category_set
+                && !valid.contains(&sample.primary_category)
+            {
+                issues.push(ValidationIssue::new(
+                    ValidationKind::InvalidCategory,
+                    ValidationPath::sample(&sample.id).with_category(&sample.primary_category),
+                    format!("Invalid category: '{}'", sample.primary_category),
+                ));
             }
 
             // Validate labels against config
             if let Some(ref valid) = valid_label_set {
                 for label in &sample.expected_labels {
                     if !valid.contains(label) {
-                        errors.push(ValidationError {
-                            sample_id: sample.id.clone(),
-                            message: format!("Invalid label: '{}'", label),
-                        });
+                        issues.push(ValidationIssue::new(
+                            ValidationKind::InvalidLabel,
+                            ValidationPath::sample(&sample.id).with_label(label),
+                            format!("Invalid label: '{}' is not in any category", label),
+                        ));
                     }
                 }
             }
         }
 
-        errors
+        match issues.is_empty() {
+            true => Ok(()),
+            false => Err(ValidationError::new(issues)),
+        }
+    }
+}
+
+/// A stable pseudo-random sort key for a sample id (FNV-1a folded into a
+/// SplitMix64 finalizer), independent of the Rust version and platform.
+fn sample_key(seed: u64, id: &str) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64 ^ seed;
+    for byte in id.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
     }
+
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^ (hash >> 31)
 }
 
 impl Default for SampleDataset {
@@ -147,9 +253,9 @@ mod tests {
             metadata: None,
         });
 
-        let errors = dataset.validate();
-        assert_eq!(errors.len(), 1);
-        assert!(errors[0].message.contains("Duplicate"));
+        let err = dataset.validate().unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert!(err.issues[0].message.contains("Duplicate"));
     }
 
     #[test]
@@ -167,8 +273,8 @@ mod tests {
             metadata: None,
         });
 
-        let errors = dataset.validate();
-        assert!(errors.iter().any(|e| e.message.contains("Empty text")));
+        let err = dataset.validate().unwrap_err();
+        assert!(err.issues.iter().any(|e| e.message.contains("Empty text")));
     }
 
     #[test]
@@ -187,8 +293,14 @@ mod tests {
         });
 
         let valid_labels = vec!["positive".to_string(), "negative".to_string()];
-        let errors = dataset.validate_with_labels(Some(&valid_labels));
-        assert!(errors.iter().any(|e| e.message.contains("Invalid label")));
+        let err = dataset
+            .validate_with_labels(Some(&valid_labels))
+            .unwrap_err();
+        assert!(
+            err.issues
+                .iter()
+                .any(|e| e.message.contains("Invalid label"))
+        );
     }
 
     #[test]
@@ -207,11 +319,197 @@ mod tests {
         });
 
         let valid_categories = vec!["sentiment".to_string(), "emotion".to_string()];
-        let errors = dataset.validate_with_config(Some(&valid_categories), None);
+        let err = dataset
+            .validate_with_config(Some(&valid_categories), None)
+            .unwrap_err();
         assert!(
-            errors
+            err.issues
                 .iter()
                 .any(|e| e.message.contains("Invalid category"))
         );
+        assert_eq!(
+            err.issues[0].path.category.as_deref(),
+            Some("unknown_category")
+        );
+    }
+
+    #[test]
+    fn dataset_infers_missing_difficulty() {
+        let json = r#"{
+            "version": "1.0.0",
+            "created": "2026-01-01",
+            "samples": [
+                {
+                    "id": "explicit",
+                    "text": "Hi",
+                    "expected_decision": "accept",
+                    "expected_labels": ["positive"],
+                    "primary_category": "emotional",
+                    "difficulty": "easy"
+                },
+                {
+                    "id": "inferred",
+                    "text": "Hi",
+                    "expected_decision": "accept",
+                    "expected_labels": ["positive", "joy", "gratitude"],
+                    "primary_category": "emotional"
+                }
+            ]
+        }"#;
+
+        let dataset: SampleDataset = serde_json::from_str(json).unwrap();
+
+        assert_eq!(dataset.samples[0].difficulty, Difficulty::Easy);
+        assert_eq!(dataset.samples[1].difficulty, Difficulty::Hard);
+    }
+
+    #[test]
+    fn dataset_validate_against_reports_every_issue() {
+        let sample = |id: &str, text: &str, label: &str| Sample {
+            id: id.to_string(),
+            text: text.to_string(),
+            context: None,
+            expected_decision: Decision::Accept,
+            expected_labels: vec![label.to_string()],
+            primary_category: "emotional".to_string(),
+            difficulty: Difficulty::Easy,
+            notes: None,
+            metadata: None,
+        };
+
+        let mut dataset = SampleDataset::new();
+        dataset
+            .samples
+            .push(sample("test-001", "Hello", "positive"));
+        dataset
+            .samples
+            .push(sample("test-001", "Hello again", "positive"));
+        dataset
+            .samples
+            .push(sample("test-002", "\t \n", "positive"));
+        dataset
+            .samples
+            .push(sample("test-003", "Hello", "NotARealLabel"));
+
+        let mut emotional = crate::CategoryConfig::default();
+        emotional
+            .labels
+            .insert("positive".to_string(), crate::LabelConfig::default());
+        let mut config = EvalConfig::default();
+        config.categories.insert("emotional".to_string(), emotional);
+
+        let err = dataset.validate_against(&config).unwrap_err();
+        let messages: Vec<String> = err.issues.iter().map(|e| e.to_string()).collect();
+
+        assert_eq!(err.len(), 3, "{}", err);
+        assert!(err.to_string().starts_with("3 validation issue(s):"));
+        assert!(messages.contains(&"[test-001] Duplicate sample ID".to_string()));
+        assert!(messages.contains(&"[test-002] Empty text".to_string()));
+        assert!(messages[2].starts_with("[test-003] Invalid label: 'NotARealLabel'"));
+
+        let kinds: Vec<ValidationKind> = err.issues.iter().map(|e| e.code).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ValidationKind::DuplicateId,
+                ValidationKind::EmptyText,
+                ValidationKind::InvalidLabel
+            ]
+        );
+        assert!(kinds[0].is_fatal() && kinds[1].is_fatal() && !kinds[2].is_fatal());
+        assert_eq!(err.issues[2].path.label.as_deref(), Some("NotARealLabel"));
+
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json["issues"][0]["code"], "duplicate_id");
+        assert_eq!(json["issues"][0]["path"]["sample_id"], "test-001");
+        assert_eq!(json["issues"][2]["path"]["label"], "NotARealLabel");
+        assert!(json["issues"][0]["path"].get("label").is_none());
+    }
+
+    fn stratified_dataset() -> SampleDataset {
+        let mut dataset = SampleDataset::new();
+
+        for (category, count) in [("emotional", 100), ("factual", 200), ("task", 50)] {
+            for i in 0..count {
+                dataset.samples.push(Sample {
+                    id: format!("{}-{:03}", category, i),
+                    text: format!("Sample {} about {}", i, category),
+                    context: None,
+                    expected_decision: Decision::Accept,
+                    expected_labels: vec!["positive".to_string()],
+                    primary_category: category.to_string(),
+                    difficulty: Difficulty::Easy,
+                    notes: None,
+                    metadata: None,
+                });
+            }
+        }
+
+        dataset
+    }
+
+    fn ids(dataset: &SampleDataset) -> Vec<&str> {
+        dataset.samples.iter().map(|s| s.id.as_str()).collect()
+    }
+
+    #[test]
+    fn sample_preserves_category_proportions() {
+        let dataset = stratified_dataset();
+        let subset = dataset.sample(0.1, 7);
+
+        assert_eq!(subset.samples.len(), 35);
+
+        for category in ["emotional", "factual", "task"] {
+            let share = |d: &SampleDataset| {
+                d.samples
+                    .iter()
+                    .filter(|s| s.primary_category == category)
+                    .count() as f64
+                    / d.samples.len() as f64
+            };
+
+            assert!(
+                (share(&subset) - share(&dataset)).abs() < 0.02,
+                "{} share drifted: {} vs {}",
+                category,
+                share(&subset),
+                share(&dataset)
+            );
+        }
+    }
+
+    #[test]
+    fn sample_is_deterministic_per_seed() {
+        let dataset = stratified_dataset();
+
+        assert_eq!(ids(&dataset.sample(0.1, 7)), ids(&dataset.sample(0.1, 7)));
+        assert_ne!(ids(&dataset.sample(0.1, 7)), ids(&dataset.sample(0.1, 8)));
+    }
+
+    #[test]
+    fn sample_keeps_dataset_order_and_small_categories() {
+        let dataset = stratified_dataset();
+        let subset = dataset.sample(0.01, 1);
+
+        let positions: Vec<usize> = subset
+            .samples
+            .iter()
+            .map(|s| dataset.samples.iter().position(|d| d.id == s.id).unwrap())
+            .collect();
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+        assert!(subset.samples.iter().any(|s| s.primary_category == "task"));
+
+        assert!(dataset.sample(0.0, 1).samples.is_empty());
+        assert_eq!(dataset.sample(1.0, 1).samples.len(), dataset.samples.len());
+    }
+
+    #[test]
+    fn filter_keeps_matching_samples() {
+        let dataset = stratified_dataset();
+        let task = dataset.filter(|s| s.primary_category == "task");
+
+        assert_eq!(task.samples.len(), 50);
+        assert_eq!(task.version, dataset.version);
+        assert!(task.samples.iter().all(|s| s.primary_category == "task"));
     }
 }
@@ -8,3 +8,169 @@ pub enum Difficulty {
     Medium,
     Hard,
 }
+
+impl Difficulty {
+    /// Infer a difficulty for a sample that doesn't declare one, using the
+    /// default `DifficultyThresholds`.
+    pub fn infer(text: &str, expected_labels: &[String]) -> Self {
+        Self::infer_with(text, expected_labels, &DifficultyThresholds::default())
+    }
+
+    /// Infer a difficulty from the text length and label count.
+    ///
+    /// Very short or very long text adds one point, several expected labels
+    /// add one and many add two. No points is `Easy`, one is `Medium` and
+    /// two or more is `Hard`.
+    pub fn infer_with(
+        text: &str,
+        expected_labels: &[String],
+        thresholds: &DifficultyThresholds,
+    ) -> Self {
+        let len = text.chars().count();
+        let mut points = 0;
+
+        if len <= thresholds.short_text_limit || len > thresholds.long_text_limit {
+            points += 1;
+        }
+
+        if expected_labels.len() >= thresholds.many_labels {
+            points += 2;
+        } else if expected_labels.len() >= thresholds.several_labels {
+            points += 1;
+        }
+
+        match points {
+            0 => Self::Easy,
+            1 => Self::Medium,
+            _ => Self::Hard,
+        }
+    }
+}
+
+/// Boundaries used by `Difficulty::infer_with`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DifficultyThresholds {
+    /// Text of at most this many characters counts as very short
+    #[serde(default = "DifficultyThresholds::short_text_limit")]
+    pub short_text_limit: usize,
+
+    /// Text of more than this many characters counts as very long
+    #[serde(default = "DifficultyThresholds::long_text_limit")]
+    pub long_text_limit: usize,
+
+    /// At least this many expected labels makes a sample harder
+    #[serde(default = "DifficultyThresholds::several_labels")]
+    pub several_labels: usize,
+
+    /// At least this many expected labels makes a sample much harder
+    #[serde(default = "DifficultyThresholds::many_labels")]
+    pub many_labels: usize,
+}
+
+impl DifficultyThresholds {
+    fn short_text_limit() -> usize {
+        20
+    }
+
+    fn long_text_limit() -> usize {
+        200
+    }
+
+    fn several_labels() -> usize {
+        2
+    }
+
+    fn many_labels() -> usize {
+        3
+    }
+}
+
+impl Default for DifficultyThresholds {
+    fn default() -> Self {
+        Self {
+            short_text_limit: Self::short_text_limit(),
+            long_text_limit: Self::long_text_limit(),
+            several_labels: Self::several_labels(),
+            many_labels: Self::many_labels(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(n: usize) -> Vec<String> {
+        (0..n).map(|i| format!("label{}", i)).collect()
+    }
+
+    fn text(len: usize) -> String {
+        "a".repeat(len)
+    }
+
+    #[test]
+    fn text_length_boundaries() {
+        assert_eq!(Difficulty::infer(&text(20), &labels(1)), Difficulty::Medium);
+        assert_eq!(Difficulty::infer(&text(21), &labels(1)), Difficulty::Easy);
+        assert_eq!(Difficulty::infer(&text(200), &labels(1)), Difficulty::Easy);
+        assert_eq!(
+            Difficulty::infer(&text(201), &labels(1)),
+            Difficulty::Medium
+        );
+    }
+
+    #[test]
+    fn label_count_boundaries() {
+        assert_eq!(Difficulty::infer(&text(50), &labels(1)), Difficulty::Easy);
+        assert_eq!(Difficulty::infer(&text(50), &labels(2)), Difficulty::Medium);
+        assert_eq!(Difficulty::infer(&text(50), &labels(3)), Difficulty::Hard);
+    }
+
+    #[test]
+    fn length_and_labels_combine() {
+        assert_eq!(Difficulty::infer(&text(5), &labels(2)), Difficulty::Hard);
+        assert_eq!(Difficulty::infer(&text(500), &labels(2)), Difficulty::Hard);
+    }
+
+    #[test]
+    fn length_counts_characters() {
+        assert_eq!(
+            Difficulty::infer(&"é".repeat(21), &labels(1)),
+            Difficulty::Easy
+        );
+    }
+
+    #[test]
+    fn custom_thresholds() {
+        let thresholds = DifficultyThresholds {
+            short_text_limit: 5,
+            long_text_limit: 1000,
+            several_labels: 4,
+            many_labels: 6,
+        };
+
+        assert_eq!(
+            Difficulty::infer_with(&text(10), &labels(3), &thresholds),
+            Difficulty::Easy
+        );
+        assert_eq!(
+            Difficulty::infer_with(&text(500), &labels(4), &thresholds),
+            Difficulty::Medium
+        );
+        assert_eq!(
+            Difficulty::infer_with(&text(5), &labels(6), &thresholds),
+            Difficulty::Hard
+        );
+    }
+
+    #[test]
+    fn thresholds_deserialize_with_defaults() {
+        let thresholds: DifficultyThresholds =
+            serde_json::from_str(r#"{"many_labels": 5}"#).unwrap();
+
+        assert_eq!(thresholds.short_text_limit, 20);
+        assert_eq!(thresholds.long_text_limit, 200);
+        assert_eq!(thresholds.several_labels, 2);
+        assert_eq!(thresholds.many_labels, 5);
+    }
+}
@@ -2,17 +2,19 @@ use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex};
 
 use loom_config::Config;
-use loom_core::{Map, ident_path, value::Value};
+use loom_core::{ident_path, value::Value};
 use loom_cortex::CortexModel;
+use loom_cortex::bench::platt::CalibrationConfig;
 use loom_error::{Error, ErrorCode};
 use loom_runtime::RunContext;
+use loom_signal::Signal;
 
-use crate::config::EvalConfig;
+use crate::config::{EvalConfig, SharedEvalConfig};
 use crate::output::{CategoryOutput, EvalOutput, LabelOutput};
 
 pub struct EvalLayer {
     model: Arc<Mutex<CortexModel>>,
-    config: EvalConfig,
+    config: SharedEvalConfig,
 }
 
 impl EvalLayer {
@@ -26,7 +28,7 @@ impl EvalLayer {
         let eval_config: EvalConfig = eval_section.bind().map_err(|e| {
             Error::builder()
                 .code(ErrorCode::BadArguments)
-                .message(&format!("Failed to bind EvalConfig: {}", e))
+                .message(format!("Failed to bind EvalConfig: {}", e))
                 .build()
         })?;
 
@@ -35,23 +37,48 @@ impl EvalLayer {
         let model = eval_config.model.clone().build()?;
         Ok(Self {
             model: Arc::new(Mutex::new(model)),
-            config: eval_config,
+            config: SharedEvalConfig::new(eval_config),
         })
     }
 
-    /// Get the configuration for this layer.
-    pub fn config(&self) -> &EvalConfig {
-        &self.config
+    /// Get a snapshot of the current configuration for this layer.
+    pub fn config(&self) -> Arc<EvalConfig> {
+        self.config.load()
+    }
+
+    /// Get a handle that can push config updates to this layer from
+    /// elsewhere, e.g. a config watcher.
+    pub fn config_handle(&self) -> SharedEvalConfig {
+        self.config.clone()
+    }
+
+    /// Replace the configuration without rebuilding the model; the next
+    /// sample scored uses it. The config is checked with `validate_full`
+    /// first. The `model` section is ignored, since changing the model
+    /// requires a new layer.
+    pub fn update_config(&self, config: EvalConfig) -> loom_error::Result<()> {
+        self.config.store(config)
+    }
+
+    /// Apply the Platt params in the JSON file at `path` (e.g. the
+    /// `platt_params.json` written by `loom train`) to the current config,
+    /// without rebuilding the model. Labels not in the file keep theirs.
+    pub fn load_calibration(&self, path: impl AsRef<std::path::Path>) -> loom_error::Result<()> {
+        let calibration = CalibrationConfig::load(path)?;
+        let mut config = (*self.config.load()).clone();
+        config.apply_calibration(&calibration);
+        self.update_config(config)
     }
 
     /// Get all valid category names from the config.
     pub fn valid_categories(&self) -> Vec<String> {
-        self.config.categories.keys().cloned().collect()
+        self.config.load().categories.keys().cloned().collect()
     }
 
     /// Get all valid label names from the config.
     pub fn valid_labels(&self) -> Vec<String> {
         self.config
+            .load()
             .categories
             .values()
             .flat_map(|c| c.labels.keys().cloned())
@@ -60,6 +87,7 @@ impl EvalLayer {
 
     /// Score a single text and return the eval output.
     pub fn score(&self, text: &str) -> loom_error::Result<EvalOutput> {
+        let config = self.config.load();
         let model = self.model.lock().expect("model lock poisoned");
 
         // Extract the zero-shot model
@@ -74,16 +102,14 @@ impl EvalLayer {
         };
 
         // Get all label names from config
-        let label_names: Vec<&str> = self
-            .config
+        let label_names: Vec<&str> = config
             .categories
             .values()
             .flat_map(|c| c.labels.keys().map(|s| s.as_str()))
             .collect();
 
         // Build a static hypothesis map for the closure
-        let hypothesis_map: HashMap<String, String> = self
-            .config
+        let hypothesis_map: HashMap<String, String> = config
             .categories
             .values()
             .flat_map(|c| {
@@ -101,9 +127,10 @@ impl EvalLayer {
                 .unwrap_or_else(|| format!("This example is {}.", label))
         });
 
-        // Run zero-shot classification
+        // Run zero-shot classification on the preprocessed text
+        let text = config.preprocess.apply(text);
         let predictions =
-            zs_model.predict_multilabel(&[text], &label_names, Some(hypothesis_fn), 128)?;
+            zs_model.predict_multilabel([text.as_ref()], &label_names, Some(hypothesis_fn), 128)?;
 
         // Build a lookup map for predictions by label name
         let mut prediction_map: HashMap<&str, f32> = HashMap::new();
@@ -124,7 +151,7 @@ impl EvalLayer {
         // Build CategoryOutput for each category in config
         let mut categories = BTreeMap::new();
 
-        for (cat_name, cat_config) in &self.config.categories {
+        for (cat_name, cat_config) in &config.categories {
             let mut labels = BTreeMap::new();
 
             for (label_name, label_config) in &cat_config.labels {
@@ -137,11 +164,12 @@ impl EvalLayer {
                 labels.insert(label_name.clone(), label_output);
             }
 
-            let top_k = cat_config.top_k;
-            categories.insert(cat_name.clone(), CategoryOutput::topk(labels, top_k));
+            let category = CategoryOutput::topk(labels, cat_config.top_k)
+                .with_min_margin(cat_config.min_margin);
+            categories.insert(cat_name.clone(), category);
         }
 
-        Ok(EvalOutput::new(categories))
+        Ok(EvalOutput::aggregate(categories, &config))
     }
 }
 
@@ -150,11 +178,22 @@ impl loom_pipe::Layer for EvalLayer {
 
     fn process(&self, ctx: &RunContext) -> loom_error::Result<Value> {
         let text = ctx.input().as_str().unwrap_or_default();
-        let eval_output = self.score(text)?;
+        let mut span = ctx.span("eval.score");
+        span.attr("text_len", text.len() as i64);
+
+        let eval_output = match self.score(text) {
+            Ok(output) => output,
+            Err(e) => {
+                span.fail(e.to_string());
+                return Err(e);
+            }
+        };
 
-        let mut attrs = Map::new();
-        attrs.set("score", Value::from(eval_output.score as f64));
-        ctx.emit("eval.scored", &attrs);
+        Signal::event("eval.scored")
+            .field("score", eval_output.score as f64)
+            .field("labels", eval_output.detected_labels())
+            .field("text_len", text.len() as i64)
+            .emit(ctx.emitter());
 
         Ok(eval_output.into())
     }
@@ -162,6 +201,14 @@ impl loom_pipe::Layer for EvalLayer {
     fn name(&self) -> &'static str {
         "eval"
     }
+
+    fn expects(&self) -> Option<&'static str> {
+        Some("string")
+    }
+
+    fn produces(&self) -> Option<&'static str> {
+        Some("object")
+    }
 }
 
 #[cfg(test)]
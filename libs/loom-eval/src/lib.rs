@@ -1,25 +1,31 @@
 pub mod config;
+mod coverage;
 mod dataset;
 mod difficulty;
 mod layer;
 mod output;
+pub mod preprocess;
 pub mod result;
 mod sample;
 mod validation;
 
 // Config types
-pub use config::{CategoryConfig, EvalConfig, LabelConfig, ModifierConfig};
+pub use config::{
+    Aggregation, CategoryConfig, EvalConfig, LabelConfig, ModifierConfig, PreprocessConfig,
+    SharedEvalConfig,
+};
 
 // Core types
+pub use coverage::{CoverageReport, CoverageThresholds, LabelCoverage};
 pub use dataset::SampleDataset;
-pub use difficulty::Difficulty;
+pub use difficulty::{Difficulty, DifficultyThresholds};
 pub use layer::EvalLayer;
-pub use output::{CategoryOutput, EvalOutput, LabelOutput};
+pub use output::{CategoryOutput, EvalOutput, LabelContribution, LabelOutput};
 pub use sample::{Decision, Sample};
-pub use validation::ValidationError;
+pub use validation::{ValidationError, ValidationIssue, ValidationKind, ValidationPath};
 
 // Result types
 pub use result::{
-    CategoryMetrics, CategoryResult, EvalMetrics, EvalResult, LabelMetrics, LabelResult,
-    SampleResult,
+    CategoryDelta, CategoryMetrics, CategoryResult, Checkpoint, EvalDiff, EvalMetrics, EvalResult,
+    LabelDelta, LabelMetrics, LabelResult, MetricDelta, Progress, ProgressTracker, SampleResult,
 };
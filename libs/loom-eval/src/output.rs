@@ -1,9 +1,10 @@
 use std::collections::BTreeMap;
 
 use loom_core::value::Value;
+use loom_cortex::bench::platt::PlattParams;
 use serde::{Deserialize, Serialize};
 
-use super::config::LabelConfig;
+use super::config::{Aggregation, EvalConfig, LabelConfig};
 use crate::result::{EvalResult, SampleResult};
 use crate::{Decision, Sample};
 
@@ -12,11 +13,7 @@ use crate::{Decision, Sample};
 /// With identity params (a=1.0, b=0.0), returns raw score unchanged.
 #[inline]
 fn calibrate(raw: f32, a: f32, b: f32) -> f32 {
-    // Identity: skip calibration
-    if (a - 1.0).abs() < f32::EPSILON && b.abs() < f32::EPSILON {
-        return raw;
-    }
-    1.0 / (1.0 + (-a * raw - b).exp())
+    PlattParams { a, b }.apply(raw)
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
@@ -25,12 +22,154 @@ pub struct EvalOutput {
     pub score: f32,
     /// Categories keyed by name (mirrors config structure)
     pub categories: BTreeMap<String, CategoryOutput>,
+    /// What each label added to `score` (set by `aggregate`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contributions: Vec<LabelContribution>,
 }
 
 impl EvalOutput {
     pub fn new(categories: BTreeMap<String, CategoryOutput>) -> Self {
         let score = categories.values().map(|c| c.score).fold(0.0f32, f32::max);
-        Self { score, categories }
+        Self {
+            score,
+            categories,
+            contributions: Vec::new(),
+        }
+    }
+
+    /// Build an output whose overall score combines the labels according to
+    /// `config.aggregation`, looking up label weights in `config`.
+    ///
+    /// Labels of a category scored 0 (e.g. rejected by `min_margin`) count
+    /// as 0 under every aggregation.
+    ///
+    /// The output's `contributions` break the score down per label.
+    pub fn aggregate(categories: BTreeMap<String, CategoryOutput>, config: &EvalConfig) -> Self {
+        let mut output = Self::combine(categories, config);
+        output.contributions = output.breakdown(config);
+        output
+    }
+
+    fn combine(categories: BTreeMap<String, CategoryOutput>, config: &EvalConfig) -> Self {
+        let labels = categories.iter().flat_map(|(cat_name, category)| {
+            category.labels.iter().map(move |(name, label)| {
+                let score = if category.score > 0.0 {
+                    label.score
+                } else {
+                    0.0
+                };
+                let weight = config
+                    .category(cat_name)
+                    .and_then(|c| c.labels.get(name))
+                    .map(|l| l.weight)
+                    .unwrap_or_default();
+
+                (score, weight)
+            })
+        });
+
+        let score = match config.aggregation {
+            Aggregation::Max => return Self::new(categories),
+            Aggregation::Sum => labels.map(|(score, _)| score).sum::<f32>().min(1.0),
+            Aggregation::WeightedMean => {
+                let (total, weights) = labels.fold((0.0f32, 0.0f32), |(total, weights), (s, w)| {
+                    (total + s, weights + w)
+                });
+
+                if weights > 0.0 { total / weights } else { 0.0 }
+            }
+        };
+
+        Self {
+            score,
+            categories,
+            contributions: Vec::new(),
+        }
+    }
+
+    /// Split `score` into per-label contributions that sum to it.
+    ///
+    /// Under `Max` only the top-k labels of the best category contribute;
+    /// under `Sum` the weighted label scores are scaled down with the cap;
+    /// under `WeightedMean` each is divided by the total weight.
+    fn breakdown(&self, config: &EvalConfig) -> Vec<LabelContribution> {
+        let total_weight: f32 = self
+            .categories
+            .iter()
+            .flat_map(|(cat_name, category)| {
+                category
+                    .labels
+                    .keys()
+                    .map(move |name| label_config(config, cat_name, name))
+            })
+            .map(|l| l.map(|l| l.weight).unwrap_or_default())
+            .sum();
+
+        let effective = |category: &CategoryOutput, label: &LabelOutput| {
+            if category.score > 0.0 {
+                label.score
+            } else {
+                0.0
+            }
+        };
+
+        let total_score: f32 = self
+            .categories
+            .values()
+            .flat_map(|c| c.labels.values().map(move |l| effective(c, l)))
+            .sum();
+
+        let best = self
+            .categories
+            .iter()
+            .filter(|(_, c)| c.score > 0.0)
+            .max_by(|(_, a), (_, b)| a.score.total_cmp(&b.score))
+            .map(|(name, _)| name.as_str());
+
+        let mut contributions = Vec::new();
+
+        for (cat_name, category) in &self.categories {
+            let top_k = config
+                .category(cat_name)
+                .map(|c| c.top_k)
+                .unwrap_or(category.labels.len());
+            let take = top_k.min(category.labels.len()).max(1);
+            let top: Vec<&str> = category
+                .top_labels(take)
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect();
+
+            for (name, label) in &category.labels {
+                let (platt_a, platt_b, weight) = label_config(config, cat_name, name)
+                    .map(|l| (l.platt_a, l.platt_b, l.weight))
+                    .unwrap_or((1.0, 0.0, 0.0));
+
+                let contribution = match config.aggregation {
+                    _ if self.score == 0.0 => 0.0,
+                    Aggregation::Max if best == Some(cat_name.as_str()) => {
+                        match top.contains(&name.as_str()) {
+                            true => label.score / take as f32,
+                            false => 0.0,
+                        }
+                    }
+                    Aggregation::Max => 0.0,
+                    Aggregation::Sum => effective(category, label) * self.score / total_score,
+                    Aggregation::WeightedMean => effective(category, label) / total_weight,
+                };
+
+                contributions.push(LabelContribution {
+                    category: cat_name.clone(),
+                    label: name.clone(),
+                    raw_score: label.raw_score,
+                    calibrated_score: calibrate(label.raw_score, platt_a, platt_b),
+                    weight,
+                    contribution,
+                });
+            }
+        }
+
+        contributions
     }
 
     pub fn category(&self, name: &str) -> Option<&CategoryOutput> {
@@ -48,7 +187,10 @@ impl EvalOutput {
         self.label(name).map(|l| l.score).unwrap_or_default()
     }
 
-    /// Returns labels whose calibrated score is above their threshold.
+    /// Returns labels whose calibrated score reached their own threshold.
+    ///
+    /// Thresholds are applied per label by `LabelOutput::new`, so this holds
+    /// for outputs that were round-tripped through a `Value` as well.
     pub fn detected_labels(&self) -> Vec<String> {
         self.categories
             .values()
@@ -75,6 +217,7 @@ impl EvalOutput {
 
         let sample_result = SampleResult {
             id: sample.id.clone(),
+            category: sample.primary_category.clone(),
             expected_decision: sample.expected_decision,
             actual_decision,
             correct,
@@ -82,6 +225,7 @@ impl EvalOutput {
             expected_labels: sample.expected_labels.clone(),
             detected_labels: detected_labels.clone(),
             elapsed_ms: None,
+            contributions: self.contributions,
         };
 
         let mut result = EvalResult::new();
@@ -94,8 +238,8 @@ impl EvalOutput {
     /// Returns (label_name, raw_score) pairs for external use.
     pub fn raw_scores(&self) -> Vec<(String, f32)> {
         self.categories
-            .iter()
-            .flat_map(|(_, cat)| {
+            .values()
+            .flat_map(|cat| {
                 cat.labels
                     .iter()
                     .map(|(name, label)| (name.clone(), label.raw_score))
@@ -121,12 +265,36 @@ impl TryFrom<Value> for EvalOutput {
         serde_json::from_value(json).map_err(|e| {
             loom_error::Error::builder()
                 .code(loom_error::ErrorCode::BadArguments)
-                .message(&format!("Failed to deserialize EvalOutput: {}", e))
+                .message(format!("Failed to deserialize EvalOutput: {}", e))
                 .build()
         })
     }
 }
 
+fn label_config<'a>(
+    config: &'a EvalConfig,
+    category: &str,
+    label: &str,
+) -> Option<&'a LabelConfig> {
+    config.category(category).and_then(|c| c.labels.get(label))
+}
+
+/// How much one label added to an `EvalOutput`'s overall score.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LabelContribution {
+    pub category: String,
+    pub label: String,
+    /// Raw model output
+    pub raw_score: f32,
+    /// Platt-calibrated score, before the threshold and weight
+    pub calibrated_score: f32,
+    /// Label weight from the config
+    pub weight: f32,
+    /// Share of the overall score; the contributions of an output sum to
+    /// its `score`
+    pub contribution: f32,
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CategoryOutput {
     /// Category score (avg of top-k labels)
@@ -147,19 +315,54 @@ impl CategoryOutput {
 
     pub fn topk(labels: BTreeMap<String, LabelOutput>, k: usize) -> Self {
         let take = k.min(labels.len()).max(1);
-
-        // Sort by score for top-k calculation
-        let mut sorted: Vec<_> = labels.values().collect();
-        sorted.sort_by(|a, b| b.score.total_cmp(&a.score));
-
-        let score = if sorted.is_empty() {
-            0.0
-        } else {
-            sorted.iter().take(take).map(|l| l.score).sum::<f32>() / take as f32
+        let score = match labels.is_empty() {
+            true => 0.0,
+            false => {
+                ranked(&labels)
+                    .take(take)
+                    .map(|(_, l)| l.score)
+                    .sum::<f32>()
+                    / take as f32
+            }
         };
 
         Self { score, labels }
     }
+
+    /// The `k` highest scoring labels, best first. Equal scores are ordered
+    /// by label name, so the same labels are chosen on every run.
+    pub fn top_labels(&self, k: usize) -> Vec<(&str, &LabelOutput)> {
+        ranked(&self.labels).take(k).collect()
+    }
+
+    /// Gap between the top label's score and the runner-up's (or the top
+    /// score itself if there is only one label).
+    pub fn margin(&self) -> f32 {
+        match self.top_labels(2).as_slice() {
+            [] => 0.0,
+            [(_, top)] => top.score,
+            [(_, top), (_, runner_up), ..] => top.score - runner_up.score,
+        }
+    }
+
+    /// Reject an ambiguous prediction: zero the category score when the
+    /// `margin` is below `min_margin`. A `min_margin` of 0 never rejects.
+    pub fn with_min_margin(mut self, min_margin: f32) -> Self {
+        if self.margin() < min_margin {
+            self.score = 0.0;
+        }
+
+        self
+    }
+}
+
+/// Labels by score descending, then by name ascending.
+fn ranked(labels: &BTreeMap<String, LabelOutput>) -> impl Iterator<Item = (&str, &LabelOutput)> {
+    let mut ranked: Vec<_> = labels.iter().map(|(n, l)| (n.as_str(), l)).collect();
+    ranked.sort_by(|(a_name, a), (b_name, b)| {
+        b.score.total_cmp(&a.score).then_with(|| a_name.cmp(b_name))
+    });
+    ranked.into_iter()
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -289,7 +492,7 @@ mod tests {
         for (raw, a, b) in extreme_cases {
             let result = calibrate(raw, a, b);
             assert!(
-                result >= 0.0 && result <= 1.0,
+                (0.0..=1.0).contains(&result),
                 "Calibrated score must be in [0,1], got {} for ({}, {}, {})",
                 result,
                 raw,
@@ -396,6 +599,61 @@ mod tests {
         );
     }
 
+    fn near_tied_category(min_margin: f32) -> CategoryOutput {
+        let config = LabelConfig {
+            hypothesis: "test".to_string(),
+            weight: 1.0,
+            threshold: 0.0,
+            platt_a: 1.0,
+            platt_b: 0.0,
+        };
+
+        let mut labels = BTreeMap::new();
+        labels.insert("a".to_string(), LabelOutput::new(0.80, 0, &config));
+        labels.insert("b".to_string(), LabelOutput::new(0.78, 0, &config));
+
+        CategoryOutput::topk(labels, 1).with_min_margin(min_margin)
+    }
+
+    #[test]
+    fn category_output_near_tie_accepted_at_zero_margin() {
+        let category = near_tied_category(0.0);
+        assert!((category.margin() - 0.02).abs() < 0.001);
+        assert!((category.score - 0.80).abs() < 0.001);
+
+        let mut categories = BTreeMap::new();
+        categories.insert("intent".to_string(), category);
+        assert_eq!(EvalOutput::new(categories).decide(0.75), Decision::Accept);
+    }
+
+    #[test]
+    fn category_output_near_tie_rejected_under_margin() {
+        let category = near_tied_category(0.05);
+        assert_eq!(category.score, 0.0);
+
+        let mut categories = BTreeMap::new();
+        categories.insert("intent".to_string(), category);
+        assert_eq!(EvalOutput::new(categories).decide(0.75), Decision::Reject);
+    }
+
+    #[test]
+    fn category_output_clear_winner_passes_margin() {
+        let config = LabelConfig {
+            hypothesis: "test".to_string(),
+            weight: 1.0,
+            threshold: 0.0,
+            platt_a: 1.0,
+            platt_b: 0.0,
+        };
+
+        let mut labels = BTreeMap::new();
+        labels.insert("a".to_string(), LabelOutput::new(0.90, 0, &config));
+        labels.insert("b".to_string(), LabelOutput::new(0.40, 0, &config));
+
+        let category = CategoryOutput::topk(labels, 1).with_min_margin(0.05);
+        assert!((category.score - 0.90).abs() < 0.001);
+    }
+
     // === EvalOutput Tests ===
 
     #[test]
@@ -414,6 +672,173 @@ mod tests {
         assert!(result.category("nonexistent").is_none());
     }
 
+    fn aggregation_fixture(aggregation: Aggregation) -> EvalOutput {
+        let label = |weight: f32| LabelConfig {
+            hypothesis: "test".to_string(),
+            weight,
+            threshold: 0.0,
+            platt_a: 1.0,
+            platt_b: 0.0,
+        };
+
+        let mut config = EvalConfig {
+            aggregation,
+            ..Default::default()
+        };
+        let mut tone = crate::CategoryConfig::default();
+        tone.labels.insert("x".to_string(), label(0.5));
+        tone.labels.insert("y".to_string(), label(1.0));
+        let mut topic = crate::CategoryConfig::default();
+        topic.labels.insert("z".to_string(), label(0.8));
+        config.categories.insert("tone".to_string(), tone);
+        config.categories.insert("topic".to_string(), topic);
+
+        // Weighted label scores: x = 0.4 * 0.5, y = 0.3 * 1.0, z = 0.25 * 0.8
+        let mut categories = BTreeMap::new();
+        for (cat_name, raws) in [
+            ("tone", vec![("x", 0.4), ("y", 0.3)]),
+            ("topic", vec![("z", 0.25)]),
+        ] {
+            let mut labels = BTreeMap::new();
+            for (name, raw) in raws {
+                let label_config = &config.categories[cat_name].labels[name];
+                labels.insert(name.to_string(), LabelOutput::new(raw, 0, label_config));
+            }
+
+            categories.insert(cat_name.to_string(), CategoryOutput::topk(labels, 2));
+        }
+
+        EvalOutput::aggregate(categories, &config)
+    }
+
+    #[test]
+    fn aggregation_max_uses_best_category() {
+        let output = aggregation_fixture(Aggregation::Max);
+        assert!((output.score - 0.25).abs() < 0.001, "got {}", output.score);
+    }
+
+    #[test]
+    fn aggregation_sum_adds_weighted_label_scores() {
+        let output = aggregation_fixture(Aggregation::Sum);
+        assert!((output.score - 0.70).abs() < 0.001, "got {}", output.score);
+    }
+
+    #[test]
+    fn aggregation_weighted_mean_divides_by_total_weight() {
+        let output = aggregation_fixture(Aggregation::WeightedMean);
+        let expected = 0.70 / 2.3;
+        assert!(
+            (output.score - expected).abs() < 0.001,
+            "got {}",
+            output.score
+        );
+    }
+
+    #[test]
+    fn contributions_sum_to_score() {
+        for aggregation in [
+            Aggregation::Max,
+            Aggregation::Sum,
+            Aggregation::WeightedMean,
+        ] {
+            let output = aggregation_fixture(aggregation);
+            let total: f32 = output.contributions.iter().map(|c| c.contribution).sum();
+
+            assert_eq!(output.contributions.len(), 3);
+            assert!(
+                (total - output.score).abs() < 1e-5,
+                "{:?}: contributions sum to {}, score is {}",
+                aggregation,
+                total,
+                output.score
+            );
+        }
+
+        let output = aggregation_fixture(Aggregation::Sum);
+        let x = &output.contributions[0];
+        assert_eq!((x.category.as_str(), x.label.as_str()), ("tone", "x"));
+        assert!((x.raw_score - 0.4).abs() < 1e-6);
+        assert!((x.calibrated_score - 0.4).abs() < 1e-6);
+        assert!((x.weight - 0.5).abs() < 1e-6);
+        assert!((x.contribution - 0.2).abs() < 1e-6);
+
+        // Only the best category's labels count under `Max`
+        let output = aggregation_fixture(Aggregation::Max);
+        let share = |label: &str| {
+            output
+                .contributions
+                .iter()
+                .find(|c| c.label == label)
+                .unwrap()
+                .contribution
+        };
+        assert!((share("x") - 0.10).abs() < 1e-6);
+        assert!((share("y") - 0.15).abs() < 1e-6);
+        assert_eq!(share("z"), 0.0);
+    }
+
+    #[test]
+    fn contributions_scale_with_sum_cap() {
+        let config = LabelConfig {
+            hypothesis: "test".to_string(),
+            weight: 1.0,
+            threshold: 0.0,
+            platt_a: 1.0,
+            platt_b: 0.0,
+        };
+
+        let mut labels = BTreeMap::new();
+        labels.insert("a".to_string(), LabelOutput::new(0.9, 0, &config));
+        labels.insert("b".to_string(), LabelOutput::new(0.6, 0, &config));
+
+        let mut categories = BTreeMap::new();
+        categories.insert("c".to_string(), CategoryOutput::topk(labels, 2));
+
+        let mut eval_config = EvalConfig {
+            aggregation: Aggregation::Sum,
+            ..Default::default()
+        };
+        let mut category = crate::CategoryConfig::default();
+        category.labels.insert("a".to_string(), config.clone());
+        category.labels.insert("b".to_string(), config);
+        eval_config.categories.insert("c".to_string(), category);
+
+        let output = EvalOutput::aggregate(categories, &eval_config);
+        let shares: Vec<f32> = output
+            .contributions
+            .iter()
+            .map(|c| c.contribution)
+            .collect();
+
+        assert_eq!(output.score, 1.0);
+        assert!((shares[0] - 0.6).abs() < 1e-5, "{:?}", shares);
+        assert!((shares[1] - 0.4).abs() < 1e-5, "{:?}", shares);
+    }
+
+    #[test]
+    fn aggregation_sum_is_capped_at_one() {
+        let config = LabelConfig {
+            hypothesis: "test".to_string(),
+            weight: 1.0,
+            threshold: 0.0,
+            platt_a: 1.0,
+            platt_b: 0.0,
+        };
+
+        let mut labels = BTreeMap::new();
+        labels.insert("a".to_string(), LabelOutput::new(0.9, 0, &config));
+        labels.insert("b".to_string(), LabelOutput::new(0.8, 0, &config));
+
+        let mut categories = BTreeMap::new();
+        categories.insert("c".to_string(), CategoryOutput::topk(labels, 2));
+
+        let eval_config = EvalConfig {
+            aggregation: Aggregation::Sum,
+            ..Default::default()
+        };
+        assert_eq!(EvalOutput::aggregate(categories, &eval_config).score, 1.0);
+    }
+
     #[test]
     fn eval_output_label_lookup() {
         let config = LabelConfig {
@@ -435,4 +860,48 @@ mod tests {
         assert_eq!(result.label_score("positive"), 0.8);
         assert_eq!(result.label_score("nonexistent"), 0.0);
     }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn detected_labels_use_per_label_thresholds_after_value_roundtrip() {
+        let label = |threshold| LabelConfig {
+            hypothesis: "test".to_string(),
+            weight: 1.0,
+            threshold,
+            platt_a: 1.0,
+            platt_b: 0.0,
+        };
+
+        let mut labels = BTreeMap::new();
+        labels.insert("lenient".to_string(), LabelOutput::new(0.5, 0, &label(0.3)));
+        labels.insert("strict".to_string(), LabelOutput::new(0.5, 0, &label(0.8)));
+
+        let mut categories = BTreeMap::new();
+        categories.insert("c".to_string(), CategoryOutput::new(labels));
+        let direct = EvalOutput::new(categories);
+
+        // `loom run` reads the layer output back from the runtime's `Value`
+        let roundtrip = EvalOutput::try_from(Value::from(direct.clone())).unwrap();
+
+        let sample = Sample {
+            id: "1".to_string(),
+            text: "text".to_string(),
+            context: None,
+            expected_decision: Decision::Accept,
+            expected_labels: vec!["lenient".to_string()],
+            primary_category: "c".to_string(),
+            difficulty: crate::Difficulty::Easy,
+            notes: None,
+            metadata: None,
+        };
+
+        let direct = direct.to_result(&sample, 0.5);
+        let roundtrip = roundtrip.to_result(&sample, 0.5);
+
+        assert_eq!(direct.sample_results[0].detected_labels, vec!["lenient"]);
+        assert_eq!(
+            direct.sample_results[0].detected_labels,
+            roundtrip.sample_results[0].detected_labels
+        );
+    }
 }
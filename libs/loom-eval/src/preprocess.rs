@@ -0,0 +1,208 @@
+/// A transform applied to sample text before it reaches the model.
+pub trait TextPreprocessor: Send + Sync {
+    fn process(&self, text: &str) -> String;
+}
+
+/// Lowercases the text.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Lowercase;
+
+impl TextPreprocessor for Lowercase {
+    fn process(&self, text: &str) -> String {
+        text.to_lowercase()
+    }
+}
+
+/// Removes `http://`, `https://` and `www.` links, keeping the surrounding
+/// text and whitespace. Whitespace left dangling at the end by a removed
+/// link is trimmed; text without links is returned unchanged.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StripUrls;
+
+impl StripUrls {
+    fn is_url(word: &str) -> bool {
+        let word = word.trim_start_matches(['(', '[', '<', '"', '\'']);
+        ["http://", "https://", "www."].iter().any(|prefix| {
+            word.len() > prefix.len()
+                && word
+                    .get(..prefix.len())
+                    .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+        })
+    }
+}
+
+impl TextPreprocessor for StripUrls {
+    fn process(&self, text: &str) -> String {
+        let mut stripped = String::with_capacity(text.len());
+        let mut url_at_end = false;
+
+        for word in text.split_inclusive(char::is_whitespace) {
+            if Self::is_url(word.trim_end()) {
+                url_at_end = true;
+                continue;
+            }
+
+            if !word.trim().is_empty() {
+                url_at_end = false;
+            }
+
+            stripped.push_str(word);
+        }
+
+        if url_at_end {
+            stripped.truncate(stripped.trim_end().len());
+        }
+
+        stripped
+    }
+}
+
+/// Trims the text and collapses every run of whitespace, including
+/// newlines, into a single space.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NormalizeWhitespace;
+
+impl TextPreprocessor for NormalizeWhitespace {
+    fn process(&self, text: &str) -> String {
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+}
+
+/// Keeps the first `max_tokens` tokens of the text.
+///
+/// Tokens approximate a BERT pre-tokenizer: runs of alphanumeric characters
+/// are one token and every other non-whitespace character is a token of its
+/// own. Subword tokenization can only split these further, so a budget a
+/// little under the model's limit keeps the model from truncating instead.
+#[derive(Debug, Clone, Copy)]
+pub struct Truncate {
+    pub max_tokens: usize,
+}
+
+impl Truncate {
+    pub fn new(max_tokens: usize) -> Self {
+        Self { max_tokens }
+    }
+
+    /// Number of tokens in `text`, counted the same way as truncation.
+    pub fn count(text: &str) -> usize {
+        token_ends(text).count()
+    }
+}
+
+impl TextPreprocessor for Truncate {
+    fn process(&self, text: &str) -> String {
+        match self.max_tokens {
+            0 => String::new(),
+            max => match token_ends(text).nth(max - 1) {
+                Some(end) => text[..end].to_string(),
+                None => text.to_string(),
+            },
+        }
+    }
+}
+
+/// Byte offset just past each token in `text`.
+fn token_ends(text: &str) -> impl Iterator<Item = usize> + '_ {
+    let mut chars = text.char_indices().peekable();
+
+    std::iter::from_fn(move || {
+        while let Some((i, c)) = chars.next() {
+            if c.is_whitespace() {
+                continue;
+            }
+
+            if !c.is_alphanumeric() {
+                return Some(i + c.len_utf8());
+            }
+
+            let mut end = i + c.len_utf8();
+            while let Some(&(j, next)) = chars.peek() {
+                if !next.is_alphanumeric() {
+                    break;
+                }
+
+                end = j + next.len_utf8();
+                chars.next();
+            }
+
+            return Some(end);
+        }
+
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lowercase_handles_unicode() {
+        assert_eq!(Lowercase.process("Hello ÉCOLE"), "hello école");
+    }
+
+    #[test]
+    fn strip_urls_removes_links() {
+        assert_eq!(
+            StripUrls.process("see https://example.com/a?b=1 and WWW.test.org now"),
+            "see and now"
+        );
+        assert_eq!(
+            StripUrls.process("(http://x.io) keeps www alone"),
+            "keeps www alone"
+        );
+        assert_eq!(StripUrls.process("no links here"), "no links here");
+        assert_eq!(StripUrls.process("read this https://x.io"), "read this");
+    }
+
+    #[test]
+    fn strip_urls_leaves_text_without_links_unchanged() {
+        assert_eq!(StripUrls.process("  keep this  \n"), "  keep this  \n");
+        assert_eq!(
+            StripUrls.process("line one\nline two\n"),
+            "line one\nline two\n"
+        );
+        assert_eq!(
+            StripUrls.process("see https://x.io and more  "),
+            "see and more  "
+        );
+    }
+
+    #[test]
+    fn strip_urls_handles_multibyte_words() {
+        assert_eq!(StripUrls.process("日本語"), "日本語");
+        assert_eq!(
+            StripUrls.process("café 😀 https://例え.jp ökologisch"),
+            "café 😀 ökologisch"
+        );
+    }
+
+    #[test]
+    fn normalize_whitespace_collapses_runs() {
+        assert_eq!(NormalizeWhitespace.process("  a\t\tb \n\n c  "), "a b c");
+    }
+
+    #[test]
+    fn truncate_counts_words_and_punctuation() {
+        assert_eq!(Truncate::count("Hello, world!"), 4);
+        assert_eq!(Truncate::new(2).process("Hello, world!"), "Hello,");
+        assert_eq!(Truncate::new(3).process("Hello, world!"), "Hello, world");
+        assert_eq!(Truncate::new(10).process("Hello, world!"), "Hello, world!");
+        assert_eq!(Truncate::new(0).process("Hello"), "");
+    }
+
+    #[test]
+    fn truncate_uses_token_budget_not_bytes() {
+        // 5 tokens in 40 bytes of multi-byte characters
+        let text = "日本語 ökologisch grün straße 😀";
+        assert_eq!(Truncate::count(text), 5);
+
+        let truncated = Truncate::new(4).process(text);
+        assert_eq!(truncated, "日本語 ökologisch grün straße");
+        assert_eq!(Truncate::count(&truncated), 4);
+
+        // emoji and other symbols are a token each
+        assert_eq!(Truncate::new(2).process("😀😀😀"), "😀😀");
+    }
+}
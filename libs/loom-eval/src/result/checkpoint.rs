@@ -0,0 +1,177 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::EvalResult;
+use crate::{Sample, SampleDataset};
+
+/// Partial results of an interrupted run, used to resume it.
+///
+/// Holds the accumulated `EvalResult` of every completed sample, so merging
+/// the results of the remaining samples into it yields the same result as a
+/// fresh run over the whole dataset.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub result: EvalResult,
+}
+
+impl Checkpoint {
+    /// Create an empty checkpoint.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of completed samples.
+    pub fn len(&self) -> usize {
+        self.result.sample_results.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.result.sample_results.is_empty()
+    }
+
+    /// Ids of completed samples.
+    pub fn completed_ids(&self) -> HashSet<&str> {
+        self.result
+            .sample_results
+            .iter()
+            .map(|s| s.id.as_str())
+            .collect()
+    }
+
+    /// Samples in `dataset` that are not yet completed, in dataset order.
+    pub fn remaining<'a>(&self, dataset: &'a SampleDataset) -> Vec<&'a Sample> {
+        let completed = self.completed_ids();
+        dataset
+            .samples
+            .iter()
+            .filter(|s| !completed.contains(s.id.as_str()))
+            .collect()
+    }
+
+    /// Merge a newly completed sample's result into the checkpoint.
+    pub fn record(&mut self, result: EvalResult) {
+        self.result = std::mem::take(&mut self.result).merge(result);
+    }
+
+    /// Consume the checkpoint, returning the accumulated result.
+    pub fn into_result(self) -> EvalResult {
+        self.result
+    }
+}
+
+impl From<EvalResult> for Checkpoint {
+    fn from(result: EvalResult) -> Self {
+        Self { result }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Decision, Difficulty, SampleResult};
+
+    fn sample(id: &str, category: &str, labels: &[&str]) -> Sample {
+        Sample {
+            id: id.to_string(),
+            text: format!("text {}", id),
+            context: None,
+            expected_decision: Decision::Accept,
+            expected_labels: labels.iter().map(|l| l.to_string()).collect(),
+            primary_category: category.to_string(),
+            difficulty: Difficulty::Easy,
+            notes: None,
+            metadata: None,
+        }
+    }
+
+    fn dataset() -> SampleDataset {
+        let mut dataset = SampleDataset::new();
+        dataset.samples = vec![
+            sample("a", "task", &["reminder"]),
+            sample("b", "task", &["todo"]),
+            sample("c", "emotion", &["joy"]),
+            sample("d", "emotion", &["joy", "todo"]),
+            sample("e", "task", &[]),
+        ];
+        dataset
+    }
+
+    /// Deterministic stand-in for model scoring: detects the first expected label.
+    fn score(sample: &Sample) -> EvalResult {
+        let detected: Vec<String> = sample.expected_labels.iter().take(1).cloned().collect();
+        let actual = if detected.is_empty() {
+            Decision::Reject
+        } else {
+            Decision::Accept
+        };
+
+        let sample_result = SampleResult {
+            id: sample.id.clone(),
+            category: sample.primary_category.clone(),
+            expected_decision: sample.expected_decision,
+            actual_decision: actual,
+            correct: actual == sample.expected_decision,
+            score: 0.5,
+            expected_labels: sample.expected_labels.clone(),
+            detected_labels: detected,
+            elapsed_ms: None,
+            contributions: Vec::new(),
+        };
+
+        let mut result = EvalResult::new();
+        result.total = 1;
+        result.accumulate(sample, &sample_result);
+        result.sample_results.push(sample_result);
+        result
+    }
+
+    #[test]
+    fn resumed_run_matches_fresh_run() {
+        let dataset = dataset();
+
+        let mut fresh = Checkpoint::new();
+        for sample in &dataset.samples {
+            fresh.record(score(sample));
+        }
+
+        // Run the first two samples, persist, then "crash".
+        let mut partial = Checkpoint::new();
+        for sample in dataset.samples.iter().take(2) {
+            partial.record(score(sample));
+        }
+        let saved = serde_json::to_string(&partial).unwrap();
+
+        // Restart from the saved checkpoint and finish the run.
+        let mut resumed: Checkpoint = serde_json::from_str(&saved).unwrap();
+        let remaining = resumed.remaining(&dataset);
+        assert_eq!(remaining.len(), 3);
+        for sample in remaining {
+            resumed.record(score(sample));
+        }
+
+        let fresh = fresh.into_result();
+        let resumed = resumed.into_result();
+
+        assert_eq!(
+            serde_json::to_value(&resumed).unwrap(),
+            serde_json::to_value(&fresh).unwrap()
+        );
+        assert_eq!(resumed.metrics().accuracy, fresh.metrics().accuracy);
+    }
+
+    #[test]
+    fn remaining_skips_completed_ids() {
+        let dataset = dataset();
+        let mut checkpoint = Checkpoint::new();
+        checkpoint.record(score(&dataset.samples[1]));
+
+        let ids: Vec<_> = checkpoint
+            .remaining(&dataset)
+            .iter()
+            .map(|s| s.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["a", "c", "d", "e"]);
+        assert_eq!(checkpoint.len(), 1);
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::EvalResult;
+
+/// Change below this magnitude is treated as no change.
+const EPSILON: f32 = 1e-6;
+
+/// A metric in two runs. `None` means the run has no value for it,
+/// e.g. a label that only exists in one of the runs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetricDelta {
+    pub old: Option<f32>,
+    pub new: Option<f32>,
+}
+
+impl MetricDelta {
+    pub fn new(old: Option<f32>, new: Option<f32>) -> Self {
+        Self { old, new }
+    }
+
+    /// `new - old`, if the metric exists in both runs.
+    pub fn delta(&self) -> Option<f32> {
+        Some(self.new? - self.old?)
+    }
+
+    /// Only present in the new run.
+    pub fn is_added(&self) -> bool {
+        self.old.is_none() && self.new.is_some()
+    }
+
+    /// Only present in the old run.
+    pub fn is_removed(&self) -> bool {
+        self.old.is_some() && self.new.is_none()
+    }
+
+    /// Present in both runs and lower in the new one.
+    pub fn is_regression(&self) -> bool {
+        self.delta().is_some_and(|d| d < -EPSILON)
+    }
+
+    /// Present in both runs and higher in the new one.
+    pub fn is_improvement(&self) -> bool {
+        self.delta().is_some_and(|d| d > EPSILON)
+    }
+}
+
+/// Change in a category between two runs.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CategoryDelta {
+    pub accuracy: MetricDelta,
+}
+
+/// Change in a label between two runs.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LabelDelta {
+    pub precision: MetricDelta,
+    pub recall: MetricDelta,
+    pub f1: MetricDelta,
+}
+
+/// Metric deltas between an old and a new `EvalResult`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EvalDiff {
+    pub accuracy: MetricDelta,
+    pub precision: MetricDelta,
+    pub recall: MetricDelta,
+    pub f1: MetricDelta,
+    pub per_category: BTreeMap<String, CategoryDelta>,
+    pub per_label: BTreeMap<String, LabelDelta>,
+}
+
+impl EvalDiff {
+    /// Compare two results. Categories and labels present in only one run
+    /// have `None` on the other side.
+    pub fn between(old: &EvalResult, new: &EvalResult) -> Self {
+        let old_metrics = old.metrics();
+        let new_metrics = new.metrics();
+
+        let mut per_category: BTreeMap<String, CategoryDelta> = BTreeMap::new();
+        for (name, m) in &old_metrics.per_category {
+            per_category.entry(name.clone()).or_default().accuracy.old = Some(m.accuracy);
+        }
+        for (name, m) in &new_metrics.per_category {
+            per_category.entry(name.clone()).or_default().accuracy.new = Some(m.accuracy);
+        }
+
+        let mut per_label: BTreeMap<String, LabelDelta> = BTreeMap::new();
+        for (name, m) in &old_metrics.per_label {
+            let entry = per_label.entry(name.clone()).or_default();
+            entry.precision.old = Some(m.precision);
+            entry.recall.old = Some(m.recall);
+            entry.f1.old = Some(m.f1);
+        }
+        for (name, m) in &new_metrics.per_label {
+            let entry = per_label.entry(name.clone()).or_default();
+            entry.precision.new = Some(m.precision);
+            entry.recall.new = Some(m.recall);
+            entry.f1.new = Some(m.f1);
+        }
+
+        Self {
+            accuracy: MetricDelta::new(Some(old_metrics.accuracy), Some(new_metrics.accuracy)),
+            precision: MetricDelta::new(Some(old_metrics.precision), Some(new_metrics.precision)),
+            recall: MetricDelta::new(Some(old_metrics.recall), Some(new_metrics.recall)),
+            f1: MetricDelta::new(Some(old_metrics.f1), Some(new_metrics.f1)),
+            per_category,
+            per_label,
+        }
+    }
+
+    /// Whether overall accuracy/F1 or any category accuracy or label F1 got worse.
+    pub fn has_regressions(&self) -> bool {
+        self.accuracy.is_regression()
+            || self.f1.is_regression()
+            || self
+                .per_category
+                .values()
+                .any(|c| c.accuracy.is_regression())
+            || self.per_label.values().any(|l| l.f1.is_regression())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CategoryResult, LabelResult};
+
+    fn label(tp: usize, fp: usize, fn_: usize) -> LabelResult {
+        LabelResult {
+            expected_count: tp + fn_,
+            detected_count: tp + fp,
+            true_positives: tp,
+            false_positives: fp,
+            false_negatives: fn_,
+        }
+    }
+
+    fn result(
+        correct: usize,
+        categories: &[(&str, usize, usize)],
+        labels: &[(&str, LabelResult)],
+    ) -> EvalResult {
+        let mut result = EvalResult::new();
+        result.total = 10;
+        result.correct = correct;
+        for (name, total, correct) in categories {
+            result.per_category.insert(
+                name.to_string(),
+                CategoryResult {
+                    total: *total,
+                    correct: *correct,
+                },
+            );
+        }
+        for (name, l) in labels {
+            result.per_label.insert(name.to_string(), l.clone());
+        }
+        result
+    }
+
+    #[test]
+    fn delta_is_new_minus_old() {
+        let delta = MetricDelta::new(Some(0.8), Some(0.6));
+        assert!((delta.delta().unwrap() + 0.2).abs() < 1e-6);
+        assert!(delta.is_regression());
+        assert!(!delta.is_improvement());
+    }
+
+    #[test]
+    fn unchanged_metric_is_not_regression() {
+        let delta = MetricDelta::new(Some(0.5), Some(0.5));
+        assert!(!delta.is_regression());
+        assert!(!delta.is_improvement());
+    }
+
+    #[test]
+    fn diff_tracks_overall_and_category_changes() {
+        let old = result(8, &[("task", 5, 4)], &[]);
+        let new = result(9, &[("task", 5, 3)], &[]);
+
+        let diff = EvalDiff::between(&old, &new);
+        assert!(diff.accuracy.is_improvement());
+        assert!(diff.per_category["task"].accuracy.is_regression());
+        assert!(diff.has_regressions());
+    }
+
+    #[test]
+    fn diff_handles_added_and_removed_labels() {
+        let old = result(
+            8,
+            &[],
+            &[("shared", label(4, 0, 0)), ("gone", label(2, 1, 1))],
+        );
+        let new = result(
+            8,
+            &[],
+            &[("shared", label(4, 0, 0)), ("fresh", label(3, 0, 0))],
+        );
+
+        let diff = EvalDiff::between(&old, &new);
+        assert_eq!(diff.per_label.len(), 3);
+
+        let gone = &diff.per_label["gone"];
+        assert!(gone.f1.is_removed());
+        assert_eq!(gone.f1.delta(), None);
+        assert!(!gone.f1.is_regression());
+
+        let fresh = &diff.per_label["fresh"];
+        assert!(fresh.f1.is_added());
+        assert_eq!(fresh.f1.new, Some(1.0));
+
+        let shared = &diff.per_label["shared"];
+        assert_eq!(shared.f1.delta(), Some(0.0));
+        assert!(!diff.has_regressions());
+    }
+}
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use super::{
     CategoryMetrics, CategoryResult, EvalMetrics, LabelMetrics, LabelResult, SampleResult,
 };
-use crate::Sample;
+use crate::{CoverageReport, CoverageThresholds, Sample};
 
 /// Raw benchmark results (counts only).
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -79,6 +79,18 @@ impl EvalResult {
         }
     }
 
+    /// Label coverage of the scored samples, from the accumulated
+    /// `expected_count`s.
+    pub fn coverage(&self, thresholds: &CoverageThresholds) -> CoverageReport {
+        CoverageReport::from_counts(
+            self.per_label
+                .iter()
+                .map(|(label, result)| (label.as_str(), result.expected_count)),
+            self.total,
+            thresholds,
+        )
+    }
+
     /// Merge another EvalResult into this one.
     pub fn merge(mut self, other: EvalResult) -> EvalResult {
         self.total += other.total;
@@ -0,0 +1,168 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use super::{EvalResult, SampleResult};
+use crate::Decision;
+
+/// Suite name for samples without a recorded category.
+const UNCATEGORIZED: &str = "uncategorized";
+
+impl EvalResult {
+    /// Render the run as JUnit XML for CI test reports.
+    ///
+    /// Each sample is a `<testcase>` named after its id, grouped into one
+    /// `<testsuite>` per category. Incorrect samples carry a `<failure>`
+    /// with the expected and actual decision.
+    pub fn to_junit(&self) -> String {
+        let mut suites: BTreeMap<&str, Vec<&SampleResult>> = BTreeMap::new();
+        for sample in &self.sample_results {
+            let category = match sample.category.as_str() {
+                "" => UNCATEGORIZED,
+                category => category,
+            };
+
+            suites.entry(category).or_default().push(sample);
+        }
+
+        let failures = self.sample_results.iter().filter(|s| !s.correct).count();
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"loom\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">",
+            self.sample_results.len(),
+            failures,
+            self.elapsed_ms as f64 / 1000.0
+        );
+
+        for (category, samples) in suites {
+            let failures = samples.iter().filter(|s| !s.correct).count();
+            let _ = writeln!(
+                xml,
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">",
+                escape(category),
+                samples.len(),
+                failures
+            );
+
+            for sample in samples {
+                write_testcase(&mut xml, category, sample);
+            }
+
+            xml.push_str("  </testsuite>\n");
+        }
+
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+}
+
+fn write_testcase(xml: &mut String, category: &str, sample: &SampleResult) {
+    let _ = write!(
+        xml,
+        "    <testcase name=\"{}\" classname=\"{}\"",
+        escape(&sample.id),
+        escape(category)
+    );
+
+    if let Some(elapsed_ms) = sample.elapsed_ms {
+        let _ = write!(xml, " time=\"{:.3}\"", elapsed_ms as f64 / 1000.0);
+    }
+
+    if sample.correct {
+        xml.push_str("/>\n");
+        return;
+    }
+
+    let _ = writeln!(
+        xml,
+        ">\n      <failure message=\"expected {}, got {}\">score {:.3}; expected labels [{}]; detected labels [{}]</failure>\n    </testcase>",
+        decision(sample.expected_decision),
+        decision(sample.actual_decision),
+        sample.score,
+        escape(&sample.expected_labels.join(", ")),
+        escape(&sample.detected_labels.join(", "))
+    );
+}
+
+fn decision(decision: Decision) -> &'static str {
+    match decision {
+        Decision::Accept => "accept",
+        Decision::Reject => "reject",
+    }
+}
+
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(id: &str, category: &str, correct: bool) -> SampleResult {
+        SampleResult {
+            id: id.to_string(),
+            category: category.to_string(),
+            expected_decision: Decision::Accept,
+            actual_decision: if correct {
+                Decision::Accept
+            } else {
+                Decision::Reject
+            },
+            correct,
+            score: 0.4,
+            expected_labels: vec!["positive".to_string()],
+            detected_labels: vec![],
+            elapsed_ms: Some(12),
+            contributions: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn junit_counts_testcases_and_failures() {
+        let mut result = EvalResult::new();
+        result.sample_results = vec![
+            sample("a-1", "emotional", true),
+            sample("a-2", "emotional", false),
+            sample("b-1", "factual", true),
+            sample("c-1", "", false),
+        ];
+
+        let xml = result.to_junit();
+
+        assert_eq!(xml.matches("<testcase ").count(), 4);
+        assert_eq!(xml.matches("<failure ").count(), 2);
+        assert_eq!(xml.matches("<testsuite ").count(), 3);
+        assert!(xml.contains("<testsuites name=\"loom\" tests=\"4\" failures=\"2\""));
+        assert!(xml.contains("<testsuite name=\"emotional\" tests=\"2\" failures=\"1\">"));
+        assert!(xml.contains("<testsuite name=\"uncategorized\" tests=\"1\" failures=\"1\">"));
+        assert!(xml.contains("<failure message=\"expected accept, got reject\">"));
+        assert!(xml.contains("time=\"0.012\""));
+    }
+
+    #[test]
+    fn junit_escapes_names() {
+        let mut result = EvalResult::new();
+        result.sample_results = vec![sample("<a & \"b\">", "emotional", true)];
+
+        assert!(
+            result
+                .to_junit()
+                .contains("name=\"&lt;a &amp; &quot;b&quot;&gt;\"")
+        );
+    }
+}
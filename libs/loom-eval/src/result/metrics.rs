@@ -13,6 +13,19 @@ pub struct EvalMetrics {
     pub per_label: HashMap<String, LabelMetrics>,
 }
 
+impl EvalMetrics {
+    /// Mean of the per-label F1 scores, or `0.0` without labels. Unlike
+    /// `f1`, the harmonic mean of macro precision and recall, this is
+    /// macro-averaged F1 proper.
+    pub fn macro_f1(&self) -> f32 {
+        if self.per_label.is_empty() {
+            return 0.0;
+        }
+
+        self.per_label.values().map(|m| m.f1).sum::<f32>() / self.per_label.len() as f32
+    }
+}
+
 /// Computed metrics for a specific category.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CategoryMetrics {
@@ -1,11 +1,18 @@
 mod category;
+mod checkpoint;
+mod diff;
 mod eval;
+mod export;
 mod label;
 mod metrics;
+mod progress;
 mod sample;
 
 pub use category::*;
+pub use checkpoint::*;
+pub use diff::*;
 pub use eval::*;
 pub use label::*;
 pub use metrics::*;
+pub use progress::*;
 pub use sample::*;
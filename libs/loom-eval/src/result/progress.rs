@@ -0,0 +1,136 @@
+use std::time::{Duration, Instant};
+
+/// Progress of a run, reported after each scored sample.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Progress {
+    /// Samples completed so far, including any resumed from a checkpoint
+    pub current: usize,
+    pub total: usize,
+    /// Id of the sample just scored
+    pub sample_id: String,
+    /// Whether the sample's decision matched the expected one
+    pub correct: bool,
+    /// Time since the run started
+    pub elapsed: Duration,
+    /// Samples scored per second in this run (resumed samples excluded)
+    pub items_per_sec: f32,
+}
+
+impl Progress {
+    /// Estimated time left at the current throughput, or `None` before any
+    /// throughput is known.
+    pub fn eta(&self) -> Option<Duration> {
+        if self.items_per_sec <= 0.0 {
+            return None;
+        }
+
+        let remaining = self.total.saturating_sub(self.current) as f32;
+        Some(Duration::from_secs_f32(remaining / self.items_per_sec))
+    }
+}
+
+/// Times a run and builds its `Progress` reports.
+#[derive(Debug, Clone)]
+pub struct ProgressTracker {
+    started: Instant,
+    total: usize,
+    resumed: usize,
+    current: usize,
+}
+
+impl ProgressTracker {
+    /// Start timing a run over `total` samples, `resumed` of which were
+    /// already scored by an earlier run.
+    pub fn new(total: usize, resumed: usize) -> Self {
+        Self {
+            started: Instant::now(),
+            total,
+            resumed,
+            current: resumed,
+        }
+    }
+
+    /// Time since the run started.
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Samples scored per second by this run.
+    pub fn items_per_sec(&self) -> f32 {
+        let secs = self.elapsed().as_secs_f32();
+
+        match secs > 0.0 {
+            true => (self.current - self.resumed) as f32 / secs,
+            false => 0.0,
+        }
+    }
+
+    /// Count a scored sample and report the run's progress.
+    pub fn advance(&mut self, sample_id: impl Into<String>, correct: bool) -> Progress {
+        self.current += 1;
+
+        Progress {
+            current: self.current,
+            total: self.total,
+            sample_id: sample_id.into(),
+            correct,
+            elapsed: self.elapsed(),
+            items_per_sec: self.items_per_sec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn throughput_matches_per_item_time() {
+        let mut tracker = ProgressTracker::new(10, 0);
+        let mut progress = None;
+
+        for i in 0..5 {
+            std::thread::sleep(Duration::from_millis(20));
+            progress = Some(tracker.advance(i.to_string(), true));
+        }
+
+        let progress = progress.unwrap();
+        assert_eq!(progress.current, 5);
+        assert!(progress.elapsed >= Duration::from_millis(100));
+
+        // 20ms per item is at most 50 items/sec; allow for a slow scheduler
+        assert!(
+            progress.items_per_sec > 10.0 && progress.items_per_sec <= 50.0,
+            "{} items/sec",
+            progress.items_per_sec
+        );
+
+        let eta = progress.eta().unwrap();
+        assert!(eta >= Duration::from_millis(100), "{:?}", eta);
+    }
+
+    #[test]
+    fn resumed_samples_count_towards_current_not_throughput() {
+        let mut tracker = ProgressTracker::new(10, 8);
+        std::thread::sleep(Duration::from_millis(10));
+
+        let progress = tracker.advance("9", false);
+
+        assert_eq!(progress.current, 9);
+        assert!(progress.items_per_sec <= 100.0);
+    }
+
+    #[test]
+    fn eta_unknown_without_throughput() {
+        let progress = Progress {
+            current: 0,
+            total: 10,
+            sample_id: String::new(),
+            correct: false,
+            elapsed: Duration::ZERO,
+            items_per_sec: 0.0,
+        };
+
+        assert_eq!(progress.eta(), None);
+    }
+}
@@ -1,11 +1,15 @@
 use serde::{Deserialize, Serialize};
 
-use crate::Decision;
+use crate::{Decision, LabelContribution};
 
 /// Result for a single sample.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SampleResult {
     pub id: String,
+    /// The sample's `primary_category` (empty in results written before
+    /// it was recorded).
+    #[serde(default)]
+    pub category: String,
     pub expected_decision: Decision,
     pub actual_decision: Decision,
     pub correct: bool,
@@ -15,4 +19,7 @@ pub struct SampleResult {
     /// Per-sample inference time in milliseconds (if available).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub elapsed_ms: Option<i64>,
+    /// What each label added to `score`, explaining the decision.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contributions: Vec<LabelContribution>,
 }
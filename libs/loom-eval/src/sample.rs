@@ -6,7 +6,11 @@ use super::Difficulty;
 pub use loom_cortex::bench::Decision;
 
 /// A single benchmark sample.
+///
+/// Samples deserialized without a `difficulty` get one from
+/// `Difficulty::infer`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "SampleDef")]
 pub struct Sample {
     pub id: String,
     pub text: String,
@@ -21,3 +25,37 @@ pub struct Sample {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<serde_json::Value>,
 }
+
+/// `Sample` as written in a dataset, where `difficulty` may be omitted.
+#[derive(Deserialize)]
+struct SampleDef {
+    id: String,
+    text: String,
+    context: Option<String>,
+    expected_decision: Decision,
+    expected_labels: Vec<String>,
+    primary_category: String,
+    difficulty: Option<Difficulty>,
+    notes: Option<String>,
+    metadata: Option<serde_json::Value>,
+}
+
+impl From<SampleDef> for Sample {
+    fn from(def: SampleDef) -> Self {
+        let difficulty = def
+            .difficulty
+            .unwrap_or_else(|| Difficulty::infer(&def.text, &def.expected_labels));
+
+        Self {
+            id: def.id,
+            text: def.text,
+            context: def.context,
+            expected_decision: def.expected_decision,
+            expected_labels: def.expected_labels,
+            primary_category: def.primary_category,
+            difficulty,
+            notes: def.notes,
+            metadata: def.metadata,
+        }
+    }
+}
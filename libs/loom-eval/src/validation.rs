@@ -1,14 +1,129 @@
 use serde::{Deserialize, Serialize};
 
-/// Validation error for a benchmark sample.
-#[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct ValidationError {
+/// What a `ValidationIssue` is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationKind {
+    /// Another sample already uses this id
+    DuplicateId,
+    /// The text is empty or whitespace-only
+    EmptyText,
+    /// The sample has no expected labels
+    MissingLabels,
+    /// The primary category isn't configured
+    InvalidCategory,
+    /// An expected label isn't in any configured category
+    InvalidLabel,
+}
+
+impl ValidationKind {
+    /// Whether the sample can't be scored reliably at all: duplicate ids
+    /// overwrite each other's results and empty texts have nothing to score.
+    pub fn is_fatal(&self) -> bool {
+        matches!(self, Self::DuplicateId | Self::EmptyText)
+    }
+}
+
+/// Where in the dataset an issue is: the sample, and the category or label
+/// at fault when the issue is about one.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ValidationPath {
     pub sample_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl ValidationPath {
+    pub fn sample(sample_id: impl Into<String>) -> Self {
+        Self {
+            sample_id: sample_id.into(),
+            category: None,
+            label: None,
+        }
+    }
+
+    pub fn with_category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+/// One problem with a benchmark sample.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ValidationIssue {
+    pub code: ValidationKind,
+    pub path: ValidationPath,
     pub message: String,
 }
 
+impl ValidationIssue {
+    pub fn new(code: ValidationKind, path: ValidationPath, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            path,
+            message: message.into(),
+        }
+    }
+
+    pub fn is_fatal(&self) -> bool {
+        self.code.is_fatal()
+    }
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.path.sample_id, self.message)
+    }
+}
+
+/// Every issue found validating a benchmark dataset. Serializes as
+/// `{"issues": [...]}` for machine-readable reports.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ValidationError {
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationError {
+    pub fn new(issues: Vec<ValidationIssue>) -> Self {
+        Self { issues }
+    }
+
+    pub fn len(&self) -> usize {
+        self.issues.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    pub fn has_fatal(&self) -> bool {
+        self.issues.iter().any(ValidationIssue::is_fatal)
+    }
+}
+
+impl From<Vec<ValidationIssue>> for ValidationError {
+    fn from(issues: Vec<ValidationIssue>) -> Self {
+        Self::new(issues)
+    }
+}
+
 impl std::fmt::Display for ValidationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "[{}] {}", self.sample_id, self.message)
+        write!(f, "{} validation issue(s):", self.issues.len())?;
+
+        for issue in &self.issues {
+            write!(f, "\n  {}", issue)?;
+        }
+
+        Ok(())
     }
 }
+
+impl std::error::Error for ValidationError {}
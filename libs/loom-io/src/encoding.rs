@@ -0,0 +1,130 @@
+use std::io;
+
+/// Compression applied to a record's content, as in HTTP `Content-Encoding`.
+///
+/// Decoding gzip and zstd content requires the `gzip` and `zstd` features;
+/// without them `decode` fails with `io::ErrorKind::Unsupported`.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Encoding {
+    #[default]
+    Identity,
+    Gzip,
+    Zstd,
+}
+
+impl Encoding {
+    /// Parse a `Content-Encoding` value, `None` if unrecognized.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "identity" => Some(Self::Identity),
+            "gzip" | "x-gzip" => Some(Self::Gzip),
+            "zstd" => Some(Self::Zstd),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Identity => "identity",
+            Self::Gzip => "gzip",
+            Self::Zstd => "zstd",
+        }
+    }
+
+    pub fn is_identity(&self) -> bool {
+        matches!(self, Self::Identity)
+    }
+
+    /// Compress `content` with this encoding.
+    pub fn encode(&self, content: &[u8]) -> io::Result<Vec<u8>> {
+        match self {
+            Self::Identity => Ok(content.to_vec()),
+            #[cfg(feature = "gzip")]
+            Self::Gzip => {
+                use std::io::Write;
+
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(content)?;
+                encoder.finish()
+            }
+            #[cfg(feature = "zstd")]
+            Self::Zstd => zstd::encode_all(content, 0),
+            #[cfg(not(all(feature = "gzip", feature = "zstd")))]
+            encoding => Err(unsupported(*encoding)),
+        }
+    }
+
+    /// Decompress `content` encoded with this encoding.
+    pub fn decode(&self, content: &[u8]) -> io::Result<Vec<u8>> {
+        match self {
+            Self::Identity => Ok(content.to_vec()),
+            #[cfg(feature = "gzip")]
+            Self::Gzip => {
+                use std::io::Read;
+
+                let mut decoded = Vec::new();
+                flate2::read::GzDecoder::new(content).read_to_end(&mut decoded)?;
+                Ok(decoded)
+            }
+            #[cfg(feature = "zstd")]
+            Self::Zstd => zstd::decode_all(content),
+            #[cfg(not(all(feature = "gzip", feature = "zstd")))]
+            encoding => Err(unsupported(*encoding)),
+        }
+    }
+}
+
+impl std::fmt::Display for Encoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(not(all(feature = "gzip", feature = "zstd")))]
+fn unsupported(encoding: Encoding) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!("{} content requires the `{}` feature", encoding, encoding),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_content_encoding_header() {
+        assert_eq!(Encoding::parse("gzip"), Some(Encoding::Gzip));
+        assert_eq!(Encoding::parse(" X-GZIP "), Some(Encoding::Gzip));
+        assert_eq!(Encoding::parse("zstd"), Some(Encoding::Zstd));
+        assert_eq!(Encoding::parse("identity"), Some(Encoding::Identity));
+        assert_eq!(Encoding::parse("br"), None);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn gzip_roundtrip() {
+        let encoded = Encoding::Gzip.encode(b"hello gzip").unwrap();
+
+        assert_ne!(encoded, b"hello gzip");
+        assert_eq!(Encoding::Gzip.decode(&encoded).unwrap(), b"hello gzip");
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn zstd_roundtrip() {
+        let encoded = Encoding::Zstd.encode(b"hello zstd").unwrap();
+        assert_eq!(Encoding::Zstd.decode(&encoded).unwrap(), b"hello zstd");
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    #[test]
+    fn gzip_unsupported_without_feature() {
+        let err = Encoding::Gzip.decode(b"").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+    }
+}
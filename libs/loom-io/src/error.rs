@@ -9,6 +9,9 @@ pub enum ReadError {
     /// Read operation panicked during execution
     Panic(String),
 
+    /// Path resolves outside what the source is allowed to access
+    Forbidden(String),
+
     /// Custom error with a message
     Custom(String),
 }
@@ -22,6 +25,10 @@ impl ReadError {
         matches!(self, Self::Panic(_))
     }
 
+    pub fn is_forbidden(&self) -> bool {
+        matches!(self, Self::Forbidden(_))
+    }
+
     pub fn is_custom(&self) -> bool {
         matches!(self, Self::Custom(_))
     }
@@ -42,6 +49,7 @@ impl std::fmt::Display for ReadError {
         match self {
             Self::IO(e) => write!(f, "io error: {}", e),
             Self::Panic(msg) => write!(f, "read panicked: {}", msg),
+            Self::Forbidden(msg) => write!(f, "forbidden: {}", msg),
             Self::Custom(msg) => write!(f, "{}", msg),
         }
     }
@@ -71,6 +79,12 @@ pub enum WriteError {
     /// Write operation panicked during execution
     Panic(String),
 
+    /// Conditional write rejected because the stored record changed
+    Conflict(String),
+
+    /// Path resolves outside what the source is allowed to access
+    Forbidden(String),
+
     /// Custom error with a message
     Custom(String),
 }
@@ -84,6 +98,14 @@ impl WriteError {
         matches!(self, Self::Panic(_))
     }
 
+    pub fn is_conflict(&self) -> bool {
+        matches!(self, Self::Conflict(_))
+    }
+
+    pub fn is_forbidden(&self) -> bool {
+        matches!(self, Self::Forbidden(_))
+    }
+
     pub fn is_custom(&self) -> bool {
         matches!(self, Self::Custom(_))
     }
@@ -97,6 +119,11 @@ impl WriteError {
     pub fn panic<S: Into<String>>(msg: S) -> Self {
         Self::Panic(msg.into())
     }
+
+    /// Create a conflict error for a record at `path`
+    pub fn conflict(path: impl std::fmt::Display) -> Self {
+        Self::Conflict(format!("record was modified: {}", path))
+    }
 }
 
 impl std::fmt::Display for WriteError {
@@ -104,6 +131,8 @@ impl std::fmt::Display for WriteError {
         match self {
             Self::IO(e) => write!(f, "io error: {}", e),
             Self::Panic(msg) => write!(f, "write panicked: {}", msg),
+            Self::Conflict(msg) => write!(f, "conflict: {}", msg),
+            Self::Forbidden(msg) => write!(f, "forbidden: {}", msg),
             Self::Custom(msg) => write!(f, "{}", msg),
         }
     }
@@ -123,3 +152,14 @@ impl From<io::Error> for WriteError {
         Self::IO(err)
     }
 }
+
+impl From<ReadError> for WriteError {
+    fn from(err: ReadError) -> Self {
+        match err {
+            ReadError::IO(e) => Self::IO(e),
+            ReadError::Panic(msg) => Self::Panic(msg),
+            ReadError::Forbidden(msg) => Self::Forbidden(msg),
+            ReadError::Custom(msg) => Self::Custom(msg),
+        }
+    }
+}
@@ -16,9 +16,24 @@ impl ETag {
         Self(*hasher.finalize().as_bytes())
     }
 
+    /// A tag derived from this one and `version`, so identical content
+    /// written as different versions still gets distinct tags.
+    pub fn with_version(&self, version: usize) -> Self {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&self.0);
+        hasher.update(b"::");
+        hasher.update(&(version as u64).to_le_bytes());
+        Self(*hasher.finalize().as_bytes())
+    }
+
     pub fn as_bytes(&self) -> &[u8; 32] {
         &self.0
     }
+
+    /// Lowercase hex encoding, e.g. for storing alongside remote objects.
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|b| format!("{:02x}", b)).collect()
+    }
 }
 
 impl std::fmt::Display for ETag {
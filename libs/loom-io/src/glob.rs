@@ -0,0 +1,166 @@
+/// A path pattern used by `DataSource::find_many`.
+///
+/// Patterns and paths are compared one `/`-separated segment at a time:
+///
+/// - `*` matches any run of characters within a segment, including none
+/// - `?` matches exactly one character within a segment
+/// - `**` as a whole segment matches zero or more segments, so
+///   `data/**/*.json` matches `data/a.json` and `data/x/y/a.json`
+/// - every other character matches itself; there are no character classes
+///   or escapes, and leading dots are not special
+///
+/// Neither `*` nor `?` ever matches a `/`. A pattern without wildcards only
+/// matches the identical path.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Glob {
+    pattern: String,
+}
+
+impl Glob {
+    pub fn new(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// `true` if the pattern has no wildcards.
+    pub fn is_literal(&self) -> bool {
+        !self.pattern.contains(['*', '?'])
+    }
+
+    /// The leading segments that contain no wildcards, e.g. `data/evals` for
+    /// `data/evals/**/*.json`. Every matching path lives below this prefix,
+    /// so sources can use it to narrow a listing before matching.
+    pub fn prefix(&self) -> &str {
+        if self.is_literal() {
+            return &self.pattern;
+        }
+
+        let wildcard = self.pattern.find(['*', '?']).unwrap_or_default();
+        match self.pattern[..wildcard].rfind('/') {
+            Some(end) => &self.pattern[..end],
+            None => "",
+        }
+    }
+
+    pub fn is_match(&self, path: &str) -> bool {
+        let pattern: Vec<&str> = self.pattern.split('/').collect();
+        let path: Vec<&str> = path.split('/').collect();
+        match_segments(&pattern, &path)
+    }
+}
+
+impl From<&str> for Glob {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl std::fmt::Display for Glob {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.pattern)
+    }
+}
+
+fn match_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_segments(rest, &path[skip..])),
+        Some((segment, rest)) => match path.split_first() {
+            Some((name, path)) => match_segment(segment, name) && match_segments(rest, path),
+            None => false,
+        },
+    }
+}
+
+/// Match one segment, backtracking to the last `*` on a mismatch.
+fn match_segment(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_star_stays_within_segment() {
+        let glob = Glob::new("data/*.json");
+
+        assert!(glob.is_match("data/a.json"));
+        assert!(glob.is_match("data/.json"));
+        assert!(!glob.is_match("data/a.yaml"));
+        assert!(!glob.is_match("data/x/a.json"));
+        assert!(!glob.is_match("other/a.json"));
+    }
+
+    #[test]
+    fn test_question_mark_matches_one_char() {
+        let glob = Glob::new("sample_?.json");
+
+        assert!(glob.is_match("sample_1.json"));
+        assert!(!glob.is_match("sample_10.json"));
+        assert!(!glob.is_match("sample_.json"));
+    }
+
+    #[test]
+    fn test_double_star_matches_any_depth() {
+        let glob = Glob::new("data/**/*.json");
+
+        assert!(glob.is_match("data/a.json"));
+        assert!(glob.is_match("data/x/a.json"));
+        assert!(glob.is_match("data/x/y/a.json"));
+        assert!(!glob.is_match("data/x/a.yaml"));
+
+        assert!(Glob::new("**").is_match("a/b/c"));
+        assert!(Glob::new("data/**").is_match("data/x/y"));
+    }
+
+    #[test]
+    fn test_literal_pattern() {
+        let glob = Glob::new("data/a.json");
+
+        assert!(glob.is_literal());
+        assert!(glob.is_match("data/a.json"));
+        assert!(!glob.is_match("data/a.json.bak"));
+    }
+
+    #[test]
+    fn test_prefix() {
+        assert_eq!(Glob::new("data/evals/**/*.json").prefix(), "data/evals");
+        assert_eq!(Glob::new("data/sample_?.json").prefix(), "data");
+        assert_eq!(Glob::new("/tmp/*.json").prefix(), "/tmp");
+        assert_eq!(Glob::new("*.json").prefix(), "");
+        assert_eq!(Glob::new("data/a.json").prefix(), "data/a.json");
+    }
+}
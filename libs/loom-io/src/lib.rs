@@ -1,25 +1,35 @@
 mod document;
+mod encoding;
 mod entity;
 mod error;
 mod etag;
+mod glob;
 mod record;
 mod registry;
 pub mod sources;
 
 pub use document::*;
+pub use encoding::*;
 pub use entity::*;
 pub use error::*;
 pub use etag::*;
+pub use glob::*;
 pub use record::*;
 pub use registry::*;
 
 // Re-export loom-core types for convenience
-pub use loom_core::{Format, Id, MediaType, path, value};
+pub use loom_core::{ContentType, Format, Id, IdStrategy, MediaType, path, value};
+
+use std::pin::Pin;
 
 use async_trait::async_trait;
+use futures::Stream;
 
 use crate::path::Path;
 
+/// Records yielded by `DataSource::watch` as they change.
+pub type RecordStream = Pin<Box<dyn Stream<Item = Record> + Send>>;
+
 #[async_trait]
 pub trait DataSource: Send + Sync {
     fn name(&self) -> &str;
@@ -29,8 +39,57 @@ pub trait DataSource: Send + Sync {
     async fn find_one(&self, path: &Path) -> Result<Record, ReadError>;
     async fn find(&self, path: &Path) -> Result<Vec<Record>, ReadError>;
 
+    /// Find every record whose path matches a glob pattern (see `Glob`),
+    /// ordered by path. Sources that cannot list their records only accept
+    /// literal patterns, returning the single record if it exists.
+    async fn find_many(&self, pattern: &Path) -> Result<Vec<Record>, ReadError> {
+        if !Glob::new(pattern.to_string()).is_literal() {
+            return Err(ReadError::Custom(format!(
+                "{} does not support glob patterns",
+                self.name()
+            )));
+        }
+
+        if !self.exists(pattern).await? {
+            return Ok(Vec::new());
+        }
+
+        Ok(vec![self.find_one(pattern).await?])
+    }
+
+    /// Yield the record at `path` each time it is created or changes, for
+    /// hot-reloading. The stream ends when the source stops watching.
+    /// Sources that cannot watch return an error.
+    fn watch(&self, path: &Path) -> Result<RecordStream, ReadError> {
+        Err(ReadError::Custom(format!(
+            "{} does not support watching {}",
+            self.name(),
+            path
+        )))
+    }
+
     async fn create(&self, record: Record) -> Result<(), WriteError>;
     async fn update(&self, record: Record) -> Result<(), WriteError>;
     async fn upsert(&self, record: Record) -> Result<(), WriteError>;
+
+    /// Upsert `record` only if the stored record at its path still has
+    /// `etag`, typically the `etag` of a record read earlier, so concurrent
+    /// writers don't overwrite each other. Fails with `WriteError::Conflict`
+    /// if the stored record changed or no longer exists.
+    ///
+    /// The default reads and then writes, which is not atomic; sources
+    /// override it where the backend can check and write in one step.
+    async fn upsert_if_match(&self, record: Record, etag: ETag) -> Result<(), WriteError> {
+        if !self.exists(&record.path).await? {
+            return Err(WriteError::conflict(&record.path));
+        }
+
+        if self.find_one(&record.path).await?.etag != etag {
+            return Err(WriteError::conflict(&record.path));
+        }
+
+        self.upsert(record).await
+    }
+
     async fn delete(&self, path: &Path) -> Result<(), WriteError>;
 }
@@ -1,6 +1,9 @@
+use std::borrow::Cow;
+use std::io;
+
 use loom_core::Id;
 
-use crate::{ETag, MediaType, path::Path};
+use crate::{ETag, Encoding, MediaType, path::Path};
 
 #[derive(Debug, Clone, Hash, serde::Deserialize, serde::Serialize)]
 pub struct Record {
@@ -10,6 +13,11 @@ pub struct Record {
     pub size: usize,
     pub media_type: MediaType,
     pub content: Vec<u8>,
+
+    /// Compression of `content`, `None` if stored as is. `content_bytes`
+    /// and `content_str` decompress it transparently.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_encoding: Option<Encoding>,
 }
 
 impl Record {
@@ -21,15 +29,43 @@ impl Record {
             path,
             media_type,
             content,
+            content_encoding: None,
         }
     }
 
+    /// Mark `content` as compressed with `encoding`.
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.content_encoding = Some(encoding);
+        self
+    }
+
     pub fn from_str(path: Path, media_type: MediaType, content: &str) -> Self {
         Self::new(path, media_type, content.as_bytes().to_vec())
     }
 
-    pub fn content_str(&self) -> Result<&str, std::str::Utf8Error> {
-        std::str::from_utf8(&self.content)
+    /// The content, decompressed according to `content_encoding`.
+    pub fn content_bytes(&self) -> io::Result<Cow<'_, [u8]>> {
+        match self.content_encoding {
+            Some(encoding) if !encoding.is_identity() => {
+                Ok(Cow::Owned(encoding.decode(&self.content)?))
+            }
+            _ => Ok(Cow::Borrowed(&self.content)),
+        }
+    }
+
+    /// The decompressed content as UTF-8, failing with
+    /// `io::ErrorKind::InvalidData` if it isn't valid UTF-8.
+    pub fn content_str(&self) -> io::Result<Cow<'_, str>> {
+        let invalid = |e| io::Error::new(io::ErrorKind::InvalidData, e);
+
+        match self.content_bytes()? {
+            Cow::Borrowed(bytes) => std::str::from_utf8(bytes)
+                .map(Cow::Borrowed)
+                .map_err(invalid),
+            Cow::Owned(bytes) => String::from_utf8(bytes)
+                .map(Cow::Owned)
+                .map_err(|e| invalid(e.utf8_error())),
+        }
     }
 }
 
@@ -76,6 +112,40 @@ mod tests {
         assert_eq!(record.content_str().unwrap(), "hello world");
     }
 
+    #[test]
+    fn test_record_identity_encoding_borrows() {
+        let path = Path::File(FilePath::parse("/test/file.txt"));
+        let record =
+            Record::from_str(path, MediaType::TextPlain, "hello").with_encoding(Encoding::Identity);
+
+        assert!(matches!(
+            record.content_str().unwrap(),
+            Cow::Borrowed("hello")
+        ));
+    }
+
+    #[test]
+    fn test_record_invalid_utf8() {
+        let path = Path::File(FilePath::parse("/test/file.txt"));
+        let record = Record::new(path, MediaType::TextPlain, vec![0xff, 0xfe]);
+
+        let err = record.content_str().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_record_gzip_content_str() {
+        let path = Path::File(FilePath::parse("/test/file.json.gz"));
+        let content = Encoding::Gzip.encode(b"{\"key\": \"value\"}").unwrap();
+        let record =
+            Record::new(path, MediaType::TextJson, content.clone()).with_encoding(Encoding::Gzip);
+
+        assert_eq!(record.content, content);
+        assert_eq!(record.content_str().unwrap(), "{\"key\": \"value\"}");
+        assert_eq!(&*record.content_bytes().unwrap(), b"{\"key\": \"value\"}");
+    }
+
     #[test]
     fn test_record_equality() {
         let path = Path::File(FilePath::parse("/test/file.txt"));
@@ -1,18 +1,26 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 
 use async_trait::async_trait;
 
 use crate::MediaType;
-use crate::path::Path;
+use crate::path::{FilePath, Path};
 
-use crate::{DataSource, Id, ReadError, Record, WriteError};
+#[cfg(feature = "watch")]
+use crate::RecordStream;
+use crate::{DataSource, ETag, Glob, Id, ReadError, Record, WriteError};
+
+/// Default number of threads `FileSystemSource` uses to walk directories
+/// and read files.
+pub const DEFAULT_FILE_SYSTEM_CONCURRENCY: usize = 8;
 
 #[derive(Debug, Clone)]
 pub struct FileSystemSourceConfig {
     path: PathBuf,
     name: String,
+    sandbox: bool,
+    concurrency: usize,
 }
 
 impl FileSystemSourceConfig {
@@ -23,12 +31,22 @@ impl FileSystemSourceConfig {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    pub fn sandbox(&self) -> bool {
+        self.sandbox
+    }
+
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct FileSystemSourceBuilder {
     path: PathBuf,
     name: Option<String>,
+    sandbox: bool,
+    concurrency: usize,
 }
 
 impl FileSystemSourceBuilder {
@@ -36,6 +54,8 @@ impl FileSystemSourceBuilder {
         Self {
             path: PathBuf::from("."),
             name: None,
+            sandbox: true,
+            concurrency: DEFAULT_FILE_SYSTEM_CONCURRENCY,
         }
     }
 
@@ -49,13 +69,37 @@ impl FileSystemSourceBuilder {
         self
     }
 
+    /// Reject paths that resolve outside the root directory (default
+    /// `true`). Disable only when callers are trusted to read anywhere.
+    pub fn sandbox(mut self, sandbox: bool) -> Self {
+        self.sandbox = sandbox;
+        self
+    }
+
+    /// Set how many threads walk directories and read files at once
+    /// (default `DEFAULT_FILE_SYSTEM_CONCURRENCY`, minimum 1).
+    pub fn concurrency(mut self, limit: usize) -> Self {
+        self.concurrency = limit.max(1);
+        self
+    }
+
     pub fn build(self) -> FileSystemSource {
+        let root = match self.sandbox {
+            true => std::path::absolute(&self.path)
+                .map(|path| FilePath::from(path).normalize().to_path_buf())
+                .unwrap_or_else(|_| self.path.clone()),
+            false => self.path.clone(),
+        };
+
         FileSystemSource {
+            root,
             config: FileSystemSourceConfig {
                 path: self.path,
                 name: self.name.unwrap_or_else(|| "file_system".to_string()),
+                sandbox: self.sandbox,
+                concurrency: self.concurrency,
             },
-            cache: RwLock::new(HashMap::new()),
+            cache: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 }
@@ -66,9 +110,23 @@ impl Default for FileSystemSourceBuilder {
     }
 }
 
+/// A `DataSource` backed by files under a root directory.
+///
+/// Relative paths resolve against the root. Unless the sandbox is turned
+/// off, any path (relative or absolute) that normalizes to somewhere
+/// outside the root, such as `../../etc/passwd`, fails with
+/// `ReadError::Forbidden` / `WriteError::Forbidden`. The check is lexical,
+/// so symlinks inside the root are still followed.
+///
+/// With the `watch` feature, `watch` follows a file (or every file below a
+/// directory) and yields the new record whenever it is written, refreshing
+/// the cache so later `find_one` calls see the same content.
 pub struct FileSystemSource {
     config: FileSystemSourceConfig,
-    cache: RwLock<HashMap<Id, Record>>,
+    /// The directory full paths are built under and record paths are made
+    /// relative to: absolute and normalized when sandboxed.
+    root: PathBuf,
+    cache: Arc<RwLock<HashMap<Id, Record>>>,
 }
 
 impl FileSystemSource {
@@ -81,37 +139,129 @@ impl FileSystemSource {
     }
 
     fn full_path(&self, path: &Path) -> Result<PathBuf, ReadError> {
-        match path {
-            Path::File(file_path) => {
-                let path_buf: &std::path::Path = file_path;
-                if path_buf.is_absolute() {
-                    Ok(path_buf.to_path_buf())
-                } else {
-                    Ok(self.config.path.join(path_buf))
-                }
-            }
-            _ => Err(ReadError::Custom(
+        let Path::File(file_path) = path else {
+            return Err(ReadError::Custom(
                 "FileSystemSource only supports File paths".to_string(),
-            )),
+            ));
+        };
+
+        let full_path = self.root.join(&**file_path);
+        if !self.config.sandbox {
+            return Ok(full_path);
+        }
+
+        let root = FilePath::from(self.root.clone());
+        let full_path = FilePath::from(std::path::absolute(&full_path)?).normalize();
+
+        if !full_path.is_within(&root) {
+            return Err(ReadError::Forbidden(format!(
+                "path is outside {}: {}",
+                self.config.path.display(),
+                path
+            )));
         }
+
+        Ok(full_path.to_path_buf())
     }
 
+    /// Every file below `dir_path`, in no particular order. Each level of
+    /// the tree is listed in parallel.
     fn list_files(&self, dir_path: &std::path::Path) -> Result<Vec<PathBuf>, ReadError> {
         let mut files = Vec::new();
+        let mut dirs = Vec::new();
+
         if dir_path.is_dir() {
-            for entry in std::fs::read_dir(dir_path)? {
-                let entry = entry?;
-                let entry_path = entry.path();
-                if entry_path.is_file() {
-                    files.push(entry_path);
-                } else if entry_path.is_dir() {
-                    files.extend(self.list_files(&entry_path)?);
+            dirs.push(dir_path.to_path_buf());
+        }
+
+        while !dirs.is_empty() {
+            let listed = self.par_map(&dirs, |dir| -> Result<_, ReadError> {
+                let mut files = Vec::new();
+                let mut dirs = Vec::new();
+
+                for entry in std::fs::read_dir(dir)? {
+                    let entry_path = entry?.path();
+                    if entry_path.is_file() {
+                        files.push(entry_path);
+                    } else if entry_path.is_dir() {
+                        dirs.push(entry_path);
+                    }
                 }
+
+                Ok((files, dirs))
+            });
+
+            dirs = Vec::new();
+            for entry in listed {
+                let (level_files, level_dirs) = entry?;
+                files.extend(level_files);
+                dirs.extend(level_dirs);
             }
         }
+
         Ok(files)
     }
 
+    /// Apply `f` to every item on up to `concurrency` threads, returning
+    /// the results in the order of `items`.
+    fn par_map<T, R, F>(&self, items: &[T], f: F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Sync,
+    {
+        let threads = self.config.concurrency.min(items.len());
+        if threads <= 1 {
+            return items.iter().map(f).collect();
+        }
+
+        let chunk_size = items.len().div_ceil(threads);
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = items
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(|| chunk.iter().map(&f).collect::<Vec<_>>()))
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| match handle.join() {
+                    Ok(results) => results,
+                    Err(panic) => std::panic::resume_unwind(panic),
+                })
+                .collect()
+        })
+    }
+
+    /// Read a record, going through the cache.
+    fn read(&self, path: &Path) -> Result<Record, ReadError> {
+        let full_path = self.full_path(path)?;
+        let id = Id::new(path.to_string().as_str());
+
+        {
+            let cache = self
+                .cache
+                .read()
+                .map_err(|e| ReadError::Panic(e.to_string()))?;
+            if let Some(record) = cache.get(&id) {
+                return Ok(record.clone());
+            }
+        }
+
+        let content = std::fs::read(&full_path)?;
+        let media_type = MediaType::from_path(&full_path);
+        let record = Record::new(path.clone(), media_type, content);
+
+        {
+            let mut cache = self
+                .cache
+                .write()
+                .map_err(|e| ReadError::Panic(e.to_string()))?;
+            cache.insert(id, record.clone());
+        }
+
+        Ok(record)
+    }
+
     pub fn clear(&self) -> Result<(), ReadError> {
         let mut cache = self
             .cache
@@ -152,32 +302,7 @@ impl DataSource for FileSystemSource {
     }
 
     async fn find_one(&self, path: &Path) -> Result<Record, ReadError> {
-        let id = Id::new(path.to_string().as_str());
-
-        {
-            let cache = self
-                .cache
-                .read()
-                .map_err(|e| ReadError::Panic(e.to_string()))?;
-            if let Some(record) = cache.get(&id) {
-                return Ok(record.clone());
-            }
-        }
-
-        let full_path = self.full_path(path)?;
-        let content = std::fs::read(&full_path)?;
-        let media_type = MediaType::from_path(&full_path);
-        let record = Record::new(path.clone(), media_type, content);
-
-        {
-            let mut cache = self
-                .cache
-                .write()
-                .map_err(|e| ReadError::Panic(e.to_string()))?;
-            cache.insert(id, record.clone());
-        }
-
-        Ok(record)
+        self.read(path)
     }
 
     async fn find(&self, path: &Path) -> Result<Vec<Record>, ReadError> {
@@ -191,9 +316,7 @@ impl DataSource for FileSystemSource {
             let files = self.list_files(&full_path)?;
             let mut records = Vec::new();
             for file_path in files {
-                let relative = file_path
-                    .strip_prefix(&self.config.path)
-                    .unwrap_or(&file_path);
+                let relative = file_path.strip_prefix(&self.root).unwrap_or(&file_path);
                 let path = Path::File(crate::path::FilePath::parse(
                     relative.to_str().unwrap_or(""),
                 ));
@@ -205,12 +328,140 @@ impl DataSource for FileSystemSource {
         Ok(Vec::new())
     }
 
+    /// Relative patterns are matched against paths relative to the source
+    /// root, and absolute patterns against absolute paths. Only the
+    /// directory named by the pattern's literal prefix is walked. The walk
+    /// and the reads use up to `concurrency` threads; records are returned
+    /// sorted by path either way.
+    async fn find_many(&self, pattern: &Path) -> Result<Vec<Record>, ReadError> {
+        let Path::File(file_pattern) = pattern else {
+            return Err(ReadError::Custom(
+                "FileSystemSource only supports File paths".to_string(),
+            ));
+        };
+
+        let glob = Glob::new(file_pattern.to_string());
+        if glob.is_literal() {
+            return match self.full_path(pattern)?.is_file() {
+                true => Ok(vec![self.find_one(pattern).await?]),
+                false => Ok(Vec::new()),
+            };
+        }
+
+        let base = self.full_path(&Path::File(FilePath::parse(glob.prefix())))?;
+        let mut paths = Vec::new();
+
+        for file_path in self.list_files(&base)? {
+            let candidate = if file_pattern.is_absolute() {
+                file_path.as_path()
+            } else {
+                file_path.strip_prefix(&self.root).unwrap_or(&file_path)
+            };
+
+            if let Some(candidate) = candidate.to_str()
+                && glob.is_match(candidate)
+            {
+                paths.push(candidate.to_string());
+            }
+        }
+
+        paths.sort();
+
+        self.par_map(&paths, |path| self.read(&Path::File(FilePath::parse(path))))
+            .into_iter()
+            .collect()
+    }
+
+    /// Watches the parent directory of a file, so editors that save by
+    /// renaming a new file into place are still followed. Removals are not
+    /// reported, and repeated events for unchanged content are skipped.
+    #[cfg(feature = "watch")]
+    fn watch(&self, path: &Path) -> Result<RecordStream, ReadError> {
+        use notify::{EventKind, RecursiveMode, Watcher};
+
+        let full_path = self.full_path(path)?;
+        let Path::File(file_path) = path else {
+            unreachable!("full_path only accepts File paths");
+        };
+
+        let (dir, mode, target) = if full_path.is_dir() {
+            (full_path.canonicalize()?, RecursiveMode::Recursive, None)
+        } else {
+            let name = full_path
+                .file_name()
+                .ok_or_else(|| ReadError::Custom(format!("cannot watch {}", path)))?;
+            let parent = match full_path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent,
+                _ => std::path::Path::new("."),
+            };
+            let parent = parent.canonicalize()?;
+            let target = parent.join(name);
+
+            (parent, RecursiveMode::NonRecursive, Some(target))
+        };
+
+        let (sender, receiver) = futures::channel::mpsc::unbounded();
+        let cache = self.cache.clone();
+        let watched = file_path.to_path_buf();
+        let root = dir.clone();
+        let mut seen: HashMap<PathBuf, ETag> = HashMap::new();
+
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+                let Ok(event) = event else {
+                    return;
+                };
+
+                if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                    return;
+                }
+
+                for changed in event.paths {
+                    // Map the event back to a path in the caller's form
+                    let record_path = match &target {
+                        Some(target) if changed == *target => watched.clone(),
+                        Some(_) => continue,
+                        None => match changed.strip_prefix(&root) {
+                            Ok(relative) if changed.is_file() => watched.join(relative),
+                            _ => continue,
+                        },
+                    };
+
+                    let Ok(content) = std::fs::read(&changed) else {
+                        continue;
+                    };
+
+                    let record = Record::new(
+                        Path::File(FilePath::from(record_path)),
+                        MediaType::from_path(&changed),
+                        content,
+                    );
+
+                    if seen.get(&changed) == Some(&record.etag) {
+                        continue;
+                    }
+
+                    seen.insert(changed, record.etag);
+
+                    if let Ok(mut cache) = cache.write() {
+                        cache.insert(record.id, record.clone());
+                    }
+
+                    let _ = sender.unbounded_send(record);
+                }
+            })
+            .map_err(ReadError::custom)?;
+
+        watcher.watch(&dir, mode).map_err(ReadError::custom)?;
+
+        Ok(Box::pin(WatchStream {
+            receiver,
+            _watcher: watcher,
+        }))
+    }
+
     async fn create(&self, record: Record) -> Result<(), WriteError> {
-        let full_path = self.full_path(&record.path).map_err(|e| match e {
-            ReadError::Custom(msg) => WriteError::Custom(msg),
-            ReadError::IO(io) => WriteError::IO(io),
-            ReadError::Panic(msg) => WriteError::Panic(msg),
-        })?;
+        let full_path = self.full_path(&record.path)?;
 
         if full_path.exists() {
             return Err(WriteError::Custom(format!(
@@ -238,11 +489,7 @@ impl DataSource for FileSystemSource {
     }
 
     async fn update(&self, record: Record) -> Result<(), WriteError> {
-        let full_path = self.full_path(&record.path).map_err(|e| match e {
-            ReadError::Custom(msg) => WriteError::Custom(msg),
-            ReadError::IO(io) => WriteError::IO(io),
-            ReadError::Panic(msg) => WriteError::Panic(msg),
-        })?;
+        let full_path = self.full_path(&record.path)?;
 
         if !full_path.exists() {
             return Err(WriteError::Custom(format!(
@@ -266,11 +513,7 @@ impl DataSource for FileSystemSource {
     }
 
     async fn upsert(&self, record: Record) -> Result<(), WriteError> {
-        let full_path = self.full_path(&record.path).map_err(|e| match e {
-            ReadError::Custom(msg) => WriteError::Custom(msg),
-            ReadError::IO(io) => WriteError::IO(io),
-            ReadError::Panic(msg) => WriteError::Panic(msg),
-        })?;
+        let full_path = self.full_path(&record.path)?;
 
         if let Some(parent) = full_path.parent() {
             std::fs::create_dir_all(parent)?;
@@ -290,12 +533,29 @@ impl DataSource for FileSystemSource {
         Ok(())
     }
 
+    /// Compares against the file on disk rather than the cache, so changes
+    /// made by other writers are detected. The check and the write are not
+    /// atomic.
+    async fn upsert_if_match(&self, record: Record, etag: ETag) -> Result<(), WriteError> {
+        let full_path = self.full_path(&record.path)?;
+
+        let stored = match std::fs::read(&full_path) {
+            Ok(content) => ETag::from_bytes(MediaType::from_path(&full_path), &content),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(WriteError::conflict(&record.path));
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        if stored != etag {
+            return Err(WriteError::conflict(&record.path));
+        }
+
+        self.upsert(record).await
+    }
+
     async fn delete(&self, path: &Path) -> Result<(), WriteError> {
-        let full_path = self.full_path(path).map_err(|e| match e {
-            ReadError::Custom(msg) => WriteError::Custom(msg),
-            ReadError::IO(io) => WriteError::IO(io),
-            ReadError::Panic(msg) => WriteError::Panic(msg),
-        })?;
+        let full_path = self.full_path(path)?;
 
         if !full_path.exists() {
             return Err(WriteError::Custom(format!("file not found: {}", path)));
@@ -311,10 +571,28 @@ impl DataSource for FileSystemSource {
     }
 }
 
+/// Keeps the watcher alive for as long as the stream is held.
+#[cfg(feature = "watch")]
+struct WatchStream {
+    receiver: futures::channel::mpsc::UnboundedReceiver<Record>,
+    _watcher: notify::RecommendedWatcher,
+}
+
+#[cfg(feature = "watch")]
+impl futures::Stream for WatchStream {
+    type Item = Record;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Record>> {
+        std::pin::Pin::new(&mut self.receiver).poll_next(cx)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::path::FilePath;
     use std::env::temp_dir;
 
     fn test_dir() -> PathBuf {
@@ -464,7 +742,7 @@ mod tests {
     #[tokio::test]
     async fn test_delete_not_found() {
         let ds = test_source();
-        let path = Path::File(FilePath::parse("/nonexistent/file.txt"));
+        let path = Path::File(FilePath::parse("nonexistent/file.txt"));
 
         let result = ds.delete(&path).await;
         assert!(result.is_err());
@@ -488,16 +766,315 @@ mod tests {
         let _ = std::fs::remove_file(&file_path);
     }
 
+    #[tokio::test]
+    async fn test_upsert_if_match_conflicts_after_other_write() {
+        let dir = test_dir();
+        std::fs::create_dir_all(&dir).unwrap();
+        let file_path = dir.join("if_match.txt");
+        let path = Path::File(FilePath::parse(file_path.to_str().unwrap()));
+        std::fs::write(&file_path, "v1").unwrap();
+
+        let first = test_source();
+        let second = test_source();
+
+        let read = first.find_one(&path).await.unwrap();
+        second.upsert(make_record(&path, "v2")).await.unwrap();
+
+        let err = first
+            .upsert_if_match(make_record(&path, "v3"), read.etag)
+            .await
+            .unwrap_err();
+        assert!(err.is_conflict(), "{}", err);
+        assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "v2");
+
+        // Writing against the current version succeeds
+        let current = second.find_one(&path).await.unwrap();
+        first
+            .upsert_if_match(make_record(&path, "v3"), current.etag)
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "v3");
+
+        let _ = std::fs::remove_file(&file_path);
+    }
+
+    #[tokio::test]
+    async fn test_traversal_outside_root_is_forbidden() {
+        let root = test_dir().join("sandbox");
+        std::fs::create_dir_all(root.join("data")).unwrap();
+        std::fs::write(test_dir().join("secret.txt"), "secret").unwrap();
+        std::fs::write(root.join("data/ok.txt"), "ok").unwrap();
+
+        let ds = FileSystemSource::builder().path(&root).build();
+
+        let escape = Path::File(FilePath::parse("data/../../secret.txt"));
+        assert!(ds.find_one(&escape).await.unwrap_err().is_forbidden());
+        assert!(ds.exists(&escape).await.unwrap_err().is_forbidden());
+
+        let absolute = Path::File(FilePath::parse(
+            test_dir().join("secret.txt").to_str().unwrap(),
+        ));
+        assert!(ds.find_one(&absolute).await.unwrap_err().is_forbidden());
+
+        let write = make_record(&escape, "overwritten");
+        assert!(ds.upsert(write).await.unwrap_err().is_forbidden());
+        assert_eq!(
+            std::fs::read_to_string(test_dir().join("secret.txt")).unwrap(),
+            "secret"
+        );
+
+        let inside = Path::File(FilePath::parse("data/./../data/ok.txt"));
+        assert_eq!(
+            ds.find_one(&inside).await.unwrap().content_str().unwrap(),
+            "ok"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sandbox_can_be_disabled() {
+        let root = test_dir().join("unsandboxed");
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(test_dir().join("shared.txt"), "shared").unwrap();
+
+        let ds = FileSystemSource::builder()
+            .path(&root)
+            .sandbox(false)
+            .build();
+
+        let outside = Path::File(FilePath::parse("../shared.txt"));
+        assert_eq!(
+            ds.find_one(&outside).await.unwrap().content_str().unwrap(),
+            "shared"
+        );
+    }
+
     #[tokio::test]
     async fn test_find_one_not_found() {
         let ds = test_source();
-        let path = Path::File(FilePath::parse("/nonexistent/file.txt"));
+        let path = Path::File(FilePath::parse("nonexistent/file.txt"));
 
         let result = ds.find_one(&path).await;
         assert!(result.is_err());
         assert!(result.unwrap_err().is_io());
     }
 
+    /// A fresh tree under the temp dir:
+    ///
+    /// ```text
+    /// a.json, b.yaml, sample_1.json, sample_10.json,
+    /// nested/c.json, nested/deeper/d.json
+    /// ```
+    fn glob_source(name: &str) -> FileSystemSource {
+        FileSystemSource::builder().path(glob_dir(name)).build()
+    }
+
+    fn glob_dir(name: &str) -> PathBuf {
+        let dir = temp_dir().join(format!("loom_file_system_glob_{}", name));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("nested/deeper")).unwrap();
+
+        for file in [
+            "a.json",
+            "b.yaml",
+            "sample_1.json",
+            "sample_10.json",
+            "nested/c.json",
+            "nested/deeper/d.json",
+        ] {
+            std::fs::write(dir.join(file), "{}").unwrap();
+        }
+
+        dir
+    }
+
+    /// `path` spelled relative to the current directory, e.g.
+    /// `../../../tmp/x` for `/tmp/x`.
+    fn relative_to_cwd(path: &std::path::Path) -> PathBuf {
+        let cwd = std::env::current_dir().unwrap();
+        let mut relative = PathBuf::new();
+
+        for _ in cwd.components().skip(1) {
+            relative.push("..");
+        }
+
+        relative.join(path.strip_prefix("/").unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_relative_root_yields_relative_paths() {
+        let root = relative_to_cwd(&glob_dir("relative_root"));
+        assert!(root.is_relative());
+
+        let ds = FileSystemSource::builder().path(root).build();
+
+        let mut found: Vec<_> = ds
+            .find(&Path::File(FilePath::parse("nested")))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|r| r.path.to_string())
+            .collect();
+        found.sort();
+        assert_eq!(found, vec!["nested/c.json", "nested/deeper/d.json"]);
+
+        assert_eq!(
+            find_many_paths(&ds, "*.json").await,
+            vec!["a.json", "sample_1.json", "sample_10.json"]
+        );
+    }
+
+    async fn find_many_paths(ds: &FileSystemSource, pattern: &str) -> Vec<String> {
+        ds.find_many(&Path::File(FilePath::parse(pattern)))
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|r| r.path.to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_find_many_star() {
+        let ds = glob_source("star");
+
+        assert_eq!(
+            find_many_paths(&ds, "*.json").await,
+            vec!["a.json", "sample_1.json", "sample_10.json"]
+        );
+        assert_eq!(
+            find_many_paths(&ds, "nested/*.json").await,
+            vec!["nested/c.json"]
+        );
+        assert!(find_many_paths(&ds, "*.csv").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_many_question_mark() {
+        let ds = glob_source("question_mark");
+
+        assert_eq!(
+            find_many_paths(&ds, "sample_?.json").await,
+            vec!["sample_1.json"]
+        );
+        assert_eq!(
+            find_many_paths(&ds, "sample_??.json").await,
+            vec!["sample_10.json"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_find_many_double_star() {
+        let ds = glob_source("double_star");
+
+        assert_eq!(
+            find_many_paths(&ds, "**/*.json").await,
+            vec![
+                "a.json",
+                "nested/c.json",
+                "nested/deeper/d.json",
+                "sample_1.json",
+                "sample_10.json"
+            ]
+        );
+        assert_eq!(
+            find_many_paths(&ds, "nested/**").await,
+            vec!["nested/c.json", "nested/deeper/d.json"]
+        );
+
+        let records = ds
+            .find_many(&Path::File(FilePath::parse("nested/**/d.json")))
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].media_type, MediaType::TextJson);
+    }
+
+    #[tokio::test]
+    async fn test_find_many_parallel_is_complete_and_sorted() {
+        let dir = temp_dir().join("loom_file_system_glob_parallel");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let mut expected = Vec::new();
+        for d in 0..10 {
+            for n in 0..50 {
+                let path = format!("shard_{}/sub_{}/item_{}.json", d, n % 5, n);
+                std::fs::create_dir_all(dir.join(&path).parent().unwrap()).unwrap();
+                std::fs::write(dir.join(&path), format!("{{\"n\":{}}}", n)).unwrap();
+                expected.push(path);
+            }
+        }
+        std::fs::write(dir.join("shard_0/skip.txt"), "").unwrap();
+        expected.sort();
+
+        let sequential = FileSystemSource::builder()
+            .path(&dir)
+            .concurrency(1)
+            .build();
+        let parallel = FileSystemSource::builder()
+            .path(&dir)
+            .concurrency(16)
+            .build();
+
+        let paths = find_many_paths(&parallel, "**/*.json").await;
+        assert_eq!(paths.len(), 500);
+        assert_eq!(paths, expected);
+        assert_eq!(find_many_paths(&parallel, "**/*.json").await, paths);
+        assert_eq!(find_many_paths(&sequential, "**/*.json").await, paths);
+
+        let records = parallel
+            .find_many(&Path::File(FilePath::parse("shard_3/**/item_7.json")))
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].content_str().unwrap(), r#"{"n":7}"#);
+    }
+
+    #[tokio::test]
+    async fn test_find_many_literal() {
+        let ds = glob_source("literal");
+
+        assert_eq!(find_many_paths(&ds, "b.yaml").await, vec!["b.yaml"]);
+        assert!(find_many_paths(&ds, "missing.yaml").await.is_empty());
+    }
+
+    #[cfg(feature = "watch")]
+    #[tokio::test]
+    async fn test_watch_yields_updated_record() {
+        use futures::StreamExt;
+        use std::time::Duration;
+
+        let dir = temp_dir().join("loom_file_system_watch");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("config.json"), r#"{"v":1}"#).unwrap();
+
+        let ds = FileSystemSource::builder().path(&dir).build();
+        let path = Path::File(FilePath::parse("config.json"));
+        assert_eq!(
+            ds.find_one(&path).await.unwrap().content_str().unwrap(),
+            r#"{"v":1}"#
+        );
+
+        let mut stream = ds.watch(&path).unwrap();
+        std::fs::write(dir.join("other.json"), "{}").unwrap();
+        std::fs::write(dir.join("config.json"), r#"{"v":2}"#).unwrap();
+
+        // A single write may surface as several events (e.g. truncate, then write)
+        let record = tokio::time::timeout(Duration::from_secs(10), async {
+            loop {
+                let record = stream.next().await.unwrap();
+                if record.content_str().unwrap() == r#"{"v":2}"# {
+                    return record;
+                }
+            }
+        })
+        .await
+        .expect("watched file change was not reported");
+
+        assert_eq!(record.path, path);
+        assert_eq!(record.media_type, MediaType::TextJson);
+        assert_eq!(ds.find_one(&path).await.unwrap(), record);
+    }
+
     #[test]
     fn test_builder() {
         let ds = FileSystemSource::builder()
@@ -515,5 +1092,6 @@ mod tests {
 
         assert_eq!(ds.config().path(), std::path::Path::new("."));
         assert_eq!(ds.config().name(), "file_system");
+        assert_eq!(ds.config().concurrency(), DEFAULT_FILE_SYSTEM_CONCURRENCY);
     }
 }
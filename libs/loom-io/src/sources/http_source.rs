@@ -0,0 +1,402 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Method, StatusCode, Url};
+
+use crate::path::{Path, Scheme};
+use crate::{ContentType, MediaType};
+
+use crate::{DataSource, Encoding, ReadError, Record, WriteError};
+
+#[derive(Debug, Clone)]
+pub struct HttpSourceConfig {
+    name: String,
+    base_url: Option<String>,
+    headers: BTreeMap<String, String>,
+    timeout: Option<Duration>,
+}
+
+impl HttpSourceConfig {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn base_url(&self) -> Option<&str> {
+        self.base_url.as_deref()
+    }
+
+    pub fn headers(&self) -> &BTreeMap<String, String> {
+        &self.headers
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpSourceBuilder {
+    name: Option<String>,
+    base_url: Option<String>,
+    headers: BTreeMap<String, String>,
+    timeout: Option<Duration>,
+}
+
+impl HttpSourceBuilder {
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            base_url: None,
+            headers: BTreeMap::new(),
+            timeout: None,
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Resolve `File` paths against this URL, e.g. `data/a.json` with
+    /// `https://example.com/evals/` fetches `https://example.com/evals/data/a.json`.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Send a header with every request, e.g. `authorization`.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Fails if the HTTP client can't be created, e.g. when the TLS backend
+    /// or system proxy settings fail to load.
+    pub fn build(self) -> Result<HttpSource, ReadError> {
+        let mut client = reqwest::Client::builder();
+        if let Some(timeout) = self.timeout {
+            client = client.timeout(timeout);
+        }
+
+        Ok(HttpSource {
+            config: HttpSourceConfig {
+                name: self.name.unwrap_or_else(|| "http".to_string()),
+                base_url: self.base_url,
+                headers: self.headers,
+                timeout: self.timeout,
+            },
+            client: client.build().map_err(ReadError::custom)?,
+        })
+    }
+}
+
+impl Default for HttpSourceBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A read-only `DataSource` that fetches records over HTTP(S).
+///
+/// `find_one` issues a `GET` for an `http://` or `https://` `UriPath` (or a
+/// `File` path joined to `base_url`, which may not resolve outside it, e.g.
+/// through `..`) and takes the record's `media_type`
+/// from the response `Content-Type`, falling back to the URL's extension.
+/// Non-2xx responses are errors carrying the status. All write operations
+/// return a `WriteError`.
+///
+/// # Example
+/// ```ignore
+/// let runtime = Runtime::new().source(HttpSource::builder().build()?).build();
+///
+/// let path = Path::Uri(UriPath::parse("https://example.com/config.json")?);
+/// let config: Value = runtime.load("http", &path).await?;
+/// ```
+pub struct HttpSource {
+    config: HttpSourceConfig,
+    client: reqwest::Client,
+}
+
+impl HttpSource {
+    pub fn builder() -> HttpSourceBuilder {
+        HttpSourceBuilder::new()
+    }
+
+    pub fn config(&self) -> &HttpSourceConfig {
+        &self.config
+    }
+
+    fn url(&self, path: &Path) -> Result<Url, ReadError> {
+        match path {
+            Path::Uri(uri) if matches!(uri.scheme, Scheme::Http | Scheme::Https) => {
+                Url::parse(&uri.to_string()).map_err(ReadError::custom)
+            }
+            Path::File(file_path) if self.config.base_url.is_some() => {
+                let base = self.config.base_url.as_deref().unwrap_or_default();
+                let base = Url::parse(&format!("{}/", base.trim_end_matches('/')))
+                    .map_err(ReadError::custom)?;
+                let relative = file_path.to_string();
+                let url = base
+                    .join(relative.trim_start_matches('/'))
+                    .map_err(ReadError::custom)?;
+
+                // `join` resolves `..` segments, so check the result is still below the base
+                if !url.as_str().starts_with(base.as_str()) {
+                    return Err(ReadError::Forbidden(format!(
+                        "path is outside {}: {}",
+                        base, path
+                    )));
+                }
+
+                Ok(url)
+            }
+            _ => Err(ReadError::Custom(
+                "HttpSource only supports http(s) Uri paths, or File paths with a base_url"
+                    .to_string(),
+            )),
+        }
+    }
+
+    async fn send(&self, method: Method, path: &Path) -> Result<reqwest::Response, ReadError> {
+        let mut request = self.client.request(method, self.url(path)?);
+
+        for (name, value) in &self.config.headers {
+            request = request.header(name, value);
+        }
+
+        request.send().await.map_err(ReadError::custom)
+    }
+
+    fn read_only() -> WriteError {
+        WriteError::Custom("HttpSource is read-only".to_string())
+    }
+}
+
+#[async_trait]
+impl DataSource for HttpSource {
+    fn name(&self) -> &str {
+        &self.config.name
+    }
+
+    async fn exists(&self, path: &Path) -> Result<bool, ReadError> {
+        let response = self.send(Method::HEAD, path).await?;
+
+        match response.status() {
+            StatusCode::NOT_FOUND | StatusCode::GONE => Ok(false),
+            status if status.is_success() => Ok(true),
+            status => Err(ReadError::Custom(format!(
+                "HEAD {} failed with status {}",
+                path, status
+            ))),
+        }
+    }
+
+    async fn count(&self, path: &Path) -> Result<usize, ReadError> {
+        Ok(usize::from(self.exists(path).await?))
+    }
+
+    async fn find_one(&self, path: &Path) -> Result<Record, ReadError> {
+        let response = self.send(Method::GET, path).await?;
+        let status = response.status();
+
+        if !status.is_success() {
+            return Err(ReadError::Custom(format!(
+                "GET {} failed with status {}",
+                path, status
+            )));
+        }
+
+        let content_type = response
+            .headers()
+            .get("content-type")
+            .and_then(|v| v.to_str().ok())
+            .map(ContentType::parse)
+            .unwrap_or_default();
+
+        let media_type = match content_type.essence {
+            MediaType::Unknown | MediaType::Binary => MediaType::from_path(response.url().path()),
+            media_type => media_type,
+        };
+
+        // Records hold text as UTF-8; other charsets aren't transcoded yet
+        if media_type.is_textlike() && !content_type.is_utf8() {
+            return Err(ReadError::Custom(format!(
+                "GET {} returned unsupported charset '{}', only UTF-8 text can be decoded",
+                path,
+                content_type.charset().unwrap_or_default()
+            )));
+        }
+
+        let content_encoding = response
+            .headers()
+            .get("content-encoding")
+            .and_then(|v| v.to_str().ok())
+            .and_then(Encoding::parse);
+
+        let content = response.bytes().await.map_err(ReadError::custom)?;
+        let mut record = Record::new(path.clone(), media_type, content.to_vec());
+        record.content_encoding = content_encoding;
+
+        Ok(record)
+    }
+
+    async fn find(&self, path: &Path) -> Result<Vec<Record>, ReadError> {
+        Ok(vec![self.find_one(path).await?])
+    }
+
+    async fn create(&self, _record: Record) -> Result<(), WriteError> {
+        Err(Self::read_only())
+    }
+
+    async fn update(&self, _record: Record) -> Result<(), WriteError> {
+        Err(Self::read_only())
+    }
+
+    async fn upsert(&self, _record: Record) -> Result<(), WriteError> {
+        Err(Self::read_only())
+    }
+
+    async fn delete(&self, _path: &Path) -> Result<(), WriteError> {
+        Err(Self::read_only())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::path::{FilePath, UriPath};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Serves every request with the given status, content type and body.
+    async fn mock_server(
+        status: &'static str,
+        content_type: &'static str,
+        body: &'static str,
+    ) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buf = [0; 4096];
+                let _ = stream.read(&mut buf).await;
+
+                let response = format!(
+                    "HTTP/1.1 {}\r\ncontent-type: {}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    status,
+                    content_type,
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+
+        format!("http://{}", addr)
+    }
+
+    fn uri(url: &str) -> Path {
+        Path::Uri(UriPath::parse(url).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_find_one_sets_media_type_from_content_type() {
+        let base = mock_server(
+            "200 OK",
+            "application/json; charset=utf-8",
+            r#"{"key":"value"}"#,
+        )
+        .await;
+        let ds = HttpSource::builder().build().unwrap();
+        let path = uri(&format!("{}/config", base));
+
+        let record = ds.find_one(&path).await.unwrap();
+        assert_eq!(record.media_type, MediaType::TextJson);
+        assert_eq!(record.content_str().unwrap(), r#"{"key":"value"}"#);
+        assert_eq!(record.path, path);
+        assert!(ds.exists(&path).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_non_utf8_charset_is_error() {
+        let base = mock_server("200 OK", "text/plain; charset=ISO-8859-1", "caf\u{e9}").await;
+        let ds = HttpSource::builder().build().unwrap();
+        let path = uri(&format!("{}/notes.txt", base));
+
+        let err = ds.find_one(&path).await.unwrap_err();
+        assert!(err.to_string().contains("charset 'iso-8859-1'"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_non_success_status_is_error() {
+        let base = mock_server("404 Not Found", "text/plain", "missing").await;
+        let ds = HttpSource::builder().build().unwrap();
+        let path = uri(&format!("{}/missing.json", base));
+
+        let err = ds.find_one(&path).await.unwrap_err();
+        assert!(err.to_string().contains("404"), "{}", err);
+        assert!(!ds.exists(&path).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_file_path_uses_base_url() {
+        let base = mock_server("200 OK", "application/octet-stream", "a: 1").await;
+        let ds = HttpSource::builder()
+            .base_url(format!("{}/evals", base))
+            .build()
+            .unwrap();
+
+        let url = ds.url(&Path::File(FilePath::parse("data/a.yaml"))).unwrap();
+        assert_eq!(url.as_str(), format!("{}/evals/data/a.yaml", base));
+
+        // Generic content type falls back to the extension
+        let record = ds
+            .find_one(&Path::File(FilePath::parse("data/a.yaml")))
+            .await
+            .unwrap();
+        assert_eq!(record.media_type, MediaType::TextYaml);
+    }
+
+    #[tokio::test]
+    async fn test_writes_are_unsupported() {
+        let ds = HttpSource::builder().build().unwrap();
+        let path = uri("http://localhost/a.json");
+        let record = Record::from_str(path.clone(), MediaType::TextJson, "{}");
+
+        assert!(ds.create(record.clone()).await.is_err());
+        assert!(ds.upsert(record).await.is_err());
+        assert!(ds.delete(&path).await.is_err());
+    }
+
+    #[test]
+    fn test_file_path_cannot_escape_base_url() {
+        let ds = HttpSource::builder()
+            .base_url("https://example.com/evals")
+            .build()
+            .unwrap();
+
+        for path in ["../admin", "data/../../admin", "%2e%2e/admin"] {
+            let err = ds.url(&Path::File(FilePath::parse(path))).unwrap_err();
+            assert!(err.is_forbidden(), "{}: {}", path, err);
+        }
+
+        let url = ds
+            .url(&Path::File(FilePath::parse("data/../a.json")))
+            .unwrap();
+        assert_eq!(url.as_str(), "https://example.com/evals/a.json");
+    }
+
+    #[test]
+    fn test_unsupported_paths() {
+        let ds = HttpSource::builder().build().unwrap();
+        assert!(ds.url(&uri("s3://bucket/a.json")).is_err());
+        assert!(ds.url(&Path::File(FilePath::parse("a.json"))).is_err());
+    }
+}
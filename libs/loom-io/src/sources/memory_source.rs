@@ -5,22 +5,29 @@ use async_trait::async_trait;
 
 use crate::path::Path;
 
-use crate::{DataSource, Id, ReadError, Record, WriteError};
+use crate::value::Value;
+use crate::{DataSource, ETag, Glob, Id, IdStrategy, ReadError, Record, WriteError};
 
 #[derive(Debug, Clone)]
 pub struct MemorySourceConfig {
     name: String,
+    id_strategy: IdStrategy,
 }
 
 impl MemorySourceConfig {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    pub fn id_strategy(&self) -> IdStrategy {
+        self.id_strategy
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct MemorySourceBuilder {
     name: Option<String>,
+    id_strategy: IdStrategy,
     initial_records: Vec<Record>,
 }
 
@@ -28,6 +35,7 @@ impl MemorySourceBuilder {
     pub fn new() -> Self {
         Self {
             name: None,
+            id_strategy: IdStrategy::default(),
             initial_records: Vec::new(),
         }
     }
@@ -37,6 +45,14 @@ impl MemorySourceBuilder {
         self
     }
 
+    /// How stored records get their `id`, e.g. `IdStrategy::ContentHash` so
+    /// records with the same content share an id. Records are still looked
+    /// up by path.
+    pub fn id_strategy(mut self, id_strategy: IdStrategy) -> Self {
+        self.id_strategy = id_strategy;
+        self
+    }
+
     pub fn with_records(mut self, records: impl IntoIterator<Item = Record>) -> Self {
         self.initial_records.extend(records);
         self
@@ -50,12 +66,14 @@ impl MemorySourceBuilder {
     pub fn build(self) -> MemorySource {
         let mut records_map = HashMap::new();
         for record in self.initial_records {
-            records_map.insert(record.id, record);
+            let record = assign_id(self.id_strategy, record);
+            records_map.insert(key(&record.path), record);
         }
 
         MemorySource {
             config: MemorySourceConfig {
                 name: self.name.unwrap_or_else(|| "memory".to_string()),
+                id_strategy: self.id_strategy,
             },
             records: RwLock::new(records_map),
         }
@@ -83,6 +101,27 @@ impl MemorySource {
     }
 }
 
+/// The key records are stored under, so lookups by path work whatever the
+/// `IdStrategy`.
+fn key(path: &Path) -> Id {
+    Id::new(path.to_string().as_str())
+}
+
+/// Set `record.id` with `strategy`, keyed by the record's path. Content is
+/// hashed as a string when it is UTF-8 and as its bytes otherwise.
+fn assign_id(strategy: IdStrategy, mut record: Record) -> Record {
+    let content = match strategy {
+        IdStrategy::ContentHash => match record.content_str() {
+            Ok(text) => Value::from(text.as_ref()),
+            Err(_) => Value::from(record.content.clone()),
+        },
+        _ => Value::Null,
+    };
+
+    record.id = strategy.generate(&record.path.to_string(), &content);
+    record
+}
+
 impl Default for MemorySource {
     fn default() -> Self {
         Self::builder().build()
@@ -96,7 +135,7 @@ impl DataSource for MemorySource {
     }
 
     async fn exists(&self, path: &Path) -> Result<bool, ReadError> {
-        let id = Id::new(path.to_string().as_str());
+        let id = key(path);
         let records = self
             .records
             .read()
@@ -119,7 +158,7 @@ impl DataSource for MemorySource {
     }
 
     async fn find_one(&self, path: &Path) -> Result<Record, ReadError> {
-        let id = Id::new(path.to_string().as_str());
+        let id = key(path);
         let records = self
             .records
             .read()
@@ -146,51 +185,85 @@ impl DataSource for MemorySource {
         Ok(results)
     }
 
+    async fn find_many(&self, pattern: &Path) -> Result<Vec<Record>, ReadError> {
+        let glob = Glob::new(pattern.to_string());
+        let records = self
+            .records
+            .read()
+            .map_err(|e| ReadError::Panic(e.to_string()))?;
+
+        let mut results: Vec<Record> = records
+            .values()
+            .filter(|r| glob.is_match(&r.path.to_string()))
+            .cloned()
+            .collect();
+        results.sort_by_cached_key(|r| r.path.to_string());
+        Ok(results)
+    }
+
     async fn create(&self, record: Record) -> Result<(), WriteError> {
+        let record = assign_id(self.config.id_strategy, record);
         let mut records = self
             .records
             .write()
             .map_err(|e| WriteError::Panic(e.to_string()))?;
 
-        if records.contains_key(&record.id) {
+        if records.contains_key(&key(&record.path)) {
             return Err(WriteError::Custom(format!(
                 "record already exists: {}",
                 record.path
             )));
         }
 
-        records.insert(record.id, record);
+        records.insert(key(&record.path), record);
         Ok(())
     }
 
     async fn update(&self, record: Record) -> Result<(), WriteError> {
+        let record = assign_id(self.config.id_strategy, record);
         let mut records = self
             .records
             .write()
             .map_err(|e| WriteError::Panic(e.to_string()))?;
 
-        if !records.contains_key(&record.id) {
+        if !records.contains_key(&key(&record.path)) {
             return Err(WriteError::Custom(format!(
                 "record not found: {}",
                 record.path
             )));
         }
 
-        records.insert(record.id, record);
+        records.insert(key(&record.path), record);
         Ok(())
     }
 
     async fn upsert(&self, record: Record) -> Result<(), WriteError> {
+        let record = assign_id(self.config.id_strategy, record);
         let mut records = self
             .records
             .write()
             .map_err(|e| WriteError::Panic(e.to_string()))?;
-        records.insert(record.id, record);
+        records.insert(key(&record.path), record);
+        Ok(())
+    }
+
+    async fn upsert_if_match(&self, record: Record, etag: ETag) -> Result<(), WriteError> {
+        let record = assign_id(self.config.id_strategy, record);
+        let mut records = self
+            .records
+            .write()
+            .map_err(|e| WriteError::Panic(e.to_string()))?;
+
+        if records.get(&key(&record.path)).map(|r| r.etag) != Some(etag) {
+            return Err(WriteError::conflict(&record.path));
+        }
+
+        records.insert(key(&record.path), record);
         Ok(())
     }
 
     async fn delete(&self, path: &Path) -> Result<(), WriteError> {
-        let id = Id::new(path.to_string().as_str());
+        let id = key(path);
         let mut records = self
             .records
             .write()
@@ -267,6 +340,37 @@ mod tests {
         assert_eq!(results.len(), 2);
     }
 
+    #[tokio::test]
+    async fn test_find_many() {
+        let ds = MemorySource::builder().build();
+        for path in [
+            "/test/b.json",
+            "/test/a.json",
+            "/test/x/c.json",
+            "/test/a.txt",
+        ] {
+            ds.create(make_record(&Path::File(FilePath::parse(path))))
+                .await
+                .unwrap();
+        }
+
+        let paths = |records: Vec<Record>| {
+            records
+                .into_iter()
+                .map(|r| r.path.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let pattern = Path::File(FilePath::parse("/test/*.json"));
+        assert_eq!(
+            paths(ds.find_many(&pattern).await.unwrap()),
+            vec!["/test/a.json", "/test/b.json"]
+        );
+
+        let pattern = Path::File(FilePath::parse("/test/**"));
+        assert_eq!(ds.find_many(&pattern).await.unwrap().len(), 4);
+    }
+
     #[tokio::test]
     async fn test_create_duplicate_fails() {
         let ds = MemorySource::builder().build();
@@ -311,6 +415,80 @@ mod tests {
         assert!(ds.exists(&path).await.unwrap());
     }
 
+    #[tokio::test]
+    async fn test_upsert_if_match() {
+        let ds = MemorySource::builder().build();
+        let path = Path::File(FilePath::parse("/test/file.txt"));
+        ds.create(make_record(&path)).await.unwrap();
+
+        let read = ds.find_one(&path).await.unwrap();
+        ds.upsert(Record::from_str(
+            path.clone(),
+            MediaType::TextPlain,
+            "second",
+        ))
+        .await
+        .unwrap();
+
+        let first = Record::from_str(path.clone(), MediaType::TextPlain, "first");
+        let err = ds
+            .upsert_if_match(first.clone(), read.etag)
+            .await
+            .unwrap_err();
+        assert!(err.is_conflict());
+
+        let current = ds.find_one(&path).await.unwrap();
+        ds.upsert_if_match(first.clone(), current.etag)
+            .await
+            .unwrap();
+        assert_eq!(ds.find_one(&path).await.unwrap(), first);
+
+        let missing = Path::File(FilePath::parse("/test/missing.txt"));
+        let err = ds
+            .upsert_if_match(make_record(&missing), read.etag)
+            .await
+            .unwrap_err();
+        assert!(err.is_conflict());
+    }
+
+    #[tokio::test]
+    async fn test_content_hash_ids() {
+        let ds = MemorySource::builder()
+            .id_strategy(IdStrategy::ContentHash)
+            .build();
+        let a = Path::File(FilePath::parse("/test/a.txt"));
+        let b = Path::File(FilePath::parse("/test/b.txt"));
+
+        ds.create(make_record(&a)).await.unwrap();
+        ds.create(make_record(&b)).await.unwrap();
+
+        let a = ds.find_one(&a).await.unwrap();
+        let b = ds.find_one(&b).await.unwrap();
+        assert_eq!(a.id, b.id);
+        assert_eq!(a.id, Id::content_hash(&Value::from("hello")));
+        assert_eq!(
+            ds.count(&Path::File(FilePath::parse("/test")))
+                .await
+                .unwrap(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_uuid_ids() {
+        let ds = MemorySource::builder()
+            .id_strategy(IdStrategy::Uuid)
+            .build();
+        let path = Path::File(FilePath::parse("/test/file.txt"));
+
+        ds.upsert(make_record(&path)).await.unwrap();
+        let first = ds.find_one(&path).await.unwrap();
+        ds.upsert(make_record(&path)).await.unwrap();
+        let second = ds.find_one(&path).await.unwrap();
+
+        assert_ne!(first.id, second.id);
+    }
+
     #[tokio::test]
     async fn test_delete() {
         let ds = MemorySource::builder().build();
@@ -341,6 +519,13 @@ mod tests {
         assert!(result.unwrap_err().is_custom());
     }
 
+    #[test]
+    fn test_watch_unsupported() {
+        let ds = MemorySource::builder().build();
+        let path = Path::File(FilePath::parse("/test/file.txt"));
+        assert!(ds.watch(&path).is_err());
+    }
+
     #[test]
     fn test_builder() {
         let ds = MemorySource::builder().name("custom_memory").build();
@@ -1,5 +1,19 @@
 mod file_system_source;
+#[cfg(feature = "http")]
+mod http_source;
 mod memory_source;
+#[cfg(feature = "postgres")]
+mod postgres_source;
+#[cfg(feature = "s3")]
+mod s3;
+mod versioned_memory_source;
 
 pub use file_system_source::*;
+#[cfg(feature = "http")]
+pub use http_source::*;
 pub use memory_source::*;
+#[cfg(feature = "postgres")]
+pub use postgres_source::*;
+#[cfg(feature = "s3")]
+pub use s3::*;
+pub use versioned_memory_source::*;
@@ -0,0 +1,933 @@
+mod sign;
+
+use std::collections::BTreeMap;
+use std::io;
+
+use async_trait::async_trait;
+use reqwest::{Method, StatusCode, Url, header::HeaderMap};
+
+use crate::MediaType;
+use crate::path::{Authority, FilePath, Path, Scheme, UriPath};
+
+use crate::{DataSource, ETag, Encoding, Glob, ReadError, Record, WriteError};
+
+use sign::{Credentials, SignableRequest};
+
+/// User metadata key holding the record's `ETag` on stored objects.
+const ETAG_META: &str = "x-amz-meta-loom-etag";
+
+#[derive(Debug, Clone)]
+pub struct S3SourceConfig {
+    name: String,
+    bucket: String,
+    region: String,
+    endpoint: String,
+    prefix: String,
+    path_style: bool,
+}
+
+impl S3SourceConfig {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    pub fn region(&self) -> &str {
+        &self.region
+    }
+
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    pub fn path_style(&self) -> bool {
+        self.path_style
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct S3SourceBuilder {
+    name: Option<String>,
+    bucket: String,
+    region: String,
+    endpoint: Option<String>,
+    prefix: String,
+    path_style: Option<bool>,
+    credentials: Option<Credentials>,
+}
+
+impl S3SourceBuilder {
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            bucket: String::new(),
+            region: "us-east-1".to_string(),
+            endpoint: None,
+            prefix: String::new(),
+            path_style: None,
+            credentials: None,
+        }
+    }
+
+    /// Start from the standard AWS environment variables: `AWS_ACCESS_KEY_ID`,
+    /// `AWS_SECRET_ACCESS_KEY`, `AWS_SESSION_TOKEN`, `AWS_REGION` (or
+    /// `AWS_DEFAULT_REGION`) and `AWS_ENDPOINT_URL`.
+    pub fn from_env() -> Self {
+        let mut builder = Self::new();
+
+        if let Ok(region) =
+            std::env::var("AWS_REGION").or_else(|_| std::env::var("AWS_DEFAULT_REGION"))
+        {
+            builder = builder.region(region);
+        }
+
+        if let Ok(endpoint) = std::env::var("AWS_ENDPOINT_URL") {
+            builder = builder.endpoint(endpoint);
+        }
+
+        if let (Ok(access_key), Ok(secret_key)) = (
+            std::env::var("AWS_ACCESS_KEY_ID"),
+            std::env::var("AWS_SECRET_ACCESS_KEY"),
+        ) {
+            builder = builder.credentials(access_key, secret_key);
+
+            if let Ok(token) = std::env::var("AWS_SESSION_TOKEN") {
+                builder = builder.session_token(token);
+            }
+        }
+
+        builder
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn bucket(mut self, bucket: impl Into<String>) -> Self {
+        self.bucket = bucket.into();
+        self
+    }
+
+    pub fn region(mut self, region: impl Into<String>) -> Self {
+        self.region = region.into();
+        self
+    }
+
+    /// Use an S3-compatible endpoint such as MinIO (`http://localhost:9000`).
+    /// Custom endpoints default to path-style addressing.
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.endpoint = Some(endpoint.into());
+        self
+    }
+
+    /// Address objects as `{endpoint}/{bucket}/{key}` instead of
+    /// `{bucket}.{endpoint host}/{key}`.
+    pub fn path_style(mut self, path_style: bool) -> Self {
+        self.path_style = Some(path_style);
+        self
+    }
+
+    /// Key prefix that `File` paths are resolved under, like the root
+    /// directory of a `FileSystemSource`.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into().trim_matches('/').to_string();
+        self
+    }
+
+    /// Sign requests with these keys. Without credentials requests are
+    /// sent anonymously, which only works for public buckets.
+    pub fn credentials(
+        mut self,
+        access_key: impl Into<String>,
+        secret_key: impl Into<String>,
+    ) -> Self {
+        self.credentials = Some(Credentials {
+            access_key: access_key.into(),
+            secret_key: secret_key.into(),
+            session_token: None,
+        });
+        self
+    }
+
+    pub fn session_token(mut self, token: impl Into<String>) -> Self {
+        if let Some(credentials) = self.credentials.as_mut() {
+            credentials.session_token = Some(token.into());
+        }
+        self
+    }
+
+    pub fn build(self) -> S3Source {
+        let path_style = self.path_style.unwrap_or(self.endpoint.is_some());
+        let endpoint = self
+            .endpoint
+            .unwrap_or_else(|| format!("https://s3.{}.amazonaws.com", self.region));
+
+        S3Source {
+            config: S3SourceConfig {
+                name: self.name.unwrap_or_else(|| "s3".to_string()),
+                bucket: self.bucket,
+                region: self.region,
+                endpoint: endpoint.trim_end_matches('/').to_string(),
+                prefix: self.prefix,
+                path_style,
+            },
+            credentials: self.credentials,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+impl Default for S3SourceBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A `DataSource` backed by an S3 (or S3-compatible) bucket.
+///
+/// `File` paths are mapped to object keys under the configured prefix
+/// (`data/a.json` -> `{prefix}/data/a.json`); `s3://bucket/key` URIs address
+/// keys in the configured bucket directly. A path that is not an object is
+/// treated as a directory, matching every key below it.
+///
+/// Each stored object carries the record's `ETag` as user metadata.
+/// `create` and `update` are conditional writes (`If-None-Match: *` and
+/// `If-Match`), and `upsert` skips the upload when the stored `ETag`
+/// already matches.
+///
+/// # Example
+/// ```ignore
+/// let runtime = Runtime::new()
+///     .source(
+///         S3Source::builder()
+///             .bucket("datasets")
+///             .region("eu-west-1")
+///             .credentials(access_key, secret_key)
+///             .build(),
+///     )
+///     .build();
+///
+/// let samples: SampleDataset = runtime.load("s3", &path).await?;
+/// ```
+pub struct S3Source {
+    config: S3SourceConfig,
+    credentials: Option<Credentials>,
+    client: reqwest::Client,
+}
+
+struct Response {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Vec<u8>,
+}
+
+impl S3Source {
+    pub fn builder() -> S3SourceBuilder {
+        S3SourceBuilder::new()
+    }
+
+    pub fn config(&self) -> &S3SourceConfig {
+        &self.config
+    }
+
+    /// Map a path to the object key it refers to.
+    pub fn object_key(&self, path: &Path) -> Result<String, ReadError> {
+        match path {
+            Path::File(file_path) => {
+                let mut parts = Vec::new();
+                let file_path: &std::path::Path = file_path;
+
+                for component in file_path.components() {
+                    match component {
+                        std::path::Component::Normal(part) => {
+                            parts.push(part.to_str().ok_or_else(|| {
+                                ReadError::Custom(format!("invalid object key: {}", path))
+                            })?);
+                        }
+                        std::path::Component::ParentDir => {
+                            return Err(ReadError::Custom(format!(
+                                "path escapes the source prefix: {}",
+                                path
+                            )));
+                        }
+                        _ => {}
+                    }
+                }
+
+                let key = parts.join("/");
+                Ok(match (self.config.prefix.is_empty(), key.is_empty()) {
+                    (true, _) => key,
+                    (false, true) => self.config.prefix.clone(),
+                    (false, false) => format!("{}/{}", self.config.prefix, key),
+                })
+            }
+            Path::Uri(uri) if uri.scheme == Scheme::S3 => {
+                let bucket = uri.authority.as_ref().map(|a| a.host.as_str());
+
+                if bucket != Some(self.config.bucket.as_str()) {
+                    return Err(ReadError::Custom(format!(
+                        "uri {} is not in bucket '{}'",
+                        path, self.config.bucket
+                    )));
+                }
+
+                Ok(uri.path.trim_start_matches('/').to_string())
+            }
+            _ => Err(ReadError::Custom(
+                "S3Source only supports File and s3:// Uri paths".to_string(),
+            )),
+        }
+    }
+
+    /// Map an object key back to a path of the same kind as `like`.
+    fn path_of(&self, key: &str, like: &Path) -> Path {
+        match like {
+            Path::Uri(_) => Path::Uri(
+                UriPath::new(Scheme::S3, format!("/{}", key))
+                    .with_authority(Authority::new(self.config.bucket.clone())),
+            ),
+            _ => {
+                let relative = if self.config.prefix.is_empty() {
+                    key
+                } else {
+                    key.strip_prefix(&self.config.prefix)
+                        .map(|k| k.trim_start_matches('/'))
+                        .unwrap_or(key)
+                };
+
+                Path::File(FilePath::parse(relative))
+            }
+        }
+    }
+
+    fn url(&self, key: &str, query: &[(String, String)]) -> Result<(Url, String), String> {
+        let base = Url::parse(&self.config.endpoint).map_err(|e| e.to_string())?;
+        let encoded_key = sign::uri_encode(key, true);
+
+        let (origin, path) = if self.config.path_style {
+            (
+                self.config.endpoint.clone(),
+                format!("/{}/{}", self.config.bucket, encoded_key),
+            )
+        } else {
+            let host = base.host_str().ok_or("endpoint has no host")?;
+            let port = base.port().map(|p| format!(":{}", p)).unwrap_or_default();
+            (
+                format!(
+                    "{}://{}.{}{}",
+                    base.scheme(),
+                    self.config.bucket,
+                    host,
+                    port
+                ),
+                format!("/{}", encoded_key),
+            )
+        };
+
+        let mut url = Url::parse(&format!("{}{}", origin, path)).map_err(|e| e.to_string())?;
+
+        if !query.is_empty() {
+            url.set_query(Some(&sign::canonical_query(query)));
+        }
+
+        Ok((url, path))
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        key: &str,
+        query: &[(String, String)],
+        headers: &[(&str, String)],
+        body: Vec<u8>,
+    ) -> Result<Response, String> {
+        let (url, path) = self.url(key, query)?;
+        let payload_hash = sign::sha256_hex(&body);
+        let amz_date = chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+
+        let host = match url.port() {
+            Some(port) => format!("{}:{}", url.host_str().unwrap_or_default(), port),
+            None => url.host_str().unwrap_or_default().to_string(),
+        };
+
+        let mut signed = BTreeMap::new();
+        signed.insert("host".to_string(), host);
+        signed.insert("x-amz-content-sha256".to_string(), payload_hash.clone());
+        signed.insert("x-amz-date".to_string(), amz_date.clone());
+
+        for (name, value) in headers {
+            signed.insert(name.to_ascii_lowercase(), value.clone());
+        }
+
+        if let Some(token) = self
+            .credentials
+            .as_ref()
+            .and_then(|c| c.session_token.as_ref())
+        {
+            signed.insert("x-amz-security-token".to_string(), token.clone());
+        }
+
+        let mut request = self.client.request(method.clone(), url);
+
+        if let Some(credentials) = &self.credentials {
+            let authorization = sign::authorization(
+                credentials,
+                &self.config.region,
+                &amz_date,
+                &SignableRequest {
+                    method: method.as_str(),
+                    path: &path,
+                    query,
+                    headers: &signed,
+                    payload_hash: &payload_hash,
+                },
+            );
+
+            request = request.header("authorization", authorization);
+        }
+
+        // reqwest sets `host` from the url
+        for (name, value) in signed.iter().filter(|(name, _)| *name != "host") {
+            request = request.header(name, value);
+        }
+
+        let response = request.body(body).send().await.map_err(|e| e.to_string())?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await.map_err(|e| e.to_string())?.to_vec();
+
+        Ok(Response {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    async fn head(&self, key: &str) -> Result<Option<HeaderMap>, ReadError> {
+        let response = self
+            .send(Method::HEAD, key, &[], &[], Vec::new())
+            .await
+            .map_err(ReadError::Custom)?;
+
+        match response.status {
+            StatusCode::NOT_FOUND => Ok(None),
+            status if status.is_success() => Ok(Some(response.headers)),
+            status => Err(ReadError::Custom(error_message("HEAD", key, status, &[]))),
+        }
+    }
+
+    /// List every key starting with `prefix`, following continuation tokens.
+    async fn list(&self, prefix: &str) -> Result<Vec<String>, ReadError> {
+        let mut keys = Vec::new();
+        let mut token: Option<String> = None;
+
+        loop {
+            let mut query = vec![
+                ("list-type".to_string(), "2".to_string()),
+                ("prefix".to_string(), prefix.to_string()),
+            ];
+
+            if let Some(token) = &token {
+                query.push(("continuation-token".to_string(), token.clone()));
+            }
+
+            let response = self
+                .send(Method::GET, "", &query, &[], Vec::new())
+                .await
+                .map_err(ReadError::Custom)?;
+
+            if !response.status.is_success() {
+                return Err(ReadError::Custom(error_message(
+                    "LIST",
+                    prefix,
+                    response.status,
+                    &response.body,
+                )));
+            }
+
+            let body = String::from_utf8_lossy(&response.body);
+            keys.extend(xml_values(&body, "Key"));
+
+            token = xml_values(&body, "NextContinuationToken").pop();
+            if xml_values(&body, "IsTruncated").first().map(String::as_str) != Some("true")
+                || token.is_none()
+            {
+                break;
+            }
+        }
+
+        Ok(keys)
+    }
+
+    /// Keys below `key` when it is used as a directory.
+    async fn list_dir(&self, key: &str) -> Result<Vec<String>, ReadError> {
+        if key.is_empty() {
+            self.list("").await
+        } else {
+            self.list(&format!("{}/", key)).await
+        }
+    }
+
+    async fn put(
+        &self,
+        record: &Record,
+        condition: Option<(&str, String)>,
+    ) -> Result<Response, WriteError> {
+        let key = self.object_key(&record.path)?;
+        let mut headers = vec![
+            ("content-type", record.media_type.as_mime_str().to_string()),
+            (ETAG_META, record.etag.to_hex()),
+        ];
+        headers.extend(
+            record
+                .content_encoding
+                .map(|e| ("content-encoding", e.as_str().to_string())),
+        );
+        headers.extend(condition);
+
+        self.send(Method::PUT, &key, &[], &headers, record.content.clone())
+            .await
+            .map_err(WriteError::Custom)
+    }
+}
+
+impl Default for S3Source {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+#[async_trait]
+impl DataSource for S3Source {
+    fn name(&self) -> &str {
+        &self.config.name
+    }
+
+    async fn exists(&self, path: &Path) -> Result<bool, ReadError> {
+        let key = self.object_key(path)?;
+
+        if !key.is_empty() && self.head(&key).await?.is_some() {
+            return Ok(true);
+        }
+
+        Ok(!self.list_dir(&key).await?.is_empty())
+    }
+
+    async fn count(&self, path: &Path) -> Result<usize, ReadError> {
+        let key = self.object_key(path)?;
+
+        if !key.is_empty() && self.head(&key).await?.is_some() {
+            return Ok(1);
+        }
+
+        Ok(self.list_dir(&key).await?.len())
+    }
+
+    async fn find_one(&self, path: &Path) -> Result<Record, ReadError> {
+        let key = self.object_key(path)?;
+        let response = self
+            .send(Method::GET, &key, &[], &[], Vec::new())
+            .await
+            .map_err(ReadError::Custom)?;
+
+        if response.status == StatusCode::NOT_FOUND {
+            return Err(ReadError::IO(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("object not found: {}", key),
+            )));
+        }
+
+        if !response.status.is_success() {
+            return Err(ReadError::Custom(error_message(
+                "GET",
+                &key,
+                response.status,
+                &response.body,
+            )));
+        }
+
+        let media_type = match MediaType::from_path(&key) {
+            MediaType::Unknown => response
+                .headers
+                .get("content-type")
+                .and_then(|v| v.to_str().ok())
+                .map(MediaType::from_mime_str)
+                .unwrap_or(MediaType::Unknown),
+            media_type => media_type,
+        };
+
+        let mut record = Record::new(path.clone(), media_type, response.body);
+        record.content_encoding = response
+            .headers
+            .get("content-encoding")
+            .and_then(|v| v.to_str().ok())
+            .and_then(Encoding::parse);
+
+        Ok(record)
+    }
+
+    async fn find(&self, path: &Path) -> Result<Vec<Record>, ReadError> {
+        let key = self.object_key(path)?;
+
+        if !key.is_empty() && self.head(&key).await?.is_some() {
+            return Ok(vec![self.find_one(path).await?]);
+        }
+
+        let mut records = Vec::new();
+        for key in self.list_dir(&key).await? {
+            records.push(self.find_one(&self.path_of(&key, path)).await?);
+        }
+
+        Ok(records)
+    }
+
+    /// Lists the keys under the pattern's literal prefix and matches each
+    /// against the pattern (resolved to a key like any other path).
+    async fn find_many(&self, pattern: &Path) -> Result<Vec<Record>, ReadError> {
+        let glob = Glob::new(self.object_key(pattern)?);
+
+        if glob.is_literal() {
+            return match self.head(glob.as_str()).await? {
+                Some(_) => Ok(vec![self.find_one(pattern).await?]),
+                None => Ok(Vec::new()),
+            };
+        }
+
+        let mut records = Vec::new();
+        for key in self.list(glob.prefix()).await? {
+            if glob.is_match(&key) {
+                records.push(self.find_one(&self.path_of(&key, pattern)).await?);
+            }
+        }
+
+        Ok(records)
+    }
+
+    async fn create(&self, record: Record) -> Result<(), WriteError> {
+        let response = self
+            .put(&record, Some(("if-none-match", "*".to_string())))
+            .await?;
+
+        match response.status {
+            status if status.is_success() => Ok(()),
+            StatusCode::PRECONDITION_FAILED | StatusCode::CONFLICT => Err(WriteError::Conflict(
+                format!("object already exists: {}", record.path),
+            )),
+            status => Err(WriteError::Custom(error_message(
+                "PUT",
+                &record.path.to_string(),
+                status,
+                &response.body,
+            ))),
+        }
+    }
+
+    async fn update(&self, record: Record) -> Result<(), WriteError> {
+        let key = self.object_key(&record.path)?;
+        let Some(headers) = self.head(&key).await? else {
+            return Err(WriteError::Custom(format!(
+                "object not found: {}",
+                record.path
+            )));
+        };
+
+        // Only replace the version we just saw
+        let condition = headers
+            .get("etag")
+            .and_then(|v| v.to_str().ok())
+            .map(|etag| ("if-match", etag.to_string()));
+
+        let response = self.put(&record, condition).await?;
+
+        match response.status {
+            status if status.is_success() => Ok(()),
+            StatusCode::PRECONDITION_FAILED | StatusCode::CONFLICT => {
+                Err(WriteError::conflict(&record.path))
+            }
+            status => Err(WriteError::Custom(error_message(
+                "PUT",
+                &record.path.to_string(),
+                status,
+                &response.body,
+            ))),
+        }
+    }
+
+    async fn upsert(&self, record: Record) -> Result<(), WriteError> {
+        let key = self.object_key(&record.path)?;
+        let stored = self.head(&key).await?;
+        let stored_etag = stored
+            .as_ref()
+            .and_then(|h| h.get(ETAG_META))
+            .and_then(|v| v.to_str().ok());
+
+        if stored_etag == Some(record.etag.to_hex().as_str()) {
+            return Ok(());
+        }
+
+        let response = self.put(&record, None).await?;
+
+        if response.status.is_success() {
+            Ok(())
+        } else {
+            Err(WriteError::Custom(error_message(
+                "PUT",
+                &key,
+                response.status,
+                &response.body,
+            )))
+        }
+    }
+
+    /// Compares the stored `ETag` metadata, then writes with `If-Match` on
+    /// the object version it saw, so a write landing in between also
+    /// conflicts.
+    async fn upsert_if_match(&self, record: Record, etag: ETag) -> Result<(), WriteError> {
+        let key = self.object_key(&record.path)?;
+        let Some(headers) = self.head(&key).await? else {
+            return Err(WriteError::conflict(&record.path));
+        };
+
+        let stored_etag = headers.get(ETAG_META).and_then(|v| v.to_str().ok());
+        if stored_etag != Some(etag.to_hex().as_str()) {
+            return Err(WriteError::conflict(&record.path));
+        }
+
+        let condition = headers
+            .get("etag")
+            .and_then(|v| v.to_str().ok())
+            .map(|etag| ("if-match", etag.to_string()));
+
+        let response = self.put(&record, condition).await?;
+
+        match response.status {
+            status if status.is_success() => Ok(()),
+            StatusCode::PRECONDITION_FAILED | StatusCode::CONFLICT => {
+                Err(WriteError::conflict(&record.path))
+            }
+            status => Err(WriteError::Custom(error_message(
+                "PUT",
+                &key,
+                status,
+                &response.body,
+            ))),
+        }
+    }
+
+    async fn delete(&self, path: &Path) -> Result<(), WriteError> {
+        let key = self.object_key(path)?;
+
+        if self.head(&key).await?.is_none() {
+            return Err(WriteError::Custom(format!("object not found: {}", path)));
+        }
+
+        let response = self
+            .send(Method::DELETE, &key, &[], &[], Vec::new())
+            .await
+            .map_err(WriteError::Custom)?;
+
+        if response.status.is_success() {
+            Ok(())
+        } else {
+            Err(WriteError::Custom(error_message(
+                "DELETE",
+                &key,
+                response.status,
+                &response.body,
+            )))
+        }
+    }
+}
+
+/// Describe a failed request, including the S3 error code when the body has one.
+fn error_message(op: &str, key: &str, status: StatusCode, body: &[u8]) -> String {
+    let body = String::from_utf8_lossy(body);
+
+    match xml_values(&body, "Code").first() {
+        Some(code) => format!("s3 {} {} failed with {}: {}", op, key, status, code),
+        None => format!("s3 {} {} failed with {}", op, key, status),
+    }
+}
+
+/// Text of every `<tag>...</tag>` element in an S3 XML response.
+fn xml_values(xml: &str, tag: &str) -> Vec<String> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    let mut values = Vec::new();
+    let mut rest = xml;
+
+    while let Some(start) = rest.find(&open) {
+        rest = &rest[start + open.len()..];
+        let Some(end) = rest.find(&close) else {
+            break;
+        };
+
+        values.push(
+            rest[..end]
+                .replace("&lt;", "<")
+                .replace("&gt;", ">")
+                .replace("&quot;", "\"")
+                .replace("&apos;", "'")
+                .replace("&amp;", "&"),
+        );
+        rest = &rest[end + close.len()..];
+    }
+
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(prefix: &str) -> S3Source {
+        S3Source::builder()
+            .bucket("datasets")
+            .prefix(prefix)
+            .build()
+    }
+
+    fn file(path: &str) -> Path {
+        Path::File(FilePath::parse(path))
+    }
+
+    fn uri(uri: &str) -> Path {
+        Path::Uri(UriPath::parse(uri).unwrap())
+    }
+
+    #[test]
+    fn test_file_path_maps_under_prefix() {
+        let ds = source("/evals/");
+        assert_eq!(ds.config().prefix(), "evals");
+        assert_eq!(
+            ds.object_key(&file("data/a.json")).unwrap(),
+            "evals/data/a.json"
+        );
+        assert_eq!(
+            ds.object_key(&file("/data/./a.json")).unwrap(),
+            "evals/data/a.json"
+        );
+        assert_eq!(ds.object_key(&file("")).unwrap(), "evals");
+    }
+
+    #[test]
+    fn test_file_path_without_prefix() {
+        let ds = source("");
+        assert_eq!(ds.object_key(&file("/data/a.json")).unwrap(), "data/a.json");
+    }
+
+    #[test]
+    fn test_parent_dir_is_rejected() {
+        let ds = source("evals");
+        assert!(ds.object_key(&file("../secrets.json")).is_err());
+    }
+
+    #[test]
+    fn test_s3_uri_maps_to_key() {
+        let ds = source("evals");
+        assert_eq!(
+            ds.object_key(&uri("s3://datasets/raw/a.json")).unwrap(),
+            "raw/a.json"
+        );
+        assert!(ds.object_key(&uri("s3://other/raw/a.json")).is_err());
+        assert!(ds.object_key(&uri("https://datasets/raw/a.json")).is_err());
+    }
+
+    #[test]
+    fn test_path_of_round_trips() {
+        let ds = source("evals");
+        let path = file("data/a.json");
+        let key = ds.object_key(&path).unwrap();
+        assert_eq!(ds.path_of(&key, &path), path);
+
+        let path = uri("s3://datasets/raw/a.json");
+        let key = ds.object_key(&path).unwrap();
+        assert_eq!(ds.object_key(&ds.path_of(&key, &path)).unwrap(), key);
+    }
+
+    #[test]
+    fn test_url_addressing() {
+        let ds = S3Source::builder()
+            .bucket("datasets")
+            .region("eu-west-1")
+            .build();
+        let (url, path) = ds.url("a b.json", &[]).unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://datasets.s3.eu-west-1.amazonaws.com/a%20b.json"
+        );
+        assert_eq!(path, "/a%20b.json");
+
+        let ds = S3Source::builder()
+            .bucket("datasets")
+            .endpoint("http://localhost:9000/")
+            .build();
+        let (url, path) = ds.url("a.json", &[]).unwrap();
+        assert_eq!(url.as_str(), "http://localhost:9000/datasets/a.json");
+        assert_eq!(path, "/datasets/a.json");
+    }
+
+    #[test]
+    fn test_xml_values() {
+        let xml = "<ListBucketResult><IsTruncated>false</IsTruncated>\
+                   <Contents><Key>a&amp;b.json</Key></Contents>\
+                   <Contents><Key>c.json</Key></Contents></ListBucketResult>";
+
+        assert_eq!(xml_values(xml, "Key"), vec!["a&b.json", "c.json"]);
+        assert_eq!(xml_values(xml, "IsTruncated"), vec!["false"]);
+        assert!(xml_values(xml, "NextContinuationToken").is_empty());
+    }
+
+    /// Runs against a real bucket, e.g. MinIO:
+    ///
+    /// ```sh
+    /// LOOM_S3_TEST_ENDPOINT=http://localhost:9000 LOOM_S3_TEST_BUCKET=loom \
+    /// AWS_ACCESS_KEY_ID=minioadmin AWS_SECRET_ACCESS_KEY=minioadmin \
+    /// cargo test -p loom-io --features s3 s3
+    /// ```
+    #[tokio::test]
+    async fn test_minio_round_trip() {
+        let (Ok(endpoint), Ok(bucket)) = (
+            std::env::var("LOOM_S3_TEST_ENDPOINT"),
+            std::env::var("LOOM_S3_TEST_BUCKET"),
+        ) else {
+            return;
+        };
+
+        let ds = S3SourceBuilder::from_env()
+            .endpoint(endpoint)
+            .bucket(bucket)
+            .prefix("loom-io-test")
+            .build();
+
+        let path = file("round_trip/a.json");
+        let _ = ds.delete(&path).await;
+
+        let record = Record::from_str(path.clone(), MediaType::TextJson, "{\"a\":1}");
+        ds.create(record.clone()).await.unwrap();
+        assert!(ds.create(record.clone()).await.unwrap_err().is_conflict());
+        assert!(ds.exists(&path).await.unwrap());
+        assert_eq!(ds.count(&file("round_trip")).await.unwrap(), 1);
+
+        let read = ds.find_one(&path).await.unwrap();
+        assert_eq!(read, record);
+        assert_eq!(read.media_type, MediaType::TextJson);
+
+        let updated = Record::from_str(path.clone(), MediaType::TextJson, "{\"a\":2}");
+        ds.update(updated.clone()).await.unwrap();
+        ds.upsert(updated.clone()).await.unwrap();
+        assert_eq!(ds.find(&file("round_trip")).await.unwrap(), vec![updated]);
+
+        ds.delete(&path).await.unwrap();
+        assert!(!ds.exists(&path).await.unwrap());
+        assert!(ds.find_one(&path).await.unwrap_err().is_io());
+    }
+}
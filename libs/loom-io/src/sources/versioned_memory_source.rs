@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use async_trait::async_trait;
+
+use crate::path::Path;
+
+use crate::{DataSource, ETag, Glob, Id, ReadError, Record, WriteError};
+
+#[derive(Debug, Clone)]
+pub struct VersionedMemorySourceConfig {
+    name: String,
+}
+
+impl VersionedMemorySourceConfig {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VersionedMemorySourceBuilder {
+    name: Option<String>,
+    initial_records: Vec<Record>,
+}
+
+impl VersionedMemorySourceBuilder {
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            initial_records: Vec::new(),
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Seed records; repeated paths become successive versions.
+    pub fn with_records(mut self, records: impl IntoIterator<Item = Record>) -> Self {
+        self.initial_records.extend(records);
+        self
+    }
+
+    pub fn with_record(mut self, record: Record) -> Self {
+        self.initial_records.push(record);
+        self
+    }
+
+    pub fn build(self) -> VersionedMemorySource {
+        let mut versions: HashMap<Id, Vec<Record>> = HashMap::new();
+        for record in self.initial_records {
+            push_version(versions.entry(record.id).or_default(), record);
+        }
+
+        VersionedMemorySource {
+            config: VersionedMemorySourceConfig {
+                name: self.name.unwrap_or_else(|| "memory".to_string()),
+            },
+            versions: RwLock::new(versions),
+        }
+    }
+}
+
+impl Default for VersionedMemorySourceBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An in-memory `DataSource` that keeps every version written to a path.
+///
+/// Reads behave like `MemorySource` and see the latest version. Every
+/// `create`, `update` and `upsert` appends a new version, even when the
+/// content is unchanged, which makes it easy for tests to assert how a
+/// document evolved through a pipeline's `save` calls. Each version's
+/// `ETag` mixes in its version number, so `upsert_if_match` tells apart
+/// versions with identical content. `delete` drops the path along with its
+/// history.
+///
+/// # Example
+/// ```ignore
+/// let source = VersionedMemorySource::default();
+/// // ... run a pipeline that saves to `path` three times ...
+///
+/// assert_eq!(source.history(&path)?.len(), 3);
+/// assert_eq!(source.read_version(&path, 1)?.unwrap().content_str()?, "first");
+/// ```
+pub struct VersionedMemorySource {
+    config: VersionedMemorySourceConfig,
+    versions: RwLock<HashMap<Id, Vec<Record>>>,
+}
+
+impl VersionedMemorySource {
+    pub fn builder() -> VersionedMemorySourceBuilder {
+        VersionedMemorySourceBuilder::new()
+    }
+
+    pub fn config(&self) -> &VersionedMemorySourceConfig {
+        &self.config
+    }
+
+    /// Every version written to `path`, oldest first.
+    pub fn history(&self, path: &Path) -> Result<Vec<Record>, ReadError> {
+        Ok(self
+            .read()?
+            .get(&Id::new(path.to_string().as_str()))
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    /// Version `n` of `path`, counting from 1 for the first write.
+    pub fn read_version(&self, path: &Path, n: usize) -> Result<Option<Record>, ReadError> {
+        let versions = self.read()?;
+        let Some(history) = versions.get(&Id::new(path.to_string().as_str())) else {
+            return Ok(None);
+        };
+
+        Ok(n.checked_sub(1).and_then(|i| history.get(i)).cloned())
+    }
+
+    fn read(&self) -> Result<RwLockReadGuard<'_, HashMap<Id, Vec<Record>>>, ReadError> {
+        self.versions
+            .read()
+            .map_err(|e| ReadError::Panic(e.to_string()))
+    }
+
+    fn write(&self) -> Result<RwLockWriteGuard<'_, HashMap<Id, Vec<Record>>>, WriteError> {
+        self.versions
+            .write()
+            .map_err(|e| WriteError::Panic(e.to_string()))
+    }
+
+    fn latest(&self) -> Result<Vec<Record>, ReadError> {
+        Ok(self
+            .read()?
+            .values()
+            .filter_map(|history| history.last())
+            .cloned()
+            .collect())
+    }
+}
+
+/// Append `record` as the next version in `history`, tagging it with its
+/// version number.
+fn push_version(history: &mut Vec<Record>, mut record: Record) {
+    record.etag = record.etag.with_version(history.len() + 1);
+    history.push(record);
+}
+
+impl Default for VersionedMemorySource {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+#[async_trait]
+impl DataSource for VersionedMemorySource {
+    fn name(&self) -> &str {
+        &self.config.name
+    }
+
+    async fn exists(&self, path: &Path) -> Result<bool, ReadError> {
+        let id = Id::new(path.to_string().as_str());
+        Ok(self.read()?.contains_key(&id))
+    }
+
+    async fn count(&self, path: &Path) -> Result<usize, ReadError> {
+        let path_str = path.to_string();
+        Ok(self
+            .latest()?
+            .iter()
+            .filter(|r| r.path.to_string().starts_with(&path_str))
+            .count())
+    }
+
+    async fn find_one(&self, path: &Path) -> Result<Record, ReadError> {
+        let id = Id::new(path.to_string().as_str());
+
+        self.read()?
+            .get(&id)
+            .and_then(|history| history.last())
+            .cloned()
+            .ok_or_else(|| ReadError::Custom(format!("record not found: {}", path)))
+    }
+
+    async fn find(&self, path: &Path) -> Result<Vec<Record>, ReadError> {
+        let path_str = path.to_string();
+        Ok(self
+            .latest()?
+            .into_iter()
+            .filter(|r| r.path.to_string().starts_with(&path_str))
+            .collect())
+    }
+
+    async fn find_many(&self, pattern: &Path) -> Result<Vec<Record>, ReadError> {
+        let glob = Glob::new(pattern.to_string());
+        let mut results: Vec<Record> = self
+            .latest()?
+            .into_iter()
+            .filter(|r| glob.is_match(&r.path.to_string()))
+            .collect();
+
+        results.sort_by_cached_key(|r| r.path.to_string());
+        Ok(results)
+    }
+
+    async fn create(&self, record: Record) -> Result<(), WriteError> {
+        let mut versions = self.write()?;
+
+        if versions.contains_key(&record.id) {
+            return Err(WriteError::Custom(format!(
+                "record already exists: {}",
+                record.path
+            )));
+        }
+
+        push_version(versions.entry(record.id).or_default(), record);
+        Ok(())
+    }
+
+    async fn update(&self, record: Record) -> Result<(), WriteError> {
+        let mut versions = self.write()?;
+
+        let Some(history) = versions.get_mut(&record.id) else {
+            return Err(WriteError::Custom(format!(
+                "record not found: {}",
+                record.path
+            )));
+        };
+
+        push_version(history, record);
+        Ok(())
+    }
+
+    async fn upsert(&self, record: Record) -> Result<(), WriteError> {
+        push_version(self.write()?.entry(record.id).or_default(), record);
+        Ok(())
+    }
+
+    async fn upsert_if_match(&self, record: Record, etag: ETag) -> Result<(), WriteError> {
+        let mut versions = self.write()?;
+
+        match versions.get_mut(&record.id) {
+            Some(history) if history.last().map(|r| r.etag) == Some(etag) => {
+                push_version(history, record);
+                Ok(())
+            }
+            _ => Err(WriteError::conflict(&record.path)),
+        }
+    }
+
+    async fn delete(&self, path: &Path) -> Result<(), WriteError> {
+        let id = Id::new(path.to_string().as_str());
+
+        if self.write()?.remove(&id).is_none() {
+            return Err(WriteError::Custom(format!("record not found: {}", path)));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MediaType, path::FilePath};
+
+    fn file(path: &str) -> Path {
+        Path::File(FilePath::parse(path))
+    }
+
+    fn make_record(path: &Path, content: &str) -> Record {
+        Record::from_str(path.clone(), MediaType::TextPlain, content)
+    }
+
+    #[tokio::test]
+    async fn test_upserts_are_kept_as_versions() {
+        let ds = VersionedMemorySource::default();
+        let path = file("/doc.txt");
+
+        for content in ["first", "second", "third"] {
+            ds.upsert(make_record(&path, content)).await.unwrap();
+        }
+
+        let history = ds.history(&path).unwrap();
+        assert_eq!(history.len(), 3);
+        assert_ne!(history[0].etag, history[1].etag);
+        assert_ne!(history[1].etag, history[2].etag);
+
+        for (n, content) in ["first", "second", "third"].iter().enumerate() {
+            let version = ds.read_version(&path, n + 1).unwrap().unwrap();
+            assert_eq!(version.content_str().unwrap(), *content);
+        }
+
+        assert!(ds.read_version(&path, 0).unwrap().is_none());
+        assert!(ds.read_version(&path, 4).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_reads_return_latest_version() {
+        let ds = VersionedMemorySource::builder()
+            .with_record(make_record(&file("/a.txt"), "v1"))
+            .with_record(make_record(&file("/a.txt"), "v2"))
+            .build();
+
+        ds.update(make_record(&file("/a.txt"), "v3")).await.unwrap();
+
+        let latest = ds.find_one(&file("/a.txt")).await.unwrap();
+        assert_eq!(latest.content_str().unwrap(), "v3");
+        assert_eq!(ds.find(&file("/")).await.unwrap(), vec![latest.clone()]);
+        assert_eq!(ds.count(&file("/")).await.unwrap(), 1);
+        assert_eq!(ds.history(&file("/a.txt")).unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_upsert_if_match_checks_latest_version() {
+        let ds = VersionedMemorySource::default();
+        let path = file("/a.txt");
+        ds.create(make_record(&path, "v1")).await.unwrap();
+
+        let read = ds.find_one(&path).await.unwrap();
+        ds.upsert(make_record(&path, "v2")).await.unwrap();
+
+        let err = ds
+            .upsert_if_match(make_record(&path, "v3"), read.etag)
+            .await
+            .unwrap_err();
+        assert!(err.is_conflict());
+        assert_eq!(ds.history(&path).unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_identical_upserts_get_distinct_etags() {
+        let ds = VersionedMemorySource::default();
+        let path = file("/a.txt");
+        ds.upsert(make_record(&path, "same")).await.unwrap();
+
+        let read = ds.find_one(&path).await.unwrap();
+        ds.upsert(make_record(&path, "same")).await.unwrap();
+
+        let history = ds.history(&path).unwrap();
+        assert_ne!(history[0].etag, history[1].etag);
+
+        let err = ds
+            .upsert_if_match(make_record(&path, "same"), read.etag)
+            .await
+            .unwrap_err();
+        assert!(err.is_conflict());
+    }
+
+    #[tokio::test]
+    async fn test_delete_drops_history() {
+        let ds = VersionedMemorySource::default();
+        let path = file("/a.txt");
+
+        ds.upsert(make_record(&path, "v1")).await.unwrap();
+        ds.upsert(make_record(&path, "v2")).await.unwrap();
+        ds.delete(&path).await.unwrap();
+
+        assert!(ds.history(&path).unwrap().is_empty());
+        assert!(!ds.exists(&path).await.unwrap());
+        assert!(ds.create(make_record(&path, "v1")).await.is_ok());
+    }
+
+    #[test]
+    fn test_builder_defaults() {
+        let ds = VersionedMemorySource::builder().build();
+        assert_eq!(ds.config().name(), "memory");
+    }
+}
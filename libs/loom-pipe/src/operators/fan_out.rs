@@ -1,6 +1,9 @@
 use crate::{Build, Operator, Pipe, Source};
 
 /// Fan-out: send the same input to multiple operators, collect all outputs
+///
+/// Branches run one after another, so outputs are always in branch order.
+/// Use `Parallel` to run them concurrently.
 pub struct FanOut<Input, Output> {
     branches: Vec<Box<dyn FnOnce(Source<Input>) -> Source<Output> + Send>>,
     _marker: std::marker::PhantomData<fn(Input) -> Output>,
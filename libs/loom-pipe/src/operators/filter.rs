@@ -1,3 +1,8 @@
+use std::future::Future;
+use std::pin::Pin;
+
+use loom_sync::tasks::Task;
+
 use crate::{Build, Operator, Pipe, Source};
 
 /// Filter: gate a single value based on a predicate (T -> Option<T>)
@@ -82,9 +87,76 @@ where
 
 impl<T: Send + 'static, P: Pipe<T> + Sized> FilterPipe<T> for P {}
 
+type BoxFuture = Pin<Box<dyn Future<Output = bool> + Send>>;
+
+/// FilterAsync: keep the items of a sequence whose async predicate resolves
+/// to `true` (Vec<T> -> Task<Vec<T>>)
+///
+/// Predicates for all items run concurrently on the tokio runtime; the
+/// kept items stay in their original order.
+pub struct FilterAsync<T> {
+    predicate: Box<dyn Fn(&T) -> BoxFuture + Send + Sync>,
+}
+
+impl<T> FilterAsync<T>
+where
+    T: Send + 'static,
+{
+    pub fn new<P, Fut>(predicate: P) -> Self
+    where
+        P: Fn(&T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = bool> + Send + 'static,
+    {
+        Self {
+            predicate: Box::new(move |x| Box::pin(predicate(x))),
+        }
+    }
+}
+
+impl<T> Operator<Vec<T>> for FilterAsync<T>
+where
+    T: Send + 'static,
+{
+    type Output = Task<Vec<T>>;
+
+    fn apply(self, src: Source<Vec<T>>) -> Source<Self::Output> {
+        Source::new(move || {
+            let items = src.build();
+            let checks: Vec<BoxFuture> = items.iter().map(|x| (self.predicate)(x)).collect();
+
+            loom_sync::spawn!(async move {
+                let keep = futures::future::join_all(checks).await;
+
+                items
+                    .into_iter()
+                    .zip(keep)
+                    .filter_map(|(item, keep)| keep.then_some(item))
+                    .collect::<Vec<_>>()
+            })
+        })
+    }
+}
+
+/// Extension trait for filtering sequences with an async predicate
+pub trait FilterAsyncPipe<T>: Pipe<Vec<T>> + Sized
+where
+    T: Send + 'static,
+{
+    fn filter_async<P, Fut>(self, predicate: P) -> Source<Task<Vec<T>>>
+    where
+        P: Fn(&T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = bool> + Send + 'static,
+    {
+        self.pipe(FilterAsync::new(predicate))
+    }
+}
+
+impl<T: Send + 'static, P: Pipe<Vec<T>> + Sized> FilterAsyncPipe<T> for P {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::operators::Await;
 
     #[test]
     fn allows_matching_single_value() {
@@ -127,4 +199,50 @@ mod tests {
         let result = Source::from(42).filter_block(|x| *x > 0).build();
         assert_eq!(result, None);
     }
+
+    #[test]
+    fn filter_async_drops_even_indexed_items() {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let _guard = rt.enter();
+
+        let items: Vec<(usize, &str)> = vec!["a", "b", "c", "d", "e"]
+            .into_iter()
+            .enumerate()
+            .collect();
+        let result = Source::from(items)
+            .pipe(FilterAsync::new(|(i, _): &(usize, &str)| {
+                let i = *i;
+                async move {
+                    // Later items resolve first, so order comes from the input.
+                    tokio::time::sleep(std::time::Duration::from_millis(10 - i as u64)).await;
+                    i % 2 == 1
+                }
+            }))
+            .pipe(Await::new())
+            .build();
+
+        assert_eq!(result.unwrap(), vec![(1, "b"), (3, "d")]);
+    }
+
+    #[test]
+    fn filter_async_pipe_trait() {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let _guard = rt.enter();
+
+        let result = Source::from(vec![1, -2, 3])
+            .filter_async(|x| {
+                let x = *x;
+                async move { x > 0 }
+            })
+            .pipe(Await::new())
+            .build();
+
+        assert_eq!(result.unwrap(), vec![1, 3]);
+    }
 }
@@ -1,11 +1,19 @@
+use futures::StreamExt;
+use futures::stream::FuturesUnordered;
 use loom_sync::tasks::{Task, TaskError, TaskResult};
 
 use crate::{Build, Operator, Pipe, Source};
 
 /// Parallel: execute multiple operators concurrently using tasks
 /// Unlike FanOut which executes sequentially, Parallel spawns tasks for each branch
+///
+/// By default results come back in the order the branches were added, so a
+/// slow first branch holds back the ones after it. With `ordered(false)`
+/// results are emitted as branches complete instead, which lets downstream
+/// stages see fast results first but makes the output order unpredictable.
 pub struct Parallel<Input, Output> {
     branches: Vec<Box<dyn FnOnce(Input) -> Output + Send>>,
+    ordered: bool,
     _marker: std::marker::PhantomData<fn(Input) -> Output>,
 }
 
@@ -17,10 +25,18 @@ where
     pub fn new() -> Self {
         Self {
             branches: Vec::new(),
+            ordered: true,
             _marker: std::marker::PhantomData,
         }
     }
 
+    /// Return results in branch order (`true`, the default) or in
+    /// completion order (`false`).
+    pub fn ordered(mut self, ordered: bool) -> Self {
+        self.ordered = ordered;
+        self
+    }
+
     pub fn add<F>(mut self, f: F) -> Self
     where
         F: FnOnce(Input) -> Output + Send + 'static,
@@ -61,6 +77,11 @@ where
                 })
                 .collect();
 
+            if !self.ordered {
+                let completed: FuturesUnordered<Task<Output>> = tasks.into_iter().collect();
+                return futures::executor::block_on(completed.collect());
+            }
+
             // Wait for all tasks to complete
             tasks
                 .into_iter()
@@ -113,6 +134,13 @@ where
         self
     }
 
+    /// Return results in fork order (`true`, the default) or in completion
+    /// order (`false`)
+    pub fn ordered(mut self, ordered: bool) -> Self {
+        self.parallel = self.parallel.ordered(ordered);
+        self
+    }
+
     /// Wait for all forked branches and collect results
     pub fn join(self) -> Vec<TaskResult<O>> {
         self.build()
@@ -267,6 +295,39 @@ mod tests {
         assert_eq!(counter.load(Ordering::SeqCst), 3);
     }
 
+    fn out_of_order(ordered: bool) -> Vec<u64> {
+        use std::thread;
+        use std::time::Duration;
+
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let _guard = rt.enter();
+
+        // The first branch finishes last.
+        let delays = [60u64, 30, 0];
+        let mut builder = Source::from(()).parallel().ordered(ordered);
+        for delay in delays {
+            builder = builder.fork(move |_| {
+                thread::sleep(Duration::from_millis(delay));
+                delay
+            });
+        }
+
+        builder.join().into_iter().map(|r| r.unwrap()).collect()
+    }
+
+    #[test]
+    fn ordered_restores_branch_order() {
+        assert_eq!(out_of_order(true), vec![60, 30, 0]);
+    }
+
+    #[test]
+    fn unordered_emits_as_completed() {
+        assert_eq!(out_of_order(false), vec![0, 30, 60]);
+    }
+
     #[test]
     fn parallel_pipe_trait() {
         let rt = tokio::runtime::Builder::new_multi_thread()
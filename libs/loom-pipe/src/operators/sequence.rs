@@ -57,6 +57,50 @@ where
     }
 }
 
+/// Enumerate operator - pairs each element with its 0-based index
+pub struct Enumerate;
+
+impl<T> Operator<Vec<T>> for Enumerate
+where
+    T: Send + 'static,
+{
+    type Output = Vec<(usize, T)>;
+
+    fn apply(self, src: Source<Vec<T>>) -> Source<Self::Output> {
+        Source::new(move || src.build().into_iter().enumerate().collect())
+    }
+}
+
+/// MapIndexed operator - maps each element along with its 0-based index
+pub struct MapIndexed<F> {
+    f: F,
+}
+
+impl<F> MapIndexed<F> {
+    pub fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<T, U, F> Operator<Vec<T>> for MapIndexed<F>
+where
+    T: Send + 'static,
+    U: Send + 'static,
+    F: Fn(usize, T) -> U + Send + 'static,
+{
+    type Output = Vec<U>;
+
+    fn apply(self, src: Source<Vec<T>>) -> Source<Self::Output> {
+        Source::new(move || {
+            src.build()
+                .into_iter()
+                .enumerate()
+                .map(|(i, x)| (self.f)(i, x))
+                .collect()
+        })
+    }
+}
+
 /// Chunk operator - splits into fixed-size chunks
 pub struct Chunk {
     size: usize,
@@ -156,6 +200,20 @@ where
         self.pipe(FlatMap::new(f))
     }
 
+    /// Pairs each element with its 0-based index
+    fn enumerate(self) -> Source<Vec<(usize, T)>> {
+        self.pipe(Enumerate)
+    }
+
+    /// Maps each element along with its 0-based index
+    fn map_indexed<U, F>(self, f: F) -> Source<Vec<U>>
+    where
+        U: Send + 'static,
+        F: Fn(usize, T) -> U + Send + 'static,
+    {
+        self.pipe(MapIndexed::new(f))
+    }
+
     /// Splits into fixed-size chunks
     fn chunk(self, size: usize) -> Source<Vec<Vec<T>>>
     where
@@ -183,6 +241,7 @@ impl<T: Send + 'static, P: Pipe<Vec<T>> + Sized> SequencePipe<T> for P {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::operators::MapPipe;
 
     #[test]
     fn flatten_nested_vectors() {
@@ -214,6 +273,21 @@ mod tests {
         assert_eq!(result, Vec::<i32>::new());
     }
 
+    #[test]
+    fn enumerate_pairs_with_index() {
+        let result = Source::from(vec!["a", "b", "c"]).enumerate().build();
+        assert_eq!(result, vec![(0, "a"), (1, "b"), (2, "c")]);
+    }
+
+    #[test]
+    fn map_indexed_counts_after_filter() {
+        let result = Source::from(vec![5, -1, 7, -3, 9])
+            .map(|items: Vec<i32>| items.into_iter().filter(|x| *x > 0).collect::<Vec<_>>())
+            .map_indexed(|i, x| format!("{}/{}", i, x))
+            .build();
+        assert_eq!(result, vec!["0/5", "1/7", "2/9"]);
+    }
+
     #[test]
     fn chunk_exact_fit() {
         let result = Source::from(vec![1, 2, 3, 4]).chunk(2).build();
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use loom_sync::tasks::{Task, TaskError, TaskResult};
 
 use crate::{Build, Operator, Pipe, Source};
@@ -34,6 +36,42 @@ where
     }
 }
 
+/// AwaitTimeout: like `Await`, but gives up after `duration`
+///
+/// A task that hasn't resolved in time yields
+/// `TaskResult::Error(TaskError::Timeout(duration))` and is dropped, so a
+/// stuck upstream can't hang the pipeline. The deadline is a
+/// `tokio::time::timeout`, so like `Fork` it needs a Tokio runtime (with
+/// time enabled) to be entered.
+pub struct AwaitTimeout {
+    duration: Duration,
+}
+
+impl AwaitTimeout {
+    pub fn new(duration: Duration) -> Self {
+        Self { duration }
+    }
+}
+
+impl<T> Operator<Task<T>> for AwaitTimeout
+where
+    T: Send + 'static,
+{
+    type Output = TaskResult<T>;
+
+    fn apply(self, src: Source<Task<T>>) -> Source<Self::Output> {
+        let duration = self.duration;
+        Source::new(move || {
+            let task = src.build();
+
+            match futures::executor::block_on(tokio::time::timeout(duration, task)) {
+                Ok(result) => result,
+                Err(_) => TaskResult::Error(TaskError::Timeout(duration)),
+            }
+        })
+    }
+}
+
 pub trait AwaitPipe<T>: Pipe<Task<T>> + Sized
 where
     T: Send + 'static,
@@ -41,6 +79,11 @@ where
     fn wait(self) -> Source<TaskResult<T>> {
         self.pipe(Await::new())
     }
+
+    /// Wait for the task, failing with `TaskError::Timeout` after `duration`
+    fn wait_timeout(self, duration: Duration) -> Source<TaskResult<T>> {
+        self.pipe(AwaitTimeout::new(duration))
+    }
 }
 
 impl<T: Send + 'static, P: Pipe<Task<T>> + Sized> AwaitPipe<T> for P {}
@@ -90,7 +133,7 @@ mod tests {
         let _guard = rt.enter();
         let result = Source::from(10)
             .pipe(Fork::new(|x| x + 5))
-            .pipe(Await::default())
+            .pipe(Await)
             .build();
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 15);
@@ -110,4 +153,46 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 15);
     }
+
+    #[test]
+    fn wait_timeout_resolves_in_time() {
+        use super::AwaitPipe;
+        use crate::operators::ForkPipe;
+
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let _guard = rt.enter();
+        let result = Source::from(5)
+            .fork(|x| x * 3)
+            .wait_timeout(Duration::from_secs(5))
+            .build();
+        assert_eq!(result.unwrap(), 15);
+    }
+
+    #[test]
+    fn wait_timeout_gives_up_on_stuck_task() {
+        use super::AwaitPipe;
+
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let _guard = rt.enter();
+
+        // Keep the resolver alive so the task never resolves or drops.
+        let (task, resolver) = loom_sync::spawn!();
+        let started = std::time::Instant::now();
+        let result: TaskResult<i32> = Source::new(move || task)
+            .wait_timeout(Duration::from_millis(50))
+            .build();
+
+        assert!(started.elapsed() >= Duration::from_millis(50));
+        assert_eq!(
+            result.unwrap_err(),
+            TaskError::Timeout(Duration::from_millis(50))
+        );
+        drop(resolver);
+    }
 }
@@ -1,3 +1,5 @@
+use loom_error::Result;
+
 use super::{Layer, Pipeline};
 
 /// Builder for constructing pipelines.
@@ -16,6 +18,12 @@ impl<C> PipelineBuilder<C> {
         self
     }
 
+    /// Check the layers' `expects`/`produces` hints line up before building;
+    /// see `Pipeline::validate`.
+    pub fn validate(&self) -> Result<()> {
+        super::pipeline::validate(&self.layers)
+    }
+
     /// Build the final pipeline.
     pub fn build(self) -> Pipeline<C> {
         Pipeline::new(self.layers)
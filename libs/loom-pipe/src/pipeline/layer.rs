@@ -1,6 +1,40 @@
 use loom_core::value::Value;
 use loom_error::Result;
 
+/// What a layer asks the pipeline to do after it runs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LayerOutcome {
+    /// Pass the value on to the next layer.
+    Continue(Value),
+
+    /// Stop the pipeline and return the value as its result.
+    Halt(Value),
+}
+
+impl LayerOutcome {
+    pub fn is_halt(&self) -> bool {
+        matches!(self, Self::Halt(_))
+    }
+
+    pub fn value(&self) -> &Value {
+        match self {
+            Self::Continue(value) | Self::Halt(value) => value,
+        }
+    }
+
+    pub fn into_value(self) -> Value {
+        match self {
+            Self::Continue(value) | Self::Halt(value) => value,
+        }
+    }
+}
+
+impl From<Value> for LayerOutcome {
+    fn from(value: Value) -> Self {
+        Self::Continue(value)
+    }
+}
+
 /// A processing layer in a pipeline.
 ///
 /// Each layer specifies its context type via the `Input` associated type
@@ -10,7 +44,25 @@ pub trait Layer: Send + Sync {
 
     fn process(&self, ctx: &Self::Input) -> Result<Value>;
 
+    /// Run the layer and decide whether the pipeline continues. Defaults to
+    /// `process` followed by `LayerOutcome::Continue`; layers that can end
+    /// the pipeline early override this to return `LayerOutcome::Halt`.
+    fn run(&self, ctx: &Self::Input) -> Result<LayerOutcome> {
+        self.process(ctx).map(LayerOutcome::Continue)
+    }
+
     fn name(&self) -> &'static str {
         std::any::type_name::<Self>()
     }
+
+    /// The `Value::kind` this layer needs its input to be, if it needs a
+    /// particular one. Checked by `Pipeline::validate`.
+    fn expects(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// The `Value::kind` this layer always outputs, if it is fixed.
+    fn produces(&self) -> Option<&'static str> {
+        None
+    }
 }
@@ -0,0 +1,93 @@
+use std::time::Duration;
+
+use loom_core::value::{Object, Value};
+
+/// Counters for one pipeline layer, accumulated across executions.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct LayerMetrics {
+    /// `Layer::name()` of the layer
+    pub name: &'static str,
+
+    /// Number of inputs the layer was given
+    pub items_in: u64,
+
+    /// Number of inputs the layer processed successfully
+    pub items_out: u64,
+
+    /// Number of inputs the layer failed on
+    pub errors: u64,
+
+    /// Total time spent in `Layer::process`, serialized as
+    /// `total_time_ms`
+    #[serde(rename = "total_time_ms", serialize_with = "serialize_ms")]
+    pub total_time: Duration,
+}
+
+fn serialize_ms<S: serde::Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64() * 1000.0)
+}
+
+/// A snapshot of per-layer metrics, one entry per layer in pipeline order.
+///
+/// Collected by a pipeline built with `Pipeline::with_metrics`.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct PipelineMetrics {
+    pub layers: Vec<LayerMetrics>,
+}
+
+impl PipelineMetrics {
+    pub(crate) fn new(names: impl IntoIterator<Item = &'static str>) -> Self {
+        Self {
+            layers: names
+                .into_iter()
+                .map(|name| LayerMetrics {
+                    name,
+                    ..Default::default()
+                })
+                .collect(),
+        }
+    }
+
+    pub(crate) fn record(&mut self, index: usize, ok: bool, elapsed: Duration) {
+        let Some(layer) = self.layers.get_mut(index) else {
+            return;
+        };
+
+        layer.items_in += 1;
+        layer.total_time += elapsed;
+
+        match ok {
+            true => layer.items_out += 1,
+            false => layer.errors += 1,
+        }
+    }
+
+    /// Metrics of the layer named `name`, if the pipeline has one.
+    pub fn layer(&self, name: &str) -> Option<&LayerMetrics> {
+        self.layers.iter().find(|layer| layer.name == name)
+    }
+}
+
+impl From<&LayerMetrics> for Value {
+    fn from(metrics: &LayerMetrics) -> Self {
+        let mut object = Object::new();
+        object.insert("name".to_string(), Value::from(metrics.name));
+        object.insert("items_in".to_string(), Value::from(metrics.items_in));
+        object.insert("items_out".to_string(), Value::from(metrics.items_out));
+        object.insert("errors".to_string(), Value::from(metrics.errors));
+        object.insert(
+            "total_time_ms".to_string(),
+            Value::from(metrics.total_time.as_secs_f64() * 1000.0),
+        );
+        Value::Object(object)
+    }
+}
+
+impl From<&PipelineMetrics> for Value {
+    fn from(metrics: &PipelineMetrics) -> Self {
+        Value::from(metrics.layers.iter().map(Value::from).collect::<Vec<_>>())
+    }
+}
@@ -1,7 +1,9 @@
 mod builder;
 mod layer;
+mod metrics;
 mod pipeline;
 
 pub use builder::*;
 pub use layer::*;
+pub use metrics::*;
 pub use pipeline::*;
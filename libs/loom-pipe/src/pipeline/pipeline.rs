@@ -1,4 +1,9 @@
-use super::Layer;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use loom_error::{Error, ErrorCode, Result};
+
+use super::{Layer, PipelineMetrics};
 
 /// A collection of layers to be executed in sequence.
 ///
@@ -6,11 +11,24 @@ use super::Layer;
 /// (e.g. the Runtime), which creates a new context for each layer.
 pub struct Pipeline<C> {
     layers: Vec<Box<dyn Layer<Input = C>>>,
+    metrics: Option<Mutex<PipelineMetrics>>,
 }
 
 impl<C> Pipeline<C> {
     pub fn new(layers: Vec<Box<dyn Layer<Input = C>>>) -> Self {
-        Self { layers }
+        Self {
+            layers,
+            metrics: None,
+        }
+    }
+
+    /// Accumulate per-layer counts and timings across executions, read
+    /// back with `metrics`. The caller driving execution reports each layer
+    /// run with `record`.
+    pub fn with_metrics(mut self) -> Self {
+        let names = self.layers.iter().map(|layer| layer.name());
+        self.metrics = Some(Mutex::new(PipelineMetrics::new(names)));
+        self
     }
 
     pub fn layers(&self) -> &[Box<dyn Layer<Input = C>>] {
@@ -24,4 +42,143 @@ impl<C> Pipeline<C> {
     pub fn is_empty(&self) -> bool {
         self.layers.is_empty()
     }
+
+    /// Check that each layer's `expects` hint matches what the layer before
+    /// it `produces`. Pairs where either side gives no hint are skipped, so
+    /// this only catches mismatches the layers declare.
+    pub fn validate(&self) -> Result<()> {
+        validate(&self.layers)
+    }
+
+    /// Record one run of the layer at `index`. Does nothing unless the
+    /// pipeline was built `with_metrics`.
+    pub fn record(&self, index: usize, ok: bool, elapsed: Duration) {
+        if let Some(metrics) = &self.metrics {
+            metrics
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .record(index, ok, elapsed);
+        }
+    }
+
+    /// A snapshot of the metrics collected so far, or `None` if the
+    /// pipeline was not built `with_metrics`.
+    pub fn metrics(&self) -> Option<PipelineMetrics> {
+        self.metrics
+            .as_ref()
+            .map(|metrics| metrics.lock().unwrap_or_else(|e| e.into_inner()).clone())
+    }
+}
+
+pub(crate) fn validate<C>(layers: &[Box<dyn Layer<Input = C>>]) -> Result<()> {
+    let mismatches: Vec<String> = layers
+        .windows(2)
+        .filter_map(|pair| {
+            let (expects, produces) = (pair[1].expects()?, pair[0].produces()?);
+
+            (expects != produces).then(|| {
+                format!(
+                    "layer '{}' expects {} but '{}' produces {}",
+                    pair[1].name(),
+                    expects,
+                    pair[0].name(),
+                    produces
+                )
+            })
+        })
+        .collect();
+
+    if mismatches.is_empty() {
+        return Ok(());
+    }
+
+    Err(Error::builder()
+        .code(ErrorCode::BadArguments)
+        .message(mismatches.join("; "))
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use loom_core::value::{Object, Value};
+
+    use super::*;
+    use crate::PipelineBuilder;
+
+    struct Split;
+
+    impl Layer for Split {
+        type Input = Value;
+
+        fn process(&self, ctx: &Value) -> Result<Value> {
+            let words: Vec<&str> = ctx.as_str().unwrap_or_default().split(' ').collect();
+            Ok(Value::from(words))
+        }
+
+        fn name(&self) -> &'static str {
+            "split"
+        }
+
+        fn expects(&self) -> Option<&'static str> {
+            Some("string")
+        }
+
+        fn produces(&self) -> Option<&'static str> {
+            Some("array")
+        }
+    }
+
+    struct Fields;
+
+    impl Layer for Fields {
+        type Input = Value;
+
+        fn process(&self, _ctx: &Value) -> Result<Value> {
+            Ok(Value::Object(Object::new()))
+        }
+
+        fn name(&self) -> &'static str {
+            "fields"
+        }
+
+        fn expects(&self) -> Option<&'static str> {
+            Some("object")
+        }
+    }
+
+    struct Passthrough;
+
+    impl Layer for Passthrough {
+        type Input = Value;
+
+        fn process(&self, ctx: &Value) -> Result<Value> {
+            Ok(ctx.clone())
+        }
+    }
+
+    #[test]
+    fn test_validate_reports_mismatch() {
+        let builder = PipelineBuilder::new().then(Split).then(Fields);
+
+        let err = builder.validate().unwrap_err();
+
+        assert!(err.code().is_bad_arguments());
+        assert_eq!(
+            err.message(),
+            Some("layer 'fields' expects object but 'split' produces array")
+        );
+        assert!(builder.build().validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_skips_layers_without_hints() {
+        let pipeline = PipelineBuilder::new()
+            .then(Split)
+            .then(Passthrough)
+            .then(Fields)
+            .build();
+
+        assert!(pipeline.validate().is_ok());
+        assert!(PipelineBuilder::<Value>::new().validate().is_ok());
+    }
 }
@@ -41,6 +41,10 @@ pub struct LoomConfig {
     #[serde(default = "LoomConfig::default_batch_size")]
     #[validate(minimum = 1)]
     pub batch_size: usize,
+
+    /// Write a resumable checkpoint every N samples (disabled if unset)
+    #[serde(default)]
+    pub checkpoint_interval: Option<usize>,
 }
 
 impl LoomConfig {
@@ -60,6 +64,7 @@ impl Default for LoomConfig {
             strict: false,
             concurrency: Self::default_concurrency(),
             batch_size: Self::default_batch_size(),
+            checkpoint_interval: None,
         }
     }
 }
@@ -75,6 +80,7 @@ mod tests {
         assert_eq!(config.batch_size, 8);
         assert!(!config.strict);
         assert!(config.output.is_none());
+        assert!(config.checkpoint_interval.is_none());
     }
 
     #[test]
@@ -85,15 +91,19 @@ mod tests {
 
     #[test]
     fn invalid_concurrency_fails_validation() {
-        let mut config = LoomConfig::default();
-        config.concurrency = 0;
+        let config = LoomConfig {
+            concurrency: 0,
+            ..Default::default()
+        };
         assert!(config.validate().is_err());
     }
 
     #[test]
     fn invalid_batch_size_fails_validation() {
-        let mut config = LoomConfig::default();
-        config.batch_size = 0;
+        let config = LoomConfig {
+            batch_size: 0,
+            ..Default::default()
+        };
         assert!(config.validate().is_err());
     }
 
@@ -114,7 +124,8 @@ mod tests {
             "output": "results.json",
             "strict": true,
             "concurrency": 8,
-            "batch_size": 16
+            "batch_size": 16,
+            "checkpoint_interval": 100
         }"#;
         let config: LoomConfig = serde_json::from_str(json).unwrap();
 
@@ -122,6 +133,7 @@ mod tests {
         assert_eq!(config.batch_size, 16);
         assert!(config.strict);
         assert_eq!(config.output, Some(PathBuf::from("results.json")));
+        assert_eq!(config.checkpoint_interval, Some(100));
     }
 
     #[test]
@@ -1,13 +1,19 @@
-use std::sync::Arc;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
 
 use loom_core::{Map, value::Value};
 use loom_io::DataSourceRegistry;
-use loom_signal::{Emitter, Signal};
+use loom_signal::{Emitter, Signal, Span, SpanGuard};
+use loom_sync::tasks::CancellationToken;
+
+type Extensions = HashMap<TypeId, Box<dyn Any + Send + Sync>>;
 
 /// Runtime execution context providing emitter and data source access to layers.
 pub struct RunContext {
     input: Value,
     meta: Map,
+    extensions: Arc<RwLock<Extensions>>,
     emitter: Arc<dyn Emitter + Send + Sync>,
     sources: Arc<DataSourceRegistry>,
 }
@@ -21,6 +27,7 @@ impl RunContext {
         Self {
             input: input.into(),
             meta: Map::new(),
+            extensions: Arc::default(),
             emitter,
             sources,
         }
@@ -31,11 +38,27 @@ impl RunContext {
         Self {
             input,
             meta: self.meta.clone(),
+            extensions: self.extensions.clone(),
             emitter: self.emitter.clone(),
             sources: self.sources.clone(),
         }
     }
 
+    /// Create a new context for the next layer whose input is this input
+    /// deep-merged with `partial`, so enrichment layers only return the
+    /// fields they add. Objects merge key by key; any other `partial`
+    /// replaces the value it lands on, like `Value::merge`.
+    pub fn merge_next(&self, partial: Value) -> Self {
+        self.next(self.merged(partial))
+    }
+
+    /// This context's input deep-merged with `partial`.
+    pub fn merged(&self, partial: Value) -> Value {
+        let mut input = self.input.clone();
+        input.merge(partial);
+        input
+    }
+
     pub fn sources(&self) -> &DataSourceRegistry {
         &self.sources
     }
@@ -50,6 +73,42 @@ impl RunContext {
         &self.meta
     }
 
+    /// Stash a value for later layers, replacing any earlier value of the
+    /// same type. Values are keyed by type and shared by every context
+    /// derived through `next`, so they never touch the layer `Value`.
+    pub fn set<T: Any + Send + Sync>(&self, value: T) {
+        self.extensions
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(TypeId::of::<T>(), Box::new(value));
+    }
+
+    /// A copy of the value of type `T` set by this or an earlier layer.
+    pub fn get<T: Any + Clone>(&self) -> Option<T> {
+        self.extensions
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&TypeId::of::<T>())
+            .and_then(|value| value.downcast_ref::<T>())
+            .cloned()
+    }
+
+    /// The token passed to `Runtime::execute_cancellable`, for layers
+    /// that can stop long work early. `None` for other runs.
+    pub fn cancellation(&self) -> Option<CancellationToken> {
+        self.get::<CancellationToken>()
+    }
+
+    /// Whether the run has been cancelled. Always `false` outside
+    /// `Runtime::execute_cancellable`.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation().is_some_and(|t| t.is_cancelled())
+    }
+
+    pub fn emitter(&self) -> &dyn Emitter {
+        &*self.emitter
+    }
+
     pub fn emit(&self, name: &str, attrs: &Map) {
         let mut builder = Signal::new().name(name);
         for (k, v) in attrs.iter() {
@@ -57,4 +116,65 @@ impl RunContext {
         }
         self.emitter.emit(builder.build());
     }
+
+    /// Enter a timed span that is emitted with its duration when the guard drops.
+    pub fn span(&self, name: &str) -> SpanGuard<'_> {
+        Span::new(name).enter(&*self.emitter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use loom_core::value::Value;
+    use loom_error::Result;
+    use loom_pipe::Layer;
+
+    use crate::{RunContext, Runtime};
+
+    #[derive(Clone)]
+    struct Elapsed(Duration);
+
+    struct Timed;
+
+    impl Layer for Timed {
+        type Input = RunContext;
+
+        fn process(&self, ctx: &RunContext) -> Result<Value> {
+            ctx.set(Elapsed(Duration::from_millis(5)));
+            Ok(ctx.input().clone())
+        }
+
+        fn name(&self) -> &'static str {
+            "timed"
+        }
+    }
+
+    struct Report;
+
+    impl Layer for Report {
+        type Input = RunContext;
+
+        fn process(&self, ctx: &RunContext) -> Result<Value> {
+            let elapsed = ctx.get::<Elapsed>().map(|e| e.0.as_millis() as i64);
+            Ok(Value::from(elapsed.unwrap_or(-1)))
+        }
+
+        fn name(&self) -> &'static str {
+            "report"
+        }
+    }
+
+    #[test]
+    fn later_layer_reads_metadata_set_by_earlier_layer() {
+        let runtime = Runtime::new().layer(Timed).layer(Report).build();
+        assert_eq!(runtime.execute("input").unwrap(), Value::from(5i64));
+    }
+
+    #[test]
+    fn get_missing_type_is_none() {
+        let runtime = Runtime::new().layer(Report).build();
+        assert_eq!(runtime.execute("input").unwrap(), Value::from(-1i64));
+    }
 }
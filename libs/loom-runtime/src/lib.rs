@@ -1,42 +1,68 @@
 mod config;
 mod context;
+mod merge;
+mod parallel;
+mod trace;
 
 pub use config::*;
 pub use context::*;
+pub use merge::*;
+pub use parallel::*;
+pub use trace::*;
 
 use std::sync::Arc;
+use std::time::Instant;
 
+use futures::{StreamExt, TryStreamExt, stream};
 use loom_codec::CodecRegistryBuilder;
 use loom_core::{Format, MediaType, decode, encode, value::Value};
-use loom_error::Result;
-use loom_io::{DataSourceRegistry, DataSourceRegistryBuilder, path::Path};
-use loom_pipe::{Layer, Pipeline};
+use loom_error::{Error, ErrorCode, Result};
+use loom_io::{DataSource, DataSourceRegistry, DataSourceRegistryBuilder, path::Path};
+use loom_pipe::{Layer, LayerOutcome, Pipeline};
 use serde::{Serialize, de::DeserializeOwned};
 
+// Re-export cancellation types
+pub use loom_sync::tasks::CancellationToken;
+
+// Re-export pipeline metrics types
+pub use loom_pipe::{LayerMetrics, PipelineMetrics};
+
 // Re-export config types
 pub use loom_config::{Config as RConfig, ConfigError};
 
 // Re-export codec types
 #[cfg(feature = "toml")]
 pub use loom_codec::TomlCodec;
+#[cfg(feature = "zstd")]
+pub use loom_codec::ZstdCodec;
 #[cfg(feature = "yaml")]
-pub use loom_codec::YamlCodec;
-pub use loom_codec::{JsonCodec, TextCodec};
+pub use loom_codec::{FrontMatterCodec, YamlCodec};
+pub use loom_codec::{JsonCodec, Limits, NdJsonCodec, TextCodec};
 
 // Re-export IO types
-pub use loom_io::Record;
 pub use loom_io::sources::FileSystemSource;
+#[cfg(feature = "http")]
+pub use loom_io::sources::HttpSource;
+#[cfg(feature = "postgres")]
+pub use loom_io::sources::PostgresSource;
+#[cfg(feature = "s3")]
+pub use loom_io::sources::S3Source;
+pub use loom_io::{ETag, Record};
 
 // Re-export signal types
 pub use loom_signal::{
-    Emitter, Level, NoopEmitter, Signal, SignalBroadcaster, Span, Type as SignalType,
+    Emitter, Level, NoopEmitter, Signal, SignalBroadcaster, Span, SpanGuard, Type as SignalType,
     consumers::{FileEmitter, MemoryEmitter, StdoutEmitter},
 };
 
+/// Default number of records `Runtime::load_many` fetches at once.
+pub const DEFAULT_LOAD_CONCURRENCY: usize = 8;
+
 pub struct Runtime {
     sources: Arc<DataSourceRegistry>,
     pipeline: Pipeline<RunContext>,
     signals: Arc<dyn Emitter + Send + Sync>,
+    load_concurrency: usize,
 }
 
 impl Runtime {
@@ -47,47 +73,222 @@ impl Runtime {
     /// Execute the pipeline on a given input value.
     ///
     /// Creates a `RunContext` with the runtime's emitter and data sources,
-    /// then threads the value through each layer.
+    /// then threads the value through each layer. A layer returning
+    /// `LayerOutcome::Halt` ends the run early with its value; the remaining
+    /// layers are skipped and a `pipeline.halted` event is emitted.
     pub fn execute(&self, input: impl Into<Value>) -> Result<Value> {
-        let mut ctx = RunContext::new(input, self.signals.clone(), self.sources.clone());
+        let ctx = RunContext::new(input, self.signals.clone(), self.sources.clone());
+        self.run(ctx, None, None)
+    }
+
+    /// Execute the pipeline like `execute`, stopping early once `token` is
+    /// cancelled.
+    ///
+    /// The token is checked before each layer; a cancelled run emits a
+    /// `pipeline.cancelled` event and fails with `ErrorCode::Cancel`
+    /// without running the remaining layers. Layers can read the token
+    /// with `RunContext::cancellation` to stop long work of their own.
+    pub fn execute_cancellable(
+        &self,
+        input: impl Into<Value>,
+        token: &CancellationToken,
+    ) -> Result<Value> {
+        let ctx = RunContext::new(input, self.signals.clone(), self.sources.clone());
+        ctx.set(token.clone());
+        self.run(ctx, Some(token), None)
+    }
 
-        for layer in self.pipeline.layers() {
-            let output = layer.process(&ctx)?;
-            ctx = ctx.next(output);
+    /// Thread `ctx` through each layer, recording metrics, honouring halts
+    /// and `token`, and pushing a `LayerTrace` per layer run to `traces`.
+    fn run(
+        &self,
+        mut ctx: RunContext,
+        token: Option<&CancellationToken>,
+        mut traces: Option<&mut Vec<LayerTrace>>,
+    ) -> Result<Value> {
+        for (i, layer) in self.pipeline.layers().iter().enumerate() {
+            if let Some(token) = token
+                && token.is_cancelled()
+            {
+                return Err(self.cancelled(i, layer.name()));
+            }
+
+            let started = Instant::now();
+            let output = layer.run(&ctx);
+            let duration = started.elapsed();
+            self.pipeline.record(i, output.is_ok(), duration);
+            let output = output?;
+
+            if let Some(traces) = traces.as_deref_mut() {
+                traces.push(LayerTrace {
+                    name: layer.name(),
+                    input_kind: ctx.input().kind().to_string(),
+                    output_kind: output.value().kind().to_string(),
+                    duration,
+                });
+            }
+
+            match output {
+                LayerOutcome::Continue(value) => ctx = ctx.next(value),
+                LayerOutcome::Halt(value) => {
+                    self.emit_halted(i, layer.name());
+                    return Ok(value);
+                }
+            }
         }
 
         Ok(ctx.input().clone())
     }
 
+    /// Execute the pipeline like `execute`, also returning a `LayerTrace`
+    /// per layer, in order, to debug which layer produced an unexpected
+    /// result.
+    pub fn execute_traced(&self, input: impl Into<Value>) -> Result<(Value, Vec<LayerTrace>)> {
+        let ctx = RunContext::new(input, self.signals.clone(), self.sources.clone());
+        let mut traces = Vec::with_capacity(self.pipeline.layers().len());
+        let output = self.run(ctx, None, Some(&mut traces))?;
+
+        Ok((output, traces))
+    }
+
+    /// Execute the pipeline like `execute_traced`, stopping early once
+    /// `token` is cancelled, as `execute_cancellable` does.
+    pub fn execute_traced_cancellable(
+        &self,
+        input: impl Into<Value>,
+        token: &CancellationToken,
+    ) -> Result<(Value, Vec<LayerTrace>)> {
+        let ctx = RunContext::new(input, self.signals.clone(), self.sources.clone());
+        ctx.set(token.clone());
+
+        let mut traces = Vec::with_capacity(self.pipeline.layers().len());
+        let output = self.run(ctx, Some(token), Some(&mut traces))?;
+
+        Ok((output, traces))
+    }
+
+    /// Check that adjacent layers' `expects`/`produces` hints line up; see
+    /// `Pipeline::validate`. Call after building a runtime from layers
+    /// chosen at runtime, before executing it.
+    pub fn validate(&self) -> Result<()> {
+        self.pipeline.validate()
+    }
+
+    /// A snapshot of the per-layer metrics accumulated by `execute` and
+    /// `execute_traced`, or `None` unless built with `Builder::metrics`.
+    pub fn metrics(&self) -> Option<PipelineMetrics> {
+        self.pipeline.metrics()
+    }
+
+    /// Emit the current metrics snapshot as a `pipeline.metrics` event with
+    /// a `layers` field. Does nothing unless built with `Builder::metrics`.
+    pub fn emit_metrics(&self) {
+        if let Some(metrics) = self.metrics() {
+            Signal::event("pipeline.metrics")
+                .field("layers", &metrics)
+                .emit(self.signals.as_ref());
+        }
+    }
+
+    /// Flush every emitter added with `Builder::emitter`. Call before the
+    /// process exits so buffered signals are not lost.
+    pub fn flush_signals(&self) {
+        self.signals.flush();
+    }
+
+    /// Emit a `pipeline.halted` event for the layer at `index` that ended
+    /// the pipeline early.
+    fn emit_halted(&self, index: usize, name: &str) {
+        Signal::event("pipeline.halted")
+            .field("layer", name)
+            .field("index", index)
+            .emit(self.signals.as_ref());
+    }
+
+    /// Emit `pipeline.cancelled` for the layer that was about to run and
+    /// build the error `execute_cancellable` returns.
+    fn cancelled(&self, index: usize, name: &str) -> Error {
+        Signal::event("pipeline.cancelled")
+            .field("layer", name)
+            .field("index", index)
+            .emit(self.signals.as_ref());
+
+        Error::builder()
+            .code(ErrorCode::Cancel)
+            .message(format!("pipeline cancelled before layer '{}'", name))
+            .build()
+    }
+
     /// Load and deserialize data from a DataSource.
     pub async fn load<T: DeserializeOwned>(&self, source: &str, path: &Path) -> Result<T> {
-        let source = self.sources.get(source).ok_or_else(|| {
-            loom_error::Error::builder()
-                .code(loom_error::ErrorCode::NotFound)
-                .message(format!("DataSource '{}' not found", source))
-                .build()
-        })?;
+        let (data, _) = self.load_with_etag(source, path).await?;
+        Ok(data)
+    }
+
+    /// Load and deserialize several paths concurrently, returning the
+    /// values in the order of `paths`.
+    ///
+    /// At most `Builder::load_concurrency` records are fetched at once. The
+    /// first path that fails to load aborts the batch with its error; use
+    /// `load_many_results` to keep going past failures.
+    pub async fn load_many<T: DeserializeOwned>(
+        &self,
+        source: &str,
+        paths: &[Path],
+    ) -> Result<Vec<T>> {
+        stream::iter(paths)
+            .map(|path| self.load(source, path))
+            .buffered(self.load_concurrency)
+            .try_collect()
+            .await
+    }
 
+    /// Like `load_many`, but loads every path and returns a result per
+    /// path, in the order of `paths`.
+    pub async fn load_many_results<T: DeserializeOwned>(
+        &self,
+        source: &str,
+        paths: &[Path],
+    ) -> Vec<Result<T>> {
+        stream::iter(paths)
+            .map(|path| self.load(source, path))
+            .buffered(self.load_concurrency)
+            .collect()
+            .await
+    }
+
+    /// Load like `load`, also returning the record's `ETag` for a later
+    /// `save_if_match`.
+    pub async fn load_with_etag<T: DeserializeOwned>(
+        &self,
+        source: &str,
+        path: &Path,
+    ) -> Result<(T, ETag)> {
+        let source = self.source(source)?;
         let record = source.find_one(path).await.map_err(|e| {
             loom_error::Error::builder()
                 .code(loom_error::ErrorCode::Unknown)
-                .message(format!("Failed to load from path '{}': {}", path, e))
+                .message(format!("Failed to load from path '{}'", path))
+                .source(e)
                 .build()
         })?;
 
         let content = record.content_str().map_err(|e| {
             loom_error::Error::builder()
                 .code(loom_error::ErrorCode::Unknown)
-                .message(format!("Invalid UTF-8 content: {}", e))
+                .message("Invalid content")
+                .source(e)
                 .build()
         })?;
 
-        decode!(content, record.media_type.format()).map_err(|e| {
+        let data = decode!(&content, record.media_type.format()).map_err(|e| {
             loom_error::Error::builder()
                 .code(loom_error::ErrorCode::Unknown)
                 .message(format!("Deserialization failed: {}", e))
                 .build()
-        })
+        })?;
+
+        Ok((data, record.etag))
     }
 
     /// Save and serialize data to a DataSource.
@@ -98,13 +299,82 @@ impl Runtime {
         data: &T,
         format: Format,
     ) -> Result<()> {
-        let source = self.sources.get(source).ok_or_else(|| {
+        let source = self.source(source)?;
+        let record = Self::record(path, data, format)?;
+
+        source.upsert(record).await.map_err(|e| {
             loom_error::Error::builder()
-                .code(loom_error::ErrorCode::NotFound)
-                .message(format!("DataSource '{}' not found", source))
+                .code(loom_error::ErrorCode::Unknown)
+                .message(format!("Failed to save to path '{}'", path))
+                .source(e)
+                .build()
+        })?;
+
+        Ok(())
+    }
+
+    /// Save like `save`, inferring the format from the path's extension
+    /// (see `Format::from_path`; unknown extensions fall back to JSON).
+    /// Fails with `ErrorCode::BadArguments` for paths without an extension.
+    pub async fn save_auto<T: Serialize>(&self, source: &str, path: &Path, data: &T) -> Result<()> {
+        let format = match path {
+            Path::File(file_path) if file_path.extension().is_some() => {
+                Format::from_path(&**file_path)
+            }
+            _ => {
+                return Err(loom_error::Error::builder()
+                    .code(loom_error::ErrorCode::BadArguments)
+                    .message(format!(
+                        "Cannot infer a format for '{}': path has no file extension",
+                        path
+                    ))
+                    .build());
+            }
+        };
+
+        self.save(source, path, data, format).await
+    }
+
+    /// Save like `save`, but only if the stored record still has `etag`
+    /// (usually from a `Record` read earlier), failing with
+    /// `ErrorCode::Conflict` if another writer changed it in the meantime.
+    pub async fn save_if_match<T: Serialize>(
+        &self,
+        source: &str,
+        path: &Path,
+        data: &T,
+        format: Format,
+        etag: ETag,
+    ) -> Result<()> {
+        let source = self.source(source)?;
+        let record = Self::record(path, data, format)?;
+
+        source.upsert_if_match(record, etag).await.map_err(|e| {
+            let code = match e.is_conflict() {
+                true => loom_error::ErrorCode::Conflict,
+                false => loom_error::ErrorCode::Unknown,
+            };
+
+            loom_error::Error::builder()
+                .code(code)
+                .message(format!("Failed to save to path '{}'", path))
+                .source(e)
                 .build()
         })?;
 
+        Ok(())
+    }
+
+    fn source(&self, name: &str) -> Result<&dyn DataSource> {
+        self.sources.get(name).ok_or_else(|| {
+            loom_error::Error::builder()
+                .code(loom_error::ErrorCode::NotFound)
+                .message(format!("DataSource '{}' not found", name))
+                .build()
+        })
+    }
+
+    fn record<T: Serialize>(path: &Path, data: &T, format: Format) -> Result<Record> {
         let content = encode!(data, format).map_err(|e| {
             loom_error::Error::builder()
                 .code(loom_error::ErrorCode::Unknown)
@@ -114,21 +384,13 @@ impl Runtime {
 
         let media_type = match format {
             Format::Json => MediaType::TextJson,
+            Format::NdJson => MediaType::ApplicationNdjson,
             Format::Yaml => MediaType::TextYaml,
             Format::Toml => MediaType::TextToml,
             _ => MediaType::TextPlain,
         };
 
-        let record = loom_io::Record::from_str(path.clone(), media_type, &content);
-
-        source.upsert(record).await.map_err(|e| {
-            loom_error::Error::builder()
-                .code(loom_error::ErrorCode::Unknown)
-                .message(format!("Failed to save to path '{}': {}", path, e))
-                .build()
-        })?;
-
-        Ok(())
+        Ok(Record::from_str(path.clone(), media_type, &content))
     }
 }
 
@@ -137,6 +399,8 @@ pub struct Builder {
     sources: DataSourceRegistryBuilder,
     signals: SignalBroadcaster,
     layers: Vec<Box<dyn Layer<Input = RunContext>>>,
+    load_concurrency: usize,
+    metrics: bool,
 }
 
 impl Default for Builder {
@@ -146,6 +410,8 @@ impl Default for Builder {
             sources: DataSourceRegistryBuilder::default(),
             signals: SignalBroadcaster::default(),
             layers: Vec::new(),
+            load_concurrency: DEFAULT_LOAD_CONCURRENCY,
+            metrics: false,
         }
     }
 }
@@ -171,12 +437,54 @@ impl Builder {
         self
     }
 
+    /// Add already boxed layers, in order, e.g. ones chosen at runtime from
+    /// a declarative config.
+    pub fn layers(mut self, layers: Vec<Box<dyn Layer<Input = RunContext>>>) -> Self {
+        self.layers.extend(layers);
+        self
+    }
+
+    /// Add an enrichment layer whose output is deep-merged into its input
+    /// instead of replacing it; see `MergeLayer`.
+    pub fn layer_merge<L: Layer<Input = RunContext> + 'static>(self, layer: L) -> Self {
+        self.layer(MergeLayer::new(layer))
+    }
+
+    /// Add a group of independent layers that run concurrently on the same
+    /// input, merging their object outputs and failing on duplicate keys.
+    pub fn layer_parallel(self, layers: Vec<Box<dyn Layer<Input = RunContext>>>) -> Self {
+        self.layer(ParallelLayers::new(layers))
+    }
+
+    /// Like `layer_parallel`, resolving duplicate keys with `policy`.
+    pub fn layer_parallel_with(
+        self,
+        layers: Vec<Box<dyn Layer<Input = RunContext>>>,
+        policy: MergePolicy,
+    ) -> Self {
+        self.layer(ParallelLayers::new(layers).merge(policy))
+    }
+
     /// Add a signal emitter to the runtime.
     pub fn emitter<E: Emitter + Send + Sync + 'static>(mut self, emitter: E) -> Self {
         self.signals = self.signals.add(emitter);
         self
     }
 
+    /// Set how many records `Runtime::load_many` fetches at once
+    /// (default `DEFAULT_LOAD_CONCURRENCY`, minimum 1).
+    pub fn load_concurrency(mut self, limit: usize) -> Self {
+        self.load_concurrency = limit.max(1);
+        self
+    }
+
+    /// Collect per-layer metrics across executions, read with
+    /// `Runtime::metrics`.
+    pub fn metrics(mut self) -> Self {
+        self.metrics = true;
+        self
+    }
+
     pub fn build(self) -> Runtime {
         let signals: Arc<dyn Emitter + Send + Sync> = if self.signals.is_empty() {
             Arc::new(NoopEmitter)
@@ -184,13 +492,414 @@ impl Builder {
             Arc::new(self.signals)
         };
 
-        let pipeline = Pipeline::new(self.layers);
+        let mut pipeline = Pipeline::new(self.layers);
+        if self.metrics {
+            pipeline = pipeline.with_metrics();
+        }
+
         let sources = Arc::new(self.sources.build());
 
         Runtime {
             sources,
             pipeline,
             signals,
+            load_concurrency: self.load_concurrency,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use loom_io::{path::FilePath, sources::MemorySource};
+    use std::collections::BTreeMap;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// Maps a string input to its length.
+    pub(crate) struct Length;
+
+    impl Layer for Length {
+        type Input = RunContext;
+
+        fn process(&self, ctx: &RunContext) -> Result<Value> {
+            let len = ctx.input().as_str().map(|s| s.len()).unwrap_or_default();
+            Ok(Value::from(len as i64))
+        }
+
+        fn name(&self) -> &'static str {
+            "length"
+        }
+    }
+
+    /// Wraps its input in a one-element array.
+    pub(crate) struct Wrap;
+
+    impl Layer for Wrap {
+        type Input = RunContext;
+
+        fn process(&self, ctx: &RunContext) -> Result<Value> {
+            Ok(Value::Array(vec![ctx.input().clone()].into()))
+        }
+
+        fn name(&self) -> &'static str {
+            "wrap"
+        }
+    }
+
+    #[test]
+    fn metrics_accumulate_across_executions() {
+        let runtime = Runtime::new().layer(Length).layer(Wrap).metrics().build();
+
+        for _ in 0..5 {
+            runtime.execute("hello").unwrap();
+        }
+
+        let metrics = runtime.metrics().unwrap();
+        assert_eq!(metrics.layers.len(), 2);
+
+        for (layer, name) in metrics.layers.iter().zip(["length", "wrap"]) {
+            assert_eq!(layer.name, name);
+            assert_eq!(layer.items_in, 5);
+            assert_eq!(layer.items_out, 5);
+            assert_eq!(layer.errors, 0);
+        }
+    }
+
+    #[test]
+    fn validate_rejects_mismatched_layers() {
+        struct Text;
+
+        impl Layer for Text {
+            type Input = RunContext;
+
+            fn process(&self, ctx: &RunContext) -> Result<Value> {
+                Ok(ctx.input().clone())
+            }
+
+            fn name(&self) -> &'static str {
+                "text"
+            }
+
+            fn expects(&self) -> Option<&'static str> {
+                Some("string")
+            }
+        }
+
+        let runtime = Runtime::new()
+            .layer_parallel(vec![Box::new(Length)])
+            .layer(Text)
+            .build();
+
+        let err = runtime.validate().unwrap_err();
+        assert!(err.code().is_bad_arguments());
+        assert_eq!(
+            err.message(),
+            Some("layer 'text' expects string but 'parallel' produces object")
+        );
+
+        let runtime = Runtime::new().layer(Text).layer(Wrap).build();
+        assert!(runtime.validate().is_ok());
+    }
+
+    #[test]
+    fn halting_layer_skips_the_rest_of_the_pipeline() {
+        struct Halt;
+
+        impl Layer for Halt {
+            type Input = RunContext;
+
+            fn process(&self, _ctx: &RunContext) -> Result<Value> {
+                Ok(Value::from("halted"))
+            }
+
+            fn run(&self, ctx: &RunContext) -> Result<LayerOutcome> {
+                self.process(ctx).map(LayerOutcome::Halt)
+            }
+
+            fn name(&self) -> &'static str {
+                "halt"
+            }
+        }
+
+        struct Unreachable(Arc<AtomicBool>);
+
+        impl Layer for Unreachable {
+            type Input = RunContext;
+
+            fn process(&self, ctx: &RunContext) -> Result<Value> {
+                self.0.store(true, Ordering::SeqCst);
+                Ok(ctx.input().clone())
+            }
+        }
+
+        let ran = Arc::new(AtomicBool::new(false));
+        let emitter = MemoryEmitter::new();
+        let runtime = Runtime::new()
+            .layer(Halt)
+            .layer(Unreachable(ran.clone()))
+            .emitter(emitter.clone())
+            .build();
+
+        assert_eq!(runtime.execute("hello").unwrap(), Value::from("halted"));
+
+        let (output, traces) = runtime.execute_traced("hello").unwrap();
+        assert_eq!(output, Value::from("halted"));
+        assert_eq!(traces.len(), 1);
+        assert_eq!(traces[0].name, "halt");
+
+        assert!(!ran.load(Ordering::SeqCst));
+
+        let halted = emitter.find_by_name("pipeline.halted");
+        assert_eq!(halted.len(), 2);
+        assert_eq!(halted[0].field("layer"), Some(&Value::from("halt")));
+        assert_eq!(halted[0].field("index").unwrap().as_int(), Some(0));
+    }
+
+    #[test]
+    fn cancelling_between_layers_skips_the_rest() {
+        struct CancelAfter;
+
+        impl Layer for CancelAfter {
+            type Input = RunContext;
+
+            fn process(&self, ctx: &RunContext) -> Result<Value> {
+                ctx.cancellation().unwrap().cancel();
+                Ok(ctx.input().clone())
+            }
+
+            fn name(&self) -> &'static str {
+                "cancel_after"
+            }
         }
+
+        struct Unreachable(Arc<AtomicBool>);
+
+        impl Layer for Unreachable {
+            type Input = RunContext;
+
+            fn process(&self, ctx: &RunContext) -> Result<Value> {
+                self.0.store(true, Ordering::SeqCst);
+                Ok(ctx.input().clone())
+            }
+
+            fn name(&self) -> &'static str {
+                "unreachable"
+            }
+        }
+
+        let ran = Arc::new(AtomicBool::new(false));
+        let emitter = MemoryEmitter::new();
+        let runtime = Runtime::new()
+            .layer(CancelAfter)
+            .layer(Unreachable(ran.clone()))
+            .emitter(emitter.clone())
+            .build();
+
+        let token = CancellationToken::new();
+        let err = runtime.execute_cancellable("hello", &token).unwrap_err();
+
+        assert!(token.is_cancelled());
+        assert!(err.code().is_cancel(), "{}", err);
+        assert!(!ran.load(Ordering::SeqCst));
+
+        let cancelled = emitter.find_by_name("pipeline.cancelled");
+        assert_eq!(cancelled.len(), 1);
+        assert_eq!(
+            cancelled[0].field("layer"),
+            Some(&Value::from("unreachable"))
+        );
+        assert_eq!(cancelled[0].field("index").unwrap().as_int(), Some(1));
+
+        // The traced run shares the same checks
+        let token = CancellationToken::new();
+        let err = runtime
+            .execute_traced_cancellable("hello", &token)
+            .unwrap_err();
+
+        assert!(err.code().is_cancel(), "{}", err);
+        assert!(!ran.load(Ordering::SeqCst));
+        assert_eq!(emitter.find_by_name("pipeline.cancelled").len(), 2);
+
+        // Without cancelling, every layer runs
+        let token = CancellationToken::new();
+        let runtime = Runtime::new().layer(Unreachable(ran.clone())).build();
+        assert_eq!(
+            runtime.execute_cancellable("hello", &token).unwrap(),
+            Value::from("hello")
+        );
+        assert!(ran.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn metrics_are_off_by_default() {
+        let runtime = Runtime::new().layer(Length).build();
+        runtime.execute("hello").unwrap();
+
+        assert!(runtime.metrics().is_none());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn metrics_serialize_like_their_value() {
+        let runtime = Runtime::new().layer(Length).metrics().build();
+        runtime.execute("hello").unwrap();
+
+        let metrics = runtime.metrics().unwrap();
+        let json = serde_json::to_value(&metrics.layers[0]).unwrap();
+        let value = Value::from(&metrics.layers[0]);
+
+        let mut json_keys: Vec<&String> = json.as_object().unwrap().keys().collect();
+        let mut value_keys: Vec<&String> = value.as_object().unwrap().keys().collect();
+        json_keys.sort();
+        value_keys.sort();
+        assert_eq!(json_keys, value_keys);
+        assert!(json["total_time_ms"].is_f64());
+    }
+
+    fn data() -> BTreeMap<String, i64> {
+        BTreeMap::from([("score".to_string(), 1)])
+    }
+
+    fn file(path: &str) -> Path {
+        Path::File(FilePath::parse(path))
+    }
+
+    async fn saved_media_type(path: &str) -> MediaType {
+        let runtime = Runtime::new().source(MemorySource::default()).build();
+        runtime
+            .save_auto("memory", &file(path), &data())
+            .await
+            .unwrap();
+
+        let source = runtime.source("memory").unwrap();
+        source.find_one(&file(path)).await.unwrap().media_type
+    }
+
+    #[tokio::test]
+    async fn save_auto_infers_json() {
+        assert_eq!(
+            saved_media_type("out/result.json").await,
+            MediaType::TextJson
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[tokio::test]
+    async fn save_auto_infers_yaml() {
+        assert_eq!(
+            saved_media_type("out/result.yaml").await,
+            MediaType::TextYaml
+        );
+        assert_eq!(
+            saved_media_type("out/result.yml").await,
+            MediaType::TextYaml
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[tokio::test]
+    async fn save_auto_infers_toml() {
+        assert_eq!(
+            saved_media_type("out/result.toml").await,
+            MediaType::TextToml
+        );
+    }
+
+    #[tokio::test]
+    async fn save_auto_infers_ndjson() {
+        assert_eq!(
+            saved_media_type("out/result.jsonl").await,
+            MediaType::ApplicationNdjson
+        );
+    }
+
+    #[tokio::test]
+    async fn ndjson_round_trips_through_save_and_load() {
+        let runtime = Runtime::new().source(MemorySource::default()).build();
+        let rows = vec![data(), BTreeMap::from([("score".to_string(), 2)])];
+        let path = file("out/rows.jsonl");
+
+        runtime.save_auto("memory", &path, &rows).await.unwrap();
+
+        let source = runtime.source("memory").unwrap();
+        let record = source.find_one(&path).await.unwrap();
+        assert_eq!(
+            record.content_str().unwrap(),
+            "{\"score\":1}\n{\"score\":2}\n"
+        );
+
+        let loaded: Vec<BTreeMap<String, i64>> = runtime.load("memory", &path).await.unwrap();
+        assert_eq!(loaded, rows);
+    }
+
+    #[tokio::test]
+    async fn save_auto_falls_back_to_json() {
+        assert_eq!(
+            saved_media_type("out/result.out").await,
+            MediaType::TextJson
+        );
+    }
+
+    #[tokio::test]
+    async fn save_auto_rejects_paths_without_extension() {
+        let runtime = Runtime::new().source(MemorySource::default()).build();
+
+        let err = runtime
+            .save_auto("memory", &file("out/result"), &data())
+            .await
+            .unwrap_err();
+        assert!(err.code().is_bad_arguments());
+
+        let source = runtime.source("memory").unwrap();
+        assert!(!source.exists(&file("out/result")).await.unwrap());
+    }
+
+    fn scores() -> (Runtime, Vec<Path>) {
+        let paths: Vec<Path> = (1..=3).map(|i| file(&format!("scores/{i}.json"))).collect();
+        let source = MemorySource::builder()
+            .with_records(paths.iter().zip(1..).map(|(path, i)| {
+                Record::from_str(
+                    path.clone(),
+                    MediaType::TextJson,
+                    &format!("{{\"score\":{i}}}"),
+                )
+            }))
+            .build();
+        let runtime = Runtime::new().source(source).load_concurrency(2).build();
+
+        (runtime, paths)
+    }
+
+    #[tokio::test]
+    async fn load_many_preserves_input_order() {
+        let (runtime, mut paths) = scores();
+        paths.reverse();
+
+        let loaded: Vec<BTreeMap<String, i64>> = runtime.load_many("memory", &paths).await.unwrap();
+        let scores: Vec<i64> = loaded.iter().map(|d| d["score"]).collect();
+        assert_eq!(scores, vec![3, 2, 1]);
+    }
+
+    #[tokio::test]
+    async fn load_many_aborts_on_missing_path() {
+        let (runtime, mut paths) = scores();
+        paths.insert(1, file("scores/missing.json"));
+
+        let result: Result<Vec<BTreeMap<String, i64>>> = runtime.load_many("memory", &paths).await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn load_many_results_collects_per_path_errors() {
+        let (runtime, mut paths) = scores();
+        paths.insert(1, file("scores/missing.json"));
+
+        let results: Vec<Result<BTreeMap<String, i64>>> =
+            runtime.load_many_results("memory", &paths).await;
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap()["score"], 1);
+        assert!(results[1].is_err());
+        assert_eq!(results[2].as_ref().unwrap()["score"], 2);
+        assert_eq!(results[3].as_ref().unwrap()["score"], 3);
     }
 }
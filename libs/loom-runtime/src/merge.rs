@@ -0,0 +1,132 @@
+use loom_core::value::Value;
+use loom_error::Result;
+use loom_pipe::{Layer, LayerOutcome};
+
+use crate::RunContext;
+
+/// Runs an enrichment layer and deep-merges its partial output into the
+/// layer's input, instead of replacing the input with it.
+///
+/// The wrapped layer only returns the fields it adds or changes; every
+/// other field of the input passes through. See `RunContext::merge_next`.
+///
+/// # Example
+/// ```ignore
+/// let runtime = Runtime::new()
+///     .layer(Parse)
+///     .layer_merge(DetectLanguage) // returns {"language": "en"}
+///     .build();
+/// ```
+pub struct MergeLayer<L> {
+    layer: L,
+}
+
+impl<L: Layer<Input = RunContext>> MergeLayer<L> {
+    pub fn new(layer: L) -> Self {
+        Self { layer }
+    }
+
+    pub fn inner(&self) -> &L {
+        &self.layer
+    }
+}
+
+impl<L: Layer<Input = RunContext>> Layer for MergeLayer<L> {
+    type Input = RunContext;
+
+    fn process(&self, ctx: &RunContext) -> Result<Value> {
+        Ok(ctx.merged(self.layer.process(ctx)?))
+    }
+
+    /// Merges a `Continue` value like `process`; a `Halt` value is returned
+    /// as is.
+    fn run(&self, ctx: &RunContext) -> Result<LayerOutcome> {
+        Ok(match self.layer.run(ctx)? {
+            LayerOutcome::Continue(value) => LayerOutcome::Continue(ctx.merged(value)),
+            halt => halt,
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        self.layer.name()
+    }
+
+    fn expects(&self) -> Option<&'static str> {
+        self.layer.expects()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use loom_core::value::{Object, Value};
+    use loom_error::Result;
+    use loom_io::DataSourceRegistry;
+    use loom_pipe::Layer;
+
+    use crate::{NoopEmitter, RunContext, Runtime};
+
+    fn document() -> Value {
+        let mut meta = Object::new();
+        meta.insert("source".to_string(), Value::from("web"));
+
+        let mut object = Object::new();
+        object.insert("text".to_string(), Value::from("hello"));
+        object.insert("meta".to_string(), Value::Object(meta));
+        Value::Object(object)
+    }
+
+    struct DetectLanguage;
+
+    impl Layer for DetectLanguage {
+        type Input = RunContext;
+
+        fn process(&self, _ctx: &RunContext) -> Result<Value> {
+            let mut meta = Object::new();
+            meta.insert("language".to_string(), Value::from("en"));
+
+            let mut object = Object::new();
+            object.insert("meta".to_string(), Value::Object(meta));
+            Ok(Value::Object(object))
+        }
+
+        fn name(&self) -> &'static str {
+            "detect_language"
+        }
+    }
+
+    #[test]
+    fn enrichment_layer_keeps_original_fields() {
+        let runtime = Runtime::new().layer_merge(DetectLanguage).build();
+        let output = runtime.execute(document()).unwrap();
+
+        assert_eq!(output["text"], Value::from("hello"));
+        assert_eq!(output["meta"]["source"], Value::from("web"));
+        assert_eq!(output["meta"]["language"], Value::from("en"));
+    }
+
+    #[test]
+    fn plain_layer_still_replaces_input() {
+        let runtime = Runtime::new().layer(DetectLanguage).build();
+        let output = runtime.execute(document()).unwrap();
+
+        assert!(output["text"].is_null());
+        assert!(output["meta"]["source"].is_null());
+    }
+
+    #[test]
+    fn merge_next_deep_merges_into_input() {
+        let ctx = RunContext::new(
+            document(),
+            Arc::new(NoopEmitter),
+            Arc::new(DataSourceRegistry::new().build()),
+        );
+
+        let next = ctx.merge_next(DetectLanguage.process(&ctx).unwrap());
+
+        assert_eq!(next.input()["meta"]["source"], Value::from("web"));
+        assert_eq!(next.input()["meta"]["language"], Value::from("en"));
+        assert_eq!(ctx.input(), &document());
+    }
+}
@@ -0,0 +1,296 @@
+use loom_core::value::{Object, Value};
+use loom_error::{Error, ErrorCode, Result};
+use loom_pipe::{Layer, LayerOutcome};
+
+use crate::RunContext;
+
+/// How `ParallelLayers` combines keys returned by more than one member.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MergePolicy {
+    /// Fail the group if two members return the same key.
+    #[default]
+    Error,
+
+    /// Keep the value from the earliest member, in registration order.
+    FirstWins,
+
+    /// Keep the value from the latest member, in registration order.
+    LastWins,
+
+    /// Deep-merge the values with `Value::merge`, later members winning on
+    /// conflicting leaves.
+    Deep,
+}
+
+/// A group of independent layers that run concurrently on the same input.
+///
+/// Every member receives the group's `RunContext` and must return a
+/// `Value::Object`; the objects are merged in registration order according
+/// to the `MergePolicy` and the merged object is the group's output.
+///
+/// A member returning `LayerOutcome::Halt` halts the group, and with it the
+/// pipeline, with that member's value; the earliest halting member in
+/// registration order wins.
+///
+/// # Example
+/// ```ignore
+/// let runtime = Runtime::new()
+///     .layer(Tokenize)
+///     .layer_parallel(vec![Box::new(Sentiment), Box::new(Language)])
+///     .layer(Report)
+///     .build();
+/// ```
+pub struct ParallelLayers {
+    layers: Vec<Box<dyn Layer<Input = RunContext>>>,
+    policy: MergePolicy,
+}
+
+impl ParallelLayers {
+    pub fn new(layers: Vec<Box<dyn Layer<Input = RunContext>>>) -> Self {
+        Self {
+            layers,
+            policy: MergePolicy::default(),
+        }
+    }
+
+    pub fn merge(mut self, policy: MergePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn layers(&self) -> &[Box<dyn Layer<Input = RunContext>>] {
+        &self.layers
+    }
+
+    pub fn policy(&self) -> MergePolicy {
+        self.policy
+    }
+
+    fn combine(&self, outputs: Vec<(&'static str, Value)>) -> Result<Value> {
+        let mut merged = Object::new();
+
+        for (name, output) in outputs {
+            let Value::Object(object) = output else {
+                return Err(Error::builder()
+                    .code(ErrorCode::BadArguments)
+                    .message(format!(
+                        "Parallel layer '{}' returned {}, expected an object",
+                        name,
+                        output.kind()
+                    ))
+                    .build());
+            };
+
+            for (key, value) in object.iter() {
+                match (merged.get_mut(key), self.policy) {
+                    (None, _) | (Some(_), MergePolicy::LastWins) => {
+                        merged.insert(key.clone(), value.clone());
+                    }
+                    (Some(_), MergePolicy::FirstWins) => {}
+                    (Some(existing), MergePolicy::Deep) => existing.merge(value.clone()),
+                    (Some(_), MergePolicy::Error) => {
+                        return Err(Error::builder()
+                            .code(ErrorCode::Conflict)
+                            .message(format!(
+                                "Parallel layer '{}' returned key '{}' already set by another layer",
+                                name, key
+                            ))
+                            .build());
+                    }
+                }
+            }
+        }
+
+        Ok(Value::Object(merged))
+    }
+}
+
+impl Layer for ParallelLayers {
+    type Input = RunContext;
+
+    fn process(&self, ctx: &RunContext) -> Result<Value> {
+        self.run(ctx).map(LayerOutcome::into_value)
+    }
+
+    fn run(&self, ctx: &RunContext) -> Result<LayerOutcome> {
+        let outcomes = std::thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .layers
+                .iter()
+                .map(|layer| scope.spawn(|| layer.run(ctx)))
+                .collect();
+
+            handles
+                .into_iter()
+                .zip(&self.layers)
+                .map(|(handle, layer)| {
+                    let outcome = handle.join().map_err(|_| {
+                        Error::builder()
+                            .code(ErrorCode::Unknown)
+                            .message(format!("Parallel layer '{}' panicked", layer.name()))
+                            .build()
+                    })??;
+
+                    Ok((layer.name(), outcome))
+                })
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        if let Some((_, halt)) = outcomes.iter().find(|(_, outcome)| outcome.is_halt()) {
+            return Ok(LayerOutcome::Halt(halt.value().clone()));
+        }
+
+        let outputs = outcomes
+            .into_iter()
+            .map(|(name, outcome)| (name, outcome.into_value()))
+            .collect();
+
+        self.combine(outputs).map(LayerOutcome::Continue)
+    }
+
+    fn name(&self) -> &'static str {
+        "parallel"
+    }
+
+    fn produces(&self) -> Option<&'static str> {
+        Some("object")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use loom_core::value::{Object, Value};
+    use loom_error::Result;
+    use loom_pipe::{Layer, LayerOutcome};
+
+    use super::MergePolicy;
+    use crate::{RunContext, Runtime};
+
+    struct Enrich(&'static str, &'static str);
+
+    impl Layer for Enrich {
+        type Input = RunContext;
+
+        fn process(&self, ctx: &RunContext) -> Result<Value> {
+            let mut object = Object::new();
+            object.insert(self.0.to_string(), Value::from(self.1));
+            object.insert("input".to_string(), ctx.input().clone());
+            Ok(Value::Object(object))
+        }
+    }
+
+    struct Sentiment;
+
+    impl Layer for Sentiment {
+        type Input = RunContext;
+
+        fn process(&self, _ctx: &RunContext) -> Result<Value> {
+            let mut object = Object::new();
+            object.insert("sentiment".to_string(), Value::from("positive"));
+            Ok(Value::Object(object))
+        }
+    }
+
+    struct Language;
+
+    impl Layer for Language {
+        type Input = RunContext;
+
+        fn process(&self, ctx: &RunContext) -> Result<Value> {
+            let mut object = Object::new();
+            object.insert("language".to_string(), Value::from("en"));
+            object.insert("chars".to_string(), Value::from(ctx.input().len() as i64));
+            Ok(Value::Object(object))
+        }
+    }
+
+    #[test]
+    fn merges_keys_from_every_member() {
+        let runtime = Runtime::new()
+            .layer_parallel(vec![Box::new(Sentiment), Box::new(Language)])
+            .build();
+
+        let output = runtime.execute("hello").unwrap();
+        let object = output.as_object().unwrap();
+
+        assert_eq!(object.len(), 3);
+        assert_eq!(object["sentiment"], Value::from("positive"));
+        assert_eq!(object["language"], Value::from("en"));
+        assert_eq!(object["chars"], Value::from(5i64));
+    }
+
+    #[test]
+    fn key_conflicts_error_by_default() {
+        let runtime = Runtime::new()
+            .layer_parallel(vec![Box::new(Enrich("a", "1")), Box::new(Enrich("b", "2"))])
+            .build();
+
+        let err = runtime.execute("hello").unwrap_err();
+        assert!(err.code().is_conflict());
+    }
+
+    #[test]
+    fn merge_policy_resolves_conflicts() {
+        let layers = || -> Vec<Box<dyn Layer<Input = RunContext>>> {
+            vec![
+                Box::new(Enrich("key", "first")),
+                Box::new(Enrich("key", "last")),
+            ]
+        };
+
+        for (policy, expected) in [
+            (MergePolicy::FirstWins, "first"),
+            (MergePolicy::LastWins, "last"),
+            (MergePolicy::Deep, "last"),
+        ] {
+            let runtime = Runtime::new().layer_parallel_with(layers(), policy).build();
+
+            let output = runtime.execute("hello").unwrap();
+            assert_eq!(output.as_object().unwrap()["key"], Value::from(expected));
+        }
+    }
+
+    #[test]
+    fn halting_member_halts_the_pipeline() {
+        struct Block;
+
+        impl Layer for Block {
+            type Input = RunContext;
+
+            fn process(&self, _ctx: &RunContext) -> Result<Value> {
+                Ok(Value::from("blocked"))
+            }
+
+            fn run(&self, ctx: &RunContext) -> Result<LayerOutcome> {
+                self.process(ctx).map(LayerOutcome::Halt)
+            }
+        }
+
+        let runtime = Runtime::new()
+            .layer_parallel(vec![Box::new(Sentiment), Box::new(Block)])
+            .layer(Language)
+            .build();
+
+        assert_eq!(runtime.execute("hello").unwrap(), Value::from("blocked"));
+    }
+
+    #[test]
+    fn non_object_output_is_rejected() {
+        struct Scalar;
+
+        impl Layer for Scalar {
+            type Input = RunContext;
+
+            fn process(&self, _ctx: &RunContext) -> Result<Value> {
+                Ok(Value::from(1i64))
+            }
+        }
+
+        let runtime = Runtime::new()
+            .layer_parallel(vec![Box::new(Sentiment), Box::new(Scalar)])
+            .build();
+
+        let err = runtime.execute("hello").unwrap_err();
+        assert!(err.code().is_bad_arguments());
+    }
+}
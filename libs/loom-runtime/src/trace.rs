@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+/// What one pipeline layer did during `Runtime::execute_traced`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct LayerTrace {
+    /// `Layer::name()` of the layer
+    pub name: &'static str,
+
+    /// `Value::kind()` of the layer's input
+    pub input_kind: String,
+
+    /// `Value::kind()` of the layer's output
+    pub output_kind: String,
+
+    /// Time spent in `Layer::process`
+    pub duration: Duration,
+}
+
+#[cfg(test)]
+mod tests {
+    use loom_core::value::Value;
+    use loom_error::Result;
+    use loom_pipe::Layer;
+
+    use crate::tests::{Length, Wrap};
+    use crate::{MemoryEmitter, RunContext, Runtime};
+
+    #[test]
+    fn execute_traced_records_each_layer_in_order() {
+        let runtime = Runtime::new().layer(Length).layer(Wrap).build();
+
+        let (output, traces) = runtime.execute_traced("hello").unwrap();
+
+        assert_eq!(output, runtime.execute("hello").unwrap());
+        assert_eq!(traces.len(), 2);
+
+        assert_eq!(traces[0].name, "length");
+        assert_eq!(traces[0].input_kind, "string");
+        assert_eq!(traces[0].output_kind, "number");
+
+        assert_eq!(traces[1].name, "wrap");
+        assert_eq!(traces[1].input_kind, "number");
+        assert_eq!(traces[1].output_kind, "array");
+    }
+
+    #[test]
+    fn metrics_count_errors_and_emit_snapshot() {
+        struct Fail;
+
+        impl Layer for Fail {
+            type Input = RunContext;
+
+            fn process(&self, _ctx: &RunContext) -> Result<Value> {
+                Err(loom_error::Error::builder().message("boom").build())
+            }
+
+            fn name(&self) -> &'static str {
+                "fail"
+            }
+        }
+
+        let emitter = MemoryEmitter::new();
+        let runtime = Runtime::new()
+            .layer(Length)
+            .layer(Fail)
+            .layer(Wrap)
+            .emitter(emitter.clone())
+            .metrics()
+            .build();
+
+        assert!(runtime.execute("hello").is_err());
+
+        let metrics = runtime.metrics().unwrap();
+        assert_eq!(metrics.layer("length").unwrap().items_out, 1);
+        assert_eq!(metrics.layer("fail").unwrap().errors, 1);
+        assert_eq!(metrics.layer("wrap").unwrap().items_in, 0);
+
+        runtime.emit_metrics();
+
+        let signals = emitter.signals();
+        assert_eq!(signals.len(), 1);
+        assert_eq!(signals[0].name(), "pipeline.metrics");
+        assert_eq!(
+            signals[0].field("layers").unwrap()[1]["errors"].as_int(),
+            Some(1)
+        );
+    }
+}
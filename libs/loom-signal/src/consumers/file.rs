@@ -1,38 +1,64 @@
-use std::fs::{File, OpenOptions};
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, BufWriter, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use crate::{Emitter, Level, Signal};
 
+const SECONDS_PER_DAY: u64 = 86_400;
+
 /// An emitter that writes signals to a file in JSON lines format.
 ///
 /// Each signal is written as a single JSON line, making it easy to parse
 /// and process with standard tools.
 ///
+/// By default the file grows forever. Use `FileEmitter::builder` to rotate
+/// it by size and/or daily: the active file is renamed to `<path>.1`
+/// (shifting older files to `<path>.2`, ...) and a fresh file is opened in
+/// its place. Only `max_files` rotated files are kept.
+///
 /// # Example
 /// ```ignore
 /// let emitter = FileEmitter::new("signals.jsonl")?;
 /// emitter.emit(signal);
+///
+/// let emitter = FileEmitter::builder("signals.jsonl")
+///     .max_size(10 * 1024 * 1024)
+///     .max_files(5)
+///     .daily()
+///     .build()?;
 /// ```
 pub struct FileEmitter {
-    writer: Mutex<BufWriter<File>>,
+    path: PathBuf,
+    state: Mutex<FileState>,
+    rotation: Rotation,
     min_level: Level,
 }
 
+struct FileState {
+    writer: BufWriter<File>,
+    size: u64,
+    day: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Rotation {
+    max_size: Option<u64>,
+    max_files: usize,
+    daily: bool,
+}
+
 impl FileEmitter {
     /// Create a new file emitter that appends to the given path.
     /// Creates the file if it doesn't exist.
     pub fn new(path: impl Into<PathBuf>) -> io::Result<Self> {
-        let file = OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(path.into())?;
+        Self::builder(path).build()
+    }
 
-        Ok(Self {
-            writer: Mutex::new(BufWriter::new(file)),
-            min_level: Level::Trace,
-        })
+    /// Configure a file emitter with rotation.
+    pub fn builder(path: impl Into<PathBuf>) -> FileEmitterBuilder {
+        FileEmitterBuilder::new(path)
     }
 
     /// Set the minimum log level to emit.
@@ -44,6 +70,73 @@ impl FileEmitter {
     fn should_emit(&self, signal: &Signal) -> bool {
         signal.level() as u8 >= self.min_level as u8
     }
+
+    fn format(signal: &Signal) -> Option<String> {
+        #[cfg(feature = "json")]
+        {
+            serde_json::to_string(signal).ok()
+        }
+
+        #[cfg(not(feature = "json"))]
+        {
+            // Without JSON feature, write a debug representation
+            Some(format!("{:?}", signal))
+        }
+    }
+
+    fn should_rotate(&self, state: &FileState, len: u64) -> bool {
+        let over_size = self
+            .rotation
+            .max_size
+            .is_some_and(|max| state.size > 0 && state.size + len > max);
+        let new_day = self.rotation.daily && today() != state.day;
+
+        over_size || new_day
+    }
+
+    /// Shift rotated files up by one, dropping the oldest, then move the
+    /// active file to `<path>.1` and reopen `<path>`. Each step is a rename,
+    /// so a crash part way leaves every line in some file.
+    fn rotate(&self, state: &mut FileState) -> io::Result<()> {
+        state.writer.flush()?;
+
+        let max_files = self.rotation.max_files;
+
+        if max_files == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            let oldest = rotated_path(&self.path, max_files);
+            if oldest.exists() {
+                fs::remove_file(&oldest)?;
+            }
+
+            for i in (1..max_files).rev() {
+                let from = rotated_path(&self.path, i);
+                if from.exists() {
+                    fs::rename(&from, rotated_path(&self.path, i + 1))?;
+                }
+            }
+
+            fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        }
+
+        *state = FileState::open(&self.path)?;
+        Ok(())
+    }
+}
+
+impl FileState {
+    fn open(path: &Path) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        let metadata = file.metadata()?;
+        let day = metadata.modified().map(day_of).unwrap_or_else(|_| today());
+
+        Ok(Self {
+            writer: BufWriter::new(file),
+            size: metadata.len(),
+            day,
+        })
+    }
 }
 
 impl Emitter for FileEmitter {
@@ -52,25 +145,105 @@ impl Emitter for FileEmitter {
             return;
         }
 
-        #[cfg(feature = "json")]
-        {
-            if let Ok(mut writer) = self.writer.lock() {
-                if let Ok(json) = serde_json::to_string(&signal) {
-                    let _ = writeln!(writer, "{}", json);
-                    let _ = writer.flush();
-                }
+        let Some(line) = Self::format(&signal) else {
+            return;
+        };
+
+        // The lock is held across rotation so concurrent emits never write
+        // to a file that is being renamed.
+        if let Ok(mut state) = self.state.lock() {
+            let len = line.len() as u64 + 1;
+
+            if self.should_rotate(&state, len) && self.rotate(&mut state).is_err() {
+                return;
             }
-        }
 
-        #[cfg(not(feature = "json"))]
-        {
-            // Without JSON feature, write a debug representation
-            if let Ok(mut writer) = self.writer.lock() {
-                let _ = writeln!(writer, "{:?}", signal);
-                let _ = writer.flush();
+            if writeln!(state.writer, "{}", line).is_ok() {
+                state.size += len;
             }
+
+            let _ = state.writer.flush();
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            let _ = state.writer.flush();
+        }
+    }
+}
+
+/// Builder for a `FileEmitter` with a rotation policy.
+pub struct FileEmitterBuilder {
+    path: PathBuf,
+    rotation: Rotation,
+    min_level: Level,
+}
+
+impl FileEmitterBuilder {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            rotation: Rotation {
+                max_size: None,
+                max_files: 5,
+                daily: false,
+            },
+            min_level: Level::Trace,
         }
     }
+
+    /// Rotate before a write would grow the file past `bytes`.
+    pub fn max_size(mut self, bytes: u64) -> Self {
+        self.rotation.max_size = Some(bytes);
+        self
+    }
+
+    /// Number of rotated files to keep (default 5). Older files are deleted.
+    pub fn max_files(mut self, count: usize) -> Self {
+        self.rotation.max_files = count;
+        self
+    }
+
+    /// Rotate on the first write of each new UTC day.
+    pub fn daily(mut self) -> Self {
+        self.rotation.daily = true;
+        self
+    }
+
+    /// Set the minimum log level to emit.
+    pub fn level(mut self, level: Level) -> Self {
+        self.min_level = level;
+        self
+    }
+
+    /// Open (or create) the file and build the emitter.
+    pub fn build(self) -> io::Result<FileEmitter> {
+        let state = FileState::open(&self.path)?;
+
+        Ok(FileEmitter {
+            path: self.path,
+            state: Mutex::new(state),
+            rotation: self.rotation,
+            min_level: self.min_level,
+        })
+    }
+}
+
+fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(format!(".{}", index));
+    PathBuf::from(name)
+}
+
+fn day_of(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() / SECONDS_PER_DAY)
+        .unwrap_or(0)
+}
+
+fn today() -> u64 {
+    day_of(SystemTime::now())
 }
 
 #[cfg(test)]
@@ -136,4 +309,43 @@ mod tests {
 
         cleanup_test_file(path);
     }
+
+    #[test]
+    fn test_file_emitter_rotates_and_prunes() {
+        let dir = std::env::temp_dir().join("loom_signal_test_rotate");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("signals.jsonl");
+
+        let emitter = FileEmitter::builder(&path)
+            .max_size(256)
+            .max_files(2)
+            .build()
+            .unwrap();
+
+        for i in 0..20 {
+            emitter.emit(Signal::new().name(format!("test.rotate.{}", i)).build());
+        }
+
+        drop(emitter);
+
+        assert!(path.exists());
+        assert!(rotated_path(&path, 1).exists());
+        assert!(rotated_path(&path, 2).exists());
+        assert!(
+            !rotated_path(&path, 3).exists(),
+            "old files should be pruned"
+        );
+
+        for file in [path.clone(), rotated_path(&path, 1), rotated_path(&path, 2)] {
+            let len = fs::metadata(&file).unwrap().len();
+            assert!(len <= 256, "{} is {} bytes", file.display(), len);
+        }
+
+        // The newest signal is in the active file
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(contents.contains("test.rotate.19"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
 }
@@ -0,0 +1,203 @@
+use std::io::{self, Stdout, Write};
+use std::sync::Mutex;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::{Emitter, Level, Signal, Type};
+
+/// An emitter that writes each signal as one flat JSON object per line.
+///
+/// Unlike the serde representation used by `FileEmitter`, values are written
+/// as plain JSON and the timestamp as RFC 3339, so the output can be ingested
+/// directly by log pipelines:
+///
+/// ```json
+/// {"timestamp":"2024-01-01T00:00:00.000Z","level":"info","type":"span","name":"eval.score","span":"eval.score","fields":{"duration_ms":12}}
+/// ```
+///
+/// `fields` holds the signal's attributes together with its structured
+/// fields (`SignalBuilder::field`); a field wins over an attribute with the
+/// same key. `span` holds the span name for `Type::Span` signals and is
+/// `null` otherwise.
+///
+/// # Example
+/// ```ignore
+/// let emitter = JsonLinesEmitter::new().with_level(Level::Debug);
+/// emitter.emit(signal);
+///
+/// let emitter = JsonLinesEmitter::from_writer(File::create("signals.jsonl")?);
+/// ```
+pub struct JsonLinesEmitter<W: Write + Send = Stdout> {
+    writer: Mutex<W>,
+    min_level: Level,
+}
+
+impl JsonLinesEmitter<Stdout> {
+    /// Create a new emitter that writes to stdout.
+    pub fn new() -> Self {
+        Self::from_writer(io::stdout())
+    }
+}
+
+impl<W: Write + Send> JsonLinesEmitter<W> {
+    /// Create a new emitter that writes to the given writer.
+    pub fn from_writer(writer: W) -> Self {
+        Self {
+            writer: Mutex::new(writer),
+            min_level: Level::Trace,
+        }
+    }
+
+    /// Set the minimum log level to emit.
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.min_level = level;
+        self
+    }
+
+    /// Consume the emitter, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn should_emit(&self, signal: &Signal) -> bool {
+        signal.level() as u8 >= self.min_level as u8
+    }
+
+    /// Convert a signal to its JSON lines representation.
+    pub fn to_json(signal: &Signal) -> serde_json::Value {
+        let timestamp: DateTime<Utc> = signal.created_at().into();
+        let mut fields: serde_json::Map<String, serde_json::Value> = signal
+            .attributes()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.into()))
+            .collect();
+
+        if let Some(extra) = signal.fields().as_object() {
+            fields.extend(extra.iter().map(|(k, v)| (k.clone(), v.into())));
+        }
+
+        serde_json::json!({
+            "timestamp": timestamp.to_rfc3339_opts(SecondsFormat::Millis, true),
+            "level": signal.level().as_str(),
+            "type": signal.otype().as_str(),
+            "name": signal.name(),
+            "span": match signal.otype() {
+                Type::Span => Some(signal.name()),
+                _ => None,
+            },
+            "fields": fields,
+        })
+    }
+}
+
+impl Default for JsonLinesEmitter<Stdout> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W: Write + Send> Emitter for JsonLinesEmitter<W> {
+    fn emit(&self, signal: Signal) {
+        if !self.should_emit(&signal) {
+            return;
+        }
+
+        if let Ok(mut writer) = self.writer.lock()
+            && let Ok(json) = serde_json::to_string(&Self::to_json(&signal))
+        {
+            let _ = writeln!(writer, "{}", json);
+            let _ = writer.flush();
+        }
+    }
+
+    fn flush(&self) {
+        if let Ok(mut writer) = self.writer.lock() {
+            let _ = writer.flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Span;
+
+    fn lines(emitter: JsonLinesEmitter<Vec<u8>>) -> Vec<serde_json::Value> {
+        let output = String::from_utf8(emitter.into_inner()).unwrap();
+        output
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("each line should be valid JSON"))
+            .collect()
+    }
+
+    #[test]
+    fn test_writes_one_json_object_per_line() {
+        let emitter = JsonLinesEmitter::from_writer(Vec::new());
+        emitter.emit(
+            Signal::new()
+                .name("eval.scored")
+                .attr("score", 0.5)
+                .attr("label", "task")
+                .build(),
+        );
+        emitter.emit(Span::new("eval.batch").with_attr("size", 8).finish());
+
+        let lines = lines(emitter);
+        assert_eq!(lines.len(), 2);
+
+        for line in &lines {
+            for key in ["timestamp", "level", "type", "name", "span", "fields"] {
+                assert!(line.get(key).is_some(), "missing key {}", key);
+            }
+        }
+
+        assert_eq!(lines[0]["level"], "info");
+        assert_eq!(lines[0]["type"], "event");
+        assert_eq!(lines[0]["span"], serde_json::Value::Null);
+        assert_eq!(lines[0]["fields"]["score"], 0.5);
+        assert_eq!(lines[0]["fields"]["label"], "task");
+
+        assert_eq!(lines[1]["type"], "span");
+        assert_eq!(lines[1]["span"], "eval.batch");
+        assert_eq!(lines[1]["fields"]["size"], 8);
+        assert!(lines[1]["fields"]["duration_ms"].is_i64());
+    }
+
+    #[test]
+    fn test_includes_structured_fields() {
+        let emitter = JsonLinesEmitter::from_writer(Vec::new());
+        Signal::event("eval.scored")
+            .attr("sample", 3)
+            .field("label", "task")
+            .field("score", 0.91)
+            .emit(&emitter);
+
+        let lines = lines(emitter);
+        assert_eq!(lines[0]["fields"]["sample"], 3);
+        assert_eq!(lines[0]["fields"]["label"], "task");
+        assert_eq!(lines[0]["fields"]["score"], 0.91);
+    }
+
+    #[test]
+    fn test_timestamp_is_rfc3339() {
+        let emitter = JsonLinesEmitter::from_writer(Vec::new());
+        emitter.emit(Signal::new().name("test").build());
+
+        let lines = lines(emitter);
+        let timestamp = lines[0]["timestamp"].as_str().unwrap();
+        assert!(DateTime::parse_from_rfc3339(timestamp).is_ok());
+    }
+
+    #[test]
+    fn test_filters_by_level() {
+        let emitter = JsonLinesEmitter::from_writer(Vec::new()).with_level(Level::Warn);
+        emitter.emit(Signal::new().name("debug").level(Level::Debug).build());
+        emitter.emit(Signal::new().name("warn").level(Level::Warn).build());
+
+        let lines = lines(emitter);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0]["name"], "warn");
+    }
+}
@@ -1,6 +1,8 @@
 use std::sync::{Arc, Mutex};
 
-use crate::{Emitter, Signal};
+use loom_core::value::Value;
+
+use crate::{Emitter, Level, Signal};
 
 /// An emitter that collects signals in memory.
 ///
@@ -66,6 +68,31 @@ impl MemoryEmitter {
         self.signals.lock().ok().and_then(|s| s.last().cloned())
     }
 
+    /// Find signals matching the predicate, in emission order.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let warnings = emitter.find(|s| {
+    ///     s.level() == Level::Warn
+    ///         && s.field("message").and_then(|v| v.as_str()).is_some_and(|m| m.contains("threshold"))
+    /// });
+    /// assert_eq!(warnings.len(), 1);
+    /// ```
+    pub fn find(&self, predicate: impl Fn(&Signal) -> bool) -> Vec<Signal> {
+        self.signals
+            .lock()
+            .map(|s| s.iter().filter(|s| predicate(s)).cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Count signals emitted at exactly `level`.
+    pub fn count_at_level(&self, level: Level) -> usize {
+        self.signals
+            .lock()
+            .map(|s| s.iter().filter(|s| s.level() == level).count())
+            .unwrap_or(0)
+    }
+
     /// Find signals by name.
     pub fn find_by_name(&self, name: &str) -> Vec<Signal> {
         self.signals()
@@ -73,6 +100,15 @@ impl MemoryEmitter {
             .filter(|s| s.name() == name)
             .collect()
     }
+
+    /// Find signals whose structured field `key` equals `value`.
+    pub fn find_by_field(&self, key: &str, value: impl Into<Value>) -> Vec<Signal> {
+        let value = value.into();
+        self.signals()
+            .into_iter()
+            .filter(|s| s.field(key) == Some(&value))
+            .collect()
+    }
 }
 
 impl Default for MemoryEmitter {
@@ -84,10 +120,10 @@ impl Default for MemoryEmitter {
 impl Emitter for MemoryEmitter {
     fn emit(&self, signal: Signal) {
         if let Ok(mut signals) = self.signals.lock() {
-            if let Some(cap) = self.capacity {
-                if signals.len() >= cap {
-                    signals.remove(0); // Ring buffer behavior
-                }
+            if let Some(cap) = self.capacity
+                && signals.len() >= cap
+            {
+                signals.remove(0); // Ring buffer behavior
             }
             signals.push(signal);
         }
@@ -191,4 +227,53 @@ mod tests {
         assert_eq!(emitter1.len(), 1);
         assert_eq!(emitter2.len(), 1);
     }
+
+    #[test]
+    fn test_memory_emitter_find_by_field() {
+        let emitter = MemoryEmitter::new();
+
+        Signal::event("scored")
+            .field("label", "task")
+            .emit(&emitter);
+        Signal::event("scored")
+            .field("label", "other")
+            .emit(&emitter);
+
+        let found = emitter.find_by_field("label", "task");
+        assert_eq!(found.len(), 1);
+        assert!(emitter.find_by_field("missing", "task").is_empty());
+    }
+
+    #[test]
+    fn test_memory_emitter_query_helpers() {
+        let emitter = MemoryEmitter::new();
+
+        Signal::event("eval.scored").emit(&emitter);
+        Signal::event("eval.check")
+            .level(Level::Warn)
+            .field("message", "score below threshold")
+            .emit(&emitter);
+        Signal::event("eval.check")
+            .level(Level::Warn)
+            .field("message", "slow sample")
+            .emit(&emitter);
+
+        assert_eq!(emitter.count_at_level(Level::Info), 1);
+        assert_eq!(emitter.count_at_level(Level::Warn), 2);
+        assert_eq!(emitter.count_at_level(Level::Error), 0);
+
+        let mentions_threshold = emitter.find(|s| {
+            s.level() == Level::Warn
+                && s.field("message")
+                    .and_then(|v| v.as_str())
+                    .is_some_and(|m| m.contains("threshold"))
+        });
+        assert_eq!(mentions_threshold.len(), 1);
+        assert_eq!(mentions_threshold[0].name(), "eval.check");
+
+        // Next test phase starts empty
+        emitter.clear();
+        assert_eq!(emitter.count_at_level(Level::Warn), 0);
+        assert!(emitter.find(|_| true).is_empty());
+    }
 }
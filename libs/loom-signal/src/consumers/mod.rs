@@ -1,7 +1,15 @@
 mod file;
+#[cfg(feature = "json")]
+mod jsonl;
 mod memory;
+#[cfg(feature = "otel")]
+mod otlp;
 mod stdout;
 
 pub use file::*;
+#[cfg(feature = "json")]
+pub use jsonl::*;
 pub use memory::*;
+#[cfg(feature = "otel")]
+pub use otlp::*;
 pub use stdout::*;
@@ -0,0 +1,254 @@
+//! Mapping from `Signal` to the OTLP/HTTP JSON encoding.
+//!
+//! `Span` signals become OTLP spans and everything else becomes log records.
+//! See <https://opentelemetry.io/docs/specs/otlp/#json-protobuf-encoding>.
+
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use loom_core::value::{Number, Value};
+use serde_json::{Value as Json, json};
+
+use crate::{Level, Signal};
+
+const SCOPE: &str = "loom-signal";
+
+/// OTLP status code for failed spans.
+const STATUS_CODE_ERROR: u8 = 2;
+
+/// OTLP span kind for in-process operations.
+const SPAN_KIND_INTERNAL: u8 = 1;
+
+/// Encode span signals as an `ExportTraceServiceRequest`.
+pub fn traces(service_name: &str, signals: &[&Signal]) -> Json {
+    let spans: Vec<Json> = signals.iter().map(|s| span(s)).collect();
+
+    json!({
+        "resourceSpans": [{
+            "resource": resource(service_name),
+            "scopeSpans": [{
+                "scope": { "name": SCOPE },
+                "spans": spans,
+            }],
+        }],
+    })
+}
+
+/// Encode non-span signals as an `ExportLogsServiceRequest`.
+pub fn logs(service_name: &str, signals: &[&Signal]) -> Json {
+    let records: Vec<Json> = signals.iter().map(|s| log_record(s)).collect();
+
+    json!({
+        "resourceLogs": [{
+            "resource": resource(service_name),
+            "scopeLogs": [{
+                "scope": { "name": SCOPE },
+                "logRecords": records,
+            }],
+        }],
+    })
+}
+
+fn resource(service_name: &str) -> Json {
+    json!({ "attributes": [key_value("service.name", &Value::from(service_name))] })
+}
+
+fn span(signal: &Signal) -> Json {
+    let attrs = signal.attributes();
+    let end = unix_nanos(signal.created_at());
+    let duration_ms = attrs
+        .get("duration_ms")
+        .and_then(|v| v.as_int())
+        .unwrap_or(0);
+    let start = end.saturating_sub(duration_ms.max(0) as u128 * 1_000_000);
+
+    let span_id = attrs.get("span_id").and_then(|v| v.as_int()).unwrap_or(0);
+    let trace_id = attrs
+        .get("trace_id")
+        .and_then(|v| v.as_int())
+        .unwrap_or(span_id);
+
+    let fields = signal
+        .fields()
+        .as_object()
+        .into_iter()
+        .flat_map(|f| f.iter());
+    let attributes: Vec<Json> = attrs
+        .iter()
+        .chain(fields)
+        .filter(|(k, _)| !matches!(k.as_str(), "span_id" | "parent_span_id" | "trace_id"))
+        .map(|(k, v)| key_value(k, v))
+        .collect();
+
+    let mut span = json!({
+        "traceId": trace_id_hex(trace_id as u64),
+        "spanId": span_id_hex(span_id as u64),
+        "name": signal.name(),
+        "kind": SPAN_KIND_INTERNAL,
+        "startTimeUnixNano": start.to_string(),
+        "endTimeUnixNano": end.to_string(),
+        "attributes": attributes,
+    });
+
+    if let Some(parent_id) = attrs.get("parent_span_id").and_then(|v| v.as_int()) {
+        span["parentSpanId"] = Json::from(span_id_hex(parent_id as u64));
+    }
+
+    if signal.level() == Level::Error {
+        let message = attrs
+            .get("error")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default();
+        span["status"] = json!({ "code": STATUS_CODE_ERROR, "message": message });
+    }
+
+    span
+}
+
+fn log_record(signal: &Signal) -> Json {
+    let fields = signal
+        .fields()
+        .as_object()
+        .into_iter()
+        .flat_map(|f| f.iter());
+    let attributes: Vec<Json> = signal
+        .attributes()
+        .iter()
+        .chain(fields)
+        .map(|(k, v)| key_value(k, v))
+        .collect();
+
+    json!({
+        "timeUnixNano": unix_nanos(signal.created_at()).to_string(),
+        "severityNumber": severity_number(signal.level()),
+        "severityText": signal.level().as_str().to_uppercase(),
+        "body": { "stringValue": signal.name() },
+        "attributes": attributes,
+    })
+}
+
+/// OTLP `SeverityNumber`, using the first value of each range.
+fn severity_number(level: Level) -> u8 {
+    match level {
+        Level::Trace => 1,
+        Level::Debug => 5,
+        Level::Info => 9,
+        Level::Warn => 13,
+        Level::Error => 17,
+    }
+}
+
+fn key_value(key: &str, value: &Value) -> Json {
+    json!({ "key": key, "value": any_value(value) })
+}
+
+fn any_value(value: &Value) -> Json {
+    match value {
+        Value::Null => json!({}),
+        Value::Bool(b) => json!({ "boolValue": b }),
+        // OTLP JSON encodes 64-bit integers as strings
+        Value::Number(Number::Int(i)) => json!({ "intValue": i.to_string() }),
+        Value::Number(Number::Float(f)) => json!({ "doubleValue": f }),
+        Value::String(s) => json!({ "stringValue": s }),
+        Value::Array(arr) => {
+            let values: Vec<Json> = arr.iter().map(any_value).collect();
+            json!({ "arrayValue": { "values": values } })
+        }
+        Value::Object(obj) => {
+            let values: Vec<Json> = obj.iter().map(|(k, v)| key_value(k, v)).collect();
+            json!({ "kvlistValue": { "values": values } })
+        }
+    }
+}
+
+fn unix_nanos(time: SystemTime) -> u128 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0)
+}
+
+/// Span ids are only unique within a process, so trace ids are prefixed
+/// with a per-process value to keep them apart in the collector.
+fn trace_id_hex(trace_id: u64) -> String {
+    static PROCESS: OnceLock<u64> = OnceLock::new();
+    let process = *PROCESS.get_or_init(|| {
+        (unix_nanos(SystemTime::now()) as u64) ^ ((std::process::id() as u64) << 32)
+    });
+
+    format!("{:016x}{:016x}", process, trace_id)
+}
+
+fn span_id_hex(span_id: u64) -> String {
+    format!("{:016x}", span_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Span;
+
+    #[test]
+    fn test_span_maps_ids_and_timing() {
+        let parent = Span::new("parent");
+        let child = Span::new("child")
+            .with_parent(parent.context())
+            .with_attr("size", 8)
+            .finish();
+
+        let request = traces("loom", &[&child]);
+        let span = &request["resourceSpans"][0]["scopeSpans"][0]["spans"][0];
+
+        assert_eq!(span["name"], "child");
+        assert_eq!(span["parentSpanId"], span_id_hex(parent.id()));
+        assert_eq!(span["traceId"].as_str().unwrap().len(), 32);
+        assert!(
+            span["traceId"]
+                .as_str()
+                .unwrap()
+                .ends_with(&span_id_hex(parent.id()))
+        );
+
+        let start: u128 = span["startTimeUnixNano"].as_str().unwrap().parse().unwrap();
+        let end: u128 = span["endTimeUnixNano"].as_str().unwrap().parse().unwrap();
+        assert!(start <= end);
+
+        let attrs = span["attributes"].as_array().unwrap();
+        assert!(
+            attrs
+                .iter()
+                .any(|a| a["key"] == "size" && a["value"]["intValue"] == "8")
+        );
+        assert!(!attrs.iter().any(|a| a["key"] == "span_id"));
+    }
+
+    #[test]
+    fn test_failed_span_has_error_status() {
+        let signal = Span::new("op").finish_with_error("boom");
+        let request = traces("loom", &[&signal]);
+        let span = &request["resourceSpans"][0]["scopeSpans"][0]["spans"][0];
+
+        assert_eq!(span["status"]["code"], STATUS_CODE_ERROR);
+        assert_eq!(span["status"]["message"], "boom");
+    }
+
+    #[test]
+    fn test_event_maps_to_log_record() {
+        let signal = Signal::new()
+            .name("eval.scored")
+            .level(Level::Warn)
+            .attr("score", 0.5)
+            .build();
+
+        let request = logs("loom", &[&signal]);
+        let record = &request["resourceLogs"][0]["scopeLogs"][0]["logRecords"][0];
+
+        assert_eq!(record["body"]["stringValue"], "eval.scored");
+        assert_eq!(record["severityNumber"], 13);
+        assert_eq!(record["severityText"], "WARN");
+        assert_eq!(record["attributes"][0]["value"]["doubleValue"], 0.5);
+        assert_eq!(
+            request["resourceLogs"][0]["resource"]["attributes"][0]["value"]["stringValue"],
+            "loom"
+        );
+    }
+}
@@ -0,0 +1,338 @@
+mod encode;
+
+use std::collections::BTreeMap;
+use std::future::poll_fn;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use loom_sync::chan::tokio::{TokioReceiver, TokioSender};
+use loom_sync::chan::{Receiver, Sender};
+use loom_sync::tasks::Task;
+
+use crate::{Emitter, Level, Signal, Type};
+
+/// Settings for `OtlpEmitter`.
+///
+/// Every field has a default, so the struct can be bound from a partial
+/// config section such as `signals.otlp`:
+///
+/// ```yaml
+/// signals:
+///   otlp:
+///     endpoint: http://collector:4318
+///     service_name: loom-eval
+///     headers:
+///       authorization: Bearer ...
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct OtlpConfig {
+    /// Base URL of the collector's OTLP/HTTP receiver
+    pub endpoint: String,
+
+    /// Reported as the `service.name` resource attribute
+    pub service_name: String,
+
+    /// Extra headers sent with every export request
+    pub headers: BTreeMap<String, String>,
+
+    /// Export as soon as this many signals are buffered
+    pub batch_size: usize,
+
+    /// Export buffered signals at least this often
+    pub flush_interval_ms: u64,
+
+    /// Timeout for each export request
+    pub timeout_ms: u64,
+}
+
+impl OtlpConfig {
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for OtlpConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: "http://localhost:4318".to_string(),
+            service_name: "loom".to_string(),
+            headers: BTreeMap::new(),
+            batch_size: 512,
+            flush_interval_ms: 1000,
+            timeout_ms: 10_000,
+        }
+    }
+}
+
+/// An emitter that exports signals to an OpenTelemetry collector.
+///
+/// `Span` signals are exported as OTLP spans (keeping the `span_id`,
+/// `parent_span_id` and `trace_id` attributes as span/trace ids) and all
+/// other signals as OTLP log records. Only the OTLP/HTTP JSON protocol is
+/// supported; point `endpoint` at the collector's HTTP receiver (port 4318
+/// by default), not its gRPC one.
+///
+/// `emit` only queues the signal. A background task batches queued signals
+/// and exports them when `batch_size` is reached or every
+/// `flush_interval_ms`. Export failures are dropped, like write failures in
+/// the other emitters. Exporting is async, so `Emitter::flush` and
+/// `Emitter::shutdown` can't wait for it; await the inherent `shutdown`
+/// instead.
+///
+/// # Example
+/// ```ignore
+/// let emitter = OtlpEmitter::new(OtlpConfig::new("http://collector:4318"))?;
+/// emitter.emit(signal);
+///
+/// // Export anything still buffered before exiting
+/// emitter.shutdown().await;
+/// ```
+pub struct OtlpEmitter {
+    sender: TokioSender<Signal>,
+    task: Mutex<Task<()>>,
+    min_level: Level,
+}
+
+impl OtlpEmitter {
+    /// Create a new emitter and start its export task.
+    ///
+    /// Fails if the HTTP client can't be created, e.g. when the TLS backend
+    /// or system proxy settings fail to load.
+    ///
+    /// # Panics
+    /// Panics if called outside of a tokio runtime.
+    pub fn new(config: OtlpConfig) -> Result<Self, reqwest::Error> {
+        let exporter = Exporter::new(config)?;
+        let (sender, receiver) = loom_sync::open!();
+        let task = loom_sync::spawn!(async move { exporter.run(receiver).await });
+
+        Ok(Self {
+            sender,
+            task: Mutex::new(task),
+            min_level: Level::Trace,
+        })
+    }
+
+    /// Set the minimum log level to emit.
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.min_level = level;
+        self
+    }
+
+    /// Stop accepting signals and wait until everything queued is exported.
+    pub async fn shutdown(self) {
+        let Self { sender, task, .. } = self;
+        drop(sender);
+
+        let task = task
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let _ = task.await;
+    }
+
+    fn should_emit(&self, signal: &Signal) -> bool {
+        signal.level() as u8 >= self.min_level as u8
+    }
+}
+
+impl Emitter for OtlpEmitter {
+    fn emit(&self, signal: Signal) {
+        if !self.should_emit(&signal) {
+            return;
+        }
+
+        let _ = self.sender.send(signal);
+    }
+}
+
+struct Exporter {
+    client: reqwest::Client,
+    config: OtlpConfig,
+}
+
+impl Exporter {
+    fn new(config: OtlpConfig) -> Result<Self, reqwest::Error> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(config.timeout_ms))
+            .build()?;
+
+        Ok(Self { client, config })
+    }
+
+    async fn run(self, mut receiver: TokioReceiver<Signal>) {
+        let interval = Duration::from_millis(self.config.flush_interval_ms);
+        let batch_size = self.config.batch_size.max(1);
+        let mut batch = Vec::with_capacity(batch_size);
+        let mut deadline = tokio::time::Instant::now() + interval;
+
+        loop {
+            let next = poll_fn(|cx| receiver.recv_poll(cx));
+
+            match tokio::time::timeout_at(deadline, next).await {
+                Ok(Ok(signal)) => {
+                    batch.push(signal);
+
+                    if batch.len() >= batch_size {
+                        self.export(std::mem::take(&mut batch)).await;
+                        deadline = tokio::time::Instant::now() + interval;
+                    }
+                }
+                // All senders are gone
+                Ok(Err(_)) => break,
+                Err(_) => {
+                    if !batch.is_empty() {
+                        self.export(std::mem::take(&mut batch)).await;
+                    }
+
+                    deadline = tokio::time::Instant::now() + interval;
+                }
+            }
+        }
+
+        if !batch.is_empty() {
+            self.export(batch).await;
+        }
+    }
+
+    async fn export(&self, batch: Vec<Signal>) {
+        let (spans, logs): (Vec<&Signal>, Vec<&Signal>) =
+            batch.iter().partition(|s| s.otype() == Type::Span);
+
+        let service_name = &self.config.service_name;
+
+        if !spans.is_empty() {
+            self.post("v1/traces", &encode::traces(service_name, &spans))
+                .await;
+        }
+
+        if !logs.is_empty() {
+            self.post("v1/logs", &encode::logs(service_name, &logs))
+                .await;
+        }
+    }
+
+    async fn post(&self, path: &str, body: &serde_json::Value) {
+        let url = format!("{}/{}", self.config.endpoint.trim_end_matches('/'), path);
+        let mut request = self.client.post(url).json(body);
+
+        for (name, value) in &self.config.headers {
+            request = request.header(name, value);
+        }
+
+        let _ = request.send().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Span;
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpListener;
+    use tokio::sync::mpsc;
+
+    /// Accepts OTLP/HTTP requests and forwards `(path, body)` for each one.
+    async fn mock_collector() -> (String, mpsc::UnboundedReceiver<(String, serde_json::Value)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let (read, mut write) = stream.into_split();
+                let mut reader = BufReader::new(read);
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).await.unwrap();
+                let path = request_line.split_whitespace().nth(1).unwrap().to_string();
+
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).await.unwrap();
+                    let line = line.trim_end();
+
+                    if line.is_empty() {
+                        break;
+                    }
+
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).await.unwrap();
+
+                write
+                    .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+                    .await
+                    .unwrap();
+
+                let _ = tx.send((path, serde_json::from_slice(&body).unwrap()));
+            }
+        });
+
+        (endpoint, rx)
+    }
+
+    #[tokio::test]
+    async fn test_exports_span_to_collector() {
+        let (endpoint, mut requests) = mock_collector().await;
+        let emitter = OtlpEmitter::new(OtlpConfig {
+            service_name: "loom-test".to_string(),
+            ..OtlpConfig::new(endpoint)
+        })
+        .unwrap();
+
+        emitter.emit(Span::new("eval.score").with_attr("size", 8).finish());
+        emitter.shutdown().await;
+
+        let (path, body) = requests.recv().await.unwrap();
+        assert_eq!(path, "/v1/traces");
+
+        let resource = &body["resourceSpans"][0];
+        assert_eq!(
+            resource["resource"]["attributes"][0]["value"]["stringValue"],
+            "loom-test"
+        );
+
+        let span = &resource["scopeSpans"][0]["spans"][0];
+        assert_eq!(span["name"], "eval.score");
+        assert_eq!(span["spanId"].as_str().unwrap().len(), 16);
+    }
+
+    #[tokio::test]
+    async fn test_flushes_on_batch_size() {
+        let (endpoint, mut requests) = mock_collector().await;
+        let emitter = OtlpEmitter::new(OtlpConfig {
+            batch_size: 2,
+            flush_interval_ms: 60_000,
+            ..OtlpConfig::new(endpoint)
+        })
+        .unwrap()
+        .with_level(Level::Info);
+
+        emitter.emit(Signal::new().name("debug").level(Level::Debug).build());
+        emitter.emit(Signal::new().name("first").build());
+        emitter.emit(Signal::new().name("second").build());
+
+        // Exported without waiting for the interval or shutdown
+        let (path, body) = requests.recv().await.unwrap();
+        assert_eq!(path, "/v1/logs");
+
+        let records = body["resourceLogs"][0]["scopeLogs"][0]["logRecords"]
+            .as_array()
+            .unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["body"]["stringValue"], "first");
+
+        emitter.shutdown().await;
+    }
+}
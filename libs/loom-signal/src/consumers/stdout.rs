@@ -45,13 +45,21 @@ impl StdoutEmitter {
     }
 
     fn format_human(&self, signal: &Signal) -> String {
-        format!(
+        let mut output = format!(
             "[{}] {} {} {:?}",
             signal.level(),
             signal.otype(),
             signal.name(),
             signal.attributes()
-        )
+        );
+
+        if let Some(fields) = signal.fields().as_object()
+            && !fields.is_empty()
+        {
+            output.push_str(&format!(" {}", fields));
+        }
+
+        output
     }
 }
 
@@ -82,6 +90,10 @@ impl Emitter for StdoutEmitter {
 
         let _ = writeln!(io::stdout(), "{}", output);
     }
+
+    fn flush(&self) {
+        let _ = io::stdout().flush();
+    }
 }
 
 #[cfg(test)]
@@ -53,6 +53,18 @@ impl Emitter for SignalBroadcaster {
             emitter.emit(signal.clone());
         }
     }
+
+    fn flush(&self) {
+        for emitter in &self.emitters {
+            emitter.flush();
+        }
+    }
+
+    fn shutdown(&self) {
+        for emitter in &self.emitters {
+            emitter.shutdown();
+        }
+    }
 }
 
 /// A no-op emitter that discards all signals.
@@ -128,6 +140,76 @@ mod tests {
         assert_eq!(*count2.lock().unwrap(), 1);
     }
 
+    /// Holds signals until `flush`, like an emitter with a write buffer.
+    struct BufferedEmitter {
+        pending: Mutex<Vec<Signal>>,
+        persisted: Arc<Mutex<Vec<Signal>>>,
+    }
+
+    impl Emitter for BufferedEmitter {
+        fn emit(&self, signal: Signal) {
+            self.pending.lock().unwrap().push(signal);
+        }
+
+        fn flush(&self) {
+            let mut pending = self.pending.lock().unwrap();
+            self.persisted.lock().unwrap().append(&mut pending);
+        }
+    }
+
+    #[test]
+    fn test_broadcaster_flushes_all() {
+        let persisted = Arc::new(Mutex::new(Vec::new()));
+        let buffered = || BufferedEmitter {
+            pending: Mutex::new(Vec::new()),
+            persisted: persisted.clone(),
+        };
+
+        let broadcaster = SignalBroadcaster::new().add(buffered()).add(buffered());
+        broadcaster.emit(Signal::new().name("test").build());
+        assert!(persisted.lock().unwrap().is_empty());
+
+        broadcaster.flush();
+        assert_eq!(persisted.lock().unwrap().len(), 2);
+
+        broadcaster.emit(Signal::new().name("last").build());
+        broadcaster.shutdown();
+
+        let persisted = persisted.lock().unwrap();
+        assert_eq!(persisted.len(), 4);
+        assert_eq!(persisted[3].name(), "last");
+    }
+
+    /// Records that `shutdown` ran, without flushing anything.
+    struct ClosingEmitter {
+        closed: Arc<Mutex<bool>>,
+    }
+
+    impl Emitter for ClosingEmitter {
+        fn emit(&self, _signal: Signal) {}
+
+        fn shutdown(&self) {
+            *self.closed.lock().unwrap() = true;
+        }
+    }
+
+    #[test]
+    fn test_broadcaster_shuts_down_all() {
+        let closed = [Arc::new(Mutex::new(false)), Arc::new(Mutex::new(false))];
+        let broadcaster: Box<dyn Emitter> = Box::new(
+            SignalBroadcaster::new()
+                .add(ClosingEmitter {
+                    closed: closed[0].clone(),
+                })
+                .add(ClosingEmitter {
+                    closed: closed[1].clone(),
+                }),
+        );
+
+        broadcaster.shutdown();
+        assert!(closed.iter().all(|closed| *closed.lock().unwrap()));
+    }
+
     #[test]
     fn test_noop_emitter() {
         let emitter = NoopEmitter;
@@ -3,18 +3,31 @@ pub mod consumers;
 mod emitter;
 mod level;
 mod otype;
+mod sampled;
 mod span;
 
 pub use attr::*;
 pub use emitter::*;
 pub use level::*;
 pub use otype::*;
+pub use sampled::*;
 pub use span::*;
 
-use loom_core::value::Value;
+use loom_core::value::{Object, Value};
 
 pub trait Emitter {
     fn emit(&self, signal: Signal);
+
+    /// Write out any signals the emitter has buffered. Does nothing by
+    /// default, for emitters that write each signal as it is emitted.
+    fn flush(&self) {}
+
+    /// Flush and release the emitter's resources; call on graceful shutdown
+    /// so trailing signals are not lost when the process exits. Takes
+    /// `&self` so it can be called on shared and boxed emitters.
+    fn shutdown(&self) {
+        self.flush();
+    }
 }
 
 pub trait Consumer {
@@ -27,6 +40,8 @@ pub struct Signal {
     level: Level,
     name: String,
     attributes: Attributes,
+    #[serde(default = "empty_fields")]
+    fields: Value,
     created_at: std::time::SystemTime,
 }
 
@@ -35,6 +50,20 @@ impl Signal {
         SignalBuilder::new()
     }
 
+    /// Start building an `Event` signal with the given name.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Signal::event("scored")
+    ///     .field("label", "task")
+    ///     .field("score", 0.91)
+    ///     .level(Level::Info)
+    ///     .emit(&emitter);
+    /// ```
+    pub fn event(name: impl Into<String>) -> SignalBuilder {
+        SignalBuilder::new().otype(Type::Event).name(name)
+    }
+
     pub fn otype(&self) -> Type {
         self.otype
     }
@@ -51,6 +80,15 @@ impl Signal {
         &self.attributes
     }
 
+    /// Caller-supplied context, always a `Value::Object`.
+    pub fn fields(&self) -> &Value {
+        &self.fields
+    }
+
+    pub fn field(&self, key: &str) -> Option<&Value> {
+        self.fields.as_object().and_then(|fields| fields.get(key))
+    }
+
     pub fn created_at(&self) -> std::time::SystemTime {
         self.created_at
     }
@@ -62,6 +100,7 @@ pub struct SignalBuilder {
     level: Option<Level>,
     name: Option<String>,
     attributes: AttributesBuilder,
+    fields: Object,
 }
 
 impl SignalBuilder {
@@ -94,13 +133,66 @@ impl SignalBuilder {
         self
     }
 
+    /// Add a structured field. Fields carry caller context, while attributes
+    /// hold signal metadata such as `duration_ms`.
+    pub fn field(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.fields.insert(key.into(), value.into());
+        self
+    }
+
+    /// Build the signal and emit it.
+    pub fn emit(self, emitter: &dyn Emitter) {
+        emitter.emit(self.build());
+    }
+
     pub fn build(self) -> Signal {
         Signal {
             otype: self.otype.unwrap_or(Type::Event),
             level: self.level.unwrap_or(Level::Info),
             name: self.name.unwrap_or_default(),
             attributes: self.attributes.build(),
+            fields: Value::Object(self.fields),
             created_at: std::time::SystemTime::now(),
         }
     }
 }
+
+fn empty_fields() -> Value {
+    Value::Object(Object::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consumers::MemoryEmitter;
+
+    #[test]
+    fn test_event_builder_carries_typed_fields() {
+        let emitter = MemoryEmitter::new();
+
+        Signal::event("scored")
+            .field("label", "task")
+            .field("score", 0.91)
+            .level(Level::Warn)
+            .emit(&emitter);
+
+        let signal = emitter.last().unwrap();
+        assert_eq!(signal.otype(), Type::Event);
+        assert_eq!(signal.name(), "scored");
+        assert_eq!(signal.level(), Level::Warn);
+        assert!(signal.fields().is_object());
+        assert_eq!(signal.field("label").and_then(|v| v.as_str()), Some("task"));
+        assert_eq!(signal.field("score").and_then(|v| v.as_float()), Some(0.91));
+        assert!(matches!(
+            signal.field("score"),
+            Some(Value::Number(loom_core::value::Number::Float(_)))
+        ));
+        assert!(signal.field("missing").is_none());
+    }
+
+    #[test]
+    fn test_build_without_fields_is_empty_object() {
+        let signal = Signal::new().name("test").build();
+        assert_eq!(signal.fields(), &Value::Object(Object::new()));
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::{Emitter, Signal, Type};
+
+/// Number of tracked keys above which expired ones are pruned.
+const PRUNE_THRESHOLD: usize = 1024;
+
+/// An emitter decorator that collapses duplicate signals.
+///
+/// Signals with the same type, name and `message` field are duplicates.
+/// The first one in each `window` is passed to the inner emitter and the
+/// rest are counted. When a duplicate arrives after the window, or on
+/// `flush`, a `signal.suppressed` event with the count is emitted at the
+/// level of the suppressed signals.
+///
+/// # Example
+/// ```ignore
+/// let emitter = SampledEmitter::new(StdoutEmitter::new(), Duration::from_secs(10));
+///
+/// for _ in 0..1000 {
+///     emitter.emit(Signal::new().name("cache.miss").level(Level::Warn).build());
+/// }
+///
+/// emitter.flush(); // "999 more 'cache.miss' signals suppressed"
+/// ```
+pub struct SampledEmitter<E> {
+    inner: E,
+    window: Duration,
+    windows: Mutex<HashMap<Key, Window>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Key {
+    otype: Type,
+    name: String,
+    message: Option<String>,
+}
+
+struct Window {
+    started: Instant,
+    suppressed: u64,
+    last: Signal,
+}
+
+impl<E: Emitter> SampledEmitter<E> {
+    pub fn new(inner: E, window: Duration) -> Self {
+        Self {
+            inner,
+            window,
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.inner
+    }
+
+    fn key(signal: &Signal) -> Key {
+        Key {
+            otype: signal.otype(),
+            name: signal.name().to_string(),
+            message: signal
+                .field("message")
+                .and_then(|m| m.as_str())
+                .map(str::to_string),
+        }
+    }
+
+    fn summary(&self, window: &Window) -> Signal {
+        let name = window.last.name();
+
+        Signal::event("signal.suppressed")
+            .level(window.last.level())
+            .field("signal", name)
+            .field("suppressed", window.suppressed)
+            .field("window_ms", self.window.as_millis() as u64)
+            .field(
+                "message",
+                format!("{} more '{}' signals suppressed", window.suppressed, name),
+            )
+            .build()
+    }
+}
+
+impl<E: Emitter> Emitter for SampledEmitter<E> {
+    fn emit(&self, signal: Signal) {
+        let now = Instant::now();
+        let mut summary = None;
+
+        if let Ok(mut windows) = self.windows.lock() {
+            if windows.len() > PRUNE_THRESHOLD {
+                windows.retain(|_, w| w.suppressed > 0 || now - w.started < self.window);
+            }
+
+            match windows.get_mut(&Self::key(&signal)) {
+                Some(window) if now - window.started < self.window => {
+                    window.suppressed += 1;
+                    window.last = signal;
+                    return;
+                }
+                Some(window) => {
+                    if window.suppressed > 0 {
+                        summary = Some(self.summary(window));
+                    }
+
+                    window.started = now;
+                    window.suppressed = 0;
+                    window.last = signal.clone();
+                }
+                None => {
+                    let window = Window {
+                        started: now,
+                        suppressed: 0,
+                        last: signal.clone(),
+                    };
+
+                    windows.insert(Self::key(&signal), window);
+                }
+            }
+        }
+
+        // Emit outside the lock so a slow inner emitter doesn't block
+        // other keys.
+        if let Some(summary) = summary {
+            self.inner.emit(summary);
+        }
+
+        self.inner.emit(signal);
+    }
+
+    /// Emit a summary for every key with suppressed signals, then flush the
+    /// inner emitter.
+    fn flush(&self) {
+        let summaries: Vec<Signal> = match self.windows.lock() {
+            Ok(mut windows) => windows
+                .values_mut()
+                .filter(|w| w.suppressed > 0)
+                .map(|w| {
+                    let summary = self.summary(w);
+                    w.suppressed = 0;
+                    summary
+                })
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+
+        for summary in summaries {
+            self.inner.emit(summary);
+        }
+
+        self.inner.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Level;
+    use crate::consumers::MemoryEmitter;
+
+    fn warning(name: &str) -> Signal {
+        Signal::new().name(name).level(Level::Warn).build()
+    }
+
+    #[test]
+    fn test_collapses_duplicates_into_summary() {
+        let memory = MemoryEmitter::new();
+        let emitter = SampledEmitter::new(memory.clone(), Duration::from_secs(60));
+
+        for _ in 0..1000 {
+            emitter.emit(warning("cache.miss"));
+        }
+
+        emitter.emit(warning("cache.evicted"));
+        assert_eq!(memory.len(), 2);
+
+        emitter.flush();
+
+        let summaries = memory.find_by_name("signal.suppressed");
+        assert_eq!(memory.len(), 3);
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].level(), Level::Warn);
+        assert_eq!(
+            summaries[0].field("suppressed").unwrap().as_int(),
+            Some(999)
+        );
+        assert_eq!(
+            summaries[0].field("signal").unwrap().as_str(),
+            Some("cache.miss")
+        );
+
+        // Nothing left to summarize
+        emitter.flush();
+        assert_eq!(memory.len(), 3);
+    }
+
+    #[test]
+    fn test_distinct_messages_are_not_duplicates() {
+        let memory = MemoryEmitter::new();
+        let emitter = SampledEmitter::new(memory.clone(), Duration::from_secs(60));
+
+        for i in 0..3 {
+            emitter.emit(
+                Signal::event("layer.failed")
+                    .field("message", format!("layer {} failed", i))
+                    .build(),
+            );
+        }
+
+        assert_eq!(memory.len(), 3);
+    }
+
+    #[test]
+    fn test_new_window_emits_summary_and_signal() {
+        let memory = MemoryEmitter::new();
+        let emitter = SampledEmitter::new(memory.clone(), Duration::from_millis(20));
+
+        for _ in 0..10 {
+            emitter.emit(warning("cache.miss"));
+        }
+
+        std::thread::sleep(Duration::from_millis(30));
+        emitter.emit(warning("cache.miss"));
+
+        let names: Vec<String> = memory
+            .signals()
+            .iter()
+            .map(|s| s.name().to_string())
+            .collect();
+
+        assert_eq!(names, ["cache.miss", "signal.suppressed", "cache.miss"]);
+        assert_eq!(
+            memory.signals()[1].field("suppressed").unwrap().as_int(),
+            Some(9)
+        );
+    }
+}
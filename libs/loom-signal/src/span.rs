@@ -1,14 +1,36 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
 use loom_core::value::Value;
 
-use crate::{Attributes, Level, Signal, Type};
+use crate::{Attributes, Emitter, Level, Signal, Type};
+
+static NEXT_SPAN_ID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    /// Spans entered on this thread, innermost last.
+    static ACTIVE_SPANS: RefCell<Vec<SpanContext>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Identifies a span and the trace (tree of spans) it belongs to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SpanContext {
+    /// Id of the root span of the trace
+    pub trace_id: u64,
+    pub span_id: u64,
+}
 
 /// A span represents a timed operation.
 ///
 /// Create a span at the start of an operation and call `finish()` when done
 /// to convert it to a Signal with duration information.
 ///
+/// Each span gets a process-unique id. A span created while another span is
+/// entered on the same thread records that span as its parent and joins its
+/// trace; the ids are added to the finished signal as `span_id`,
+/// `parent_span_id` and `trace_id`.
+///
 /// # Example
 /// ```ignore
 /// let span = Span::new("my.operation")
@@ -18,8 +40,14 @@ use crate::{Attributes, Level, Signal, Type};
 /// // ... do work ...
 ///
 /// emitter.emit(span.finish());
+///
+/// // Or emit automatically when the guard is dropped
+/// let _guard = Span::new("my.operation").enter(&emitter);
 /// ```
 pub struct Span {
+    id: u64,
+    trace_id: u64,
+    parent_id: Option<u64>,
     name: String,
     level: Level,
     attributes: Attributes,
@@ -30,7 +58,13 @@ impl Span {
     /// Create a new span with the given name.
     /// The start time is captured immediately.
     pub fn new(name: impl Into<String>) -> Self {
+        let id = NEXT_SPAN_ID.fetch_add(1, Ordering::Relaxed);
+        let parent = ACTIVE_SPANS.with(|spans| spans.borrow().last().copied());
+
         Self {
+            id,
+            trace_id: parent.map(|p| p.trace_id).unwrap_or(id),
+            parent_id: parent.map(|p| p.span_id),
             name: name.into(),
             level: Level::Info,
             attributes: Attributes::new().build(),
@@ -58,40 +92,133 @@ impl Span {
         self.start_time.elapsed()
     }
 
+    /// Set the parent span explicitly, e.g. for work continued on another thread.
+    pub fn with_parent(mut self, parent: SpanContext) -> Self {
+        self.trace_id = parent.trace_id;
+        self.parent_id = Some(parent.span_id);
+        self
+    }
+
     /// Get the span name.
     pub fn name(&self) -> &str {
         &self.name
     }
 
+    /// Get the span id.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Get the id of the enclosing span, if any.
+    pub fn parent_id(&self) -> Option<u64> {
+        self.parent_id
+    }
+
+    /// Get the span and trace ids, e.g. to pass to `with_parent` on another thread.
+    pub fn context(&self) -> SpanContext {
+        SpanContext {
+            trace_id: self.trace_id,
+            span_id: self.id,
+        }
+    }
+
+    /// Enter the span, making it the parent of spans created on this thread
+    /// until the returned guard is dropped. Dropping the guard finishes the
+    /// span and emits it to `emitter`.
+    pub fn enter(self, emitter: &dyn Emitter) -> SpanGuard<'_> {
+        ACTIVE_SPANS.with(|spans| spans.borrow_mut().push(self.context()));
+        SpanGuard {
+            context: self.context(),
+            span: Some(self),
+            emitter,
+        }
+    }
+
     /// Finish the span and convert it to a Signal.
     /// Adds `duration_ms` attribute automatically.
     pub fn finish(self) -> Signal {
-        Signal::new()
-            .otype(Type::Span)
-            .level(self.level)
-            .name(self.name)
-            .attributes(self.attributes)
-            .attr("duration_ms", self.start_time.elapsed().as_millis() as i64)
-            .build()
+        let level = self.level;
+        self.into_signal(level).build()
     }
 
     /// Finish the span with an error.
     /// Sets level to Error and adds an `error` attribute.
     pub fn finish_with_error(self, error: impl Into<String>) -> Signal {
-        Signal::new()
+        self.into_signal(Level::Error)
+            .attr("error", error.into())
+            .build()
+    }
+
+    fn into_signal(self, level: Level) -> crate::SignalBuilder {
+        let mut builder = Signal::new()
             .otype(Type::Span)
-            .level(Level::Error)
+            .level(level)
             .name(self.name)
             .attributes(self.attributes)
             .attr("duration_ms", self.start_time.elapsed().as_millis() as i64)
-            .attr("error", error.into())
-            .build()
+            .attr("span_id", self.id as i64)
+            .attr("trace_id", self.trace_id as i64);
+
+        if let Some(parent_id) = self.parent_id {
+            builder = builder.attr("parent_span_id", parent_id as i64);
+        }
+
+        builder
+    }
+}
+
+/// Guard returned by `Span::enter`. Emits the finished span when dropped.
+pub struct SpanGuard<'a> {
+    context: SpanContext,
+    span: Option<Span>,
+    emitter: &'a dyn Emitter,
+}
+
+impl SpanGuard<'_> {
+    /// Get the id of the entered span.
+    pub fn id(&self) -> u64 {
+        self.context.span_id
+    }
+
+    /// Get the span and trace ids of the entered span.
+    pub fn context(&self) -> SpanContext {
+        self.context
+    }
+
+    /// Add an attribute to the span before it is emitted.
+    pub fn attr(&mut self, key: impl Into<String>, value: impl Into<Value>) {
+        if let Some(span) = self.span.take() {
+            self.span = Some(span.with_attr(key, value));
+        }
+    }
+
+    /// Finish the span with an error instead of waiting for drop.
+    pub fn fail(mut self, error: impl Into<String>) {
+        if let Some(span) = self.span.take() {
+            self.emitter.emit(span.finish_with_error(error));
+        }
+    }
+}
+
+impl Drop for SpanGuard<'_> {
+    fn drop(&mut self) {
+        ACTIVE_SPANS.with(|spans| {
+            let mut spans = spans.borrow_mut();
+            if let Some(pos) = spans.iter().rposition(|c| *c == self.context) {
+                spans.remove(pos);
+            }
+        });
+
+        if let Some(span) = self.span.take() {
+            self.emitter.emit(span.finish());
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::consumers::MemoryEmitter;
 
     #[test]
     fn test_span_creation() {
@@ -131,4 +258,76 @@ mod tests {
         assert_eq!(signal.level(), Level::Error);
         assert!(signal.attributes().exists("error"));
     }
+
+    #[test]
+    fn test_span_guard_emits_duration_on_drop() {
+        let emitter = MemoryEmitter::new();
+
+        {
+            let _guard = Span::new("test.guard").enter(&emitter);
+            std::thread::sleep(Duration::from_millis(5));
+            assert!(emitter.is_empty());
+        }
+
+        let signal = emitter.last().unwrap();
+        assert_eq!(signal.name(), "test.guard");
+        assert_eq!(signal.otype(), Type::Span);
+
+        let duration = signal.attributes()["duration_ms"].as_int().unwrap();
+        assert!(duration > 0);
+    }
+
+    #[test]
+    fn test_nested_spans_record_parent() {
+        let emitter = MemoryEmitter::new();
+
+        let (outer_id, inner_id) = {
+            let outer = Span::new("outer").enter(&emitter);
+            let inner = Span::new("inner").enter(&emitter);
+            (outer.id(), inner.id())
+        };
+
+        let inner = &emitter.find_by_name("inner")[0];
+        assert_eq!(
+            inner.attributes()["span_id"].as_int(),
+            Some(inner_id as i64)
+        );
+        assert_eq!(
+            inner.attributes()["parent_span_id"].as_int(),
+            Some(outer_id as i64)
+        );
+
+        assert_eq!(
+            inner.attributes()["trace_id"].as_int(),
+            Some(outer_id as i64)
+        );
+
+        let outer = &emitter.find_by_name("outer")[0];
+        assert!(!outer.attributes().exists("parent_span_id"));
+
+        // Both guards are dropped, so new spans are roots again
+        assert_eq!(Span::new("after").parent_id(), None);
+    }
+
+    #[test]
+    fn test_with_parent_joins_trace() {
+        let root = Span::new("root");
+        let child = Span::new("child").with_parent(root.context());
+
+        assert_eq!(child.parent_id(), Some(root.id()));
+        assert_eq!(child.context().trace_id, root.id());
+    }
+
+    #[test]
+    fn test_span_guard_fail_emits_error() {
+        let emitter = MemoryEmitter::new();
+        let mut guard = Span::new("test.fail").enter(&emitter);
+        guard.attr("input", "x");
+        guard.fail("boom");
+
+        assert_eq!(emitter.len(), 1);
+        let signal = emitter.last().unwrap();
+        assert_eq!(signal.level(), Level::Error);
+        assert!(signal.attributes().exists("input"));
+    }
 }
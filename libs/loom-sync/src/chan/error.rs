@@ -107,6 +107,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn send_error_clone() {
         let err = SendError::Closed;
         let cloned = err.clone();
@@ -161,6 +162,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn recv_error_clone() {
         let err = RecvError::Closed;
         let cloned = err.clone();
@@ -234,6 +236,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn chan_error_clone() {
         let err = ChanError::Send(SendError::Timeout);
         let cloned = err.clone();
@@ -13,6 +13,16 @@ pub trait Channel {
     fn status(&self) -> Status;
     fn len(&self) -> usize;
     fn capacity(&self) -> Option<usize>;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether the channel is closed and drained, i.e. a receiver will
+    /// never yield another message.
+    fn is_closed(&self) -> bool {
+        self.status().is_closed()
+    }
 }
 
 pub trait Sender: Channel + Send + Sync + 'static {
@@ -30,7 +40,17 @@ pub trait Receiver: Channel + Send {
     type Item: Send;
 
     fn close(&mut self);
+
+    /// Block until a message arrives. Fails with `RecvError::Closed` once
+    /// every sender is gone (or the receiver was closed) and the buffer is
+    /// drained, so a worker loop can exit when its producer goes away.
     fn recv(&mut self) -> Result<Self::Item, error::RecvError>;
+
+    /// Take a buffered message without blocking. Fails with
+    /// `RecvError::Empty` if nothing is buffered but senders remain, and
+    /// with `RecvError::Closed` like `recv`.
+    fn try_recv(&mut self) -> Result<Self::Item, error::RecvError>;
+
     fn recv_poll(
         &mut self,
         cx: &mut std::task::Context<'_>,
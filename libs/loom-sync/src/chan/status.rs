@@ -1,6 +1,6 @@
 #[repr(u8)]
 #[non_exhaustive]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
 pub enum Status {
     /// Senders may still send (subject to capacity).
     Open,
@@ -9,42 +9,25 @@ pub enum Status {
     Draining,
 
     /// Closed and empty; receiver will never yield another message.
+    #[default]
     Closed,
 }
 
 impl Status {
     pub fn is_open(&self) -> bool {
-        match self {
-            Self::Open => true,
-            _ => false,
-        }
+        matches!(self, Self::Open)
     }
 
     pub fn is_draining(&self) -> bool {
-        match self {
-            Self::Draining => true,
-            _ => false,
-        }
+        matches!(self, Self::Draining)
     }
 
     pub fn is_closed(&self) -> bool {
-        match self {
-            Self::Closed => true,
-            _ => false,
-        }
+        matches!(self, Self::Closed)
     }
 
     pub fn is_closing(&self) -> bool {
-        match self {
-            Self::Draining | Self::Closed => true,
-            _ => false,
-        }
-    }
-}
-
-impl Default for Status {
-    fn default() -> Self {
-        Self::Closed
+        matches!(self, Self::Draining | Self::Closed)
     }
 }
 
@@ -169,7 +152,7 @@ mod tests {
     #[test]
     fn clone_works() {
         let status = Status::Open;
-        let cloned = status.clone();
+        let cloned = status;
         assert_eq!(status, cloned);
     }
 
@@ -117,6 +117,34 @@ mod tests {
         assert_eq!(rx.status(), Status::Closed);
     }
 
+    #[test]
+    fn recv_returns_closed_after_sender_drop() {
+        let (tx, mut rx): (super::TokioSender<i32>, super::TokioReceiver<i32>) = open!(10);
+
+        tx.send(1).unwrap();
+        drop(tx);
+
+        assert!(!rx.is_closed());
+        assert_eq!(rx.recv(), Ok(1));
+        assert_eq!(rx.recv(), Err(crate::chan::error::RecvError::Closed));
+        assert!(rx.is_closed());
+    }
+
+    #[test]
+    fn try_recv_distinguishes_empty_from_closed() {
+        use crate::chan::error::RecvError;
+
+        let (tx, mut rx): (super::TokioSender<i32>, super::TokioReceiver<i32>) = open!(10);
+
+        assert_eq!(rx.try_recv(), Err(RecvError::Empty));
+
+        tx.send(7).unwrap();
+        drop(tx);
+
+        assert_eq!(rx.try_recv(), Ok(7));
+        assert_eq!(rx.try_recv(), Err(RecvError::Closed));
+    }
+
     #[test]
     fn channel_status_closed_after_receiver_close() {
         let (tx, mut rx): (super::TokioSender<i32>, super::TokioReceiver<i32>) = open!(10);
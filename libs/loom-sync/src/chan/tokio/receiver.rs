@@ -45,39 +45,29 @@ impl<T: Send + 'static> Receiver for TokioReceiver<T> {
         }
 
         while !self.receiver.is_empty() {
-            let _ = self.receiver.recv();
+            let _ = self.receiver.try_recv();
         }
 
         self.receiver.close();
     }
 
     fn recv(&mut self) -> Result<Self::Item, RecvError> {
-        match self.receiver.block_recv() {
-            None => {
-                if self.status().is_closed() {
-                    Err(RecvError::Closed)
-                } else {
-                    Err(RecvError::Empty)
-                }
-            }
-            Some(v) => Ok(v),
-        }
+        // tokio only yields `None` once all senders are gone and the buffer
+        // is drained
+        self.receiver.block_recv().ok_or(RecvError::Closed)
+    }
+
+    fn try_recv(&mut self) -> Result<Self::Item, RecvError> {
+        self.receiver.try_recv().map_err(|e| match e {
+            mpsc::error::TryRecvError::Empty => RecvError::Empty,
+            mpsc::error::TryRecvError::Disconnected => RecvError::Closed,
+        })
     }
 
     fn recv_poll(&mut self, cx: &mut Context<'_>) -> Poll<Result<Self::Item, RecvError>> {
-        match self.receiver.poll_recv(cx) {
-            Poll::Pending => Poll::Pending,
-            Poll::Ready(v) => Poll::Ready(match v {
-                None => {
-                    if self.status().is_closed() {
-                        Err(RecvError::Closed)
-                    } else {
-                        Err(RecvError::Empty)
-                    }
-                }
-                Some(v) => Ok(v),
-            }),
-        }
+        self.receiver
+            .poll_recv(cx)
+            .map(|v| v.ok_or(RecvError::Closed))
     }
 }
 
@@ -107,17 +97,11 @@ impl<T> MpscReceiver<T> {
     }
 
     pub fn is_bound(&self) -> bool {
-        match self {
-            Self::Bound(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::Bound(_))
     }
 
     pub fn is_unbound(&self) -> bool {
-        match self {
-            Self::UnBound(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::UnBound(_))
     }
 
     pub fn is_empty(&self) -> bool {
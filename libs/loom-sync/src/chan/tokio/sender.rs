@@ -105,17 +105,11 @@ impl<T> MpscSender<T> {
     }
 
     pub fn is_bound(&self) -> bool {
-        match self {
-            Self::Bound(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::Bound(_))
     }
 
     pub fn is_unbound(&self) -> bool {
-        match self {
-            Self::UnBound(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::UnBound(_))
     }
 
     pub fn is_closed(&self) -> bool {
@@ -232,17 +226,11 @@ impl<T> std::fmt::Debug for MpscWeakSender<T> {
 
 impl<T> MpscWeakSender<T> {
     pub fn is_bound(&self) -> bool {
-        match self {
-            Self::Bound(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::Bound(_))
     }
 
     pub fn is_unbound(&self) -> bool {
-        match self {
-            Self::UnBound(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::UnBound(_))
     }
 
     pub fn weak_count(&self) -> usize {
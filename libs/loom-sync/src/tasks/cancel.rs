@@ -0,0 +1,122 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+///
+/// ## CancellationToken
+/// a cloneable flag for asking work to stop, e.g. when the client
+/// that requested it disconnects. Clones share the same state, so
+/// cancelling any of them cancels them all.
+///
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<Inner>);
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Cancel the token and wake every task awaiting `cancelled`.
+    /// Cancelling twice does nothing.
+    pub fn cancel(&self) {
+        if self.0.cancelled.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let wakers = std::mem::take(&mut *self.0.wakers.lock().unwrap_or_else(|e| e.into_inner()));
+
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+
+    /// A future that resolves once the token is cancelled, for racing
+    /// against async work. Works on any executor.
+    pub fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled { token: self }
+    }
+}
+
+///
+/// ## Cancelled
+/// the future returned by `CancellationToken::cancelled`
+///
+#[derive(Debug)]
+pub struct Cancelled<'a> {
+    token: &'a CancellationToken,
+}
+
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        let mut wakers = self
+            .token
+            .0
+            .wakers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+
+        // Check again under the lock so a `cancel` between the first
+        // check and registering the waker isn't missed
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        if !wakers.iter().any(|w| w.will_wake(cx.waker())) {
+            wakers.push(cx.waker().clone());
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_share_cancellation() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+
+        assert!(!clone.is_cancelled());
+        token.cancel();
+        token.cancel();
+        assert!(clone.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn cancelled_resolves_after_cancel() {
+        let token = CancellationToken::new();
+        let handle = {
+            let token = token.clone();
+            tokio::spawn(async move { token.cancelled().await })
+        };
+
+        tokio::task::yield_now().await;
+        assert!(!handle.is_finished());
+
+        token.cancel();
+        tokio::time::timeout(std::time::Duration::from_secs(5), handle)
+            .await
+            .expect("cancelled() did not resolve")
+            .unwrap();
+    }
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::chan::error::{RecvError, SendError};
 
 /// Errors that can occur during task execution or when awaiting a task
@@ -20,31 +22,65 @@ pub enum TaskError {
 
     /// Failed to send the task result
     Send(SendError),
+
+    /// Gave up waiting for the task after the given duration
+    Timeout(Duration),
+
+    /// Error from a task spawned with a name
+    Named { name: String, error: Box<TaskError> },
 }
 
 impl TaskError {
     pub fn is_cancelled(&self) -> bool {
-        matches!(self, Self::Cancelled)
+        matches!(self.inner(), Self::Cancelled)
     }
 
     pub fn is_panic(&self) -> bool {
-        matches!(self, Self::Panic(_))
+        matches!(self.inner(), Self::Panic(_))
     }
 
     pub fn is_custom(&self) -> bool {
-        matches!(self, Self::Custom(_))
+        matches!(self.inner(), Self::Custom(_))
     }
 
     pub fn is_dropped(&self) -> bool {
-        matches!(self, Self::Dropped)
+        matches!(self.inner(), Self::Dropped)
     }
 
     pub fn is_recv(&self) -> bool {
-        matches!(self, Self::Recv(_))
+        matches!(self.inner(), Self::Recv(_))
     }
 
     pub fn is_send(&self) -> bool {
-        matches!(self, Self::Send(_))
+        matches!(self.inner(), Self::Send(_))
+    }
+
+    pub fn is_timeout(&self) -> bool {
+        matches!(self.inner(), Self::Timeout(_))
+    }
+
+    /// Name of the task that failed, if it was spawned with one.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Named { name, .. } => Some(name),
+            _ => None,
+        }
+    }
+
+    /// The error without any task name attached.
+    pub fn inner(&self) -> &Self {
+        match self {
+            Self::Named { error, .. } => error.inner(),
+            err => err,
+        }
+    }
+
+    /// Attach the name of the failed task.
+    pub fn named<S: Into<String>>(name: S, error: Self) -> Self {
+        Self::Named {
+            name: name.into(),
+            error: Box::new(error),
+        }
     }
 
     /// Create a custom error from any error type
@@ -67,6 +103,8 @@ impl std::fmt::Display for TaskError {
             Self::Dropped => write!(f, "task handle dropped"),
             Self::Recv(e) => write!(f, "recv error: {}", e),
             Self::Send(e) => write!(f, "send error: {}", e),
+            Self::Timeout(d) => write!(f, "task timed out after {:?}", d),
+            Self::Named { name, error } => write!(f, "task '{}' failed: {}", name, error),
         }
     }
 }
@@ -76,6 +114,7 @@ impl std::error::Error for TaskError {
         match self {
             Self::Recv(e) => Some(e),
             Self::Send(e) => Some(e),
+            Self::Named { error, .. } => Some(error.as_ref()),
             _ => None,
         }
     }
@@ -128,7 +167,7 @@ mod tests {
 
     #[test]
     fn task_error_custom_from_error() {
-        let io_err = std::io::Error::new(std::io::ErrorKind::Other, "io error");
+        let io_err = std::io::Error::other("io error");
         let err = TaskError::custom(io_err);
         assert!(err.is_custom());
         assert!(err.to_string().contains("io error"));
@@ -188,6 +227,22 @@ mod tests {
         assert_eq!(format!("{}", err), "send error: full");
     }
 
+    #[test]
+    fn display_timeout() {
+        let err = TaskError::Timeout(Duration::from_millis(50));
+        assert!(err.is_timeout());
+        assert_eq!(format!("{}", err), "task timed out after 50ms");
+    }
+
+    #[test]
+    fn display_named() {
+        let err = TaskError::named("score", TaskError::Custom("bad input".to_string()));
+        assert!(err.is_custom());
+        assert_eq!(err.name(), Some("score"));
+        assert_eq!(format!("{}", err), "task 'score' failed: bad input");
+        assert!(err.source().is_some());
+    }
+
     // === Error Source ===
 
     #[test]
@@ -92,6 +92,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn clone_test() {
         let id1 = TaskId::new();
         let id2 = id1.clone();
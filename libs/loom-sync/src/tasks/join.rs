@@ -1,3 +1,6 @@
+#[cfg(feature = "tokio")]
+use crate::tasks::{Task, TaskError, TaskResult};
+
 /// Join multiple tasks concurrently (heterogeneous types).
 /// Re-exports futures::join! since Task<T> implements Future.
 ///
@@ -51,10 +54,39 @@ macro_rules! wait {
     }};
 }
 
+/// Await a dynamic number of tasks concurrently, returning each task's
+/// result in input order.
+///
+/// # Example
+/// ```ignore
+/// let tasks: Vec<Task<i32>> = samples.iter().map(|s| spawn!(...)).collect();
+/// let results = join_all(tasks).await;
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn join_all<T: Send + 'static>(tasks: Vec<Task<T>>) -> Vec<TaskResult<T>> {
+    futures::future::join_all(tasks).await
+}
+
+/// Like `join_all`, but resolves to the first error (a cancelled task
+/// counts as `TaskError::Cancelled`) without waiting for the rest, which
+/// are dropped. On success the values are in input order.
+#[cfg(feature = "tokio")]
+pub async fn try_join_all<T: Send + 'static>(tasks: Vec<Task<T>>) -> Result<Vec<T>, TaskError> {
+    use futures::FutureExt;
+
+    futures::future::try_join_all(
+        tasks
+            .into_iter()
+            .map(|task| task.map(TaskResult::into_result)),
+    )
+    .await
+}
+
 #[cfg(all(test, feature = "tokio"))]
 mod tests {
+    use super::{join_all, try_join_all};
     use crate::spawn;
-    use crate::tasks::{Task, TaskResult};
+    use crate::tasks::{Task, TaskError, TaskResult};
 
     // ==================== Async tests ====================
 
@@ -142,6 +174,72 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_join_all_empty() {
+        let tasks: Vec<Task<i32>> = vec![];
+
+        assert!(join_all(tasks).await.is_empty());
+        assert_eq!(try_join_all(Vec::<Task<i32>>::new()).await, Ok(vec![]));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_join_all_preserves_order() {
+        let tasks: Vec<Task<u64>> = (1..=4)
+            .map(|i| {
+                spawn!(move || {
+                    std::thread::sleep(std::time::Duration::from_millis(40 - i * 10));
+                    i
+                })
+            })
+            .collect();
+
+        let values: Vec<u64> = join_all(tasks)
+            .await
+            .into_iter()
+            .map(TaskResult::unwrap)
+            .collect();
+
+        assert_eq!(values, vec![1, 2, 3, 4]);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_try_join_all_ok() {
+        let tasks: Vec<Task<i32>> = (0..3).map(|i| spawn!(move || i * 2)).collect();
+
+        assert_eq!(try_join_all(tasks).await, Ok(vec![0, 2, 4]));
+    }
+
+    async fn mixed() -> Vec<Task<i32>> {
+        let (t1, r1): (Task<i32>, _) = spawn!();
+        let (t2, r2): (Task<i32>, _) = spawn!();
+        let (t3, r3): (Task<i32>, _) = spawn!();
+
+        r1.ok_async(1).await.unwrap();
+        r2.fail_async(TaskError::Custom("bad sample".to_string()))
+            .await
+            .unwrap();
+        r3.ok_async(3).await.unwrap();
+
+        vec![t1, t2, t3]
+    }
+
+    #[tokio::test]
+    async fn test_join_all_keeps_errors_in_place() {
+        let results = join_all(mixed().await).await;
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_error());
+        assert!(results[2].is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_try_join_all_returns_first_error() {
+        let err = try_join_all(mixed().await).await.unwrap_err();
+
+        assert_eq!(err, TaskError::Custom("bad sample".to_string()));
+    }
+
     // ==================== Sync tests ====================
 
     #[test]
@@ -291,8 +389,7 @@ mod tests {
     async fn test_spawn_blocking_closure_returning_result_err() {
         let task = spawn!(
             || {
-                let value: Result<i32, std::io::Error> =
-                    Err(std::io::Error::new(std::io::ErrorKind::Other, "test error"));
+                let value: Result<i32, std::io::Error> = Err(std::io::Error::other("test error"));
                 value
             },
             result
@@ -323,10 +420,7 @@ mod tests {
     async fn test_spawn_async_returning_result_err() {
         let task = spawn!(
             async {
-                let value: Result<i32, std::io::Error> = Err(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "async error",
-                ));
+                let value: Result<i32, std::io::Error> = Err(std::io::Error::other("async error"));
                 value
             },
             result
@@ -1,3 +1,4 @@
+mod cancel;
 mod error;
 mod id;
 mod join;
@@ -9,8 +10,11 @@ mod task;
 #[cfg(feature = "tokio")]
 pub mod tokio;
 
+pub use cancel::*;
 pub use error::*;
 pub use id::*;
+#[cfg(feature = "tokio")]
+pub use join::*;
 pub use resolver::*;
 pub use result::*;
 pub use status::*;
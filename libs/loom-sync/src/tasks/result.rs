@@ -8,23 +8,23 @@ pub enum TaskResult<T: Send + 'static> {
 
 impl<T: Send + 'static> TaskResult<T> {
     pub fn is_cancelled(&self) -> bool {
-        match self {
-            Self::Cancelled => true,
-            _ => false,
-        }
+        matches!(self, Self::Cancelled)
     }
 
     pub fn is_error(&self) -> bool {
-        match self {
-            Self::Error(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::Error(_))
     }
 
     pub fn is_ok(&self) -> bool {
+        matches!(self, Self::Ok(_))
+    }
+
+    /// Convert to a `Result`, treating cancellation as `TaskError::Cancelled`.
+    pub fn into_result(self) -> Result<T, TaskError> {
         match self {
-            Self::Ok(_) => true,
-            _ => false,
+            Self::Ok(value) => Ok(value),
+            Self::Cancelled => Err(TaskError::Cancelled),
+            Self::Error(err) => Err(err),
         }
     }
 
@@ -5,8 +5,9 @@ use std::sync::atomic::{AtomicU8, Ordering};
 /// represents the state of a Task
 ///
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
 pub enum TaskStatus {
+    #[default]
     Pending,
     Cancelled,
     Error,
@@ -15,38 +16,23 @@ pub enum TaskStatus {
 
 impl TaskStatus {
     pub fn is_pending(&self) -> bool {
-        match self {
-            Self::Pending => true,
-            _ => false,
-        }
+        matches!(self, Self::Pending)
     }
 
     pub fn is_cancelled(&self) -> bool {
-        match self {
-            Self::Cancelled => true,
-            _ => false,
-        }
+        matches!(self, Self::Cancelled)
     }
 
     pub fn is_error(&self) -> bool {
-        match self {
-            Self::Error => true,
-            _ => false,
-        }
+        matches!(self, Self::Error)
     }
 
     pub fn is_ok(&self) -> bool {
-        match self {
-            Self::Ok => true,
-            _ => false,
-        }
+        matches!(self, Self::Ok)
     }
 
     pub fn is_complete(&self) -> bool {
-        match self {
-            Self::Cancelled | Self::Error | Self::Ok => true,
-            _ => false,
-        }
+        matches!(self, Self::Cancelled | Self::Error | Self::Ok)
     }
 
     pub fn from_u8(value: u8) -> Self {
@@ -74,12 +60,6 @@ impl From<AtomicU8> for TaskStatus {
     }
 }
 
-impl Default for TaskStatus {
-    fn default() -> Self {
-        Self::Pending
-    }
-}
-
 impl std::fmt::Display for TaskStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -212,7 +192,7 @@ mod tests {
     #[test]
     fn clone_and_copy() {
         let status = TaskStatus::Ok;
-        let cloned = status.clone();
+        let cloned = status;
         let copied: TaskStatus = status;
 
         assert_eq!(status, cloned);
@@ -2,7 +2,7 @@ use std::task::Poll;
 
 use crate::{
     chan,
-    tasks::{TaskId, TaskResult, TaskStatus},
+    tasks::{TaskError, TaskId, TaskResult, TaskStatus},
 };
 
 ///
@@ -12,6 +12,7 @@ use crate::{
 ///
 pub struct Task<T: Send + 'static> {
     id: TaskId,
+    name: Option<String>,
     status: TaskStatus,
     receiver: Box<dyn chan::Receiver<Item = TaskResult<T>>>,
 }
@@ -20,6 +21,7 @@ impl<T: Send + 'static> Task<T> {
     pub fn new<R: chan::Receiver<Item = TaskResult<T>> + 'static>(receiver: R) -> Self {
         Self {
             id: TaskId::new(),
+            name: None,
             status: TaskStatus::Pending,
             receiver: Box::new(receiver),
         }
@@ -29,6 +31,17 @@ impl<T: Send + 'static> Task<T> {
         self.id
     }
 
+    /// Label the task for diagnostics. Errors from a named task carry the
+    /// name, so a failure among several joined tasks is easy to place.
+    pub fn named<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
     pub fn status(&self) -> TaskStatus {
         self.status
     }
@@ -38,7 +51,26 @@ impl<T: Send + 'static> Task<T> {
     }
 
     pub fn wait(&mut self) -> Result<TaskResult<T>, chan::error::RecvError> {
-        self.receiver.recv()
+        self.receiver.recv().map(|res| self.label(res))
+    }
+
+    fn label(&self, result: TaskResult<T>) -> TaskResult<T> {
+        match (result, &self.name) {
+            (TaskResult::Error(err), Some(name)) => {
+                TaskResult::Error(TaskError::named(name.clone(), err))
+            }
+            (result, _) => result,
+        }
+    }
+}
+
+impl<T: Send + 'static> std::fmt::Debug for Task<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Task")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("status", &self.status)
+            .finish()
     }
 }
 
@@ -75,11 +107,11 @@ impl<T: Send + 'static> Future for Task<T> {
                         self.status = TaskStatus::Ok;
                     }
 
-                    Poll::Ready(value)
+                    Poll::Ready(self.label(value))
                 }
                 Err(err) => {
                     self.status = TaskStatus::Error;
-                    Poll::Ready(TaskResult::Error(err.into()))
+                    Poll::Ready(self.label(TaskResult::Error(err.into())))
                 }
             },
         }
@@ -89,7 +121,7 @@ impl<T: Send + 'static> Future for Task<T> {
 impl<T: Send + 'static> Drop for Task<T> {
     fn drop(&mut self) {
         if !(self.receiver.status().is_closed()) {
-            let _ = self.receiver.close();
+            self.receiver.close();
         }
     }
 }
@@ -315,4 +347,32 @@ mod tests {
         let (task, _resolver): (Task<i32>, _) = spawn!();
         assert_eq!(task.channel().status(), Status::Open);
     }
+
+    #[test]
+    fn test_named_task_error_mentions_name() {
+        let (mut task, resolver): (Task<i32>, _) = spawn!(name = "score");
+        assert_eq!(task.name(), Some("score"));
+        assert!(format!("{:?}", task).contains("score"));
+
+        std::thread::spawn(move || {
+            resolver
+                .fail(TaskError::Custom("bad input".to_string()))
+                .unwrap();
+        });
+
+        let err = task.wait().unwrap().unwrap_err();
+        assert!(err.is_custom());
+        assert!(err.to_string().contains("score"), "{}", err);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_named_spawn_error_mentions_name() {
+        let ok = spawn!(name = "length", || 5);
+        let failed: Task<i32> = spawn!(name = "score", || Err(std::fmt::Error), result);
+
+        let (ok, failed) = crate::join!(ok, failed);
+
+        assert_eq!(ok.unwrap(), 5);
+        assert!(failed.unwrap_err().to_string().contains("'score'"));
+    }
 }
@@ -8,6 +8,7 @@
 /// - `spawn!(future)` - any future
 /// - `spawn!(|| expr, result)` - blocking closure returning Result
 /// - `spawn!(async { ... }, result)` - async block returning Result
+/// - `spawn!(name = "score", ...)` - any of the above, labelled for diagnostics
 ///
 /// # Examples
 /// ```ignore
@@ -15,6 +16,7 @@
 /// let task = spawn!(|| expensive_computation());
 /// let task = spawn!(async { fetch().await });
 /// let task = spawn!(async { try_fetch().await }, result);
+/// let task = spawn!(name = "score", || score(&sample));
 /// ```
 #[macro_export]
 macro_rules! spawn {
@@ -26,6 +28,17 @@ macro_rules! spawn {
         (task, handle)
     }};
 
+    // Named task/resolver pair: spawn!(name = "score")
+    (name = $name:expr) => {{
+        let (task, handle) = $crate::spawn!();
+        (task.named($name), handle)
+    }};
+
+    // Named task: spawn!(name = "score", || { ... }) and any other form
+    (name = $name:expr, $($rest:tt)+) => {{
+        $crate::spawn!($($rest)+).named($name)
+    }};
+
     // Blocking closure: spawn!(|| { ... })
     (|| $body:expr) => {{
         let (task, handle) = $crate::spawn!();